@@ -13,6 +13,20 @@ pub use weights::*;
 #[cfg(test)]
 mod mock;
 
+/// Default `Config` of the pallet, for `#[derive_impl]`
+pub mod config_preludes {
+	use crate::DefaultConfig;
+
+	/// Defaults of the access segregator: no extrinsic is registered for tests and quick-start runtimes
+	pub struct TestDefaultConfig;
+
+	#[frame_support::register_default_impl(TestDefaultConfig)]
+	impl DefaultConfig for TestDefaultConfig {
+		type Extrinsics = ();
+		type WeightInfo = ();
+	}
+}
+
 #[allow(unused_variables)]
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
@@ -38,14 +52,17 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	#[pallet::config]
+	#[pallet::config(with_default)]
 	pub trait Config: frame_system::Config {
+		#[pallet::no_default]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Origin used to administer the pallet
+		#[pallet::no_default]
 		type BridgeCommitteeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Current pallet index defined in runtime
+		#[pallet::no_default]
 		type PalletIndex: Get<u8>;
 
 		/// Registered extrinsics
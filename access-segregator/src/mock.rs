@@ -4,6 +4,7 @@
 #![cfg(test)]
 
 use frame_support::{
+	derive_impl,
 	pallet_prelude::ConstU32,
 	parameter_types,
 	sp_runtime::{
@@ -96,12 +97,12 @@ parameter_types! {
 	].to_vec();
 }
 
+#[derive_impl(sygma_access_segregator::config_preludes::TestDefaultConfig as sygma_access_segregator::DefaultConfig)]
 impl sygma_access_segregator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = PalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
-	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
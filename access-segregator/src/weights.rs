@@ -29,7 +29,7 @@
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
-use frame_support::{traits::Get, weights::Weight};
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions for `sygma_access_segregator`.
@@ -47,3 +47,18 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
+
+// For backwards compatibility and tests
+impl super::WeightInfo for () {
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:0 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn grant_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
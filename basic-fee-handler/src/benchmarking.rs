@@ -18,7 +18,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn set_fee() {
-		let dest_domain_id: DomainID = 1;
+		let dest_domain_id: DomainID = DomainID::new(1);
 		let native_location: MultiLocation = MultiLocation::here();
 		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
 
@@ -0,0 +1,83 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Accounts derived from the bridge `PalletId`.
+//!
+//! Every account the bridge holds funds in is derived here, so that a derivation is never
+//! duplicated with a slightly different seed:
+//!
+//! - `pallet_account`: `modl ++ pallet_id`, reserve account of the native asset and sender of the
+//!   transfers forwarded by the bridge
+//! - `token_reserve_account`: reserve account of the other tokens
+//! - `escrow_account`: holds inbound transfers that could not be credited to their beneficiary
+//! - `remote_sender_account`: acts on behalf of a sender on a remote domain
+//! - `fee_prefund_account`: holds the deposit fees prefunded by accounts
+//! - `ack_fee_pot_account`: pays the fees of the acknowledgments sent to origin domains
+//! - `fee_vesting_account`: holds the fees of a resource until they vest to its beneficiary
+//!
+//! Sub account seeds are hashed with blake2_256 because `into_sub_account_truncating` keeps only
+//! the first 20 bytes of the encoded seed, which would drop the domain or most of the resource id.
+//!
+//! Funds are held by these accounts: changing a derivation moves them to an account nobody
+//! controls. The derived addresses are pinned by tests for a fixed `PalletId`.
+
+use codec::{Decode, Encode};
+use frame_support::PalletId;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::AccountIdConversion;
+use sygma_traits::{DomainID, ResourceId};
+
+/// Account of the bridge pallet
+pub fn pallet_account<AccountId: Encode + Decode>(pallet_id: PalletId) -> AccountId {
+	pallet_id.into_account_truncating()
+}
+
+/// Reserve account of the tokens other than the native one, `modl ++ pallet_id ++ index`. Unlike
+/// the other sub accounts the seed is not hashed, it's the derivation deployed runtimes hold the
+/// reserves in.
+pub fn token_reserve_account<AccountId: Encode + Decode>(
+	pallet_id: PalletId,
+	index: u32,
+) -> AccountId {
+	pallet_id.into_sub_account_truncating(index)
+}
+
+/// Account escrowing the undeliverable inbound transfers of a resource
+pub fn escrow_account<AccountId: Encode + Decode>(
+	pallet_id: PalletId,
+	resource_id: &ResourceId,
+) -> AccountId {
+	sub_account(pallet_id, (b"escrow", resource_id))
+}
+
+/// Account acting on behalf of `sender` of the remote domain, `sender` is the address in the
+/// format of that domain
+pub fn remote_sender_account<AccountId: Encode + Decode>(
+	pallet_id: PalletId,
+	domain_id: DomainID,
+	sender: &[u8],
+) -> AccountId {
+	sub_account(pallet_id, (b"remote", domain_id, sender))
+}
+
+/// Account holding the deposit fees prefunded by all accounts
+pub fn fee_prefund_account<AccountId: Encode + Decode>(pallet_id: PalletId) -> AccountId {
+	sub_account(pallet_id, b"prefund")
+}
+
+/// Account paying the fees of the acknowledgments, funded by the committee
+pub fn ack_fee_pot_account<AccountId: Encode + Decode>(pallet_id: PalletId) -> AccountId {
+	sub_account(pallet_id, b"ackfee")
+}
+
+/// Account holding the fees of a resource vesting to its beneficiary
+pub fn fee_vesting_account<AccountId: Encode + Decode>(
+	pallet_id: PalletId,
+	resource_id: &ResourceId,
+) -> AccountId {
+	sub_account(pallet_id, (b"vesting", resource_id))
+}
+
+fn sub_account<AccountId: Encode + Decode>(pallet_id: PalletId, seed: impl Encode) -> AccountId {
+	pallet_id.into_sub_account_truncating(blake2_256(&seed.encode()))
+}
@@ -7,14 +7,15 @@
 use super::*;
 use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_support::{crypto::ecdsa::ECDSAExt, traits::Currency};
+use frame_support::{crypto::ecdsa::ECDSAExt, traits::Currency, BoundedVec};
 use frame_system::RawOrigin as SystemOrigin;
 use primitive_types::U256;
-use sp_runtime::AccountId32;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{AccountId32, MultiSignature, Permill};
 use sp_std::{borrow::Borrow, prelude::*};
 
 use sygma_fee_handler_router::FeeHandlerType;
-use sygma_traits::{ChainID, DomainID, MpcAddress, ResourceId};
+use sygma_traits::{ChainID, DepositNonce, DomainID, FeeConfig, MpcAddress, ResourceId};
 
 use crate::Pallet as SygmaBridge;
 use sygma_basic_feehandler::Pallet as BasicFeeHandler;
@@ -51,7 +52,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn pause_bridge() {
-		let dest_domain_id: DomainID = 0;
+		let dest_domain_id: DomainID = DomainID::new(0);
 		let dest_chain_id: ChainID = U256::from(1);
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
@@ -59,12 +60,12 @@ mod benchmarks {
 		#[extrinsic_call]
 		pause_bridge(SystemOrigin::Root, dest_domain_id);
 
-		assert!(IsPaused::<T>::get(dest_domain_id));
+		assert!(SygmaBridge::<T>::is_paused(dest_domain_id));
 	}
 
 	#[benchmark]
 	fn unpause_bridge() {
-		let dest_domain_id: DomainID = 0;
+		let dest_domain_id: DomainID = DomainID::new(0);
 		let dest_chain_id: ChainID = U256::from(1);
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
@@ -73,7 +74,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		unpause_bridge(SystemOrigin::Root, dest_domain_id);
 
-		assert!(!IsPaused::<T>::get(dest_domain_id));
+		assert!(!SygmaBridge::<T>::is_paused(dest_domain_id));
 	}
 
 	#[benchmark]
@@ -88,25 +89,28 @@ mod benchmarks {
 
 	#[benchmark]
 	fn register_domain() {
-		let dest_domain_id: DomainID = 0;
+		let dest_domain_id: DomainID = DomainID::new(0);
 		let dest_chain_id: ChainID = U256::from(1);
+		// registering an unregistered domain back pauses it as well
+		UnregisteredChainIds::<T>::insert(dest_domain_id, dest_chain_id);
 
 		#[extrinsic_call]
 		register_domain(SystemOrigin::Root, dest_domain_id, dest_chain_id);
 
 		assert!(DestDomainIds::<T>::get(&dest_domain_id));
+		assert!(SygmaBridge::<T>::is_paused(dest_domain_id));
 	}
 
 	#[benchmark]
 	fn unregister_domain() {
-		let dest_domain_id: DomainID = 0;
+		let dest_domain_id: DomainID = DomainID::new(0);
 		let dest_chain_id: ChainID = U256::from(1);
 
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
 
 		#[extrinsic_call]
-		unregister_domain(SystemOrigin::Root, dest_domain_id, dest_chain_id);
+		unregister_domain(SystemOrigin::Root, dest_domain_id, dest_chain_id, false);
 
 		assert!(!DestDomainIds::<T>::get(&dest_domain_id));
 	}
@@ -117,7 +121,7 @@ mod benchmarks {
 		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
 		let native_location: MultiLocation = MultiLocation::here();
 
-		let dest_domain_id: DomainID = 1;
+		let dest_domain_id: DomainID = DomainID::new(1);
 		let dest_chain_id: ChainID = U256::from(1);
 		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
 		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
@@ -147,6 +151,7 @@ mod benchmarks {
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
 		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
 
 		#[extrinsic_call]
 		deposit(
@@ -156,7 +161,7 @@ mod benchmarks {
 				parents: 0,
 				interior: X2(
 					slice_to_generalkey(b"ethereum recipient"),
-					slice_to_generalkey(&[dest_domain_id]),
+					slice_to_generalkey(&[dest_domain_id.into_inner()]),
 				),
 			}),
 		);
@@ -168,13 +173,15 @@ mod benchmarks {
 
 	#[benchmark]
 	fn retry() {
-		let dest_domain_id: DomainID = 1;
+		let dest_domain_id: DomainID = DomainID::new(1);
 		let dest_chain_id: ChainID = U256::from(1);
 		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
 
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
 		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
+		frame_system::Pallet::<T>::set_block_number(123u32.into());
 
 		#[extrinsic_call]
 		retry(SystemOrigin::Root, 123, dest_domain_id);
@@ -184,7 +191,7 @@ mod benchmarks {
 	fn execute_proposal(n: Linear<1, 1_000>) {
 		let caller = whitelisted_caller::<AccountId32>();
 		let amount = 200_000_000_000_000u128;
-		let dest_domain_id: DomainID = 1;
+		let dest_domain_id: DomainID = DomainID::new(1);
 		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
 		let native_resourceid: ResourceId =
 			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
@@ -214,6 +221,7 @@ mod benchmarks {
 			deposit_nonce: 1,
 			resource_id: native_resourceid,
 			data: SygmaBridge::<T>::create_deposit_data(
+				DataFormat::EvmAbi,
 				amount,
 				MultiLocation::new(
 					0,
@@ -245,42 +253,1316 @@ mod benchmarks {
 		assert_eq!(Balances::<T, _>::free_balance(bridge_account), (amount - 200000000).into());
 	}
 
+	#[benchmark]
+	fn verify_signature(b: Linear<100, 100_000>) {
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		let test_mpc_addr: MpcAddress = MpcAddress(pub_key.to_eth_address().unwrap());
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+
+		// Hashing the digest scales with the size of the batch, carried by the proposal data
+		let proposals = vec![Proposal {
+			origin_domain_id: DomainID::new(1),
+			deposit_nonce: 1,
+			resource_id: [1u8; 32],
+			data: vec![1u8; b as usize],
+		}];
+		let final_message = SygmaBridge::<T>::proposal_digest(&proposals);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &pub_key, &final_message)
+			.expect("Generates signature");
+
+		let verified;
+		#[block]
+		{
+			let digest = SygmaBridge::<T>::proposal_digest(&proposals);
+			verified = SygmaBridge::<T>::verify_by_mpc_address(digest, signature.encode());
+		}
+
+		assert!(verified);
+	}
+
+	#[benchmark]
+	fn per_proposal_fungible() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let amount = 200_000_000_000_000u128;
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount).into(),
+		);
+		SygmaBridge::<T>::register_domain(
+			SystemOrigin::Root.into(),
+			origin_domain_id,
+			U256::from(1),
+		)
+		.unwrap();
+
+		let proposal = Proposal {
+			origin_domain_id,
+			deposit_nonce: 1,
+			resource_id: native_resourceid,
+			data: SygmaBridge::<T>::create_deposit_data(
+				DataFormat::EvmAbi,
+				amount,
+				MultiLocation::new(
+					0,
+					X1(Junction::AccountId32 { network: None, id: caller.clone().into() }),
+				)
+				.encode(),
+			),
+		};
+
+		#[block]
+		{
+			SygmaBridge::<T>::execute_proposal_internal(&proposal).unwrap();
+			SygmaBridge::<T>::note_proposal_executed(&proposal);
+		}
+
+		assert!(SygmaBridge::<T>::is_proposal_executed(1, origin_domain_id));
+		assert_eq!(Balances::<T, _>::free_balance(caller), 200000000.into());
+	}
+
 	#[benchmark]
 	fn pause_all_bridges() {
-		let domain_size = 2;
+		let domain_size: u8 = 2;
 
 		for i in 1..domain_size + 1 {
-			SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), i, U256::from(i)).unwrap();
+			SygmaBridge::<T>::register_domain(
+				SystemOrigin::Root.into(),
+				DomainID::new(i),
+				U256::from(i),
+			)
+			.unwrap();
 		}
 
 		#[extrinsic_call]
 		pause_all_bridges(SystemOrigin::Root);
 
 		for i in 1..domain_size + 1 {
-			assert!(IsPaused::<T>::get(i));
+			assert!(SygmaBridge::<T>::is_paused(DomainID::new(i)));
 		}
 	}
 
 	#[benchmark]
 	fn unpause_all_bridges() {
-		let domain_size = 2;
+		let domain_size: u8 = 2;
 		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
 
 		for i in 1..domain_size + 1 {
-			SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), i, U256::from(i)).unwrap();
+			SygmaBridge::<T>::register_domain(
+				SystemOrigin::Root.into(),
+				DomainID::new(i),
+				U256::from(i),
+			)
+			.unwrap();
 		}
 
 		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
 
 		for i in 1..domain_size + 1 {
-			SygmaBridge::<T>::pause_bridge(SystemOrigin::Root.into(), i).unwrap();
+			SygmaBridge::<T>::pause_bridge(SystemOrigin::Root.into(), DomainID::new(i)).unwrap();
 		}
 
 		#[extrinsic_call]
 		unpause_all_bridges(SystemOrigin::Root);
 
 		for i in 1..domain_size + 1 {
-			assert!(!IsPaused::<T>::get(i));
+			assert!(!SygmaBridge::<T>::is_paused(DomainID::new(i)));
+		}
+	}
+
+	#[benchmark]
+	fn claim_escrowed_transfer() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let amount = 200_000_000_000_000u128;
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let escrow_account = SygmaBridge::<T>::escrow_account(&native_resourceid);
+		let beneficiary = MultiLocation::new(
+			0,
+			X1(Junction::AccountId32 { network: None, id: caller.clone().into() }),
+		);
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&escrow_account,
+			(amount * 2).into(),
+		);
+		UnclaimedTransfers::<T>::insert(
+			origin_domain_id,
+			1,
+			EscrowedTransfer {
+				resource_id: native_resourceid,
+				beneficiary,
+				asset_id: MultiLocation::here().into(),
+				amount,
+				escrowed_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		#[extrinsic_call]
+		claim_escrowed_transfer(SystemOrigin::Signed(caller.clone().into()), origin_domain_id, 1);
+
+		assert!(UnclaimedTransfers::<T>::get(origin_domain_id, 1).is_none());
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
+	}
+
+	#[benchmark]
+	fn sweep_escrowed_transfer() {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let amount = 200_000_000_000_000u128;
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let escrow_account = SygmaBridge::<T>::escrow_account(&native_resourceid);
+		let treasury_balance = Balances::<T, _>::free_balance(treasury_account.clone());
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&escrow_account,
+			(amount * 2).into(),
+		);
+		UnclaimedTransfers::<T>::insert(
+			origin_domain_id,
+			1,
+			EscrowedTransfer {
+				resource_id: native_resourceid,
+				beneficiary: MultiLocation::new(
+					0,
+					X1(Junction::AccountId32 { network: None, id: [1u8; 32] }),
+				),
+				asset_id: MultiLocation::here().into(),
+				amount,
+				escrowed_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::EscrowRetentionPeriod::get(),
+		);
+
+		#[extrinsic_call]
+		sweep_escrowed_transfer(SystemOrigin::Root, origin_domain_id, 1);
+
+		assert!(UnclaimedTransfers::<T>::get(origin_domain_id, 1).is_none());
+		assert_eq!(
+			Balances::<T, _>::free_balance(treasury_account),
+			treasury_balance + amount.into()
+		);
+	}
+
+	#[benchmark]
+	fn reserve_nonce() {
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let caller = whitelisted_caller::<AccountId32>();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
+
+		#[extrinsic_call]
+		reserve_nonce(SystemOrigin::Signed(caller.into()), dest_domain_id);
+
+		assert!(NonceReservations::<T>::get(dest_domain_id, 0).is_some());
+		assert_eq!(DepositCounts::<T>::get(dest_domain_id), 1);
+	}
+
+	#[benchmark]
+	fn commit_deposit() {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_location: MultiLocation = MultiLocation::here();
+
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let dest_chain_id: ChainID = U256::from(1);
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let caller = whitelisted_caller::<AccountId32>();
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * 2).into(),
+		);
+
+		BasicFeeHandler::<T>::set_fee(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			fee,
+		)
+		.unwrap();
+		FeeHandlerRouter::<T>::set_fee_handler(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			FeeHandlerType::BasicFeeHandler,
+		)
+		.unwrap();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
+		SygmaBridge::<T>::reserve_nonce(
+			SystemOrigin::Signed(caller.clone().into()).into(),
+			dest_domain_id,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		commit_deposit(
+			SystemOrigin::Signed(caller.clone().into()),
+			0,
+			Box::new((Concrete(native_location), Fungible(amount)).into()),
+			Box::new(MultiLocation {
+				parents: 0,
+				interior: X2(
+					slice_to_generalkey(b"ethereum recipient"),
+					slice_to_generalkey(&[dest_domain_id.into_inner()]),
+				),
+			}),
+		);
+
+		assert!(NonceReservations::<T>::get(dest_domain_id, 0).is_none());
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
+		assert_eq!(Balances::<T, _>::free_balance(bridge_account), (amount - fee).into());
+		assert_eq!(Balances::<T, _>::free_balance(treasury_account), fee.into());
+	}
+
+	#[benchmark]
+	fn reclaim_nonce_reservation() {
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let caller = whitelisted_caller::<AccountId32>();
+
+		NonceReservations::<T>::insert(
+			dest_domain_id,
+			0,
+			(T::AccountId::from(caller.clone()), frame_system::Pallet::<T>::block_number()),
+		);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + 1u32.into(),
+		);
+
+		#[extrinsic_call]
+		reclaim_nonce_reservation(SystemOrigin::Signed(caller.into()), dest_domain_id, 0);
+
+		assert!(NonceReservations::<T>::get(dest_domain_id, 0).is_none());
+	}
+
+	#[benchmark]
+	fn add_relayer() {
+		let relayer = whitelisted_caller::<AccountId32>();
+
+		#[extrinsic_call]
+		add_relayer(SystemOrigin::Root, relayer.clone().into());
+
+		assert!(Relayers::<T>::get(T::AccountId::from(relayer)));
+	}
+
+	#[benchmark]
+	fn remove_relayer() {
+		let relayer = whitelisted_caller::<AccountId32>();
+		Relayers::<T>::insert(T::AccountId::from(relayer.clone()), true);
+
+		#[extrinsic_call]
+		remove_relayer(SystemOrigin::Root, relayer.clone().into());
+
+		assert!(!Relayers::<T>::get(T::AccountId::from(relayer)));
+	}
+
+	#[benchmark]
+	fn set_mpc_signers(s: Linear<1, 16>) {
+		let signers: Vec<MpcAddress> = (0..s).map(|i| MpcAddress([i as u8; 20])).collect();
+
+		#[extrinsic_call]
+		set_mpc_signers(SystemOrigin::Root, signers, s);
+
+		assert_eq!(MpcThreshold::<T>::get(), s);
+	}
+
+	#[benchmark]
+	fn verify_mpc_threshold(s: Linear<1, 16>) {
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_keys: Vec<_> =
+			(0..s).map(|_| sp_io::crypto::ecdsa_generate(key_type, None)).collect();
+		let signers: Vec<MpcAddress> =
+			pub_keys.iter().map(|k| MpcAddress(k.to_eth_address().unwrap())).collect();
+		SygmaBridge::<T>::set_mpc_signers(SystemOrigin::Root.into(), signers, s).unwrap();
+
+		let final_message = [1u8; 32];
+		let signatures: Vec<u8> = pub_keys
+			.iter()
+			.flat_map(|k| {
+				sp_io::crypto::ecdsa_sign_prehashed(key_type, k, &final_message)
+					.expect("Generates signature")
+					.0
+			})
+			.collect();
+
+		let verified;
+		#[block]
+		{
+			verified = SygmaBridge::<T>::verify_by_mpc_threshold(final_message, signatures);
+		}
+
+		assert!(verified);
+	}
+
+	#[benchmark]
+	fn prune_deposit_records(n: Linear<1, 1_000>) {
+		let caller = whitelisted_caller::<AccountId32>();
+		let dest_domain_id: DomainID = DomainID::new(1);
+
+		for deposit_nonce in 0..n as u64 {
+			DepositRecords::<T>::insert(
+				(dest_domain_id, deposit_nonce),
+				DepositRecord {
+					sender: T::AccountId::from(caller.clone()),
+					asset_id: MultiLocation::here().into(),
+					amount: 1,
+					recorded_at: frame_system::Pallet::<T>::block_number(),
+					refund_requested: false,
+					cancelled: false,
+				},
+			);
+			DepositRecordQueue::<T>::insert(deposit_nonce, (dest_domain_id, deposit_nonce));
 		}
+		DepositRecordQueueTail::<T>::put(n as u64);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::DepositRecordRetention::get(),
+		);
+
+		#[extrinsic_call]
+		prune_deposit_records(SystemOrigin::Signed(caller.into()), n);
+
+		assert_eq!(DepositRecords::<T>::count(), 0);
+		assert_eq!(DepositRecordQueueHead::<T>::get(), n as u64);
+	}
+
+	#[benchmark]
+	fn request_refund() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let dest_domain_id: DomainID = DomainID::new(1);
+
+		DepositRecords::<T>::insert(
+			(dest_domain_id, 0),
+			DepositRecord {
+				sender: T::AccountId::from(caller.clone()),
+				asset_id: MultiLocation::here().into(),
+				amount: 1,
+				recorded_at: frame_system::Pallet::<T>::block_number(),
+				refund_requested: false,
+				cancelled: false,
+			},
+		);
+
+		#[extrinsic_call]
+		request_refund(SystemOrigin::Signed(caller.into()), dest_domain_id, 0);
+
+		assert!(DepositRecords::<T>::get((dest_domain_id, 0)).unwrap().refund_requested);
+	}
+
+	#[benchmark]
+	fn refund_deposit() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let amount = 200_000_000_000_000u128;
+		let dest_domain_id: DomainID = DomainID::new(1);
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount * 2).into(),
+		);
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			amount.into(),
+		);
+		DepositRecords::<T>::insert(
+			(dest_domain_id, 0),
+			DepositRecord {
+				sender: T::AccountId::from(caller.clone()),
+				asset_id: MultiLocation::here().into(),
+				amount,
+				recorded_at: frame_system::Pallet::<T>::block_number(),
+				refund_requested: true,
+				cancelled: false,
+			},
+		);
+
+		#[extrinsic_call]
+		refund_deposit(SystemOrigin::Root, dest_domain_id, 0);
+
+		assert!(DepositRecords::<T>::get((dest_domain_id, 0)).is_none());
+		assert_eq!(Balances::<T, _>::free_balance(caller), (amount * 2).into());
+	}
+
+	#[benchmark]
+	fn deposit_with_route() {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_location: MultiLocation = MultiLocation::here();
+
+		let via_domain_id: DomainID = DomainID::new(1);
+		let final_domain_id: DomainID = DomainID::new(2);
+		let dest_chain_id: ChainID = U256::from(1);
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let caller = whitelisted_caller::<AccountId32>();
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * 2).into(),
+		);
+
+		BasicFeeHandler::<T>::set_fee(
+			SystemOrigin::Root.into(),
+			via_domain_id,
+			Box::new(native_location.clone().into()),
+			fee,
+		)
+		.unwrap();
+		FeeHandlerRouter::<T>::set_fee_handler(
+			SystemOrigin::Root.into(),
+			via_domain_id,
+			Box::new(native_location.clone().into()),
+			FeeHandlerType::BasicFeeHandler,
+		)
+		.unwrap();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), via_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), via_domain_id).unwrap();
+
+		#[extrinsic_call]
+		deposit_with_route(
+			SystemOrigin::Signed(caller.clone().into()),
+			Box::new((Concrete(native_location), Fungible(amount)).into()),
+			Box::new(MultiLocation {
+				parents: 0,
+				interior: X2(
+					slice_to_generalkey(b"ethereum recipient"),
+					slice_to_generalkey(&[final_domain_id.into_inner()]),
+				),
+			}),
+			via_domain_id,
+		);
+
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
+		assert_eq!(Balances::<T, _>::free_balance(bridge_account), (amount - fee).into());
+		assert_eq!(Balances::<T, _>::free_balance(treasury_account), fee.into());
+	}
+
+	#[benchmark]
+	fn set_health_oracle() {
+		let oracle = whitelisted_caller::<AccountId32>();
+
+		#[extrinsic_call]
+		set_health_oracle(SystemOrigin::Root, Some(oracle.clone().into()));
+
+		assert_eq!(HealthOracle::<T>::get(), Some(T::AccountId::from(oracle)));
+	}
+
+	#[benchmark]
+	fn report_domain_health() {
+		let oracle = whitelisted_caller::<AccountId32>();
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let dest_chain_id: ChainID = U256::from(1);
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::set_health_oracle(SystemOrigin::Root.into(), Some(oracle.clone().into()))
+			.unwrap();
+
+		#[extrinsic_call]
+		report_domain_health(SystemOrigin::Signed(oracle.into()), dest_domain_id, false);
+
+		assert_eq!(DomainHealth::<T>::get(dest_domain_id), Some(false));
+	}
+
+	#[benchmark]
+	fn set_inbound_limit() {
+		let resource_id: ResourceId = [1u8; 32];
+		let limit = InboundLimit { max_single: 1_000, max_per_window: 10_000 };
+
+		#[extrinsic_call]
+		set_inbound_limit(SystemOrigin::Root, resource_id, Some(limit));
+
+		assert_eq!(InboundLimits::<T>::get(resource_id), Some(limit));
+	}
+
+	#[benchmark]
+	fn clear_inbound_window() {
+		let resource_id: ResourceId = [1u8; 32];
+		InboundWindows::<T>::insert(
+			resource_id,
+			InboundWindow {
+				started_at: frame_system::Pallet::<T>::block_number(),
+				amount: 10_000,
+				exhausted: true,
+			},
+		);
+
+		#[extrinsic_call]
+		clear_inbound_window(SystemOrigin::Root, resource_id);
+
+		assert!(InboundWindows::<T>::get(resource_id).is_none());
+	}
+
+	#[benchmark]
+	fn set_fee_discount() {
+		let account = whitelisted_caller::<AccountId32>();
+
+		#[extrinsic_call]
+		set_fee_discount(SystemOrigin::Root, account.clone().into(), Some(Permill::one()));
+
+		assert_eq!(FeeDiscounts::<T>::get(T::AccountId::from(account)), Some(Permill::one()));
+	}
+
+	#[benchmark]
+	fn pause_bridge_with_reason() {
+		let dest_domain_id: DomainID = DomainID::new(0);
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		let reason = PauseReason::CircuitBreaker { resource: Some([1u8; 32]) };
+		let note: PauseNote = vec![1u8; MAX_PAUSE_NOTE_LEN as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		pause_bridge_with_reason(SystemOrigin::Root, dest_domain_id, reason, Some(note));
+
+		assert!(SygmaBridge::<T>::is_paused(dest_domain_id));
+		assert_eq!(PauseReasons::<T>::get(dest_domain_id).map(|info| info.reason), Some(reason));
+	}
+
+	#[benchmark]
+	fn prefund_fees() {
+		let native_location: MultiLocation = MultiLocation::here();
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let caller = whitelisted_caller::<AccountId32>();
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * 2).into(),
+		);
+
+		#[extrinsic_call]
+		prefund_fees(
+			SystemOrigin::Signed(caller.clone().into()),
+			Box::new(native_location.into()),
+			amount,
+		);
+
+		assert_eq!(
+			FeePrefunds::<T>::get(T::AccountId::from(caller), AssetId::from(native_location)),
+			amount
+		);
+	}
+
+	#[benchmark]
+	fn withdraw_prefund() {
+		let native_location: MultiLocation = MultiLocation::here();
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let caller = whitelisted_caller::<AccountId32>();
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * 2).into(),
+		);
+		SygmaBridge::<T>::prefund_fees(
+			SystemOrigin::Signed(caller.clone().into()).into(),
+			Box::new(native_location.into()),
+			amount,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		withdraw_prefund(
+			SystemOrigin::Signed(caller.clone().into()),
+			Box::new(native_location.into()),
+			amount,
+		);
+
+		assert_eq!(
+			FeePrefunds::<T>::get(T::AccountId::from(caller), AssetId::from(native_location)),
+			0
+		);
+	}
+
+	#[benchmark]
+	fn rebind_resource() {
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let new_asset: AssetId =
+			MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"rebound"))).into();
+
+		#[extrinsic_call]
+		rebind_resource(SystemOrigin::Root, native_resourceid, Box::new(new_asset), 10u32.into());
+
+		assert_eq!(
+			ResourceRebinds::<T>::get(native_resourceid).map(|rebind| rebind.new_asset),
+			Some(new_asset)
+		);
+	}
+
+	#[benchmark]
+	fn set_data_format() {
+		let dest_domain_id: DomainID = DomainID::new(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+
+		#[extrinsic_call]
+		set_data_format(SystemOrigin::Root, dest_domain_id, DataFormat::Scale);
+
+		assert_eq!(DataFormats::<T>::get(dest_domain_id), Some(DataFormat::Scale));
+	}
+
+	#[benchmark]
+	fn set_auto_retry() {
+		let resource_id: ResourceId = [1u8; 32];
+
+		#[extrinsic_call]
+		set_auto_retry(SystemOrigin::Root, resource_id, true);
+
+		assert!(AutoRetryEnabled::<T>::get(resource_id));
+	}
+
+	#[benchmark]
+	fn set_global_tvl_cap() {
+		let cap = 1_000_000_000_000_000_000_000u128;
+
+		#[extrinsic_call]
+		set_global_tvl_cap(SystemOrigin::Root, Some(cap));
+
+		assert_eq!(GlobalTvlCap::<T>::get(), Some(cap));
+	}
+
+	#[benchmark]
+	fn onboard_resource(n: Linear<0, 100>) {
+		let resource_id: ResourceId = [9u8; 32];
+		let asset: AssetId =
+			MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"onboarded"))).into();
+		let mut fees = vec![];
+		for i in 0..n {
+			let dest_domain_id = DomainID::new(i as u8);
+			SygmaBridge::<T>::register_domain(
+				SystemOrigin::Root.into(),
+				dest_domain_id,
+				U256::from(i),
+			)
+			.unwrap();
+			fees.push((dest_domain_id, FeeConfig::Basic(1_000_000_000_000)));
+		}
+		let spec = OnboardingSpec {
+			asset,
+			resource_id,
+			fees,
+			inbound_limit: Some(InboundLimit { max_single: 1_000, max_per_window: 10_000 }),
+			mintable: false,
+			decimals: 18,
+		};
+
+		#[extrinsic_call]
+		onboard_resource(SystemOrigin::Root, Box::new(spec));
+
+		assert_eq!(
+			OnboardedResources::<T>::get(resource_id).map(|resource| resource.asset),
+			Some(asset)
+		);
+	}
+
+	#[benchmark]
+	fn offboard_resource() {
+		let resource_id: ResourceId = [9u8; 32];
+		let asset: AssetId =
+			MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"onboarded"))).into();
+		SygmaBridge::<T>::onboard_resource(
+			SystemOrigin::Root.into(),
+			Box::new(OnboardingSpec {
+				asset,
+				resource_id,
+				fees: vec![],
+				inbound_limit: Some(InboundLimit { max_single: 1_000, max_per_window: 10_000 }),
+				mintable: false,
+				decimals: 18,
+			}),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		offboard_resource(SystemOrigin::Root, resource_id, true);
+
+		assert!(OnboardedResources::<T>::get(resource_id).is_none());
+	}
+
+	#[benchmark]
+	fn set_min_reserve_balance() {
+		let resource_id: ResourceId = [1u8; 32];
+
+		#[extrinsic_call]
+		set_min_reserve_balance(SystemOrigin::Root, resource_id, Some(1_000_000_000_000));
+
+		assert_eq!(MinReserveBalance::<T>::get(resource_id), Some(1_000_000_000_000));
+	}
+
+	#[benchmark]
+	fn prune_deposit_data(n: Linear<1, 1_000>) {
+		let caller = whitelisted_caller::<AccountId32>();
+		let dest_domain_id: DomainID = DomainID::new(1);
+
+		let data: BoundedVec<u8, T::MaxDepositDataSize> =
+			vec![1u8; T::MaxDepositDataSize::get() as usize].try_into().unwrap();
+		for deposit_nonce in 0..n as u64 {
+			DepositDataOf::<T>::insert(
+				dest_domain_id,
+				deposit_nonce,
+				StoredDepositData {
+					stored_at: frame_system::Pallet::<T>::block_number(),
+					data: data.clone(),
+				},
+			);
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::DepositDataRetention::get(),
+		);
+		let keys: Vec<(DomainID, DepositNonce)> =
+			(0..n as u64).map(|deposit_nonce| (dest_domain_id, deposit_nonce)).collect();
+
+		#[extrinsic_call]
+		prune_deposit_data(SystemOrigin::Signed(caller.into()), keys);
+
+		assert!(DepositDataOf::<T>::iter_prefix(dest_domain_id).next().is_none());
+	}
+
+	#[benchmark]
+	fn set_token_bucket() {
+		let resource_id: ResourceId = [1u8; 32];
+		let config = TokenBucketConfig { capacity: 1_000_000, refill_per_block: 1_000 };
+
+		#[extrinsic_call]
+		set_token_bucket(SystemOrigin::Root, resource_id, Some(config));
+
+		assert_eq!(TokenBucketConfigs::<T>::get(resource_id), Some(config));
+	}
+
+	#[benchmark]
+	fn set_fee_reserve_threshold() {
+		let native_location: MultiLocation = MultiLocation::here();
+		let threshold = 1_000_000_000_000u128;
+
+		#[extrinsic_call]
+		set_fee_reserve_threshold(
+			SystemOrigin::Root,
+			Box::new(native_location.into()),
+			Some(threshold),
+		);
+
+		assert_eq!(FeeReserveThresholds::<T>::get(AssetId::from(native_location)), Some(threshold));
+	}
+
+	#[benchmark]
+	fn set_ack_enabled() {
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		set_ack_enabled(SystemOrigin::Root, dest_domain_id, true);
+
+		assert!(AckEnabled::<T>::get(dest_domain_id));
+	}
+
+	#[benchmark]
+	fn set_acks_halted() {
+		#[extrinsic_call]
+		set_acks_halted(SystemOrigin::Root, true);
+
+		assert!(AcksHalted::<T>::get());
+	}
+
+	#[benchmark]
+	fn resolve_binding_conflict() {
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let registered_asset: AssetId =
+			MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"registered"))).into();
+		// runtime upgrade binding a registered resource
+		OnboardedResources::<T>::insert(
+			native_resourceid,
+			OnboardedResource { asset: registered_asset, decimals: 18, mintable: false },
+		);
+
+		#[extrinsic_call]
+		resolve_binding_conflict(SystemOrigin::Root, native_resourceid, BindingSource::Constant);
+
+		assert!(OnboardedResources::<T>::get(native_resourceid).is_none());
+	}
+
+	#[benchmark]
+	fn prune_execution_receipts(n: Linear<1, 1_000>) {
+		let caller = whitelisted_caller::<AccountId32>();
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let recipient: MultiLocation =
+			Junction::AccountId32 { network: None, id: [1u8; 32] }.into();
+
+		for deposit_nonce in 0..n as u64 {
+			ExecutionReceipts::<T>::insert(
+				origin_domain_id,
+				deposit_nonce,
+				ExecutionReceipt {
+					recipient,
+					asset: MultiLocation::here().into(),
+					amount: 1_000_000_000_000,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::ExecutionReceiptRetention::get(),
+		);
+		let keys: Vec<(DomainID, DepositNonce)> =
+			(0..n as u64).map(|deposit_nonce| (origin_domain_id, deposit_nonce)).collect();
+
+		#[extrinsic_call]
+		prune_execution_receipts(SystemOrigin::Signed(caller.into()), keys);
+
+		assert!(ExecutionReceipts::<T>::iter_prefix(origin_domain_id).next().is_none());
+	}
+
+	#[benchmark]
+	fn add_denied_recipient() {
+		let recipient = vec![1u8; MAX_RECIPIENT_LEN as usize];
+
+		#[extrinsic_call]
+		add_denied_recipient(SystemOrigin::Root, recipient.clone());
+
+		assert!(SygmaBridge::<T>::is_recipient_denied(&recipient));
+	}
+
+	#[benchmark]
+	fn remove_denied_recipient() {
+		let recipient = vec![1u8; MAX_RECIPIENT_LEN as usize];
+		RecipientDenylist::<T>::insert(DeniedRecipient::truncate_from(recipient.clone()), ());
+
+		#[extrinsic_call]
+		remove_denied_recipient(SystemOrigin::Root, recipient.clone());
+
+		assert!(!SygmaBridge::<T>::is_recipient_denied(&recipient));
+	}
+
+	#[benchmark]
+	fn deposit_with_permit() {
+		let native_location: MultiLocation = MultiLocation::here();
+		let dest_domain_id: DomainID = DomainID::new(1);
+		let dest_chain_id: ChainID = U256::from(1);
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let caller = whitelisted_caller::<AccountId32>();
+		let public = sp_io::crypto::sr25519_generate(KeyTypeId(*b"sygm"), None);
+		let owner = AccountId32::from(public);
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&owner.clone().into(),
+			(amount * 2).into(),
+		);
+
+		BasicFeeHandler::<T>::set_fee(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.into()),
+			fee,
+		)
+		.unwrap();
+		FeeHandlerRouter::<T>::set_fee_handler(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.into()),
+			FeeHandlerType::BasicFeeHandler,
+		)
+		.unwrap();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), MpcAddress([1u8; 20]))
+			.unwrap();
+		SygmaBridge::<T>::unpause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
+
+		let permit = DepositPermit {
+			owner: owner.clone().into(),
+			asset: (Concrete(native_location), Fungible(amount)).into(),
+			dest: MultiLocation {
+				parents: 0,
+				interior: X2(
+					slice_to_generalkey(b"ethereum recipient"),
+					slice_to_generalkey(&[dest_domain_id.into_inner()]),
+				),
+			},
+			nonce: 0,
+			deadline: frame_system::Pallet::<T>::block_number(),
+		};
+		let signature = MultiSignature::Sr25519(
+			sp_io::crypto::sr25519_sign(
+				KeyTypeId(*b"sygm"),
+				&public,
+				&SygmaBridge::<T>::permit_payload(&permit),
+			)
+			.unwrap(),
+		);
+
+		#[extrinsic_call]
+		deposit_with_permit(SystemOrigin::Signed(caller.into()), Box::new(permit), signature);
+
+		assert_eq!(Balances::<T, _>::free_balance(owner.clone()), amount.into());
+		assert_eq!(PermitNonces::<T>::get(T::AccountId::from(owner)), 1);
+	}
+
+	#[benchmark]
+	fn pause_route() {
+		let dest_domain_id: DomainID = DomainID::new(0);
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		pause_route(SystemOrigin::Root, RouteDirection::Outbound, dest_domain_id);
+
+		assert!(SygmaBridge::<T>::is_route_paused(RouteDirection::Outbound, dest_domain_id));
+	}
+
+	#[benchmark]
+	fn unpause_route() {
+		let dest_domain_id: DomainID = DomainID::new(0);
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::pause_bridge(SystemOrigin::Root.into(), dest_domain_id).unwrap();
+
+		#[extrinsic_call]
+		unpause_route(SystemOrigin::Root, RouteDirection::Outbound, dest_domain_id);
+
+		assert!(!SygmaBridge::<T>::is_route_paused(RouteDirection::Outbound, dest_domain_id));
+		assert!(SygmaBridge::<T>::is_route_paused(RouteDirection::Inbound, dest_domain_id));
+	}
+
+	#[benchmark]
+	fn schedule_domain_sunset() {
+		let dest_domain_id: DomainID = DomainID::new(0);
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		let deposit_cutoff_block = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		schedule_domain_sunset(SystemOrigin::Root, dest_domain_id, deposit_cutoff_block);
+
+		assert_eq!(DomainSunsets::<T>::get(dest_domain_id), Some(deposit_cutoff_block));
+	}
+
+	#[benchmark]
+	fn cancel_deposit() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let amount = 200_000_000_000_000u128;
+		let dest_domain_id: DomainID = DomainID::new(1);
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount * 2).into(),
+		);
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			amount.into(),
+		);
+		DepositRecords::<T>::insert(
+			(dest_domain_id, 0),
+			DepositRecord {
+				sender: T::AccountId::from(caller.clone()),
+				asset_id: MultiLocation::here().into(),
+				amount,
+				recorded_at: frame_system::Pallet::<T>::block_number(),
+				refund_requested: true,
+				cancelled: false,
+			},
+		);
+
+		#[extrinsic_call]
+		cancel_deposit(SystemOrigin::Signed(caller.clone().into()), dest_domain_id, 0);
+
+		assert!(DepositRecords::<T>::get((dest_domain_id, 0)).unwrap().cancelled);
+		assert!(CancelledDeposits::<T>::contains_key(dest_domain_id, 0));
+		assert_eq!(Balances::<T, _>::free_balance(caller), (amount * 2).into());
+	}
+
+	#[benchmark]
+	fn emergency_withdraw() {
+		let native_location: MultiLocation = MultiLocation::here();
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let to = T::AccountId::from(whitelisted_caller::<AccountId32>());
+		let from_account = SygmaBridge::<T>::bridge_account(&BridgeAccount::Pallet);
+		SygmaBridge::<T>::register_domain(
+			SystemOrigin::Root.into(),
+			DomainID::new(1),
+			U256::from(1),
+		)
+		.unwrap();
+		SygmaBridge::<T>::pause_all_bridges(SystemOrigin::Root.into()).unwrap();
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&from_account,
+			(amount * 2).into(),
+		);
+		SygmaBridge::<T>::emergency_withdraw(
+			SystemOrigin::Root.into(),
+			BridgeAccount::Pallet,
+			Box::new(native_location.into()),
+			amount,
+			to.clone(),
+		)
+		.unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::EmergencyWithdrawalDelay::get(),
+		);
+
+		#[extrinsic_call]
+		emergency_withdraw(
+			SystemOrigin::Root,
+			BridgeAccount::Pallet,
+			Box::new(native_location.into()),
+			amount,
+			to.clone(),
+		);
+
+		assert_eq!(Balances::<T, _>::free_balance(from_account), amount.into());
+		assert_eq!(Balances::<T, _>::free_balance(to), amount.into());
+	}
+
+	#[benchmark]
+	fn set_resource_decimals() {
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let dest_domain_id: DomainID = DomainID::new(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+
+		#[extrinsic_call]
+		set_resource_decimals(SystemOrigin::Root, native_resourceid, Some(dest_domain_id), Some(6));
+
+		assert_eq!(SygmaBridge::<T>::resource_decimals(&native_resourceid, dest_domain_id), 6);
+	}
+
+	#[benchmark]
+	fn set_settlement_delay() {
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let delay = frame_system::pallet_prelude::BlockNumberFor::<T>::from(10u32);
+
+		#[extrinsic_call]
+		set_settlement_delay(SystemOrigin::Root, native_resourceid, Some(delay));
+
+		assert_eq!(RequiredSettlementDelay::<T>::get(native_resourceid), Some(delay));
+	}
+
+	#[benchmark]
+	fn finalize_proposal() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let amount = 200_000_000_000_000u128;
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount).into(),
+		);
+		SygmaBridge::<T>::register_domain(
+			SystemOrigin::Root.into(),
+			origin_domain_id,
+			U256::from(1),
+		)
+		.unwrap();
+		let data = SygmaBridge::<T>::create_deposit_data(
+			DataFormat::EvmAbi,
+			amount,
+			MultiLocation::new(
+				0,
+				X1(Junction::AccountId32 { network: None, id: caller.clone().into() }),
+			)
+			.encode(),
+		);
+		PendingSettlements::<T>::insert(
+			origin_domain_id,
+			1,
+			PendingSettlement {
+				resource_id: native_resourceid,
+				data: BoundedVec::truncate_from(data),
+				release_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		#[extrinsic_call]
+		finalize_proposal(SystemOrigin::Signed(caller.into()), origin_domain_id, 1);
+
+		assert!(SygmaBridge::<T>::is_proposal_executed(1, origin_domain_id));
+		assert!(!PendingSettlements::<T>::contains_key(origin_domain_id, 1));
+	}
+
+	#[benchmark]
+	fn emergency_freeze(r: Linear<0, 100>, d: Linear<0, 256>) {
+		for i in 0..r {
+			OnboardedResources::<T>::insert(
+				[i as u8 + 100; 32],
+				OnboardedResource {
+					asset: MultiLocation::new(1, X2(Parachain(3000), GeneralIndex(i.into())))
+						.into(),
+					decimals: 18,
+					mintable: false,
+				},
+			);
+		}
+		for i in 0..d {
+			SygmaBridge::<T>::register_domain(
+				SystemOrigin::Root.into(),
+				DomainID::new(i as u8),
+				U256::from(i),
+			)
+			.unwrap();
+		}
+
+		#[extrinsic_call]
+		emergency_freeze(SystemOrigin::Root, MAX_FREEZE_RESOURCES, 256);
+
+		let snapshot = FreezeSnapshot::<T>::get().unwrap();
+		assert_eq!(snapshot.domains.len(), d as usize);
+		assert!(SygmaBridge::<T>::is_halted());
+	}
+
+	#[benchmark]
+	fn clear_freeze_snapshot() {
+		SygmaBridge::<T>::emergency_freeze(SystemOrigin::Root.into(), MAX_FREEZE_RESOURCES, 256)
+			.unwrap();
+
+		#[extrinsic_call]
+		clear_freeze_snapshot(SystemOrigin::Root);
+
+		assert!(FreezeSnapshot::<T>::get().is_none());
+	}
+
+	#[benchmark]
+	fn cancel_settlement() {
+		let origin_domain_id: DomainID = DomainID::new(1);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		PendingSettlements::<T>::insert(
+			origin_domain_id,
+			1,
+			PendingSettlement {
+				resource_id: native_resourceid,
+				data: BoundedVec::truncate_from(vec![0u8; 128]),
+				release_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		#[extrinsic_call]
+		cancel_settlement(SystemOrigin::Root, origin_domain_id, 1);
+
+		assert!(SygmaBridge::<T>::is_proposal_executed(1, origin_domain_id));
+		assert!(PendingSettlements::<T>::get(origin_domain_id, 1).is_none());
+	}
+
+	#[benchmark]
+	fn update_domain_chain_id() {
+		let dest_domain_id: DomainID = DomainID::new(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+
+		#[extrinsic_call]
+		update_domain_chain_id(SystemOrigin::Root, dest_domain_id, U256::from(2));
+
+		assert_eq!(DestChainIds::<T>::get(dest_domain_id), Some(U256::from(2)));
+	}
+
+	#[benchmark]
+	fn set_recipient_inbound_cap() {
+		let resource_id: ResourceId = [1u8; 32];
+		let recipient = vec![1u8; MAX_RECIPIENT_LEN as usize];
+		RecipientInbound::<T>::insert(
+			(resource_id, DeniedRecipient::truncate_from(recipient.clone())),
+			1_000u128,
+		);
+
+		#[extrinsic_call]
+		set_recipient_inbound_cap(SystemOrigin::Root, resource_id, recipient.clone(), None);
+
+		assert_eq!(
+			RecipientInbound::<T>::get((resource_id, DeniedRecipient::truncate_from(recipient))),
+			0
+		);
+	}
+
+	#[benchmark]
+	fn correct_mpc_key() {
+		let dest_domain_id: DomainID = DomainID::new(0);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), MpcAddress([1u8; 20]))
+			.unwrap();
+		let mpc_addr = MpcAddress([2u8; 20]);
+
+		#[extrinsic_call]
+		correct_mpc_key(SystemOrigin::Root, mpc_addr);
+
+		assert_eq!(MpcAddr::<T>::get(), mpc_addr);
+		assert!(MpcKeyGraceEnd::<T>::get().is_none());
+	}
+
+	#[benchmark]
+	fn set_fee_vesting() {
+		let beneficiary = whitelisted_caller::<AccountId32>();
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+		#[extrinsic_call]
+		set_fee_vesting(SystemOrigin::Root, native_resourceid, beneficiary.into(), 100);
+
+		assert_eq!(
+			FeeVestings::<T>::get(native_resourceid).map(|schedule| schedule.per_block),
+			Some(100)
+		);
+	}
+
+	#[benchmark]
+	fn release_vested_fees() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let amount = 200_000_000_000_000u128;
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&SygmaBridge::<T>::fee_vesting_account(&native_resourceid),
+			(amount * 2).into(),
+		);
+		FeeVestings::<T>::insert(
+			native_resourceid,
+			FeeVestingSchedule {
+				beneficiary: caller.clone().into(),
+				asset_id: MultiLocation::here().into(),
+				accrued: amount,
+				released: 0,
+				per_block: amount,
+				start: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + 1u32.into(),
+		);
+
+		#[extrinsic_call]
+		release_vested_fees(SystemOrigin::Signed(caller.clone().into()), native_resourceid);
+
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
 	}
 }
@@ -0,0 +1,111 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Default `Config` of the bridge, for `#[derive_impl]`.
+//!
+//! `TestDefaultConfig` sets the constants of the bridge to values suited to tests and
+//! quick-start runtimes of `AccountId32` accounts and `u64` block numbers: the optional features
+//! are disabled, the retention periods and limits are short. The types bound to the assets,
+//! the fee handler and the XCM configuration of a runtime have no default and must be set:
+//!
+//! ```ignore
+//! #[derive_impl(sygma_bridge::config_preludes::TestDefaultConfig as sygma_bridge::DefaultConfig)]
+//! impl sygma_bridge::Config for Runtime {
+//!     type RuntimeEvent = RuntimeEvent;
+//!     type FeeHandler = SygmaBasicFeeHandler;
+//!     // ...
+//! }
+//! ```
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU32, ConstU64, ConstU8},
+	weights::Weight,
+	PalletId,
+};
+use sp_runtime::AccountId32;
+use sp_std::collections::btree_map::BTreeMap;
+use sygma_traits::{AssetMatchingMode, ChainID, DomainID, ResourceId, VerifyingContractAddress};
+use xcm::latest::{AssetId, MultiLocation};
+
+use crate::{accounts, DefaultConfig, NotificationTarget};
+
+parameter_types! {
+	pub const TestPalletId: PalletId = PalletId(*b"sygma/01");
+	pub TestEIP712ChainID: ChainID = primitive_types::U256([1u64; 4]);
+	pub TestDestVerifyingContractAddress: VerifyingContractAddress =
+		primitive_types::H160([1u8; 20]);
+	pub TestFeeReserveAccount: AccountId32 = AccountId32::new([100u8; 32]);
+	// The native asset is held in the account of the bridge pallet
+	pub TestTransferReserveAccounts: BTreeMap<AssetId, AccountId32> =
+		BTreeMap::from([(MultiLocation::here().into(), accounts::pallet_account(TestPalletId::get()))]);
+	pub const TestAssetMatchingMode: AssetMatchingMode = AssetMatchingMode::Strict;
+	pub const TestLocalDomainId: DomainID = DomainID::new(3);
+	pub TestAckResourceId: ResourceId = [0xacu8; 32];
+	pub TestAckFeeAsset: AssetId = MultiLocation::here().into();
+	pub TestMultiAssetResourceId: ResourceId = [0x3au8; 32];
+	pub TestDepositNotificationTarget: NotificationTarget = NotificationTarget {
+		dest: MultiLocation::parent(),
+		call_index: [0, 0],
+		weight: Weight::zero(),
+	};
+}
+
+/// Defaults of the constants of the bridge for tests
+pub struct TestDefaultConfig;
+
+#[frame_support::register_default_impl(TestDefaultConfig)]
+impl DefaultConfig for TestDefaultConfig {
+	type TransferReserveAccounts = TestTransferReserveAccounts;
+	type DestVerifyingContractAddress = TestDestVerifyingContractAddress;
+	type EIP712ChainID = TestEIP712ChainID;
+	type FeeReserveAccount = TestFeeReserveAccount;
+	type AssetMatchingMode = TestAssetMatchingMode;
+	type PauseOnDepositNonceAnomaly = ConstBool<false>;
+	type EscrowRetentionPeriod = ConstU64<100>;
+	type NonceReservationTimeout = ConstU64<10>;
+	type RequireRelayerAllowlist = ConstBool<false>;
+	type RequireMpcThreshold = ConstBool<false>;
+	type EnableSignatureCache = ConstBool<false>;
+	type EnableOffchainMetrics = ConstBool<false>;
+	type MaxMpcSigners = ConstU32<16>;
+	type DepositRecordRetention = ConstU64<10>;
+	type CancellationWindow = ConstU64<5>;
+	type EmergencyWithdrawalDelay = ConstU64<10>;
+	type KeySetGracePeriod = ConstU64<10>;
+	type MaxRetryAge = ConstU64<50>;
+	type LocalDomainId = TestLocalDomainId;
+	type ExpectedDestParents = ConstU8<0>;
+	type UsedNoncesPruneThreshold = ConstU32<0>;
+	type MaxUsedNoncesPrunedPerCall = ConstU32<2>;
+	type InboundLimitWindow = ConstU64<10>;
+	type MaxManualInterventions = ConstU32<3>;
+	type MaxPauseHistory = ConstU32<3>;
+	type AllowPartialTransfer = ConstBool<false>;
+	type KeepFeeOnFailedDeposit = ConstBool<false>;
+	type EnableDepositPermits = ConstBool<false>;
+	type MaxDepositDataSize = ConstU32<256>;
+	type EnableAccountHistory = ConstBool<false>;
+	type HashDepositData = ConstBool<false>;
+	type CompressDepositData = ConstBool<false>;
+	type DepositDataRetention = ConstU64<10>;
+	type ExecutionReceiptRetention = ConstU64<10>;
+	type MaxHistoryPerAccount = ConstU32<3>;
+	type MaxAutoRetryAttempts = ConstU32<3>;
+	type MaxAutoRetriesPerBlock = ConstU32<2>;
+	type MaxFeeReserveChecksPerBlock = ConstU32<2>;
+	type AckResourceId = TestAckResourceId;
+	type AckFeeAsset = TestAckFeeAsset;
+	type MaxAcksPerBlock = ConstU32<2>;
+	type EnableDepositRoots = ConstBool<false>;
+	type MaxAssetsPerDeposit = ConstU32<2>;
+	type MultiAssetResourceId = TestMultiAssetResourceId;
+	type EnableSettlementCancellation = ConstBool<false>;
+	type AttachAssetMetadata = ConstBool<false>;
+	type EnableDepositNotification = ConstBool<false>;
+	type DepositNotificationTarget = TestDepositNotificationTarget;
+	type EnableRecipientFormat = ConstBool<false>;
+	type EnableBatchDeduplication = ConstBool<false>;
+	type PalletId = TestPalletId;
+	type WeightInfo = ();
+}
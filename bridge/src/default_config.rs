@@ -0,0 +1,209 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Minimal runtime leaving every constant of the Sygma pallets to their `TestDefaultConfig`,
+//! checking the defaults are coherent enough to bridge the native asset.
+
+#![cfg(test)]
+
+use crate as sygma_bridge;
+use frame_support::{
+	assert_ok, derive_impl, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Get},
+};
+use frame_system::EnsureRoot;
+use primitive_types::U256;
+use sp_core::hash::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage, DispatchError, DispatchResult,
+};
+use sygma_traits::{DomainID, FeeConfig, MpcAddress, ResourceId, ResourceOnboarder, TransferType};
+use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
+use xcm_builder::{AccountId32Aliases, CurrencyAdapter, IsConcrete};
+
+use crate::{
+	mock::{slice_to_generalkey, DestinationDataParser, SygmaDecimalConverter},
+	DataFormat,
+};
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+type Balance = u128;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		AccessSegregator: sygma_access_segregator = 4,
+		SygmaBasicFeeHandler: sygma_basic_feehandler = 5,
+		SygmaBridge: sygma_bridge = 6,
+	}
+);
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<2>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+#[derive_impl(sygma_access_segregator::config_preludes::TestDefaultConfig as sygma_access_segregator::DefaultConfig)]
+impl sygma_access_segregator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
+	type PalletIndex = ConstU8<4>;
+}
+
+#[derive_impl(sygma_basic_feehandler::config_preludes::TestDefaultConfig as sygma_basic_feehandler::DefaultConfig)]
+impl sygma_basic_feehandler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletIndex = ConstU8<5>;
+}
+
+parameter_types! {
+	pub NativeResourceId: ResourceId = [1u8; 32];
+	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> =
+		vec![(MultiLocation::here().into(), NativeResourceId::get())];
+	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(MultiLocation::here().into(), 12u8)];
+	pub const NativeLocation: MultiLocation = MultiLocation::here();
+}
+
+pub type NativeTransactor = CurrencyAdapter<
+	Balances,
+	IsConcrete<NativeLocation>,
+	AccountId32Aliases<(), AccountId32>,
+	AccountId32,
+	(),
+>;
+
+/// Onboarding is not part of the minimal runtime
+pub struct NoOnboarding;
+impl ResourceOnboarder for NoOnboarding {
+	fn set_fee(_domain: DomainID, _asset: &XcmAssetId, _fee: FeeConfig) -> DispatchResult {
+		Err(DispatchError::Other("onboarding unsupported"))
+	}
+
+	fn create_asset(_asset: &XcmAssetId, _decimals: u8) -> DispatchResult {
+		Err(DispatchError::Other("onboarding unsupported"))
+	}
+}
+
+#[derive_impl(sygma_bridge::config_preludes::TestDefaultConfig as sygma_bridge::DefaultConfig)]
+impl sygma_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FeeHandler = SygmaBasicFeeHandler;
+	type FeeDistributor = sygma_bridge::FeeReserveDistributor<Runtime>;
+	type DomainRegistry = sygma_bridge::LocalDomainRegistry<Runtime>;
+	type ResourceOnboarder = NoOnboarding;
+	type AssetTransactor = NativeTransactor;
+	type BalanceInspector = ();
+	type AssetMetadataProvider = ();
+	type AmountNormalizer = ();
+	type DepositNotifier = ();
+	type ResourcePairs = ResourcePairs;
+	type IsReserve = xcm_builder::NativeAsset;
+	type ExtractDestData = DestinationDataParser;
+	type PalletIndex = ConstU8<6>;
+	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
+}
+
+const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
+const DEST_DOMAIN_ID: DomainID = DomainID::new(1);
+
+#[test]
+fn deposit_should_work_with_the_default_config() {
+	let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> { balances: vec![(ALICE, 1_000_000_000_000_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		System::set_block_number(1);
+		let fee = 1_000_000_000_000u128;
+		let amount = 200_000_000_000_000u128;
+
+		assert_ok!(SygmaBridge::register_domain(
+			RuntimeOrigin::root(),
+			DEST_DOMAIN_ID,
+			U256::from(1)
+		));
+		assert_ok!(SygmaBasicFeeHandler::set_fee(
+			RuntimeOrigin::root(),
+			DEST_DOMAIN_ID,
+			Box::new(NativeLocation::get().into()),
+			fee
+		));
+		assert_ok!(SygmaBridge::set_mpc_address(RuntimeOrigin::root(), MpcAddress([1u8; 20])));
+		assert_ok!(SygmaBridge::unpause_bridge(RuntimeOrigin::root(), DEST_DOMAIN_ID));
+
+		assert_ok!(SygmaBridge::deposit(
+			RuntimeOrigin::signed(ALICE),
+			Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+			Box::new(MultiLocation {
+				parents: 0,
+				interior: X2(slice_to_generalkey(b"ethereum recipient"), slice_to_generalkey(&[1])),
+			}),
+		));
+
+		let reserve_account = sygma_bridge::accounts::pallet_account::<AccountId32>(
+			sygma_bridge::config_preludes::TestPalletId::get(),
+		);
+		assert_eq!(Balances::free_balance(reserve_account), amount - fee);
+		assert_eq!(
+			Balances::free_balance(sygma_bridge::config_preludes::TestFeeReserveAccount::get()),
+			fee
+		);
+		assert_eq!(
+			System::events().last().map(|record| record.event.clone()),
+			Some(RuntimeEvent::SygmaBridge(sygma_bridge::Event::Deposit {
+				dest_domain_id: DEST_DOMAIN_ID,
+				resource_id: NativeResourceId::get(),
+				deposit_nonce: 0,
+				transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+				sender: ALICE,
+				transfer_type: TransferType::FungibleTransfer,
+				// 200 - 1 in 18 decimals
+				deposit_data: SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					199_000_000_000_000_000_000,
+					b"ethereum recipient".to_vec(),
+				),
+				handler_response: vec![],
+			}))
+		);
+	})
+}
@@ -0,0 +1,182 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Transaction extensions of the bridge.
+//!
+//! A failed extrinsic is reverted along with the events it deposited, so `deposit` can not emit
+//! an event about its own failure. `ReportDepositFailure` notes the sender of the deposit calls
+//! before dispatch and emits `DepositFailed` in `post_dispatch`, which runs after the revert.
+//!
+//! `CheckFreshProposals` keeps `execute_fresh_proposals` calls whose proposals expected fresh
+//! are all executed out of the transaction pool and the block. Each proposal still expected
+//! fresh is provided as a tag, so of two relayers racing with overlapping batches only one
+//! enters the pool.
+//!
+//! Add them to the `SignedExtra` of the runtime. Calls dispatched by other means, e.g. from
+//! another pallet, are not checked.
+
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchResult,
+};
+use sp_std::{fmt, marker::PhantomData, vec::Vec};
+
+use crate::{Call, Config, Event, Pallet, Proposal};
+
+/// Emit `DepositFailed` when `deposit`, `deposit_with_route` or `commit_deposit` fails
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ReportDepositFailure<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> ReportDepositFailure<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for ReportDepositFailure<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for ReportDepositFailure<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ReportDepositFailure")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ReportDepositFailure<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "ReportDepositFailure";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// Sender of the deposit, `None` for the other calls
+	type Pre = Option<T::AccountId>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(match call.is_sub_type() {
+			Some(Call::deposit { .. })
+			| Some(Call::deposit_with_route { .. })
+			| Some(Call::commit_deposit { .. }) => Some(who.clone()),
+			_ => None,
+		})
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(Some(sender)), Err(error)) = (pre, result) {
+			// Emit DepositFailed event
+			Pallet::<T>::deposit_event(Event::DepositFailed {
+				sender,
+				reason: Pallet::<T>::deposit_failure_reason(error),
+			});
+		}
+		Ok(())
+	}
+}
+
+/// Reject `execute_fresh_proposals` if the proposals expected fresh are all executed
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckFreshProposals<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckFreshProposals<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Validity of a batch, providing the proposals expected fresh that are not executed
+	pub fn validate_proposals(
+		proposals: &[Proposal],
+		expected_fresh: &[u8],
+	) -> TransactionValidity {
+		if !Pallet::<T>::is_fresh_bitmap_valid(proposals, expected_fresh) {
+			return Err(InvalidTransaction::Call.into());
+		}
+		if Pallet::<T>::are_fresh_proposals_executed(proposals, expected_fresh) {
+			return Err(InvalidTransaction::Stale.into());
+		}
+		let provides: Vec<_> = Pallet::<T>::expected_fresh_proposals(proposals, expected_fresh)
+			.filter(|p| !Pallet::<T>::is_proposal_executed(p.deposit_nonce, p.origin_domain_id))
+			.map(|p| ("CheckFreshProposals", p.origin_domain_id, p.deposit_nonce).encode())
+			.collect();
+		Ok(ValidTransaction { provides, ..Default::default() })
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckFreshProposals<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckFreshProposals<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckFreshProposals")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckFreshProposals<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckFreshProposals";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(Call::execute_fresh_proposals { proposals, expected_fresh, .. }) => {
+				Self::validate_proposals(proposals, expected_fresh)
+			},
+			_ => Ok(ValidTransaction::default()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
@@ -10,54 +10,89 @@ pub use weights::*;
 
 pub use self::pallet::*;
 
+pub mod accounts;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod config_preludes;
+pub mod extension;
+pub mod metrics;
 pub mod migration;
+pub mod nonces;
 pub mod weights;
 
-mod eip712;
-mod encode;
-
 #[cfg(test)]
-mod mock;
+mod default_config;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod scenario;
 
 #[allow(unused_variables)]
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
 pub mod pallet {
 	use codec::{Decode, Encode};
-	use ethabi::{encode as abi_encode, token::Token};
 	use frame_support::dispatch::RawOrigin;
 	use frame_support::{
 		dispatch::DispatchResult,
 		pallet_prelude::*,
-		traits::{ContainsPair, StorageVersion},
+		traits::{ContainsPair, OriginTrait, StorageVersion},
 		transactional, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use primitive_types::U256;
 	use scale_info::TypeInfo;
-	use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+	use sp_core::hexdisplay::HexDisplay;
+	use sp_io::{
+		crypto::secp256k1_ecdsa_recover,
+		hashing::{blake2_256, keccak_256},
+	};
 	use sp_runtime::{
-		traits::{AccountIdConversion, Clear},
-		RuntimeDebug,
+		traits::{AccountIdConversion, Clear, SaturatedConversion, Saturating, Verify, Zero},
+		AccountId32, ModuleError, MultiSignature, Permill, RuntimeDebug,
 	};
-	use sp_std::collections::btree_map::BTreeMap;
+	use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 	use sp_std::{boxed::Box, convert::From, vec, vec::Vec};
 	use xcm::latest::{prelude::*, MultiLocation};
 	use xcm_executor::traits::TransactAsset;
 
+	use sygma_fee_handler_router::FeeHandlerType;
 	use sygma_traits::{
-		Bridge, ChainID, DecimalConverter, DepositNonce, DomainID, ExtractDestinationData,
-		FeeHandler, MpcAddress, ResourceId, TransferType, VerifyingContractAddress,
+		AmountNormalizer, AssetBalanceInspector, AssetMatchingMode, AssetMetadataProvider, Bridge,
+		BridgeEmergency, ChainID, DecimalConverter, DepositFailureReason, DepositNonce,
+		DepositValidationError, DistributeFee, DomainID, ExecutionFailureReason,
+		ExtractDestinationData, FeeConfig, FeeHandler, IsRegisteredDomain, MpcAddress, ResourceId,
+		ResourceOnboarder, TransferType, VerifyingContractAddress, EXTRA_TAG_ASSET_METADATA,
+		EXTRA_TAG_ROUTE, EXTRA_TAG_SOURCE_AMOUNT,
 	};
 
-	use crate::eip712;
-	use crate::encode::{abi::encode_packed, SolidityDataType};
+	use crate::{accounts, nonces};
+	use sygma_codec::ProposalRef;
+	pub use sygma_codec::{AckStatus, DataFormat, RecipientFormat};
 
-	#[allow(dead_code)]
-	const LOG_TARGET: &str = "runtime::sygmabridge";
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	/// Target of the bridge logs, none of them is above debug level. The arguments are only
+	/// formatted when the level is enabled.
+	pub(crate) const LOG_TARGET: &str = "runtime::sygmabridge";
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+	/// Max length of the detail kept in `FailedProposals`
+	pub const MAX_FAILURE_DETAIL_LEN: u32 = 64;
+
+	/// Max number of nonces covered by a call of `deposits_in_range`
+	pub const MAX_DEPOSIT_RANGE: u64 = 256;
+
+	/// Max length of the note of a pause
+	pub const MAX_PAUSE_NOTE_LEN: u32 = 128;
+
+	pub type PauseNote = BoundedVec<u8, ConstU32<MAX_PAUSE_NOTE_LEN>>;
+
+	/// Max length of a recipient in `RecipientDenylist`
+	pub const MAX_RECIPIENT_LEN: u32 = 128;
+
+	/// Recipient as carried by deposit data, in the format of the domain it's credited on
+	pub type DeniedRecipient = BoundedVec<u8, ConstU32<MAX_RECIPIENT_LEN>>;
+
+	/// Max number of resources captured by `emergency_freeze`
+	pub const MAX_FREEZE_RESOURCES: u32 = 128;
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
 	pub struct Proposal {
@@ -67,6 +102,457 @@ pub mod pallet {
 		pub data: Vec<u8>,
 	}
 
+	/// Inbound transfer that could not be credited to its beneficiary and is held in the escrow
+	/// holding account of the resource until it gets claimed
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct EscrowedTransfer<BlockNumber> {
+		pub resource_id: ResourceId,
+		pub beneficiary: MultiLocation,
+		pub asset_id: AssetId,
+		pub amount: u128,
+		pub escrowed_at: BlockNumber,
+	}
+
+	/// Fees of a resource vesting to `beneficiary` instead of going to `FeeDistributor`. The fees
+	/// in `asset_id` are held in the fee vesting account of the resource, up to `per_block` of
+	/// them vest each block from `start`.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct FeeVestingSchedule<AccountId, BlockNumber> {
+		pub beneficiary: AccountId,
+		pub asset_id: AssetId,
+		/// Fees accrued to the schedule since `start`
+		pub accrued: u128,
+		/// Part of `accrued` released to `beneficiary`
+		pub released: u128,
+		pub per_block: u128,
+		pub start: BlockNumber,
+	}
+
+	/// Failure of a proposal, the detail is the name of the error truncated to
+	/// `MAX_FAILURE_DETAIL_LEN` bytes
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct FailedProposal {
+		pub reason: ExecutionFailureReason,
+		pub detail: BoundedVec<u8, ConstU32<MAX_FAILURE_DETAIL_LEN>>,
+	}
+
+	/// Failed proposal queued for the auto retry, `attempts` is the number of auto retries that
+	/// failed so far
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct QueuedProposal<Data> {
+		pub data: Data,
+		pub attempts: u32,
+	}
+
+	/// Proposal of a resource with a settlement delay, recorded by `execute_proposal` and
+	/// released by `finalize_proposal` from block `release_at`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct PendingSettlement<Data, BlockNumber> {
+		pub resource_id: ResourceId,
+		pub data: Data,
+		pub release_at: BlockNumber,
+	}
+
+	/// Deposit data whose hash was emitted by `Deposit`, kept until it gets pruned after
+	/// `DepositDataRetention`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct StoredDepositData<BlockNumber, Data> {
+		pub stored_at: BlockNumber,
+		pub data: Data,
+	}
+
+	/// Inbound transfer credited by an executed proposal, kept until it gets pruned after
+	/// `ExecutionReceiptRetention`. Its blake2_256 hash is carried by `ProposalExecution`.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct ExecutionReceipt<BlockNumber> {
+		pub recipient: MultiLocation,
+		pub asset: AssetId,
+		/// Amount credited to the recipient, in the decimals of the local asset
+		pub amount: u128,
+		pub block: BlockNumber,
+	}
+
+	/// Deposit signed off-chain by `owner`, submitted by anyone with `deposit_with_permit`.
+	/// `nonce` is the next nonce of `PermitNonces` of the owner, the permit can not be used
+	/// after `deadline`.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub struct DepositPermit<AccountId, BlockNumber> {
+		pub owner: AccountId,
+		pub asset: MultiAsset,
+		pub dest: MultiLocation,
+		pub nonce: u64,
+		pub deadline: BlockNumber,
+	}
+
+	/// Bridge activity of a block, counted for the offchain metrics if `EnableOffchainMetrics`
+	/// is set
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+	)]
+	pub struct ActivityCounters<BlockNumber> {
+		pub block: BlockNumber,
+		/// Deposits initiated by accounts
+		pub deposits: u32,
+		pub executions: u32,
+		pub failed_executions: u32,
+	}
+
+	/// Outbound transfer kept for refunding until it gets pruned after `DepositRecordRetention`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct DepositRecord<AccountId, BlockNumber> {
+		pub sender: AccountId,
+		pub asset_id: AssetId,
+		/// Bridged amount, the fee is not refundable
+		pub amount: u128,
+		pub recorded_at: BlockNumber,
+		pub refund_requested: bool,
+		/// Set once the sender cancels the deposit, it can no longer be refunded
+		pub cancelled: bool,
+	}
+
+	/// Deposit record exported by `deposits_in_range`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub struct DepositRecordSummary<BlockNumber> {
+		pub deposit_nonce: DepositNonce,
+		pub sender: [u8; 32],
+		pub asset_id: AssetId,
+		/// Bridged amount, in local decimals
+		pub amount: u128,
+		pub recorded_at: BlockNumber,
+		pub refund_requested: bool,
+		pub cancelled: bool,
+		/// blake2_256 hash of the `deposit_data` of the `Deposit` event, to check the event of an
+		/// archive against. `None` for a deposit recorded before the hashes were kept.
+		pub deposit_data_hash: Option<[u8; 32]>,
+	}
+
+	/// Nonce of a range of deposits exported by `deposits_in_range`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub enum DepositRangeEntry<BlockNumber> {
+		/// Deposit whose record is retained
+		Recorded(DepositRecordSummary<BlockNumber>),
+		/// Nonce assigned without a record retained: the record is pruned or refunded, or the
+		/// deposit never had one, as acknowledgments, forwarded and multi-asset transfers. Its
+		/// `Deposit` event has to be read from an archive.
+		NotRetained(DepositNonce),
+	}
+
+	/// Range of deposits to a domain, returned by `deposits_in_range`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub struct DepositRange<BlockNumber> {
+		/// Entries of the nonces of the range, by nonce
+		pub entries: Vec<DepositRangeEntry<BlockNumber>>,
+		/// Nonce the rest of the requested range starts from, `None` if the range is covered
+		pub next_nonce: Option<DepositNonce>,
+	}
+
+	/// Caps on the amount of a resource bridged in by proposals, in the 18 decimals of the deposit
+	/// data
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct InboundLimit {
+		/// Max amount of a single proposal, a proposal above it is failed for good
+		pub max_single: u128,
+		/// Max amount executed within `InboundLimitWindow` blocks
+		pub max_per_window: u128,
+	}
+
+	/// Token bucket throttling the deposits of a resource, in the 18 decimals of deposit data. A
+	/// deposit takes its bridged amount of tokens out of the bucket.
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct TokenBucketConfig {
+		/// Max number of tokens the bucket holds, i.e. the largest burst of deposits
+		pub capacity: u128,
+		/// Number of tokens added to the bucket each block
+		pub refill_per_block: u128,
+	}
+
+	/// Amount of a resource bridged in since the current window started
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+	)]
+	pub struct InboundWindow<BlockNumber> {
+		pub started_at: BlockNumber,
+		pub amount: u128,
+		/// Set once a proposal would exceed `max_per_window`, no proposal of the resource is
+		/// executed until the window rolls or it is cleared
+		pub exhausted: bool,
+	}
+
+	/// Why a domain is paused
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub enum PauseReason {
+		Governance,
+		/// Tripped by a safety check, `resource` is the resource that tripped it, `None` for a
+		/// check of the whole domain such as the deposit nonce anomaly. Only the bridge committee
+		/// origin can clear it.
+		CircuitBreaker {
+			resource: Option<ResourceId>,
+		},
+		/// Pause requested by the MPC
+		MpcSignal,
+		/// Planned pause, e.g. for a maintenance of the dest domain
+		Scheduled,
+		/// Chain-wide emergency stop, lifted by `restore_from_snapshot`
+		SafeMode,
+	}
+
+	/// Direction of the transfers of a route, seen from this chain
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum RouteDirection {
+		/// Proposals executed from the domain
+		Inbound,
+		/// Deposits to the domain
+		Outbound,
+	}
+
+	impl RouteDirection {
+		pub const ALL: [RouteDirection; 2] = [RouteDirection::Inbound, RouteDirection::Outbound];
+	}
+
+	/// Reason and note of a domain pause
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct PauseInfo {
+		pub reason: PauseReason,
+		pub note: Option<PauseNote>,
+	}
+
+	/// State of a resource captured by `emergency_freeze`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct ResourceFreezeState {
+		pub resource_id: ResourceId,
+		/// Balance of the reserve account of the resource, `None` if the asset is not held in
+		/// reserve or its balance can not be read
+		pub reserve_balance: Option<u128>,
+		/// Failed proposals of the resource pending a retry
+		pub failed_proposals: u32,
+	}
+
+	/// Nonces of a domain captured by `emergency_freeze`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct DomainFreezeState {
+		pub domain_id: DomainID,
+		/// Highest nonce of the deposits to the domain
+		pub deposit_nonce: DepositNonce,
+		/// Every proposal from the domain below this nonce has been executed
+		pub min_unpruned_nonce: DepositNonce,
+	}
+
+	/// State of the bridge captured by `emergency_freeze` at block `frozen_at`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct BridgeFreezeSnapshot<BlockNumber> {
+		pub frozen_at: BlockNumber,
+		pub resources: BoundedVec<ResourceFreezeState, ConstU32<MAX_FREEZE_RESOURCES>>,
+		pub domains: BoundedVec<DomainFreezeState, ConstU32<256>>,
+	}
+
+	/// Whether the bridge is usable for an asset to a dest domain, gathered by `bridge_status`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct BridgeStatus {
+		pub domain_registered: bool,
+		pub domain_paused: bool,
+		/// Reason of the pause, `None` if the domain is not paused or was paused before reasons
+		/// were recorded
+		pub pause_reason: Option<PauseReason>,
+		pub pause_note: Option<Vec<u8>>,
+		/// Dest domain is not reported unhealthy by the health oracle
+		pub domain_healthy: bool,
+		pub mpc_address_set: bool,
+		/// Resource the asset is bound to, `None` if the asset is not bound
+		pub resource_id: Option<ResourceId>,
+		/// Fee of the probe amount, `None` if no fee is configured
+		pub fee: Option<u128>,
+		/// Whether the reserve account can release the probe amount, always true for an asset
+		/// not reserved on this chain. `None` if no probe amount is given.
+		pub reserve_sufficient: Option<bool>,
+		/// Min amount of a deposit, it has to exceed the fee
+		pub min_transfer: Option<u128>,
+		/// Max amount of a deposit, `None` as deposits are not capped
+		pub max_transfer: Option<u128>,
+	}
+
+	impl BridgeStatus {
+		/// Return true if a deposit of the asset to the dest domain can go through
+		pub fn is_usable(&self) -> bool {
+			self.domain_registered
+				&& !self.domain_paused
+				&& self.domain_healthy
+				&& self.mpc_address_set
+				&& self.resource_id.is_some()
+				&& self.fee.is_some()
+				&& self.reserve_sufficient != Some(false)
+		}
+	}
+
+	/// Config and live state of the route of a resource to a domain, gathered by `route_info`.
+	/// Amounts of the rate limits are in the 18 decimals of deposit data, the fee and the min
+	/// transfer are in the asset.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub struct RouteInfo<BlockNumber> {
+		/// Resource is bound to an asset, the domain is registered and has a data format
+		pub enabled: bool,
+		/// Asset the resource is bound to, `None` if the resource is not bound
+		pub asset_id: Option<AssetId>,
+		pub domain_registered: bool,
+		pub data_format: Option<DataFormat>,
+		/// Decimals of the amounts of the resource exchanged with the domain
+		pub decimals: u8,
+		/// Deposits to the domain are paused
+		pub outbound_paused: bool,
+		/// Proposals from the domain are paused
+		pub inbound_paused: bool,
+		/// Reason of the pause, `None` if the domain is paused in neither direction
+		pub pause_reason: Option<PauseReason>,
+		/// Deposits are rejected whether the route is paused or not: the domain is reported
+		/// unhealthy or is past its sunset
+		pub blocked: bool,
+		pub domain_healthy: bool,
+		/// Deposit cutoff block of the domain, `None` if no sunset is scheduled
+		pub sunset_at: Option<BlockNumber>,
+		/// The resource tripped the circuit breaker pausing the deposits to the domain
+		pub circuit_broken: bool,
+		/// Fee of a deposit, `None` if no fee is configured
+		pub fee: Option<u128>,
+		/// Min amount of a deposit, it has to exceed the fee
+		pub min_transfer: Option<u128>,
+		/// Tokens the bucket of the resource holds, the max amount bridged out right now. `None`
+		/// if the deposits of the resource are not throttled.
+		pub available_tokens: Option<u128>,
+		/// Caps on the amount bridged in by proposals, `None` if the resource has none
+		pub inbound_limit: Option<InboundLimit>,
+		/// Inbound window of the resource, `None` if no window is running
+		pub inbound_window: Option<InboundWindow<BlockNumber>>,
+		/// Delay of the settlement of the proposals of the resource, `None` if they are
+		/// executed right away
+		pub settlement_delay: Option<BlockNumber>,
+	}
+
+	/// Break-glass operation recorded in `ManualInterventions`
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum InterventionOperation {
+		SweepEscrowedTransfer,
+		RefundDeposit,
+		ClearInboundWindow,
+		EmergencyWithdraw,
+		CorrectMpcKey,
+	}
+
+	/// What a break-glass operation was applied to
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum InterventionSubject {
+		/// Transfer identified by its domain and deposit nonce, the origin domain of an inbound
+		/// transfer or the dest domain of an outbound one
+		Transfer {
+			domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		},
+		Resource {
+			resource_id: ResourceId,
+		},
+		Account {
+			account: BridgeAccount,
+		},
+		MpcKey {
+			old_mpc_addr: MpcAddress,
+			mpc_addr: MpcAddress,
+		},
+	}
+
+	/// Location notified of each deposit by an XCM of `DepositNotifier`. The XCM transacts
+	/// the call of index `call_index` with `(dest_domain_id, deposit_nonce, transfer_id)` as
+	/// arguments, with the sovereign account of this chain as origin.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct NotificationTarget {
+		pub dest: MultiLocation,
+		/// Pallet index and call index of the call at `dest`
+		pub call_index: [u8; 2],
+		/// Weight of the call at `dest`
+		pub weight: Weight,
+	}
+
+	/// Account derived from the bridge `PalletId` that funds can be recovered from with
+	/// `emergency_withdraw`, see `accounts`. Proposals can not credit any of them.
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum BridgeAccount {
+		Pallet,
+		TokenReserve,
+		Escrow { resource_id: ResourceId },
+		FeePrefund,
+		AckFeePot,
+		FeeVesting { resource_id: ResourceId },
+	}
+
+	/// Entry of `ManualInterventions`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct ManualIntervention<BlockNumber> {
+		pub block: BlockNumber,
+		pub operation: InterventionOperation,
+		/// blake2_256 of the encoded origin of the call
+		pub operator_origin_hash: [u8; 32],
+		pub subject: InterventionSubject,
+		/// Amount moved by the operation, if it moves any
+		pub amount: Option<u128>,
+	}
+
+	/// Entry of `DepositHistory` and `ExecutionHistory`, `domain_id` is the dest domain of a
+	/// deposit and the origin domain of an execution
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct TransferSummary<BlockNumber> {
+		pub domain_id: DomainID,
+		pub deposit_nonce: DepositNonce,
+		pub resource_id: ResourceId,
+		/// Amount bridged, in local decimals
+		pub amount: u128,
+		pub block: BlockNumber,
+	}
+
+	/// Binding of a resource moved from `old_asset` to `new_asset` at block `effective_at`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct ResourceRebind<BlockNumber> {
+		pub old_asset: AssetId,
+		pub new_asset: AssetId,
+		pub effective_at: BlockNumber,
+	}
+
+	/// Everything `onboard_resource` sets up for a new resource
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+	pub struct OnboardingSpec {
+		pub asset: AssetId,
+		pub resource_id: ResourceId,
+		/// Fee of the deposits to each domain, the domains must be registered
+		pub fees: Vec<(DomainID, FeeConfig)>,
+		/// Inbound limit of the resource, not limited if `None`
+		pub inbound_limit: Option<InboundLimit>,
+		/// Whether the asset is minted by the bridge rather than held in reserve, its asset is
+		/// created by `ResourceOnboarder`
+		pub mintable: bool,
+		/// Decimals of the asset on this chain
+		pub decimals: u8,
+	}
+
+	/// Resource bound by `onboard_resource`
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct OnboardedResource {
+		pub asset: AssetId,
+		pub decimals: u8,
+		pub mintable: bool,
+	}
+
+	/// Where the binding of a resource comes from, by decreasing precedence
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum BindingSource {
+		/// `ResourceRebinds`, set by `rebind_resource`
+		Override,
+		/// `OnboardedResources`, registered by `onboard_resource`
+		Registry,
+		/// `T::ResourcePairs` of the runtime
+		Constant,
+	}
+
 	pub trait WeightInfo {
 		fn pause_bridge() -> Weight;
 		fn unpause_bridge() -> Weight;
@@ -76,20 +562,78 @@ pub mod pallet {
 		fn deposit() -> Weight;
 		fn retry() -> Weight;
 		fn execute_proposal(n: u32) -> Weight;
+		fn verify_signature(b: u32) -> Weight;
+		fn per_proposal_fungible() -> Weight;
 		fn pause_all_bridges() -> Weight;
 		fn unpause_all_bridges() -> Weight;
+		fn claim_escrowed_transfer() -> Weight;
+		fn sweep_escrowed_transfer() -> Weight;
+		fn reserve_nonce() -> Weight;
+		fn commit_deposit() -> Weight;
+		fn reclaim_nonce_reservation() -> Weight;
+		fn add_relayer() -> Weight;
+		fn remove_relayer() -> Weight;
+		fn set_mpc_signers(s: u32) -> Weight;
+		fn verify_mpc_threshold(s: u32) -> Weight;
+		fn prune_deposit_records(n: u32) -> Weight;
+		fn request_refund() -> Weight;
+		fn refund_deposit() -> Weight;
+		fn deposit_with_route() -> Weight;
+		fn set_health_oracle() -> Weight;
+		fn report_domain_health() -> Weight;
+		fn set_inbound_limit() -> Weight;
+		fn clear_inbound_window() -> Weight;
+		fn set_fee_discount() -> Weight;
+		fn pause_bridge_with_reason() -> Weight;
+		fn prefund_fees() -> Weight;
+		fn withdraw_prefund() -> Weight;
+		fn rebind_resource() -> Weight;
+		fn set_data_format() -> Weight;
+		fn set_auto_retry() -> Weight;
+		fn set_global_tvl_cap() -> Weight;
+		fn onboard_resource(n: u32) -> Weight;
+		fn offboard_resource() -> Weight;
+		fn set_min_reserve_balance() -> Weight;
+		fn prune_deposit_data(n: u32) -> Weight;
+		fn set_token_bucket() -> Weight;
+		fn set_fee_reserve_threshold() -> Weight;
+		fn set_ack_enabled() -> Weight;
+		fn set_acks_halted() -> Weight;
+		fn resolve_binding_conflict() -> Weight;
+		fn prune_execution_receipts(n: u32) -> Weight;
+		fn add_denied_recipient() -> Weight;
+		fn remove_denied_recipient() -> Weight;
+		fn deposit_with_permit() -> Weight;
+		fn pause_route() -> Weight;
+		fn unpause_route() -> Weight;
+		fn schedule_domain_sunset() -> Weight;
+		fn cancel_deposit() -> Weight;
+		fn emergency_withdraw() -> Weight;
+		fn set_resource_decimals() -> Weight;
+		fn set_settlement_delay() -> Weight;
+		fn finalize_proposal() -> Weight;
+		fn emergency_freeze(r: u32, d: u32) -> Weight;
+		fn clear_freeze_snapshot() -> Weight;
+		fn cancel_settlement() -> Weight;
+		fn update_domain_chain_id() -> Weight;
+		fn set_recipient_inbound_cap() -> Weight;
+		fn correct_mpc_key() -> Weight;
+		fn set_fee_vesting() -> Weight;
+		fn release_vested_fees() -> Weight;
 	}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	#[pallet::config]
+	#[pallet::config(with_default)]
 	pub trait Config: frame_system::Config + sygma_access_segregator::Config {
+		#[pallet::no_default]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Bridge transfer reserve accounts mapping with designated assets
 		#[pallet::constant]
+		#[pallet::no_default_bounds]
 		type TransferReserveAccounts: Get<BTreeMap<AssetId, Self::AccountId>>;
 
 		/// EIP712 Verifying contract address
@@ -104,30 +648,311 @@ pub mod pallet {
 
 		/// Fee reserve account
 		#[pallet::constant]
+		#[pallet::no_default_bounds]
 		type FeeReserveAccount: Get<Self::AccountId>;
 
 		/// Fee information getter
+		#[pallet::no_default]
 		type FeeHandler: FeeHandler;
 
+		/// Receiver of the fees collected by deposits, `FeeReserveDistributor` deposits them to
+		/// `FeeReserveAccount`. The fees of a resource with a schedule set by `set_fee_vesting`
+		/// vest to its beneficiary instead.
+		#[pallet::no_default]
+		type FeeDistributor: DistributeFee<Self::AccountId>;
+
+		/// Domains deposits and proposals are accepted for, `LocalDomainRegistry` reads the
+		/// domains registered with `register_domain`
+		#[pallet::no_default]
+		type DomainRegistry: IsRegisteredDomain;
+
+		/// Onboarding steps of `onboard_resource` carried out outside the bridge,
+		/// `FeeHandlerOnboarder` sets the fees with the fee handler pallets
+		#[pallet::no_default]
+		type ResourceOnboarder: ResourceOnboarder;
+
 		/// Implementation of withdraw and deposit an asset.
+		#[pallet::no_default]
 		type AssetTransactor: TransactAsset;
 
+		/// Balance reader used to check the amount `AssetTransactor` credits to the beneficiary of
+		/// a proposal
+		#[pallet::no_default]
+		type BalanceInspector: AssetBalanceInspector;
+
 		/// AssetId and ResourceId pairs
+		#[pallet::no_default]
 		type ResourcePairs: Get<Vec<(AssetId, ResourceId)>>;
 
+		/// How strictly a deposited asset has to match the `AssetId` registered in
+		/// `ResourcePairs`, see `AssetMatchingMode` for the trade-offs of each mode
+		#[pallet::constant]
+		type AssetMatchingMode: Get<AssetMatchingMode>;
+
+		/// Pause the dest domain when a deposit nonce gap is detected
+		#[pallet::constant]
+		type PauseOnDepositNonceAnomaly: Get<bool>;
+
+		/// Number of blocks an escrowed transfer stays claimable before it can be swept
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type EscrowRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks a nonce reserved by `reserve_nonce` can be committed within
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type NonceReservationTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Require `execute_proposal` to be submitted by a relayer in `Relayers`
+		#[pallet::constant]
+		type RequireRelayerAllowlist: Get<bool>;
+
+		/// Require `execute_proposal` to be signed by at least `MpcThreshold` of `MpcSigners`
+		/// rather than by `MpcAddr` alone
+		#[pallet::constant]
+		type RequireMpcThreshold: Get<bool>;
+
+		/// Cache the result of verifying the MPC signature of a batch until the end of the block,
+		/// so that the same signature submitted again in the block is not recovered again
+		#[pallet::constant]
+		type EnableSignatureCache: Get<bool>;
+
+		/// Whether the activity of each block is counted in `BlockActivity` and exported to the
+		/// offchain local storage by the offchain worker, which also needs the
+		/// `offchain-metrics` feature
+		#[pallet::constant]
+		type EnableOffchainMetrics: Get<bool>;
+
+		/// Max number of MPC signers
+		#[pallet::constant]
+		type MaxMpcSigners: Get<u32>;
+
+		/// Number of blocks a deposit record is kept before it can be pruned
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type DepositRecordRetention: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after a deposit within which its sender can cancel it, zero disables
+		/// the cancellation
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type CancellationWindow: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks between the scheduling of an emergency withdrawal and the block it
+		/// can be executed from, it can be executed for as many blocks after
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type EmergencyWithdrawalDelay: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after the MPC address is first set within which the committee may
+		/// correct it once with `correct_mpc_key`, a correction is never allowed if 0
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type KeySetGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after which a deposit can no longer be retried, it may have been
+		/// resolved off-chain since
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type MaxRetryAge: Get<BlockNumberFor<Self>>;
+
+		/// Domain id of this chain, used in the transfer id of deposits
+		#[pallet::constant]
+		type LocalDomainId: Get<DomainID>;
+
+		/// Expected `parents` of the dest `MultiLocation` in deposit, any other value is a
+		/// different chain level (local, relay chain, sibling) than the one the deployment routes
+		/// bridge transfers through
+		#[pallet::constant]
+		type ExpectedDestParents: Get<u8>;
+
+		/// Number of `UsedNonces` entries of an origin domain above which fully used entries are
+		/// pruned during `execute_proposal`, 0 disables the pruning
+		#[pallet::constant]
+		type UsedNoncesPruneThreshold: Get<u32>;
+
+		/// Max number of `UsedNonces` entries pruned by one `execute_proposal`
+		#[pallet::constant]
+		type MaxUsedNoncesPrunedPerCall: Get<u32>;
+
+		/// Number of blocks of the window `InboundLimit::max_per_window` applies to
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type InboundLimitWindow: Get<BlockNumberFor<Self>>;
+
+		/// Max number of entries kept in `ManualInterventions`, the oldest entry is evicted
+		/// when a new one is recorded
+		#[pallet::constant]
+		type MaxManualInterventions: Get<u32>;
+
+		/// Max number of pause and unpause actions kept in the `PauseHistory` of a domain, the
+		/// oldest entry is evicted when a new one is recorded
+		#[pallet::constant]
+		type MaxPauseHistory: Get<u32>;
+
+		/// Whether a proposal whose beneficiary is credited less than the requested amount is
+		/// executed, with the shortfall recorded in `TransferShortfalls`. If false the proposal
+		/// fails with `PartialTransferNotAllowed`.
+		#[pallet::constant]
+		type AllowPartialTransfer: Get<bool>;
+
+		/// Whether the fee paid from the prefund balance is kept when the deposited amount can not
+		/// be withdrawn from the sender, as an anti-spam measure. The deposit is then not made
+		/// and the call succeeds with `FeeRetainedOnFailure`, since a failing call would revert
+		/// the fee along with it. If false the deposit fails and nothing is taken.
+		#[pallet::constant]
+		type KeepFeeOnFailedDeposit: Get<bool>;
+
+		/// Whether deposits can be submitted on behalf of their owner with a signed permit
+		#[pallet::constant]
+		type EnableDepositPermits: Get<bool>;
+
+		/// Max size in bytes of deposit data: amount, recipient and the optional extra section.
+		/// It bounds the data of deposits, forwarded transfers and inbound proposals, longer
+		/// payloads could exceed the limits of the dest domain.
+		#[pallet::constant]
+		type MaxDepositDataSize: Get<u32>;
+
+		/// Whether deposits and executions are indexed per account in `DepositHistory` and
+		/// `ExecutionHistory`, for front-ends without an indexer
+		#[pallet::constant]
+		type EnableAccountHistory: Get<bool>;
+
+		/// Whether the `Deposit` event carries the blake2_256 hash of the deposit data instead of
+		/// the data itself. The data is stored in `DepositDataOf` for relayers to fetch, which
+		/// keeps large payloads out of the block events.
+		#[pallet::constant]
+		type HashDepositData: Get<bool>;
+
+		/// Whether deposit data, outbound and inbound, starts with a data version byte and is
+		/// LZ4 compressed when that makes it smaller, see `sygma_codec::compress`. The domains
+		/// connected to the bridge must agree on it.
+		#[pallet::constant]
+		type CompressDepositData: Get<bool>;
+
+		/// Number of blocks the data of a deposit is kept in `DepositDataOf` before it can be
+		/// pruned
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type DepositDataRetention: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks the receipt of an executed proposal is kept in `ExecutionReceipts`
+		/// before it can be pruned
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type ExecutionReceiptRetention: Get<BlockNumberFor<Self>>;
+
+		/// Max number of entries kept per account in `DepositHistory` and `ExecutionHistory`,
+		/// the oldest entry is evicted when a new one is recorded
+		#[pallet::constant]
+		type MaxHistoryPerAccount: Get<u32>;
+
+		/// Number of failed auto retries after which a queued proposal is dropped from
+		/// `AutoRetryQueue`, it can still be executed by relayers
+		#[pallet::constant]
+		type MaxAutoRetryAttempts: Get<u32>;
+
+		/// Max number of queued proposals retried by `on_idle` in a block
+		#[pallet::constant]
+		type MaxAutoRetriesPerBlock: Get<u32>;
+
+		/// Max number of fee assets whose `FeeReserveAccount` balance is checked against
+		/// `FeeReserveThresholds` by `on_initialize` in a block, the next block carries on with
+		/// the following assets
+		#[pallet::constant]
+		type MaxFeeReserveChecksPerBlock: Get<u32>;
+
+		/// Resource id of the acknowledgments sent back to the origin domain of executed
+		/// proposals, handled by the generic message handler of that domain
+		#[pallet::constant]
+		type AckResourceId: Get<ResourceId>;
+
+		/// Asset the fee of an acknowledgment is paid in from `ack_fee_pot_account`, the amount
+		/// is the fee set for the origin domain
+		#[pallet::constant]
+		type AckFeeAsset: Get<AssetId>;
+
+		/// Max number of acknowledgments sent in a block, each of them consumes an outbound
+		/// deposit nonce of the origin domain
+		#[pallet::constant]
+		type MaxAcksPerBlock: Get<u32>;
+
+		/// Whether the deposits of each block are committed to a Merkle root stored in
+		/// `DepositRoots`, for dest domains verifying deposits with a light client of this chain
+		#[pallet::constant]
+		type EnableDepositRoots: Get<bool>;
+
+		/// Max number of assets moved by a `deposit_multi`, multi-asset deposits are disabled
+		/// if 0
+		#[pallet::constant]
+		type MaxAssetsPerDeposit: Get<u32>;
+
+		/// Resource id of multi-asset deposits, handled by the multi-asset handler of the dest
+		/// domain. The resource of each asset is carried by its line item in the deposit data.
+		#[pallet::constant]
+		type MultiAssetResourceId: Get<ResourceId>;
+
+		/// Whether the committee may cancel a pending settlement with `cancel_settlement`
+		/// before its release
+		#[pallet::constant]
+		type EnableSettlementCancellation: Get<bool>;
+
+		/// Whether the metadata of the deposited asset is attached to the deposit data, for the
+		/// dest domain to register an asset bridged for the first time
+		#[pallet::constant]
+		type AttachAssetMetadata: Get<bool>;
+
+		/// Metadata of the assets, attached to deposit data if `AttachAssetMetadata` is set
+		#[pallet::no_default]
+		type AssetMetadataProvider: AssetMetadataProvider;
+
+		/// Scale of the amounts of each resource to the common unit of `GlobalTvl`,
+		/// `GlobalTvlCap` and `AccountVolume`
+		#[pallet::no_default]
+		type AmountNormalizer: AmountNormalizer;
+
+		/// Router of the XCM notifying `DepositNotificationTarget` of the deposits
+		#[pallet::no_default]
+		type DepositNotifier: SendXcm;
+
+		/// Whether each deposit is notified to `DepositNotificationTarget`. A notification that
+		/// can not be sent does not fail the deposit. Transfers forwarded and acknowledgments sent
+		/// by the bridge while executing proposals are not notified: no user deposited them, and
+		/// the weight of `execute_proposal` does not cover sending an XCM per proposal.
+		#[pallet::constant]
+		type EnableDepositNotification: Get<bool>;
+
+		/// Location notified of the deposits and the call it dispatches
+		#[pallet::constant]
+		type DepositNotificationTarget: Get<NotificationTarget>;
+
+		/// Whether the recipient of deposit data is prefixed with its format, see
+		/// `RecipientFormat`. Proposals then carry a prefixed recipient as well.
+		#[pallet::constant]
+		type EnableRecipientFormat: Get<bool>;
+
+		/// Whether a proposal occurring more than once in a batch is only processed at its first
+		/// occurrence. The repeats are skipped rather than failed as `ProposalAlreadyComplete`.
+		#[pallet::constant]
+		type EnableBatchDeduplication: Get<bool>;
+
 		/// Return true if asset reserved on current chain
+		#[pallet::no_default]
 		type IsReserve: ContainsPair<MultiAsset, MultiLocation>;
 
 		/// Extract dest data from given MultiLocation
+		#[pallet::no_default]
 		type ExtractDestData: ExtractDestinationData;
 
 		/// Config ID for the current pallet instance
 		type PalletId: Get<PalletId>;
 
 		/// Current pallet index defined in runtime
+		#[pallet::no_default]
 		type PalletIndex: Get<u8>;
 
 		/// Asset decimal converter
+		#[pallet::no_default]
 		type DecimalConverter: DecimalConverter;
 
 		/// Type representing the weight of this pallet
@@ -138,27 +963,33 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// When initial bridge transfer send to dest domain
-		/// args: [dest_domain_id, resource_id, deposit_nonce, sender, transfer_type,
+		/// When initial bridge transfer send to dest domain, `deposit_data` is the blake2_256 hash
+		/// of the data stored in `DepositDataOf` if `HashDepositData` is set
+		/// args: [dest_domain_id, resource_id, deposit_nonce, transfer_id, sender, transfer_type,
 		/// deposit_data, handler_response, ]
 		Deposit {
 			dest_domain_id: DomainID,
 			resource_id: ResourceId,
 			deposit_nonce: DepositNonce,
+			transfer_id: [u8; 32],
 			sender: T::AccountId,
 			transfer_type: TransferType,
 			deposit_data: Vec<u8>,
 			handler_response: Vec<u8>,
 		},
-		/// When proposal was executed successfully
+		/// When proposal was executed successfully, `receipt_hash` is the blake2_256 hash of its
+		/// `ExecutionReceipt`, `None` if no receipt is kept, e.g. for an escrowed transfer
 		ProposalExecution {
 			origin_domain_id: DomainID,
 			deposit_nonce: DepositNonce,
 			data_hash: [u8; 32],
+			receipt_hash: Option<[u8; 32]>,
 		},
 		/// When proposal was faild to execute
+		/// args: [error, reason, origin_domain_id, deposit_nonce]
 		FailedHandlerExecution {
 			error: Vec<u8>,
+			reason: ExecutionFailureReason,
 			origin_domain_id: DomainID,
 			deposit_nonce: DepositNonce,
 		},
@@ -167,7 +998,7 @@ pub mod pallet {
 		Retry { deposit_on_block_height: u128, dest_domain_id: DomainID, sender: T::AccountId },
 		/// When bridge is paused
 		/// args: [dest_domain_id]
-		BridgePaused { dest_domain_id: DomainID },
+		BridgePaused { dest_domain_id: DomainID, reason: PauseReason, note: Option<PauseNote> },
 		/// When bridge is unpaused
 		/// args: [dest_domain_id]
 		BridgeUnpaused { dest_domain_id: DomainID },
@@ -185,75 +1016,608 @@ pub mod pallet {
 		},
 		/// When all bridges are paused
 		AllBridgePaused { sender: T::AccountId },
+		/// When MPC address is set
+		/// args: [mpc_addr]
+		MpcAddressSet { mpc_addr: MpcAddress },
 		/// When all bridges are unpaused
 		AllBridgeUnpaused { sender: T::AccountId },
-	}
-
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Account has not gained access permission
-		AccessDenied,
-		/// Protected operation, must be performed by relayer
-		BadMpcSignature,
-		/// Insufficient balance on sender account
-		InsufficientBalance,
-		/// Asset transactor execution failed
-		TransactFailed,
-		/// The withdrawn amount can not cover the fee payment
-		FeeTooExpensive,
-		/// MPC address not set
-		MissingMpcAddress,
-		/// MPC address can not be updated
-		MpcAddrNotUpdatable,
-		/// Bridge is paused
-		BridgePaused,
-		/// Bridge is unpaused
-		BridgeUnpaused,
-		/// Fee config option missing
-		MissingFeeConfig,
-		/// Asset not bound to a resource id
-		AssetNotBound,
-		/// Proposal has either failed or succeeded
-		ProposalAlreadyComplete,
-		/// Proposal list empty
-		EmptyProposalList,
-		/// Transactor operation failed
-		TransactorFailed,
-		/// Deposit data not correct
-		InvalidDepositData,
-		/// Dest domain not supported
-		DestDomainNotSupported,
-		/// Dest chain id not match
-		DestChainIDNotMatch,
-		/// Failed to extract destination data
-		ExtractDestDataFailed,
-		/// Failed on the decimal converter
-		DecimalConversionFail,
-		/// Deposit nonce has reached max integer value
-		DepositNonceOverflow,
-		/// Asset not bound to a liquidity holder account
-		NoLiquidityHolderAccountBound,
-		/// Function unimplemented
-		Unimplemented,
-	}
-
-	/// Deposit counter of dest domain
-	#[pallet::storage]
+		/// When the deposit nonce about to be assigned does not follow the last assigned one
+		/// args: [domain, expected, actual]
+		DepositNonceAnomaly { domain: DomainID, expected: DepositNonce, actual: DepositNonce },
+		/// When an inbound transfer can not be credited to its beneficiary and is escrowed
+		/// args: [origin_domain_id, deposit_nonce, beneficiary, asset_id, amount]
+		TransferEscrowed {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			beneficiary: MultiLocation,
+			asset_id: AssetId,
+			amount: u128,
+		},
+		/// When an escrowed transfer is credited to its beneficiary
+		/// args: [origin_domain_id, deposit_nonce, beneficiary]
+		EscrowedTransferClaimed {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			beneficiary: MultiLocation,
+		},
+		/// When an expired escrowed transfer is swept to the fee reserve account
+		/// args: [origin_domain_id, deposit_nonce]
+		EscrowedTransferSwept { origin_domain_id: DomainID, deposit_nonce: DepositNonce },
+		/// When a deposit nonce is reserved for a later `commit_deposit`
+		/// args: [dest_domain_id, deposit_nonce, owner, expires_at]
+		DepositNonceReserved {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			owner: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// When an expired nonce reservation is reclaimed, the nonce will never be deposited
+		/// args: [dest_domain_id, deposit_nonce]
+		NonceReservationReclaimed { dest_domain_id: DomainID, deposit_nonce: DepositNonce },
+		/// When a relayer is allowed to submit proposals
+		/// args: [relayer]
+		RelayerAdded { relayer: T::AccountId },
+		/// When a relayer is no longer allowed to submit proposals
+		/// args: [relayer]
+		RelayerRemoved { relayer: T::AccountId },
+		/// When MPC signers and their threshold are set
+		/// args: [signers, threshold]
+		MpcSignersSet { signers: Vec<MpcAddress>, threshold: u32 },
+		/// When deposit records older than `DepositRecordRetention` are pruned
+		/// args: [count]
+		DepositRecordsPruned { count: u32 },
+		/// When the sender of a deposit requests it to be refunded
+		/// args: [dest_domain_id, deposit_nonce, sender]
+		RefundRequested {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: T::AccountId,
+		},
+		/// When a deposit is refunded to its sender
+		/// args: [dest_domain_id, deposit_nonce, sender, asset_id, amount]
+		DepositRefunded {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: T::AccountId,
+			asset_id: AssetId,
+			amount: u128,
+		},
+		/// When a deposit is cancelled by its sender, relayers drop it
+		/// args: [dest_domain_id, deposit_nonce, sender, asset_id, amount]
+		DepositCancelled {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: T::AccountId,
+			asset_id: AssetId,
+			amount: u128,
+		},
+		/// When an inbound transfer routed through this chain is forwarded to its final domain
+		/// args: [origin_domain_id, deposit_nonce, dest_domain_id, forwarded_deposit_nonce]
+		TransferForwarded {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			dest_domain_id: DomainID,
+			forwarded_deposit_nonce: DepositNonce,
+		},
+		/// When the account reporting dest domain health is set
+		/// args: [oracle]
+		HealthOracleSet { oracle: Option<T::AccountId> },
+		/// When the reported health of a dest domain changes
+		/// args: [domain, healthy]
+		DomainHealthChanged { domain: DomainID, healthy: bool },
+		/// When the inbound limit of a resource is set or removed
+		/// args: [resource_id, limit]
+		InboundLimitSet { resource_id: ResourceId, limit: Option<InboundLimit> },
+		/// When a proposal would exceed the inbound window cap of a resource, proposals of the
+		/// resource are not executed until the window rolls or it is cleared
+		/// args: [resource_id, window_started_at]
+		InboundWindowExhausted { resource_id: ResourceId, window_started_at: BlockNumberFor<T> },
+		/// When the inbound window of a resource is cleared
+		/// args: [resource_id]
+		InboundWindowCleared { resource_id: ResourceId },
+		/// When the beneficiary of a proposal is credited less than the requested amount
+		/// args: [origin_domain_id, deposit_nonce, requested, credited]
+		PartialTransferCredited {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			requested: u128,
+			credited: u128,
+		},
+		/// When the fee discount of an account is set, `None` if it is removed
+		/// args: [account, discount]
+		FeeDiscountSet { account: T::AccountId, discount: Option<Permill> },
+		/// When an account prefunds its deposit fees
+		/// args: [account, asset_id, amount]
+		FeesPrefunded { account: T::AccountId, asset_id: AssetId, amount: u128 },
+		/// When a deposit fee is paid from the prefund balance
+		/// args: [account, asset_id, amount]
+		PrefundConsumed { account: T::AccountId, asset_id: AssetId, amount: u128 },
+		/// When an account withdraws its unused prefund balance
+		/// args: [account, asset_id, amount]
+		PrefundWithdrawn { account: T::AccountId, asset_id: AssetId, amount: u128 },
+		/// When the reserve account of a resource can not cover a proposal, emitted before the
+		/// proposal fails with `InsufficientReserve`
+		/// args: [resource_id, required, available]
+		ReserveShortfall { resource_id: ResourceId, required: u128, available: u128 },
+		/// When the auto retry of the failed proposals of a resource is enabled or disabled
+		/// args: [resource_id, enabled]
+		AutoRetrySet { resource_id: ResourceId, enabled: bool },
+		/// When a queued proposal is dropped after `MaxAutoRetryAttempts` failed auto retries
+		/// args: [resource_id, origin_domain_id, deposit_nonce]
+		AutoRetryAbandoned {
+			resource_id: ResourceId,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		},
+		/// When the deposit data format of a domain is set
+		/// args: [domain_id, format]
+		DataFormatSet { domain_id: DomainID, format: DataFormat },
+		/// When a resource is rebound to a new asset from block `effective_at`
+		/// args: [resource_id, old_asset, new_asset, effective_at]
+		ResourceRebound {
+			resource_id: ResourceId,
+			old_asset: AssetId,
+			new_asset: AssetId,
+			effective_at: BlockNumberFor<T>,
+		},
+		/// When the global TVL cap is set, `None` if it is removed
+		/// args: [cap]
+		GlobalTvlCapSet { cap: Option<u128> },
+		/// When a deposit of a signed transaction failed, emitted by the `ReportDepositFailure`
+		/// extension once the changes of the deposit are reverted
+		/// args: [sender, reason]
+		DepositFailed { sender: T::AccountId, reason: DepositFailureReason },
+		/// When a resource is onboarded, after the events of the individual steps
+		/// args: [resource_id, asset, decimals, mintable]
+		ResourceOnboarded { resource_id: ResourceId, asset: AssetId, decimals: u8, mintable: bool },
+		/// When an onboarded resource is offboarded, `reserve_balance` is left in its reserve
+		/// account, `None` if it can not be read
+		/// args: [resource_id, asset, reserve_balance]
+		ResourceOffboarded {
+			resource_id: ResourceId,
+			asset: AssetId,
+			reserve_balance: Option<u128>,
+		},
+		/// When the minimum reserve balance of a resource is set or removed
+		/// args: [resource_id, minimum]
+		MinReserveBalanceSet { resource_id: ResourceId, minimum: Option<u128> },
+		/// When deposit data older than `DepositDataRetention` is pruned
+		/// args: [count]
+		DepositDataPruned { count: u32 },
+		/// When all domains are paused by the chain-wide emergency stop
+		/// args: [paused_before]
+		EmergencyPauseEntered { paused_before: u32 },
+		/// When the pause states from before the emergency stop are restored
+		EmergencyPauseExited,
+		/// When the token bucket of a resource is set or removed, a new bucket starts full
+		/// args: [resource_id, config]
+		TokenBucketSet { resource_id: ResourceId, config: Option<TokenBucketConfig> },
+		/// When the fee reserve threshold of an asset is set or removed
+		/// args: [asset_id, threshold]
+		FeeReserveThresholdSet { asset_id: AssetId, threshold: Option<u128> },
+		/// When the balance of `FeeReserveAccount` in a fee asset is found below its threshold,
+		/// it's emitted again only after the balance recovered
+		/// args: [asset_id, balance]
+		FeeReserveLow { asset_id: AssetId, balance: u128 },
+		/// When acknowledgments of the proposals from a domain are enabled or disabled
+		/// args: [domain_id, enabled]
+		AckEnabledSet { domain_id: DomainID, enabled: bool },
+		/// When all acknowledgments are halted or resumed
+		/// args: [halted]
+		AcksHaltedSet { halted: bool },
+		/// When the acknowledgment of an executed proposal is deposited back to its origin
+		/// domain with `ack_nonce`
+		/// args: [origin_domain_id, deposit_nonce, ack_nonce, status]
+		AckSent {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			ack_nonce: DepositNonce,
+			status: AckStatus,
+		},
+		/// When the acknowledgment of an executed proposal can not be sent, the proposal stays
+		/// executed
+		/// args: [origin_domain_id, deposit_nonce, error]
+		AckFailed { origin_domain_id: DomainID, deposit_nonce: DepositNonce, error: Vec<u8> },
+		/// When the conflicting bindings of a resource are resolved, it's bound to `asset`
+		/// args: [resource_id, kept, asset]
+		BindingConflictResolved { resource_id: ResourceId, kept: BindingSource, asset: AssetId },
+		/// When execution receipts older than `ExecutionReceiptRetention` are pruned
+		/// args: [count]
+		ExecutionReceiptsPruned { count: u32 },
+		/// When the deposited amount can not be withdrawn from the sender and the fee paid from
+		/// the prefund balance is kept, no deposit is made
+		/// args: [account, dest_domain_id, asset_id, fee]
+		FeeRetainedOnFailure {
+			account: T::AccountId,
+			dest_domain_id: DomainID,
+			asset_id: AssetId,
+			fee: u128,
+		},
+		/// When a recipient is added to the denylist
+		/// args: [recipient]
+		RecipientDenylisted { recipient: Vec<u8> },
+		/// When a recipient is removed from the denylist
+		/// args: [recipient]
+		RecipientDenylistRemoved { recipient: Vec<u8> },
+		/// When a deposit is submitted with a permit of its owner
+		/// args: [owner, nonce, submitter]
+		DepositPermitUsed { owner: T::AccountId, nonce: u64, submitter: T::AccountId },
+		/// When a single direction of a domain is paused
+		/// args: [direction, domain_id]
+		RoutePaused { direction: RouteDirection, domain_id: DomainID },
+		/// When a single direction of a domain is unpaused
+		/// args: [direction, domain_id]
+		RouteUnpaused { direction: RouteDirection, domain_id: DomainID },
+		/// When the sunset of a domain is scheduled
+		/// args: [domain_id, deposit_cutoff_block]
+		DomainSunsetScheduled { domain_id: DomainID, deposit_cutoff_block: BlockNumberFor<T> },
+		/// When the deposit cutoff block of a domain is reached
+		/// args: [domain_id]
+		DomainSunset { domain_id: DomainID },
+		/// When an emergency withdrawal is scheduled by the committee
+		/// args: [from, asset_id, amount, to, executable_at]
+		EmergencyWithdrawalScheduled {
+			from: BridgeAccount,
+			asset_id: AssetId,
+			amount: u128,
+			to: T::AccountId,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// When funds are moved out of a bridge account by an emergency withdrawal
+		/// args: [from, asset_id, amount, to]
+		EmergencyWithdrawal {
+			from: BridgeAccount,
+			asset_id: AssetId,
+			amount: u128,
+			to: T::AccountId,
+		},
+		/// When the decimals of a resource on a domain, or its default decimals if `domain_id`
+		/// is `None`, are set or removed
+		/// args: [resource_id, domain_id, decimals]
+		ResourceDecimalsSet {
+			resource_id: ResourceId,
+			domain_id: Option<DomainID>,
+			decimals: Option<u8>,
+		},
+		/// When the settlement delay of a resource is set or removed
+		/// args: [resource_id, delay]
+		SettlementDelaySet { resource_id: ResourceId, delay: Option<BlockNumberFor<T>> },
+		/// When a proposal of a resource with a settlement delay is recorded, to be finalized
+		/// from `release_at`
+		/// args: [origin_domain_id, deposit_nonce, resource_id, release_at]
+		SettlementScheduled {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			resource_id: ResourceId,
+			release_at: BlockNumberFor<T>,
+		},
+		/// When the bridge is frozen, `snapshot_hash` is the blake2_256 hash of the encoded
+		/// `FreezeSnapshot`
+		/// args: [sender, snapshot_hash]
+		BridgeFrozen { sender: T::AccountId, snapshot_hash: [u8; 32] },
+		/// When the snapshot of a freeze is cleared
+		FreezeSnapshotCleared,
+		/// When a pending settlement is cancelled by the committee, its nonce is consumed
+		/// args: [origin_domain_id, deposit_nonce, resource_id]
+		SettlementCancelled {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			resource_id: ResourceId,
+		},
+		/// When a proposal is not executed as the amount to credit disagrees with its source
+		/// amount hint. `expected_amount` is the hint converted to the local decimals, `None`
+		/// if it can't be converted.
+		/// args: [origin_domain_id, deposit_nonce, hinted_amount, hinted_decimals,
+		/// expected_amount, credited_amount]
+		DecimalMismatch {
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			hinted_amount: u128,
+			hinted_decimals: u8,
+			expected_amount: Option<u128>,
+			credited_amount: u128,
+		},
+		/// When an unregistered domain is registered back, it's paused until unpaused explicitly
+		/// args: [sender, domain_id, chain_id]
+		DomainReregistered { sender: T::AccountId, domain_id: DomainID, chain_id: ChainID },
+		/// When the chain ID of a domain is changed
+		/// args: [domain_id, old_chain_id, chain_id]
+		DomainChainIdUpdated { domain_id: DomainID, old_chain_id: ChainID, chain_id: ChainID },
+		/// When the inbound cap of a recipient of a resource is set, `None` if it is removed
+		/// args: [resource_id, recipient, cap]
+		RecipientInboundCapSet { resource_id: ResourceId, recipient: Vec<u8>, cap: Option<u128> },
+		/// When the XCM notifying a deposit can not be sent, the deposit goes on
+		/// args: [dest_domain_id, deposit_nonce, error]
+		DepositNotificationFailed {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			error: SendError,
+		},
+		/// When the MPC address is corrected within its grace period
+		/// args: [old_mpc_addr, mpc_addr]
+		MpcKeyCorrected { old_mpc_addr: MpcAddress, mpc_addr: MpcAddress },
+		/// When the fee vesting schedule of a resource is set
+		/// args: [resource_id, beneficiary, per_block]
+		FeeVestingSet { resource_id: ResourceId, beneficiary: T::AccountId, per_block: u128 },
+		/// When the vested fees of a resource are released to its beneficiary
+		/// args: [resource_id, beneficiary, amount]
+		VestedFeesReleased { resource_id: ResourceId, beneficiary: T::AccountId, amount: u128 },
+		/// When a proposal occurs again in the batch it's executed in, the repeat is skipped
+		/// args: [origin_domain_id, deposit_nonce]
+		DuplicateProposalSkipped { origin_domain_id: DomainID, deposit_nonce: DepositNonce },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account has not gained access permission
+		AccessDenied,
+		/// Protected operation, must be performed by relayer
+		BadMpcSignature,
+		/// Insufficient balance on sender account
+		InsufficientBalance,
+		/// Asset transactor execution failed
+		TransactFailed,
+		/// The withdrawn amount can not cover the fee payment
+		FeeTooExpensive,
+		/// MPC address not set
+		MissingMpcAddress,
+		/// MPC address can not be updated
+		MpcAddrNotUpdatable,
+		/// Bridge is paused
+		BridgePaused,
+		/// Bridge is unpaused
+		BridgeUnpaused,
+		/// Fee config option missing
+		MissingFeeConfig,
+		/// Asset not bound to a resource id
+		AssetNotBound,
+		/// Proposal has either failed or succeeded
+		ProposalAlreadyComplete,
+		/// Proposal list empty
+		EmptyProposalList,
+		/// Transactor operation failed
+		TransactorFailed,
+		/// Deposit data not correct
+		InvalidDepositData,
+		/// Dest domain not supported
+		DestDomainNotSupported,
+		/// Dest chain id not match
+		DestChainIDNotMatch,
+		/// Failed to extract destination data
+		ExtractDestDataFailed,
+		/// Failed on the decimal converter
+		DecimalConversionFail,
+		/// Deposit nonce has reached max integer value
+		DepositNonceOverflow,
+		/// Asset not bound to a liquidity holder account
+		NoLiquidityHolderAccountBound,
+		/// No escrowed transfer for the given origin domain and deposit nonce
+		EscrowedTransferNotFound,
+		/// Escrowed transfer is still within its retention period
+		EscrowRetentionNotExpired,
+		/// Deposit nonce not reserved by the caller
+		NonceNotReserved,
+		/// Deposit nonce reservation has expired
+		NonceReservationExpired,
+		/// Deposit nonce reservation has not expired yet
+		NonceReservationNotExpired,
+		/// Proposals not submitted by an allowed relayer
+		RelayerNotAllowed,
+		/// Threshold is zero, exceeds the number of MPC signers or signers are duplicated
+		InvalidMpcThreshold,
+		/// Number of MPC signers exceeds `MaxMpcSigners`
+		TooManyMpcSigners,
+		/// No deposit record for the given dest domain and deposit nonce, it may have been pruned
+		DepositRecordNotFound,
+		/// Deposit not sent by the caller
+		NotDepositSender,
+		/// Refund of the deposit already requested
+		RefundAlreadyRequested,
+		/// Refund of the deposit not requested by its sender
+		RefundNotRequested,
+		/// No deposit record is old enough to be pruned, or the oldest one has a pending refund
+		NoDepositRecordsToPrune,
+		/// The dest `MultiLocation` does not have the expected `parents`, or its interior
+		/// junctions are not in the expected order
+		InvalidDestination,
+		/// Routed transfer goes back to its origin or intermediate domain
+		InvalidRoute,
+		/// Caller is not the health oracle
+		NotHealthOracle,
+		/// Dest domain reported unhealthy by the health oracle
+		DomainUnhealthy,
+		/// Reserve account can not cover the transfer
+		InsufficientReserve,
+		/// Recipient of the proposal can not be credited
+		InvalidRecipient,
+		/// Amount is below the existential deposit of the escrow holding account
+		BelowExistentialDeposit,
+		/// Amount exceeds the max single inbound execution of the resource
+		InboundSingleCapExceeded,
+		/// Inbound window cap of the resource is exhausted
+		InboundWindowExhausted,
+		/// Inbound limit has a zero cap or a single cap above the window cap
+		InvalidInboundLimit,
+		/// Deposit is older than `MaxRetryAge`
+		DepositTooOld,
+		/// Beneficiary is credited less than the requested amount
+		PartialTransferNotAllowed,
+		/// Pause can only be cleared by the bridge committee origin
+		CommitteeOriginRequired,
+		/// Prefund balance is lower than the amount to withdraw
+		InsufficientPrefund,
+		/// Deposit data exceeds `MaxDepositDataSize`
+		DepositDataTooLarge,
+		/// Asset is already bound to a resource
+		AssetAlreadyBound,
+		/// Rebind would take effect before the current block
+		RebindInThePast,
+		/// Previous rebind of the resource is not effective yet
+		RebindPending,
+		/// Failed proposals of the resource must be retried before it's rebound
+		FailedProposalsPending,
+		/// Deposit data format of the domain is not configured
+		UnsupportedDataFormat,
+		/// Deposit would push the value locked by the bridge over `GlobalTvlCap`
+		TvlCapExceeded,
+		/// Resource is already bound to an asset
+		ResourceAlreadyBound,
+		/// Resource was not onboarded by `onboard_resource`
+		ResourceNotOnboarded,
+		/// Reserve of the resource is not drained and the offboarding does not acknowledge it
+		ReserveNotDrained,
+		/// Assets of mintable resources can not be created
+		MintableResourceNotSupported,
+		/// Withdrawal would drop the reserve of the resource below `MinReserveBalance`
+		ReserveBelowMinimum,
+		/// None of the given deposit data is stored or old enough to be pruned
+		NoDepositDataToPrune,
+		/// Deposit takes more tokens than the token bucket of the resource holds
+		RateLimitExceeded,
+		/// Token bucket has a zero capacity
+		InvalidTokenBucket,
+		/// `MaxAcksPerBlock` acknowledgments are already sent in this block
+		AckRateLimited,
+		/// `T::ResourcePairs` and the registered resources agree on the binding of the resource
+		NoBindingConflict,
+		/// Resource has no binding from the source to keep
+		BindingSourceMissing,
+		/// None of the given execution receipts is stored or old enough to be pruned
+		NoExecutionReceiptToPrune,
+		/// Recipient of the proposal is in `RecipientDenylist`
+		RecipientDenied,
+		/// Recipient is longer than `MAX_RECIPIENT_LEN`
+		RecipientTooLong,
+		/// Recipient is not in `RecipientDenylist`
+		RecipientNotDenied,
+		/// `EnableDepositPermits` is not set
+		DepositPermitsDisabled,
+		/// Permit is not signed by its owner
+		InvalidPermitSignature,
+		/// Permit nonce is not the next nonce of its owner, e.g. the permit is replayed
+		InvalidPermitNonce,
+		/// Permit deadline has passed
+		PermitExpired,
+		/// Assets of a multi-asset deposit are missing or a resource appears more than once
+		InvalidAssetList,
+		/// Multi-asset deposit has more than `MaxAssetsPerDeposit` assets
+		TooManyAssets,
+		/// Deposit cutoff block of the dest domain is reached
+		DomainSunset,
+		/// `CancellationWindow` of the deposit has elapsed
+		CancellationWindowElapsed,
+		/// Deposit is cancelled by its sender
+		DepositAlreadyCancelled,
+		/// Deposit cutoff block is not in the future
+		InvalidSunsetBlock,
+		/// Proposals from the domain are executed behind the deposits made to it, unregister it
+		/// with `force` to drop them
+		DomainBacklogNotDrained,
+		/// Some route of a domain is not paused, emergency withdrawals and MPC key corrections
+		/// require every domain to be paused in both directions
+		BridgeNotHalted,
+		/// Emergency withdrawal is scheduled and its delay has not elapsed yet
+		EmergencyWithdrawalTimelocked,
+		/// Amounts in these decimals can not be converted from or to 18 decimals
+		InvalidDecimals,
+		/// Proposal is already recorded for the settlement delay of its resource
+		SettlementPending,
+		/// No settlement is pending for the proposal
+		SettlementNotFound,
+		/// Release block of the settlement is not reached yet
+		SettlementDelayNotElapsed,
+		/// Snapshot of the previous freeze must be cleared first
+		FreezeSnapshotExists,
+		/// No freeze snapshot is stored
+		FreezeSnapshotNotFound,
+		/// More resources or domains are registered than the freeze was weighed for
+		FreezeWitnessTooLow,
+		/// More resources are registered than a freeze snapshot holds
+		TooManyResourcesToSnapshot,
+		/// Cancellation of pending settlements is disabled by `EnableSettlementCancellation`
+		SettlementCancellationDisabled,
+		/// Amount to credit disagrees with the source amount hint of the deposit data
+		DecimalMismatch,
+		/// Name or symbol of the asset metadata to attach to deposit data is too long
+		AssetMetadataTooLarge,
+		/// `execute_proposal` was reentered while executing a batch
+		ReentrancyDetected,
+		/// The bitmap of proposals expected fresh does not cover the batch
+		InvalidFreshBitmap,
+		/// Every proposal expected fresh is already executed
+		ProposalsAlreadyExecuted,
+		/// Proposal would push the amount received by its recipient over `RecipientInboundCap`
+		RecipientCapExceeded,
+		/// Proposal credits the fee reserve, a transfer reserve or a bridge account
+		ProtectedAccountRecipient,
+		/// Fee reserve or a transfer reserve is one of the bridge accounts
+		ProtocolAccountConflict,
+		/// MPC address is past its grace period or was corrected already
+		MpcKeyNotCorrectable,
+		/// A proposal was executed under the MPC address
+		MpcKeyInUse,
+		/// Fees can not vest at a rate of zero
+		InvalidVestingRate,
+		/// Resource has no fee vesting schedule
+		NoFeeVesting,
+		/// No fee of the resource vested since the last release
+		NoVestedFees,
+		/// Recipients are not prefixed with their format
+		RecipientFormatDisabled,
+		/// Format of the recipient can not be told from the dest
+		UnknownRecipientFormat,
+		/// Recipient format not handled by the domain it's credited on
+		RecipientFormatMismatch,
+		/// Block height of the deposit to retry is in the future
+		InvalidRetryHeight,
+		/// Fees of another asset than the one the resource is bound to are still vesting
+		VestingAssetMismatch,
+		/// Function unimplemented
+		Unimplemented,
+	}
+
+	/// Deposit counter of dest domain
+	#[pallet::storage]
 	#[pallet::getter(fn deposit_counts)]
 	pub type DepositCounts<T> = StorageMap<_, Twox64Concat, DomainID, DepositNonce, ValueQuery>;
 
-	/// Bridge Pause indicator
+	/// Last deposit nonce assigned to a deposit of dest domain, used to detect gaps in
+	/// `DepositCounts`
+	#[pallet::storage]
+	#[pallet::getter(fn last_deposit_nonces)]
+	pub type LastDepositNonces<T> = StorageMap<_, Twox64Concat, DomainID, DepositNonce>;
+
+	/// Bridge Pause indicator, per direction of a domain: a paused `Inbound` route stops the
+	/// proposals from the domain, a paused `Outbound` route the deposits to it.
 	/// Bridge is unpaused initially, until pause
 	/// After mpc address setup, bridge should be paused until ready to unpause
 	#[pallet::storage]
-	#[pallet::getter(fn is_paused)]
-	pub type IsPaused<T> = StorageMap<_, Twox64Concat, DomainID, bool, ValueQuery>;
+	pub type PausedRoutes<T> =
+		StorageMap<_, Twox64Concat, (RouteDirection, DomainID), bool, ValueQuery>;
 
 	/// Pre-set MPC address
 	#[pallet::storage]
 	#[pallet::getter(fn mpc_addr)]
 	pub type MpcAddr<T> = StorageValue<_, MpcAddress, ValueQuery>;
 
+	/// Last `MaxPauseHistory` pause and unpause actions of a domain, oldest first, each with
+	/// whether the domain is paused after the action and the block of the action
+	#[pallet::storage]
+	#[pallet::getter(fn pause_history)]
+	pub type PauseHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		BoundedVec<(bool, BlockNumberFor<T>), T::MaxPauseHistory>,
+		ValueQuery,
+	>;
+
+	/// Last block the MPC address can be corrected at with `correct_mpc_key`, removed by the
+	/// correction or once a route is unpaused
+	#[pallet::storage]
+	#[pallet::getter(fn mpc_key_grace_end)]
+	pub type MpcKeyGraceEnd<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
 	/// Mark whether a deposit nonce was used. Used to mark execution status of a proposal.
 	#[pallet::storage]
 	#[pallet::getter(fn used_nonces)]
@@ -267,6 +1631,23 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Number of `UsedNonces` entries of an origin domain
+	#[pallet::storage]
+	#[pallet::getter(fn used_nonces_count)]
+	pub type UsedNoncesCount<T> = StorageMap<_, Twox64Concat, DomainID, u32, ValueQuery>;
+
+	/// High-water mark of pruned `UsedNonces` of an origin domain, every nonce below it has been
+	/// executed
+	#[pallet::storage]
+	#[pallet::getter(fn min_unpruned_nonce)]
+	pub type MinUnprunedNonce<T> = StorageMap<_, Twox64Concat, DomainID, DepositNonce, ValueQuery>;
+
+	/// Highest consumed nonce of an origin domain, the proposals of the domain are executed or
+	/// invalidated up to it
+	#[pallet::storage]
+	#[pallet::getter(fn highest_executed_nonce)]
+	pub type HighestExecutedNonce<T> = StorageMap<_, Twox64Concat, DomainID, DepositNonce>;
+
 	/// Mark supported dest domainID
 	#[pallet::storage]
 	#[pallet::getter(fn dest_domain_ids)]
@@ -279,1191 +1660,14935 @@ pub mod pallet {
 	#[pallet::getter(fn dest_chain_ids)]
 	pub type DestChainIds<T: Config> = StorageMap<_, Twox64Concat, DomainID, ChainID>;
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T>
-	where
-		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
-	{
-		/// Pause bridge, this would lead to bridge transfer failure before it being unpaused.
-		#[pallet::call_index(0)]
-		#[pallet::weight(< T as Config >::WeightInfo::pause_bridge())]
-		pub fn pause_bridge(origin: OriginFor<T>, dest_domain_id: DomainID) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					origin
-				),
-				Error::<T>::AccessDenied
-			);
-			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
+	/// ChainID of the unregistered domains, a domain is registered back with the same chainID
+	/// unless it's changed by `update_domain_chain_id`
+	#[pallet::storage]
+	#[pallet::getter(fn unregistered_chain_ids)]
+	pub type UnregisteredChainIds<T: Config> = StorageMap<_, Twox64Concat, DomainID, ChainID>;
 
-			// Mark as paused
-			IsPaused::<T>::insert(dest_domain_id, true);
+	/// Inbound transfers held in escrow, keyed by (origin_domain_id, deposit_nonce)
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_transfers)]
+	pub type UnclaimedTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		EscrowedTransfer<BlockNumberFor<T>>,
+	>;
 
-			// Emit BridgePause event
-			Self::deposit_event(Event::BridgePaused { dest_domain_id });
-			Ok(())
-		}
+	/// Deposit nonces reserved by `reserve_nonce` and not committed yet, mapping to the owner of
+	/// the reservation and the block it expires at
+	#[pallet::storage]
+	#[pallet::getter(fn nonce_reservations)]
+	pub type NonceReservations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		(T::AccountId, BlockNumberFor<T>),
+	>;
 
-		/// Unpause bridge.
-		#[pallet::call_index(1)]
-		#[pallet::weight(< T as Config >::WeightInfo::unpause_bridge())]
-		pub fn unpause_bridge(origin: OriginFor<T>, dest_domain_id: DomainID) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"unpause_bridge".to_vec(),
-					origin
-				),
-				Error::<T>::AccessDenied
-			);
-			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
+	/// Relayers allowed to submit proposals if `RequireRelayerAllowlist` is enabled
+	#[pallet::storage]
+	#[pallet::getter(fn relayers)]
+	pub type Relayers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
 
-			// make sure the current status is paused
-			ensure!(IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgeUnpaused);
+	/// MPC signers of proposals if `RequireMpcThreshold` is enabled
+	#[pallet::storage]
+	#[pallet::getter(fn mpc_signers)]
+	pub type MpcSigners<T: Config> =
+		StorageValue<_, BoundedVec<MpcAddress, T::MaxMpcSigners>, ValueQuery>;
 
-			// Mark as unpaused
-			IsPaused::<T>::insert(dest_domain_id, false);
+	/// Number of distinct `MpcSigners` signatures required by a batch of proposals
+	#[pallet::storage]
+	#[pallet::getter(fn mpc_threshold)]
+	pub type MpcThreshold<T> = StorageValue<_, u32, ValueQuery>;
 
-			// Emit BridgeUnpause event
-			Self::deposit_event(Event::BridgeUnpaused { dest_domain_id });
-			Ok(())
-		}
+	/// Results of the MPC signature verifications of the current block if
+	/// `EnableSignatureCache` is set, keyed by the hash of the signing message, the signature and
+	/// the expected signers. It's cleared in `on_finalize`, so a cached result is always the one
+	/// verifying again would return.
+	#[pallet::storage]
+	pub type VerifiedSignatures<T> = StorageMap<_, Identity, [u8; 32], bool>;
 
-		/// Mark an ECDSA address as a MPC account.
-		#[pallet::call_index(2)]
-		#[pallet::weight(< T as Config >::WeightInfo::set_mpc_address())]
-		pub fn set_mpc_address(origin: OriginFor<T>, addr: MpcAddress) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"set_mpc_address".to_vec(),
-					origin
-				),
-				Error::<T>::AccessDenied
-			);
-			// Cannot set MPC address as it's already set
-			ensure!(MpcAddr::<T>::get().is_clear(), Error::<T>::MpcAddrNotUpdatable);
+	/// Outbound transfers that can still be refunded, keyed by (dest_domain_id, deposit_nonce)
+	#[pallet::storage]
+	pub type DepositRecords<T: Config> = CountedStorageMap<
+		_,
+		Twox64Concat,
+		(DomainID, DepositNonce),
+		DepositRecord<T::AccountId, BlockNumberFor<T>>,
+	>;
 
-			// Set MPC account address
-			MpcAddr::<T>::set(addr);
+	/// Keys of `DepositRecords` in insertion order, pruning starts from
+	/// `DepositRecordQueueHead`
+	#[pallet::storage]
+	pub type DepositRecordQueue<T> = StorageMap<_, Twox64Concat, u64, (DomainID, DepositNonce)>;
 
-			// unpause bridge
-			Self::unpause_all_domains();
+	/// Position of the oldest entry of `DepositRecordQueue`
+	#[pallet::storage]
+	pub type DepositRecordQueueHead<T> = StorageValue<_, u64, ValueQuery>;
 
-			Ok(())
-		}
+	/// Position of the next entry of `DepositRecordQueue`
+	#[pallet::storage]
+	pub type DepositRecordQueueTail<T> = StorageValue<_, u64, ValueQuery>;
 
-		/// Mark the give dest domainID with chainID to be enabled
-		#[pallet::call_index(3)]
-		#[pallet::weight(< T as Config >::WeightInfo::register_domain())]
-		pub fn register_domain(
-			origin: OriginFor<T>,
-			dest_domain_id: DomainID,
-			dest_chain_id: ChainID,
-		) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"register_domain".to_vec(),
-					origin.clone()
-				),
-				Error::<T>::AccessDenied
-			);
+	/// blake2_256 hash of the `deposit_data` of the `Deposit` event of the deposits of
+	/// `DepositRecords`, removed along with their record
+	#[pallet::storage]
+	pub type DepositDataHashes<T> = StorageMap<_, Twox64Concat, (DomainID, DepositNonce), [u8; 32]>;
 
-			DestDomainIds::<T>::insert(dest_domain_id, true);
-			DestChainIds::<T>::insert(dest_domain_id, dest_chain_id);
+	/// Block at which deposits were cancelled, keyed by (dest_domain_id, deposit_nonce). Kept
+	/// after their records are pruned, so that a cancelled deposit executed anyway on the dest
+	/// domain can be reconciled.
+	#[pallet::storage]
+	#[pallet::getter(fn cancelled_deposits)]
+	pub type CancelledDeposits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DomainID, Twox64Concat, DepositNonce, BlockNumberFor<T>>;
 
-			// Emit register dest domain event
-			let sender = match ensure_signed(origin) {
-				Ok(sender) => sender,
-				_ => [0u8; 32].into(),
-			};
-			Self::deposit_event(Event::RegisterDestDomain {
-				sender,
-				domain_id: dest_domain_id,
-				chain_id: dest_chain_id,
-			});
-			Ok(())
-		}
+	/// Emergency withdrawals scheduled by `emergency_withdraw`, keyed by the blake2_256 of the
+	/// encoded (from, asset, amount, to), with the block they can be executed from
+	#[pallet::storage]
+	#[pallet::getter(fn emergency_withdrawals)]
+	pub type EmergencyWithdrawals<T: Config> = StorageMap<_, Identity, [u8; 32], BlockNumberFor<T>>;
 
-		/// Mark the give dest domainID with chainID to be disabled
-		#[pallet::call_index(4)]
-		#[pallet::weight(< T as Config >::WeightInfo::unregister_domain())]
-		pub fn unregister_domain(
-			origin: OriginFor<T>,
-			dest_domain_id: DomainID,
-			dest_chain_id: ChainID,
-		) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"unregister_domain".to_vec(),
-					origin.clone()
-				),
-				Error::<T>::AccessDenied
-			);
-			ensure!(
-				DestDomainIds::<T>::get(dest_domain_id)
-					&& DestChainIds::<T>::get(dest_domain_id).is_some(),
-				Error::<T>::DestDomainNotSupported
-			);
+	/// Account allowed to report dest domain health
+	#[pallet::storage]
+	#[pallet::getter(fn health_oracle)]
+	pub type HealthOracle<T: Config> = StorageValue<_, T::AccountId>;
 
-			let co_chain_id = DestChainIds::<T>::get(dest_domain_id).unwrap();
-			ensure!(co_chain_id == dest_chain_id, Error::<T>::DestChainIDNotMatch);
+	/// Dest domain health reported by `HealthOracle`, deposits to a domain reported unhealthy
+	/// are rejected until it is reported healthy again
+	#[pallet::storage]
+	#[pallet::getter(fn domain_health)]
+	pub type DomainHealth<T> = StorageMap<_, Twox64Concat, DomainID, bool>;
 
-			DestDomainIds::<T>::remove(dest_domain_id);
-			DestChainIds::<T>::remove(dest_domain_id);
+	/// Block from which deposits to the domain are rejected, ahead of its unregistration
+	#[pallet::storage]
+	#[pallet::getter(fn domain_sunsets)]
+	pub type DomainSunsets<T: Config> = StorageMap<_, Twox64Concat, DomainID, BlockNumberFor<T>>;
 
-			// Emit unregister dest domain event
-			let sender = match ensure_signed(origin) {
-				Ok(sender) => sender,
-				_ => [0u8; 32].into(),
-			};
-			Self::deposit_event(Event::UnregisterDestDomain {
-				sender,
-				domain_id: dest_domain_id,
-				chain_id: dest_chain_id,
-			});
-			Ok(())
-		}
+	/// Proposals that failed to execute, keyed by (origin_domain_id, deposit_nonce). Only
+	/// proposals that can still be retried are kept, an entry is removed once the nonce is
+	/// consumed.
+	#[pallet::storage]
+	#[pallet::getter(fn failed_proposals)]
+	pub type FailedProposals<T> =
+		StorageDoubleMap<_, Twox64Concat, DomainID, Twox64Concat, DepositNonce, FailedProposal>;
 
-		/// Initiates a transfer.
-		#[transactional]
-		#[pallet::call_index(5)]
-		#[pallet::weight(< T as Config >::WeightInfo::deposit())]
-		pub fn deposit(
-			origin: OriginFor<T>,
-			asset: Box<MultiAsset>,
-			dest: Box<MultiLocation>,
-		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
+	/// Inbound limit of resource, proposals of a resource without limit are not capped
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_limits)]
+	pub type InboundLimits<T> = StorageMap<_, Twox64Concat, ResourceId, InboundLimit>;
 
-			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+	/// Current inbound window of resource
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_windows)]
+	pub type InboundWindows<T: Config> =
+		StorageMap<_, Twox64Concat, ResourceId, InboundWindow<BlockNumberFor<T>>>;
 
-			// Extract dest (MultiLocation) to get corresponding dest domainID and Ethereum
-			// recipient address
-			let (recipient, dest_domain_id) =
-				T::ExtractDestData::extract_dest(&dest).ok_or(Error::<T>::ExtractDestDataFailed)?;
+	/// Token bucket configuration of resource, deposits are not throttled without one
+	#[pallet::storage]
+	#[pallet::getter(fn token_bucket_configs)]
+	pub type TokenBucketConfigs<T> = StorageMap<_, Twox64Concat, ResourceId, TokenBucketConfig>;
 
-			ensure!(!IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgePaused);
+	/// Tokens left in the bucket of resource and the block they were last refilled at
+	#[pallet::storage]
+	#[pallet::getter(fn token_bucket)]
+	pub type TokenBucket<T: Config> =
+		StorageMap<_, Twox64Concat, ResourceId, (u128, BlockNumberFor<T>)>;
 
-			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
+	/// Amount requested by a proposal but not credited to its beneficiary, keyed by
+	/// (origin_domain_id, deposit_nonce), in the decimals of the local asset
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_shortfalls)]
+	pub type TransferShortfalls<T> =
+		StorageDoubleMap<_, Twox64Concat, DomainID, Twox64Concat, DepositNonce, u128>;
 
-			// Extract asset (MultiAsset) to get corresponding ResourceId, transfer amount and the
-			// transfer type
-			let (resource_id, amount, transfer_type) =
-				Self::extract_asset(&asset.clone()).ok_or(Error::<T>::AssetNotBound)?;
-			// Return error if no fee handler set
-			let fee = T::FeeHandler::get_fee(dest_domain_id, *asset.clone())
-				.ok_or(Error::<T>::MissingFeeConfig)?;
+	/// Reason of the pause of a paused domain. A paused domain without entry was paused before
+	/// reasons were recorded and is cleared like a governance pause.
+	#[pallet::storage]
+	#[pallet::getter(fn pause_reasons)]
+	pub type PauseReasons<T> = StorageMap<_, Twox64Concat, DomainID, PauseInfo>;
 
-			ensure!(amount > fee, Error::<T>::FeeTooExpensive);
+	/// Routes paused before `pause_everything` along with the pause info of their domain,
+	/// restored by `restore_from_snapshot`. Set while the bridge is in the emergency stop.
+	#[pallet::storage]
+	#[pallet::getter(fn pre_pause_snapshot)]
+	pub type PrePauseSnapshot<T> =
+		StorageValue<_, BoundedVec<((RouteDirection, DomainID), PauseInfo), ConstU32<512>>>;
 
-			// Withdraw `amount` of asset from sender
-			T::AssetTransactor::withdraw_asset(
-				&asset,
-				&Junction::AccountId32 { network: None, id: sender.clone().into() }.into(),
-				None,
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
+	/// State of the bridge captured by the last `emergency_freeze`, kept until
+	/// `clear_freeze_snapshot`
+	#[pallet::storage]
+	#[pallet::getter(fn freeze_snapshot)]
+	pub type FreezeSnapshot<T: Config> = StorageValue<_, BridgeFreezeSnapshot<BlockNumberFor<T>>>;
 
-			// Deposit `fee` of asset to treasury account
-			T::AssetTransactor::deposit_asset(
-				&(asset.id, Fungible(fee)).into(),
-				&Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
-					.into(),
-				// Put empty message hash here because we are not sending XCM message
-				&XcmContext::with_message_id([0; 32]),
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
+	/// Balance of `FeeReserveAccount` in a fee asset below which `FeeReserveLow` is emitted
+	#[pallet::storage]
+	#[pallet::getter(fn fee_reserve_thresholds)]
+	pub type FeeReserveThresholds<T> = StorageMap<_, Twox64Concat, AssetId, u128>;
 
-			let bridge_amount = amount - fee;
+	/// Fee assets whose reserve was found below its threshold and already reported
+	#[pallet::storage]
+	pub type FeeReserveLowAssets<T> = StorageMap<_, Twox64Concat, AssetId, bool, ValueQuery>;
 
-			let token_reserved_account = Self::get_token_reserved_account(&asset.id)
-				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+	/// Last fee asset checked by `on_initialize`, the next block carries on after it
+	#[pallet::storage]
+	pub type FeeReserveCheckCursor<T> = StorageValue<_, AssetId>;
 
-			// Deposit `bridge_amount` of asset to reserve account if asset is reserved in local
-			// chain.
-			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
-				T::AssetTransactor::deposit_asset(
-					&(asset.id, Fungible(bridge_amount)).into(),
-					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
-					// Put empty message hash here because we are not sending XCM message
-					&XcmContext::with_message_id([0; 32]),
-				)
-				.map_err(|_| Error::<T>::TransactFailed)?;
-			}
+	/// Origin domains whose executed proposals are acknowledged back to them
+	#[pallet::storage]
+	#[pallet::getter(fn ack_enabled)]
+	pub type AckEnabled<T> = StorageMap<_, Twox64Concat, DomainID, bool, ValueQuery>;
 
-			// Bump deposit nonce
-			let deposit_nonce = DepositCounts::<T>::get(dest_domain_id);
-			DepositCounts::<T>::insert(
-				dest_domain_id,
-				deposit_nonce.checked_add(1).ok_or(Error::<T>::DepositNonceOverflow)?,
-			);
+	/// Kill switch of the acknowledgments, none is sent while it's set
+	#[pallet::storage]
+	#[pallet::getter(fn acks_halted)]
+	pub type AcksHalted<T> = StorageValue<_, bool, ValueQuery>;
 
-			// convert the asset decimal
-			let decimal_converted_amount =
-				T::DecimalConverter::convert_to(&(asset.id, bridge_amount).into())
-					.ok_or(Error::<T>::DecimalConversionFail)?;
+	/// Activity of the last block the bridge saw some, read by the offchain metrics
+	#[pallet::storage]
+	pub type BlockActivity<T: Config> = StorageValue<_, ActivityCounters<BlockNumberFor<T>>>;
 
-			// Emit Deposit event
-			Self::deposit_event(Event::Deposit {
-				dest_domain_id,
-				resource_id,
-				deposit_nonce,
-				sender: sender.clone(),
-				transfer_type,
-				deposit_data: Self::create_deposit_data(decimal_converted_amount, recipient),
-				handler_response: vec![],
-			});
+	/// Block of the last acknowledgment sent and the number of acknowledgments sent in it
+	#[pallet::storage]
+	pub type AcksInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32)>;
 
-			// Emit FeeCollected event
-			Self::deposit_event(Event::FeeCollected {
-				fee_payer: sender,
-				dest_domain_id,
-				resource_id,
-				fee_amount: fee,
-				fee_asset_id: asset.id,
-			});
+	/// Share of the deposit fee waived for an account, `Permill::one()` exempts it from the fee
+	#[pallet::storage]
+	#[pallet::getter(fn fee_discounts)]
+	pub type FeeDiscounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Permill>;
 
-			Ok(())
-		}
+	/// Deposit fees prefunded by an account in an asset, held by `fee_prefund_account`
+	#[pallet::storage]
+	#[pallet::getter(fn fee_prefunds)]
+	pub type FeePrefunds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		u128,
+		ValueQuery,
+	>;
 
-		/// This method is used to trigger the process for retrying failed deposits on the MPC side.
-		#[transactional]
-		#[pallet::call_index(6)]
-		#[pallet::weight(< T as Config >::WeightInfo::retry())]
-		pub fn retry(
-			origin: OriginFor<T>,
-			deposit_on_block_height: u128,
-			dest_domain_id: DomainID,
-		) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"retry".to_vec(),
-					origin.clone()
-				),
-				Error::<T>::AccessDenied
-			);
-			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
-			ensure!(!IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgePaused);
-			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
+	/// Fee vesting schedule of a resource, set by `set_fee_vesting`
+	#[pallet::storage]
+	#[pallet::getter(fn fee_vesting)]
+	pub type FeeVestings<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		FeeVestingSchedule<T::AccountId, BlockNumberFor<T>>,
+	>;
 
-			// Emit retry event
-			let sender = match ensure_signed(origin) {
-				Ok(sender) => sender,
-				_ => [0u8; 32].into(),
-			};
-			Self::deposit_event(Event::<T>::Retry {
-				deposit_on_block_height,
-				dest_domain_id,
-				sender,
-			});
-			Ok(())
-		}
+	/// Last `MaxHistoryPerAccount` deposits of an account, oldest first. Only written if
+	/// `EnableAccountHistory` is set.
+	#[pallet::storage]
+	pub type DepositHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<TransferSummary<BlockNumberFor<T>>, T::MaxHistoryPerAccount>,
+		ValueQuery,
+	>;
 
-		/// Executes a batch of deposit proposals (only if signature is signed by MPC).
-		#[transactional]
-		#[pallet::call_index(7)]
-		#[pallet::weight(< T as Config >::WeightInfo::execute_proposal(proposals.len() as u32))]
-		pub fn execute_proposal(
-			_origin: OriginFor<T>,
-			proposals: Vec<Proposal>,
-			signature: Vec<u8>,
-		) -> DispatchResult {
-			// Check MPC address and bridge status
-			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+	/// Last `MaxHistoryPerAccount` executed proposals crediting a local account, oldest first.
+	/// Only written if `EnableAccountHistory` is set.
+	#[pallet::storage]
+	pub type ExecutionHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<TransferSummary<BlockNumberFor<T>>, T::MaxHistoryPerAccount>,
+		ValueQuery,
+	>;
 
-			ensure!(!proposals.is_empty(), Error::<T>::EmptyProposalList);
+	/// Bindings overriding `T::ResourcePairs` for rebound resources. An entry is kept after it
+	/// takes effect since the new binding is only recorded here.
+	#[pallet::storage]
+	#[pallet::getter(fn resource_rebinds)]
+	pub type ResourceRebinds<T: Config> =
+		StorageMap<_, Twox64Concat, ResourceId, ResourceRebind<BlockNumberFor<T>>>;
 
-			// parse proposals and construct signing message to meet EIP712 typed data
-			let final_message = Self::construct_ecdsa_signing_proposals_data(&proposals);
+	/// Resources onboarded by `onboard_resource`, bound along with `T::ResourcePairs`
+	#[pallet::storage]
+	#[pallet::getter(fn onboarded_resources)]
+	pub type OnboardedResources<T> = StorageMap<_, Twox64Concat, ResourceId, OnboardedResource>;
 
-			// Verify MPC signature
-			ensure!(
-				Self::verify_by_mpc_address(final_message, signature),
-				Error::<T>::BadMpcSignature
-			);
+	/// Bridge accounts of the resources of `OnboardedResources`, with the resource they are
+	/// derived for. Proposals look their recipient up here rather than deriving the accounts of
+	/// every resource.
+	#[pallet::storage]
+	pub type ProtectedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ResourceId>;
 
-			// Execute proposals one by one.
-			// Note if one proposal failed to execute, we emit `FailedHandlerExecution` rather
-			// than revert whole transaction
-			for proposal in proposals.iter() {
-				Self::execute_proposal_internal(proposal).map_or_else(
-					|e| {
-						let err_msg: &'static str = e.into();
-						// Any error during proposal list execution will emit FailedHandlerExecution
-						Self::deposit_event(Event::FailedHandlerExecution {
-							error: err_msg.as_bytes().to_vec(),
-							origin_domain_id: proposal.origin_domain_id,
-							deposit_nonce: proposal.deposit_nonce,
-						});
-					},
-					|_| {
-						// Update proposal status
-						Self::set_proposal_executed(
-							proposal.deposit_nonce,
-							proposal.origin_domain_id,
-						);
+	/// Pairs of `T::ResourcePairs` overruled by `resolve_binding_conflict`, with the asset they
+	/// bound. A pair changed by a later runtime upgrade is not overruled anymore.
+	#[pallet::storage]
+	pub type OverruledResourcePairs<T> = StorageMap<_, Twox64Concat, ResourceId, AssetId>;
 
-						// Emit ProposalExecution
-						Self::deposit_event(Event::ProposalExecution {
-							origin_domain_id: proposal.origin_domain_id,
-							deposit_nonce: proposal.deposit_nonce,
-							data_hash: keccak_256(
-								&[
-									proposal.data.clone(),
-									T::PalletId::get().into_account_truncating(),
-								]
-								.concat(),
-							),
-						});
-					},
-				);
-			}
+	/// Deposit data format of each domain, set to `DataFormat::EvmAbi` when a domain is first
+	/// registered
+	#[pallet::storage]
+	pub type DataFormats<T> = StorageMap<_, Twox64Concat, DomainID, DataFormat>;
 
-			Ok(())
-		}
+	/// Decimals of the amounts of a resource exchanged with a domain, they take precedence over
+	/// `DefaultResourceDecimals`
+	#[pallet::storage]
+	pub type ResourceDomainDecimals<T> =
+		StorageDoubleMap<_, Twox64Concat, ResourceId, Twox64Concat, DomainID, u8>;
 
-		/// Pause all registered bridges
-		#[pallet::call_index(8)]
-		#[pallet::weight(< T as Config >::WeightInfo::pause_all_bridges())]
-		pub fn pause_all_bridges(origin: OriginFor<T>) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"pause_all_bridges".to_vec(),
-					origin.clone()
-				),
-				Error::<T>::AccessDenied
-			);
+	/// Decimals of the amounts of a resource exchanged with the domains without an entry in
+	/// `ResourceDomainDecimals`, 18 if unset
+	#[pallet::storage]
+	pub type DefaultResourceDecimals<T> = StorageMap<_, Twox64Concat, ResourceId, u8>;
 
-			// Pause all bridges
-			Self::pause_all_domains();
+	/// Resources whose queued failed proposals are retried with the leftover weight of blocks
+	#[pallet::storage]
+	pub type AutoRetryEnabled<T> = StorageMap<_, Twox64Concat, ResourceId, bool, ValueQuery>;
 
-			// Emit AllBridgePaused
-			let sender = match ensure_signed(origin) {
-				Ok(sender) => sender,
-				_ => [0u8; 32].into(),
-			};
-			Self::deposit_event(Event::AllBridgePaused { sender });
+	/// Failed proposals of each resource waiting for the auto retry, keyed by
+	/// (origin_domain_id, deposit_nonce). Proposals are queued whether the auto retry of the
+	/// resource is enabled or not.
+	#[pallet::storage]
+	pub type AutoRetryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		Twox64Concat,
+		(DomainID, DepositNonce),
+		QueuedProposal<BoundedVec<u8, T::MaxDepositDataSize>>,
+	>;
 
-			Ok(())
-		}
+	/// Number of blocks between the submission of a proposal of a resource and the release of
+	/// its funds. Proposals of the resources without delay are executed right away.
+	#[pallet::storage]
+	#[pallet::getter(fn required_settlement_delay)]
+	pub type RequiredSettlementDelay<T: Config> =
+		StorageMap<_, Twox64Concat, ResourceId, BlockNumberFor<T>>;
 
-		/// Unpause all registered bridges
-		#[pallet::call_index(9)]
-		#[pallet::weight(< T as Config >::WeightInfo::unpause_all_bridges())]
-		pub fn unpause_all_bridges(origin: OriginFor<T>) -> DispatchResult {
-			ensure!(
-				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
-					<T as Config>::PalletIndex::get(),
-					b"unpause_all_bridges".to_vec(),
-					origin.clone()
-				),
-				Error::<T>::AccessDenied
-			);
+	/// Proposals waiting for the settlement delay of their resource, keyed by
+	/// (origin_domain_id, deposit_nonce)
+	#[pallet::storage]
+	#[pallet::getter(fn pending_settlement)]
+	pub type PendingSettlements<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		PendingSettlement<BoundedVec<u8, T::MaxDepositDataSize>, BlockNumberFor<T>>,
+	>;
 
-			// Make sure MPC address is setup
-			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+	/// Number of `FailedProposals` entries of each resource
+	#[pallet::storage]
+	pub type FailedProposalCounts<T> = StorageMap<_, Twox64Concat, ResourceId, u32, ValueQuery>;
 
-			// Unpause all bridges
-			Self::unpause_all_domains();
+	/// Cap of the value locked by the bridge across all assets, in the unit of
+	/// `AmountNormalizer`. Deposits are not capped if it's not set.
+	#[pallet::storage]
+	#[pallet::getter(fn global_tvl_cap)]
+	pub type GlobalTvlCap<T> = StorageValue<_, u128>;
 
-			// Emit AllBridgeUnpaused
-			let sender = match ensure_signed(origin) {
-				Ok(sender) => sender,
-				_ => [0u8; 32].into(),
-			};
-			Self::deposit_event(Event::AllBridgeUnpaused { sender });
+	/// Value locked by the bridge across all assets, in the unit of `AmountNormalizer`. It's
+	/// increased by deposits and decreased by executed proposals and refunds, counting from the
+	/// upgrade that introduced it.
+	#[pallet::storage]
+	#[pallet::getter(fn global_tvl)]
+	pub type GlobalTvl<T> = StorageValue<_, u128, ValueQuery>;
 
-			Ok(())
-		}
-	}
+	/// Amount each account bridged out across all assets, in the unit of `AmountNormalizer`,
+	/// counting from the upgrade that introduced it. Fees taken from the amount are excluded.
+	#[pallet::storage]
+	#[pallet::getter(fn account_volume)]
+	pub type AccountVolume<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
 
-	impl<T: Config> Bridge for Pallet<T>
-	where
-		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
-	{
-		fn transfer(
-			sender: [u8; 32],
-			asset: MultiAsset,
-			dest: MultiLocation,
-			_max_weight: Option<Weight>,
-		) -> DispatchResult {
-			let sender_origin = OriginFor::<T>::from(RawOrigin::Signed(sender.into()));
-			Pallet::<T>::deposit(sender_origin, Box::from(asset), Box::from(dest))?;
-			Ok(())
+	/// Balance the reserve account of a resource keeps as an operational buffer, in the decimals
+	/// of the local asset. Executions and refunds withdrawing below it are rejected.
+	#[pallet::storage]
+	#[pallet::getter(fn min_reserve_balance)]
+	pub type MinReserveBalance<T> = StorageMap<_, Twox64Concat, ResourceId, u128>;
+
+	/// Receipts of the executed proposals crediting their recipient, keyed by
+	/// (origin_domain_id, deposit_nonce)
+	#[pallet::storage]
+	pub type ExecutionReceipts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		ExecutionReceipt<BlockNumberFor<T>>,
+	>;
+
+	/// Recipients inbound transfers are not delivered to, the proposals stay failed and are
+	/// executed once the recipient is removed
+	#[pallet::storage]
+	pub type RecipientDenylist<T> = StorageMap<_, Blake2_128Concat, DeniedRecipient, ()>;
+
+	/// Cap of the amount of a resource each recipient, as carried by deposit data, receives
+	/// from proposals overall, in the 18 decimals of deposit data
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_inbound_cap)]
+	pub type RecipientInboundCap<T> =
+		StorageMap<_, Blake2_128Concat, (ResourceId, DeniedRecipient), u128>;
+
+	/// Amount of a resource each capped recipient received from proposals, in the 18 decimals
+	/// of deposit data. Only counted while the recipient has a `RecipientInboundCap`.
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_inbound)]
+	pub type RecipientInbound<T> =
+		StorageMap<_, Blake2_128Concat, (ResourceId, DeniedRecipient), u128, ValueQuery>;
+
+	/// Nonce the next deposit permit of an account has to carry
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	pub type PermitNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Data of the deposits whose `Deposit` event carries its hash, keyed by
+	/// (dest_domain_id, deposit_nonce)
+	#[pallet::storage]
+	pub type DepositDataOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		StoredDepositData<BlockNumberFor<T>, BoundedVec<u8, T::MaxDepositDataSize>>,
+	>;
+
+	/// Last `MaxManualInterventions` break-glass operations, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn manual_interventions)]
+	pub type ManualInterventions<T: Config> = StorageValue<
+		_,
+		BoundedVec<ManualIntervention<BlockNumberFor<T>>, T::MaxManualInterventions>,
+		ValueQuery,
+	>;
+
+	/// Leaves of the deposits of the current block, in deposit order, see
+	/// `sygma_codec::commitment`. Taken by `on_finalize` to compute the root of the block.
+	#[pallet::storage]
+	pub type BlockDepositLeaves<T> = StorageMap<_, Twox64Concat, u32, [u8; 32]>;
+
+	/// Number of entries in `BlockDepositLeaves`
+	#[pallet::storage]
+	pub type BlockDepositLeafCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Set while `execute_proposal` executes its proposals, so that a transactor reentering the
+	/// pallet can not execute proposals in the middle of a batch
+	#[pallet::storage]
+	pub type ExecutionLock<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Merkle root of the deposits of a block, only stored for the blocks with deposits
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_roots)]
+	pub type DepositRoots<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, [u8; 32]>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Nonces already executed by a legacy bridge, as `(origin_domain_id, start, end)` with
+		/// `end` excluded, so that proposals of these nonces are rejected as executed. A range
+		/// from nonce 0 only moves the `MinUnprunedNonce` high-water mark, other ranges are
+		/// marked in `UsedNonces` one entry per 64 nonces.
+		pub used_nonce_ranges: Vec<(DomainID, DepositNonce, DepositNonce)>,
+		pub phantom: PhantomData<T>,
+	}
+
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { used_nonce_ranges: Vec::new(), phantom: PhantomData }
 		}
 	}
 
-	impl<T: Config> Pallet<T>
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let mut ranges = self.used_nonce_ranges.clone();
+			// A range starting at or below the high-water mark extends it, sorting the ranges
+			// makes sure no marked range ends up below the mark
+			ranges.sort();
+			for (domain_id, start, end) in ranges {
+				assert!(start <= end, "used nonce range of domain {:?} is reversed", domain_id);
+				nonces::consume_range::<T>(domain_id, start, end);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
 	where
 		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
 	{
-		/// Verifies that EIP712 typed proposal data is signed by MPC address
-		#[allow(dead_code)]
-		fn verify_by_mpc_address(signing_message: [u8; 32], signature: Vec<u8>) -> bool {
-			let sig = match signature.try_into() {
-				Ok(_sig) => _sig,
-				Err(error) => return false,
-			};
-
-			// recover the signing address
-			if let Ok(pubkey) =
-				// recover the uncompressed pubkey
-				secp256k1_ecdsa_recover(&sig, &signing_message)
-			{
-				let address = Self::public_key_to_address(&pubkey);
-
-				address == MpcAddr::<T>::get().0
-			} else {
-				false
-			}
+		/// Check the fee reserve of up to `MaxFeeReserveChecksPerBlock` fee assets and announce
+		/// the domains whose deposit cutoff block is reached
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::check_fee_reserves().saturating_add(Self::announce_domain_sunsets(n))
 		}
 
-		/// Return the TokenReservedAccount address by the given token
-		pub fn get_token_reserved_account(token_id: &AssetId) -> Option<[u8; 32]> {
-			T::TransferReserveAccounts::get()
-				.get(token_id)
-				.map(|account| (*account).clone().into())
+		/// Clear the signature verifications cached in the block, their removal is charged by
+		/// `execute_proposal`, and store the root of the deposits of the block, charged by the
+		/// deposits
+		fn on_finalize(n: BlockNumberFor<T>) {
+			if T::EnableSignatureCache::get() {
+				let _ = VerifiedSignatures::<T>::clear(u32::MAX, None);
+			}
+			Self::store_deposit_root(n);
 		}
 
-		/// convert the ECDSA 64-byte uncompressed pubkey to H160 address
-		pub fn public_key_to_address(public_key: &[u8]) -> [u8; 20] {
-			let hash = keccak_256(public_key);
-			let final_hash = array_ref![&hash, 12, 20];
-			*final_hash
+		/// Export the metrics of the block to the offchain local storage, see `metrics`
+		#[cfg(feature = "offchain-metrics")]
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			if T::EnableOffchainMetrics::get() {
+				crate::metrics::write_metrics::<T>(n);
+			}
 		}
 
-		/// Parse proposals and construct the original signing message
-		pub fn construct_ecdsa_signing_proposals_data(proposals: &Vec<Proposal>) -> [u8; 32] {
-			let proposals_typehash = keccak_256(
-                "Proposals(Proposal[] proposals)Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
-                    .as_bytes(),
-            );
-			let proposal_typehash = keccak_256(
-				"Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
-					.as_bytes(),
-			);
-
-			if proposals.is_empty() {
-				return [0u8; 32];
-			}
-
-			let mut keccak_data = Vec::new();
-			for prop in proposals {
-				let proposal_domain_id_token = Token::Uint(prop.origin_domain_id.into());
-				let proposal_deposit_nonce_token = Token::Uint(prop.deposit_nonce.into());
-				let proposal_resource_id_token = Token::FixedBytes(prop.resource_id.to_vec());
-				let proposal_data_token = Token::FixedBytes(keccak_256(&prop.data).to_vec());
-
-				keccak_data.push(keccak_256(&abi_encode(&[
-					Token::FixedBytes(proposal_typehash.to_vec()),
-					proposal_domain_id_token,
-					proposal_deposit_nonce_token,
-					proposal_resource_id_token,
-					proposal_data_token,
-				])));
-			}
-
-			// flatten the keccak_data into vec<u8>
-			let mut final_keccak_data = Vec::new();
-			for data in keccak_data {
-				for d in data {
-					final_keccak_data.push(d)
-				}
-			}
-
-			let final_keccak_data_input = &vec![SolidityDataType::Bytes(&final_keccak_data)];
-			let bytes = encode_packed(final_keccak_data_input);
-			let hashed_keccak_data = keccak_256(bytes.as_slice());
-
-			let struct_hash = keccak_256(&abi_encode(&[
-				Token::FixedBytes(proposals_typehash.to_vec()),
-				Token::FixedBytes(hashed_keccak_data.to_vec()),
-			]));
-
-			// domain separator
-			let default_eip712_domain = eip712::EIP712Domain::default();
-			let eip712_domain = eip712::EIP712Domain {
-				name: b"Bridge".to_vec(),
-				version: b"3.1.0".to_vec(),
-				chain_id: T::EIP712ChainID::get(),
-				verifying_contract: T::DestVerifyingContractAddress::get(),
-				salt: default_eip712_domain.salt,
-			};
-			let domain_separator = eip712_domain.separator();
-
-			let typed_data_hash_input = &vec![
-				SolidityDataType::String("\x19\x01"),
-				SolidityDataType::Bytes(&domain_separator),
-				SolidityDataType::Bytes(&struct_hash),
-			];
-			let bytes = encode_packed(typed_data_hash_input);
-			keccak_256(bytes.as_slice())
+		/// Retry the queued failed proposals of the resources with the auto retry enabled
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_auto_retries(remaining_weight)
 		}
 
-		/// Extract asset id and transfer amount from `MultiAsset`, currently only fungible asset
-		/// are supported.
-		fn extract_asset(asset: &MultiAsset) -> Option<(ResourceId, u128, TransferType)> {
-			match (&asset.fun, &asset.id) {
-				(Fungible(amount), _) => {
-					T::ResourcePairs::get().iter().position(|a| a.0 == asset.id).map(|idx| {
-						(T::ResourcePairs::get()[idx].1, *amount, TransferType::FungibleTransfer)
-					})
-				},
-				_ => None,
+		/// `T::ResourcePairs` must not bind a resource to several assets
+		#[cfg(feature = "std")]
+		fn integrity_test() {
+			let pairs = T::ResourcePairs::get();
+			for (i, (asset, resource_id)) in pairs.iter().enumerate() {
+				assert!(
+					!pairs[..i].iter().any(|(other, id)| id == resource_id && other != asset),
+					"ResourcePairs binds a resource to several assets"
+				);
 			}
 		}
 
-		pub fn create_deposit_data(amount: u128, recipient: Vec<u8>) -> Vec<u8> {
-			[
-				&Self::hex_zero_padding_32(amount),
-				&Self::hex_zero_padding_32(recipient.len() as u128),
-				recipient.as_slice(),
-			]
-			.concat()
-			.to_vec()
-		}
-
-		/// Extract transfer amount and recipient location from deposit data.
-		/// For fungible transfer, data passed into the function should be constructed as follows:
-		/// amount                    uint256     bytes  0 - 32
-		/// recipient data length     uint256     bytes  32 - 64
-		/// recipient data            bytes       bytes  64 - END
+		/// Setting the MPC address is the first step of the bridge setup flow, it pauses all
+		/// registered domains and they stay paused until relayers are ready. So nothing can have
+		/// been bridged while the MPC address is missing: no deposit nonce assigned or reserved,
+		/// no proposal executed and no transfer escrowed.
 		///
-		/// Only fungible transfer is supported so far.
-		fn extract_deposit_data(data: &Vec<u8>) -> Result<(u128, MultiLocation), DispatchError> {
-			if data.len() < 64 {
-				return Err(Error::<T>::InvalidDepositData.into());
+		/// `T::ResourcePairs` and the registered resources must not bind a resource to different
+		/// assets. The escrow accounts have to cover the escrowed transfers, and the reserve
+		/// accounts the refunds requested.
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			if MpcAddr::<T>::get().is_clear() {
+				ensure!(
+					DepositCounts::<T>::iter_keys().next().is_none(),
+					"Deposit nonce assigned without MPC address"
+				);
+				ensure!(
+					NonceReservations::<T>::iter_keys().next().is_none(),
+					"Deposit nonce reserved without MPC address"
+				);
+				ensure!(!nonces::any_consumed::<T>(), "Proposal executed without MPC address");
+				ensure!(
+					UnclaimedTransfers::<T>::iter_keys().next().is_none(),
+					"Transfer escrowed without MPC address"
+				);
 			}
 
-			let amount: u128 = U256::from_big_endian(&data[0..32])
-				.try_into()
-				.map_err(|_| Error::<T>::InvalidDepositData)?;
-			let recipient_len: usize = U256::from_big_endian(&data[32..64])
-				.try_into()
-				.map_err(|_| Error::<T>::InvalidDepositData)?;
-			if (data.len() - 64) != recipient_len {
-				return Err(Error::<T>::InvalidDepositData.into());
-			}
+			nonces::check_invariants::<T>()?;
 
-			let recipient = data[64..data.len()].to_vec();
-			if let Ok(location) = <MultiLocation>::decode(&mut recipient.as_slice()) {
-				Ok((amount, location))
-			} else {
-				Err(Error::<T>::InvalidDepositData.into())
-			}
-		}
+			ensure!(
+				Self::binding_conflicts().is_empty(),
+				"ResourcePairs and the registered resources bind a resource to different assets"
+			);
 
-		fn rid_to_assetid(rid: &ResourceId) -> Option<AssetId> {
-			T::ResourcePairs::get()
-				.iter()
-				.position(|a| &a.1 == rid)
-				.map(|idx| T::ResourcePairs::get()[idx].0)
-		}
+			let mut escrowed: BTreeMap<(ResourceId, AssetId), u128> = BTreeMap::new();
+			for transfer in UnclaimedTransfers::<T>::iter_values() {
+				let total = escrowed.entry((transfer.resource_id, transfer.asset_id)).or_default();
+				*total = total.saturating_add(transfer.amount);
+			}
+			for ((resource_id, asset_id), total) in escrowed {
+				ensure!(
+					Self::can_withdraw(
+						&(asset_id, total).into(),
+						Self::escrow_account(&resource_id).into()
+					),
+					"Escrow account can not cover the escrowed transfers"
+				);
+			}
 
-		fn hex_zero_padding_32(i: u128) -> [u8; 32] {
-			let mut result = [0u8; 32];
-			U256::from(i).to_big_endian(&mut result);
-			result
+			let mut refunds: BTreeMap<AssetId, u128> = BTreeMap::new();
+			for record in DepositRecords::<T>::iter_values().filter(|r| r.refund_requested) {
+				let total = refunds.entry(record.asset_id).or_default();
+				*total = total.saturating_add(record.amount);
+			}
+			for (asset_id, total) in refunds {
+				let asset: MultiAsset = (asset_id, total).into();
+				// refund of a non-reserve asset is minted
+				if !T::IsReserve::contains(&asset, &MultiLocation::here()) {
+					continue;
+				}
+				let reserve_account = Self::get_token_reserved_account(&asset_id)
+					.ok_or("Refunded asset has no reserve account")?;
+				ensure!(
+					Self::can_withdraw(&asset, reserve_account),
+					"Reserve account can not cover the requested refunds"
+				);
+			}
+			Ok(())
 		}
+	}
 
-		/// Return true if deposit nonce has been used
-		pub fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
-			(UsedNonces::<T>::get(domain_id, nonce / 64) & (1 << (nonce % 64))) != 0
-		}
+	#[pallet::call]
+	impl<T: Config> Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		/// Pause bridge, this would lead to bridge transfer failure before it being unpaused.
+		#[pallet::call_index(0)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_bridge())]
+		pub fn pause_bridge(origin: OriginFor<T>, dest_domain_id: DomainID) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(dest_domain_id)?;
 
-		/// Set bit mask for specific nonce as used
-		fn set_proposal_executed(nonce: DepositNonce, domain_id: DomainID) {
-			let mut current_nonces = UsedNonces::<T>::get(domain_id, nonce / 64);
-			current_nonces |= 1 << (nonce % 64);
-			UsedNonces::<T>::insert(domain_id, nonce / 64, current_nonces);
+			// Mark as paused
+			Self::pause_domain(
+				dest_domain_id,
+				PauseInfo { reason: PauseReason::Governance, note: None },
+			);
+			Ok(())
 		}
 
-		/// Execute a single proposal
-		fn execute_proposal_internal(proposal: &Proposal) -> DispatchResult {
-			// Check if dest domain bridge is paused
-			ensure!(!IsPaused::<T>::get(proposal.origin_domain_id), Error::<T>::BridgePaused);
-			// Check if domain is supported
+		/// Unpause bridge.
+		#[pallet::call_index(1)]
+		#[pallet::weight(< T as Config >::WeightInfo::unpause_bridge())]
+		pub fn unpause_bridge(origin: OriginFor<T>, dest_domain_id: DomainID) -> DispatchResult {
+			let is_committee = Self::is_committee(&origin);
 			ensure!(
-				DestDomainIds::<T>::get(proposal.origin_domain_id),
-				Error::<T>::DestDomainNotSupported
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unpause_bridge".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
 			);
-			// Check if proposal has executed
+			Self::ensure_registered(dest_domain_id)?;
+
+			// make sure the current status is paused
+			ensure!(Self::is_paused(dest_domain_id), Error::<T>::BridgeUnpaused);
 			ensure!(
-				!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id),
-				Error::<T>::ProposalAlreadyComplete
+				is_committee || !Self::is_circuit_breaker_pause(dest_domain_id),
+				Error::<T>::CommitteeOriginRequired
 			);
-			// Extract ResourceId from proposal data to get corresponding asset (MultiAsset)
-			let asset_id =
-				Self::rid_to_assetid(&proposal.resource_id).ok_or(Error::<T>::AssetNotBound)?;
-			// Extract Receipt from proposal data to get corresponding location (MultiLocation)
-			let (amount, location) = Self::extract_deposit_data(&proposal.data)?;
 
-			// convert the asset decimal
-			let decimal_converted_asset =
-				T::DecimalConverter::convert_from(&(asset_id, amount).into())
-					.ok_or(Error::<T>::DecimalConversionFail)?;
+			// Mark both directions as unpaused
+			Self::set_domain_paused(dest_domain_id, false);
+			PauseReasons::<T>::remove(dest_domain_id);
 
-			let token_reserved_account = Self::get_token_reserved_account(&asset_id)
-				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+			// Emit BridgeUnpause event
+			Self::deposit_event(Event::BridgeUnpaused { dest_domain_id });
+			Ok(())
+		}
 
-			// Withdraw `decimal_converted_asset` of asset from reserve account
-			if T::IsReserve::contains(&decimal_converted_asset, &MultiLocation::here()) {
-				T::AssetTransactor::withdraw_asset(
-					&decimal_converted_asset,
-					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
-					None,
-				)
-				.map_err(|_| Error::<T>::TransactFailed)?;
+		/// Mark an ECDSA address as a MPC account, all registered bridges are paused afterwards.
+		#[pallet::call_index(2)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_mpc_address())]
+		pub fn set_mpc_address(origin: OriginFor<T>, addr: MpcAddress) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_mpc_address".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			// Setting the same MPC address again is a no-op, so a replayed setup call can not pause
+			// a bridge which is already live
+			let current_addr = MpcAddr::<T>::get();
+			if current_addr == addr {
+				return Ok(());
 			}
+			// Cannot set MPC address as it's already set
+			ensure!(current_addr.is_clear(), Error::<T>::MpcAddrNotUpdatable);
 
-			// Deposit `decimal_converted_asset` of asset to dest location
-			T::AssetTransactor::deposit_asset(
-				&decimal_converted_asset,
-				&location,
-				// Put empty message hash here because we are not sending XCM message
-				&XcmContext::with_message_id([0; 32]),
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
+			// Set MPC account address
+			MpcAddr::<T>::set(addr);
+			let grace_period = T::KeySetGracePeriod::get();
+			if !grace_period.is_zero() {
+				MpcKeyGraceEnd::<T>::put(
+					frame_system::Pallet::<T>::block_number().saturating_add(grace_period),
+				);
+			}
+			Self::deposit_event(Event::MpcAddressSet { mpc_addr: addr });
+
+			// Keep all bridges paused until relayers are ready and the bridges get unpaused
+			// explicitly, so that a fresh MPC address never serves a live bridge without relayers
+			let info = PauseInfo { reason: PauseReason::Governance, note: None };
+			Self::pause_all_domains(&info);
+			DestDomainIds::<T>::iter_keys().for_each(|dest_domain_id| {
+				let PauseInfo { reason, note } =
+					PauseReasons::<T>::get(dest_domain_id).unwrap_or_else(|| info.clone());
+				Self::deposit_event(Event::BridgePaused { dest_domain_id, reason, note })
+			});
 
 			Ok(())
 		}
 
-		/// unpause all registered domains in the storage
-		fn unpause_all_domains() {
-			DestDomainIds::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, false));
-			IsPaused::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, false));
-		}
+		/// Mark the give dest domainID with chainID to be enabled
+		#[pallet::call_index(3)]
+		#[pallet::weight(< T as Config >::WeightInfo::register_domain())]
+		pub fn register_domain(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			dest_chain_id: ChainID,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"register_domain".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			// The chainID of a known domain only changes by `update_domain_chain_id`
+			let known_chain_id = DestChainIds::<T>::get(dest_domain_id)
+				.or_else(|| UnregisteredChainIds::<T>::get(dest_domain_id));
+			ensure!(
+				known_chain_id.is_none() || known_chain_id == Some(dest_chain_id),
+				Error::<T>::DestChainIDNotMatch
+			);
+			let reregistered = UnregisteredChainIds::<T>::take(dest_domain_id).is_some();
 
-		/// pause all registered domains in the storage
-		fn pause_all_domains() {
-			DestDomainIds::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, true));
-			IsPaused::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, true));
-		}
-	}
+			DestDomainIds::<T>::insert(dest_domain_id, true);
+			DestChainIds::<T>::insert(dest_domain_id, dest_chain_id);
+			// A format set before, e.g. prior to an unregistration, is kept
+			if !DataFormats::<T>::contains_key(dest_domain_id) {
+				DataFormats::<T>::insert(dest_domain_id, DataFormat::EvmAbi);
+			}
 
-	#[cfg(test)]
-	mod test {
-		use codec::{self, Encode};
-		use frame_support::{
-			assert_noop, assert_ok, crypto::ecdsa::ECDSAExt,
-			traits::tokens::fungibles::Create as FungibleCerate,
-		};
-		use parachains_common::AccountId;
-		use primitive_types::U256;
-		use sp_core::{ecdsa, ByteArray, Pair};
-		use sp_std::{boxed::Box, vec};
-		use xcm::latest::prelude::*;
+			// Emit register dest domain event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			if !reregistered {
+				Self::deposit_event(Event::RegisterDestDomain {
+					sender,
+					domain_id: dest_domain_id,
+					chain_id: dest_chain_id,
+				});
+				return Ok(());
+			}
 
-		use bridge::mock::{
-			assert_events, new_test_ext, slice_to_generalkey, AccessSegregator, Assets, Balances,
-			BridgeAccountNative, BridgeAccountOtherTokens, BridgePalletIndex, NativeLocation,
-			NativeResourceId, Runtime, RuntimeEvent, RuntimeOrigin as Origin, SygmaBasicFeeHandler,
-			SygmaBridge, SygmaFeeHandlerRouter, SygmaPercentageFeeHandler, TreasuryAccount,
-			UsdtAssetId, UsdtLocation, UsdtResourceId, ALICE, ASSET_OWNER, BOB, DEST_DOMAIN_ID,
-			ENDOWED_BALANCE,
-		};
-		use sygma_fee_handler_router::FeeHandlerType;
-		use sygma_traits::{Bridge, DomainID, MpcAddress, TransferType};
+			// A domain registered back keeps its deposit nonces and used nonces, so that nothing
+			// of its past registration is replayed, and starts paused whatever its pause state
+			// was when it got unregistered
+			Self::deposit_event(Event::DomainReregistered {
+				sender,
+				domain_id: dest_domain_id,
+				chain_id: dest_chain_id,
+			});
+			Self::pause_domain(
+				dest_domain_id,
+				PauseInfo { reason: PauseReason::Governance, note: None },
+			);
+			Ok(())
+		}
 
-		use crate as bridge;
-		use crate::{
-			mock::{AstrAssetId, AstrLocation, AstrResourceId},
-			DestChainIds, DestDomainIds, Error, Event as SygmaBridgeEvent, IsPaused, MpcAddr,
-			Proposal,
-		};
+		/// Mark the give dest domainID with chainID to be disabled. Refused while the proposals
+		/// executed from the domain are behind the deposits made to it, unless `force` is set.
+		#[pallet::call_index(4)]
+		#[pallet::weight(< T as Config >::WeightInfo::unregister_domain())]
+		pub fn unregister_domain(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			dest_chain_id: ChainID,
+			force: bool,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unregister_domain".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(
+				DestDomainIds::<T>::get(dest_domain_id)
+					&& DestChainIds::<T>::get(dest_domain_id).is_some(),
+				Error::<T>::DestDomainNotSupported
+			);
 
-		#[test]
-		fn get_token_reserved_account_test() {
-			new_test_ext().execute_with(|| {
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
-					BridgeAccountOtherTokens::get().as_slice()
-				);
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
-					BridgeAccountNative::get().as_slice()
-				);
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&AstrLocation::get().into()).unwrap(),
-					BridgeAccountOtherTokens::get().as_slice()
-				);
+			let co_chain_id = DestChainIds::<T>::get(dest_domain_id).unwrap();
+			ensure!(co_chain_id == dest_chain_id, Error::<T>::DestChainIDNotMatch);
+			// Nonces of the two directions mirror each other, the backlog is drained once the
+			// proposals from the domain are executed up to the last deposit made to it
+			let executed_count = HighestExecutedNonce::<T>::get(dest_domain_id)
+				.map_or(0, |nonce| nonce.saturating_add(1));
+			ensure!(
+				force || executed_count >= DepositCounts::<T>::get(dest_domain_id),
+				Error::<T>::DomainBacklogNotDrained
+			);
 
-				// unknown token should return None
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(
-						&MultiLocation::new(
-							2,
-							X3(
-								Parachain(1000),
-								slice_to_generalkey(b"sygma"),
-								slice_to_generalkey(b"unknown"),
-							),
-						)
-						.into()
-					),
-					None
-				);
-			})
-		}
+			DestDomainIds::<T>::remove(dest_domain_id);
+			DestChainIds::<T>::remove(dest_domain_id);
+			UnregisteredChainIds::<T>::insert(dest_domain_id, dest_chain_id);
+			DomainSunsets::<T>::remove(dest_domain_id);
 
-		#[test]
-		fn set_mpc_address() {
-			new_test_ext().execute_with(|| {
-				let default_addr: MpcAddress = MpcAddress::default();
-				let test_mpc_addr_a: MpcAddress = MpcAddress([1u8; 20]);
-				let test_mpc_addr_b: MpcAddress = MpcAddress([2u8; 20]);
+			// Emit unregister dest domain event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::UnregisterDestDomain {
+				sender,
+				domain_id: dest_domain_id,
+				chain_id: dest_chain_id,
+			});
+			Ok(())
+		}
 
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+		/// Initiates a transfer.
+		#[transactional]
+		#[pallet::call_index(5)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit())]
+		pub fn deposit(
+			origin: OriginFor<T>,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-				// set to test_mpc_addr_a
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_a));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+			Self::deposit_internal(sender, *asset, *dest, None, None, None)
+		}
 
-				// set to test_mpc_addr_b: should be MpcAddrNotUpdatable error
-				assert_noop!(
-					SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_b),
-					bridge::Error::<Runtime>::MpcAddrNotUpdatable
-				);
+		/// This method is used to trigger the process for retrying failed deposits on the MPC side.
+		#[transactional]
+		#[pallet::call_index(6)]
+		#[pallet::weight(< T as Config >::WeightInfo::retry())]
+		pub fn retry(
+			origin: OriginFor<T>,
+			deposit_on_block_height: u128,
+			dest_domain_id: DomainID,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"retry".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+			Self::ensure_registered(dest_domain_id)?;
+			let now: u128 = frame_system::Pallet::<T>::block_number().saturated_into();
+			ensure!(deposit_on_block_height <= now, Error::<T>::InvalidRetryHeight);
+			ensure!(
+				now.saturating_sub(deposit_on_block_height)
+					<= T::MaxRetryAge::get().saturated_into::<u128>(),
+				Error::<T>::DepositTooOld
+			);
 
-				// permission test: unauthorized account should not be able to set mpc address
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::set_mpc_address(unauthorized_account, test_mpc_addr_a),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
-			})
+			// Emit retry event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::<T>::Retry {
+				deposit_on_block_height,
+				dest_domain_id,
+				sender,
+			});
+			Ok(())
 		}
 
-		#[test]
-		fn pause_bridge() {
-			new_test_ext().execute_with(|| {
-				let default_addr = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+		/// Executes a batch of deposit proposals (only if signature is signed by MPC).
+		/// If `RequireMpcThreshold` is enabled, `signature` is the concatenation of the MPC
+		/// signers' signatures.
+		#[transactional]
+		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T>::execute_proposal_weight(proposals))]
+		pub fn execute_proposal(
+			origin: OriginFor<T>,
+			proposals: Vec<Proposal>,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			Self::do_execute_proposal(origin, proposals, signature)
+		}
 
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
+		/// Pause all registered bridges
+		#[pallet::call_index(8)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_all_bridges())]
+		pub fn pause_all_bridges(origin: OriginFor<T>) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_all_bridges".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
 
-				// pause bridge, should be ok
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			// Pause all bridges
+			Self::pause_all_domains(&PauseInfo { reason: PauseReason::Governance, note: None });
 
-				// pause bridge again after paused, should be ok
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			// Emit AllBridgePaused
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::AllBridgePaused { sender });
 
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::pause_bridge(unauthorized_account, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn unpause_bridge() {
-			new_test_ext().execute_with(|| {
-				let default_addr: MpcAddress = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
-
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+		/// Unpause all registered bridges
+		#[pallet::call_index(9)]
+		#[pallet::weight(< T as Config >::WeightInfo::unpause_all_bridges())]
+		pub fn unpause_all_bridges(origin: OriginFor<T>) -> DispatchResult {
+			let is_committee = Self::is_committee(&origin);
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unpause_all_bridges".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
 
-				// bridge should be paused here
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
+			// Make sure MPC address is setup
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(
+				is_committee
+					|| !PausedRoutes::<T>::iter_keys()
+						.any(|(_, d)| Self::is_circuit_breaker_pause(d)),
+				Error::<T>::CommitteeOriginRequired
+			);
 
-				// ready to unpause bridge, should be ok
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeUnpaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			// Unpause all bridges
+			Self::unpause_all_domains();
 
-				// try to unpause it again, should be error
-				assert_noop!(
-					SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::BridgeUnpaused
-				);
+			// Emit AllBridgeUnpaused
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::AllBridgeUnpaused { sender });
 
-				// permission test: unauthorized account should not be able to unpause a recognized
-				// bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::unpause_bridge(unauthorized_account, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert!(!IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_invalid_signature() {
-			new_test_ext().execute_with(|| {
-				let signature = vec![1u8];
+		/// Credit an escrowed transfer to its beneficiary, anyone can claim on behalf of the
+		/// beneficiary once the reason of the failed credit is resolved.
+		#[transactional]
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config >::WeightInfo::claim_escrowed_transfer())]
+		pub fn claim_escrowed_transfer(
+			origin: OriginFor<T>,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			let escrowed = UnclaimedTransfers::<T>::get(origin_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::EscrowedTransferNotFound)?;
+			Self::release_escrowed_transfer(&escrowed, &escrowed.beneficiary)?;
+			UnclaimedTransfers::<T>::remove(origin_domain_id, deposit_nonce);
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+			// Emit EscrowedTransferClaimed
+			Self::deposit_event(Event::EscrowedTransferClaimed {
+				origin_domain_id,
+				deposit_nonce,
+				beneficiary: escrowed.beneficiary,
+			});
 
-				// should be false
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_invalid_message() {
-			new_test_ext().execute_with(|| {
-				// generate mpc keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let public = pair.public();
-				let message = b"Something important";
-				let signature = pair.sign(&message[..]);
+		/// Move an escrowed transfer older than `EscrowRetentionPeriod` to the fee reserve account.
+		#[transactional]
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::WeightInfo::sweep_escrowed_transfer())]
+		pub fn sweep_escrowed_transfer(
+			origin: OriginFor<T>,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			let operator_origin_hash = Self::origin_hash(&origin);
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"sweep_escrowed_transfer".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
 
-				// make sure generated keypair, message and signature are all good
-				assert!(ecdsa::Pair::verify(&signature, &message[..], &public));
-				assert!(!ecdsa::Pair::verify(&signature, b"Something else", &public));
+			let escrowed = UnclaimedTransfers::<T>::get(origin_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::EscrowedTransferNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number()
+					>= escrowed.escrowed_at.saturating_add(T::EscrowRetentionPeriod::get()),
+				Error::<T>::EscrowRetentionNotExpired
+			);
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			let fee_reserve: MultiLocation =
+				Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into();
+			Self::release_escrowed_transfer(&escrowed, &fee_reserve)?;
+			UnclaimedTransfers::<T>::remove(origin_domain_id, deposit_nonce);
+			Self::record_intervention(
+				operator_origin_hash,
+				InterventionOperation::SweepEscrowedTransfer,
+				InterventionSubject::Transfer { domain_id: origin_domain_id, deposit_nonce },
+				Some(escrowed.amount),
+			);
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+			// Emit EscrowedTransferSwept
+			Self::deposit_event(Event::EscrowedTransferSwept { origin_domain_id, deposit_nonce });
 
-				// verify non matched signature against proposal list, should be false
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_valid_message_unmatched_mpc() {
-			new_test_ext().execute_with(|| {
-				// generate the signing keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-
-				// set mpc address to another random key
-				let test_mpc_addr: MpcAddress = MpcAddress([7u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+		/// Reserve the next deposit nonce of dest domain, the reserved nonce is emitted in
+		/// `DepositNonceReserved` and has to be used by `commit_deposit` before it expires.
+		#[transactional]
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::WeightInfo::reserve_nonce())]
+		pub fn reserve_nonce(origin: OriginFor<T>, dest_domain_id: DomainID) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+			Self::ensure_registered(dest_domain_id)?;
+			ensure!(
+				DomainHealth::<T>::get(dest_domain_id).unwrap_or(true),
+				Error::<T>::DomainUnhealthy
+			);
+			Self::ensure_not_sunset(dest_domain_id)?;
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+			let (deposit_nonce, nonce_events) = Self::next_deposit_nonce(dest_domain_id)?;
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::NonceReservationTimeout::get());
+			NonceReservations::<T>::insert(
+				dest_domain_id,
+				deposit_nonce,
+				(owner.clone(), expires_at),
+			);
 
-				// sign final message using generated prikey
-				let signature = pair.sign_prehashed(&final_message);
+			// Emit DepositNonceReserved event and the nonce anomaly events, if any
+			Self::deposit_event(Event::DepositNonceReserved {
+				dest_domain_id,
+				deposit_nonce,
+				owner,
+				expires_at,
+			});
+			nonce_events.into_iter().for_each(Self::deposit_event);
 
-				// verify signature, should be false because the signing address != mpc address
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_valid_message_valid_signature() {
-			new_test_ext().execute_with(|| {
-				// generate mpc keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+		/// Initiates a transfer with a nonce reserved by the caller.
+		#[transactional]
+		#[pallet::call_index(13)]
+		#[pallet::weight(< T as Config >::WeightInfo::commit_deposit())]
+		pub fn commit_deposit(
+			origin: OriginFor<T>,
+			deposit_nonce: DepositNonce,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-				// set mpc address to generated keypair's address
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+			let (_, dest_domain_id) = Self::extract_dest_data(&dest)?;
+			let (owner, expires_at) = NonceReservations::<T>::get(dest_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::NonceNotReserved)?;
+			ensure!(owner == sender, Error::<T>::NonceNotReserved);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= expires_at,
+				Error::<T>::NonceReservationExpired
+			);
+			NonceReservations::<T>::remove(dest_domain_id, deposit_nonce);
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			Self::deposit_internal(sender, *asset, *dest, Some(deposit_nonce), None, None)
+		}
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+		/// Remove an expired nonce reservation, anyone can reclaim it.
+		#[pallet::call_index(14)]
+		#[pallet::weight(< T as Config >::WeightInfo::reclaim_nonce_reservation())]
+		pub fn reclaim_nonce_reservation(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
 
-				// sign final message using generated mpc prikey
-				// `pari.sign` will hash the final message into blake2_256 then sign it, so use
-				// sign_prehashed here
-				let signature = pair.sign_prehashed(&final_message);
+			let (_, expires_at) = NonceReservations::<T>::get(dest_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::NonceNotReserved)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > expires_at,
+				Error::<T>::NonceReservationNotExpired
+			);
+			NonceReservations::<T>::remove(dest_domain_id, deposit_nonce);
 
-				// verify signature, should be true
-				assert!(SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			// Emit NonceReservationReclaimed event
+			Self::deposit_event(Event::NonceReservationReclaimed { dest_domain_id, deposit_nonce });
+
+			Ok(())
 		}
 
-		#[test]
-		fn deposit_native_asset_should_work() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+		/// Allow a relayer to submit proposals.
+		#[pallet::call_index(15)]
+		#[pallet::weight(< T as Config >::WeightInfo::add_relayer())]
+		pub fn add_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"add_relayer".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
 
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
-				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+			Relayers::<T>::insert(&relayer, true);
 
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
-				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: fee,
-						fee_asset_id: NativeLocation::get().into(),
-					}),
-				]);
-			})
+			// Emit RelayerAdded event
+			Self::deposit_event(Event::RelayerAdded { relayer });
+			Ok(())
 		}
 
-		#[test]
-		fn transfer_wrap_deposit_should_work() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+		/// Disallow a relayer to submit proposals.
+		#[pallet::call_index(16)]
+		#[pallet::weight(< T as Config >::WeightInfo::remove_relayer())]
+		pub fn remove_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"remove_relayer".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
 
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
+			Relayers::<T>::remove(&relayer);
+
+			// Emit RelayerRemoved event
+			Self::deposit_event(Event::RelayerRemoved { relayer });
+			Ok(())
+		}
+
+		/// Set the MPC signers and how many of them have to sign a batch of proposals.
+		#[pallet::call_index(17)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_mpc_signers(signers.len() as u32))]
+		pub fn set_mpc_signers(
+			origin: OriginFor<T>,
+			signers: Vec<MpcAddress>,
+			threshold: u32,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_mpc_signers".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			let mut distinct_signers = signers.clone();
+			distinct_signers.sort();
+			distinct_signers.dedup();
+			ensure!(
+				threshold > 0
+					&& threshold as usize <= signers.len()
+					&& distinct_signers.len() == signers.len(),
+				Error::<T>::InvalidMpcThreshold
+			);
+			let bounded_signers: BoundedVec<MpcAddress, T::MaxMpcSigners> =
+				signers.clone().try_into().map_err(|_| Error::<T>::TooManyMpcSigners)?;
+
+			MpcSigners::<T>::put(bounded_signers);
+			MpcThreshold::<T>::put(threshold);
+
+			// Emit MpcSignersSet event
+			Self::deposit_event(Event::MpcSignersSet { signers, threshold });
+			Ok(())
+		}
+
+		/// Prune up to `max_items` of the oldest deposit records. Pruning stops at the first
+		/// record that is still within `DepositRecordRetention`, a record with a pending refund
+		/// is moved to the back of the queue.
+		#[pallet::call_index(18)]
+		#[pallet::weight(< T as Config >::WeightInfo::prune_deposit_records(*max_items))]
+		pub fn prune_deposit_records(origin: OriginFor<T>, max_items: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let tail = DepositRecordQueueTail::<T>::get();
+			let mut new_tail = tail;
+			let mut head = DepositRecordQueueHead::<T>::get();
+			let mut count = 0u32;
+			for _ in 0..max_items {
+				if head >= tail {
+					break;
+				}
+				if let Some(key) = DepositRecordQueue::<T>::get(head) {
+					// Records refunded in the meantime are already gone
+					if let Some(record) = DepositRecords::<T>::get(key) {
+						if now < record.recorded_at.saturating_add(T::DepositRecordRetention::get())
+						{
+							break;
+						}
+						// The refund is still pending, the record is kept without holding back
+						// the records behind it
+						if record.refund_requested {
+							DepositRecordQueue::<T>::insert(new_tail, key);
+							new_tail += 1;
+						} else {
+							DepositRecords::<T>::remove(key);
+							DepositDataHashes::<T>::remove(key);
+							count += 1;
+						}
+					}
+					DepositRecordQueue::<T>::remove(head);
+				}
+				head += 1;
+			}
+			DepositRecordQueueHead::<T>::put(head);
+			if new_tail != tail {
+				DepositRecordQueueTail::<T>::put(new_tail);
+			}
+
+			ensure!(count > 0, Error::<T>::NoDepositRecordsToPrune);
+
+			// Emit DepositRecordsPruned event
+			Self::deposit_event(Event::DepositRecordsPruned { count });
+			Ok(())
+		}
+
+		/// Request a deposit to be refunded, only the sender of the deposit can do so. The
+		/// deposit record is kept from being pruned until the refund is resolved.
+		#[pallet::call_index(19)]
+		#[pallet::weight(< T as Config >::WeightInfo::request_refund())]
+		pub fn request_refund(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			DepositRecords::<T>::try_mutate((dest_domain_id, deposit_nonce), |record| {
+				let record = record.as_mut().ok_or(Error::<T>::DepositRecordNotFound)?;
+				ensure!(record.sender == sender, Error::<T>::NotDepositSender);
+				ensure!(!record.cancelled, Error::<T>::DepositAlreadyCancelled);
+				ensure!(!record.refund_requested, Error::<T>::RefundAlreadyRequested);
+				record.refund_requested = true;
+				Ok::<(), Error<T>>(())
+			})?;
+
+			// Emit RefundRequested event
+			Self::deposit_event(Event::RefundRequested { dest_domain_id, deposit_nonce, sender });
+			Ok(())
+		}
+
+		/// Refund a deposit that its sender requested to refund, e.g. once it is confirmed
+		/// that the proposal will never be executed on the dest domain.
+		#[transactional]
+		#[pallet::call_index(20)]
+		#[pallet::weight(< T as Config >::WeightInfo::refund_deposit())]
+		pub fn refund_deposit(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			let operator_origin_hash = Self::origin_hash(&origin);
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"refund_deposit".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			let record = DepositRecords::<T>::get((dest_domain_id, deposit_nonce))
+				.ok_or(Error::<T>::DepositRecordNotFound)?;
+			ensure!(!record.cancelled, Error::<T>::DepositAlreadyCancelled);
+			ensure!(record.refund_requested, Error::<T>::RefundNotRequested);
+
+			Self::return_deposit(&record)?;
+
+			DepositRecords::<T>::remove((dest_domain_id, deposit_nonce));
+			DepositDataHashes::<T>::remove((dest_domain_id, deposit_nonce));
+			Self::record_intervention(
+				operator_origin_hash,
+				InterventionOperation::RefundDeposit,
+				InterventionSubject::Transfer { domain_id: dest_domain_id, deposit_nonce },
+				Some(record.amount),
+			);
+
+			// Emit DepositRefunded event
+			Self::deposit_event(Event::DepositRefunded {
+				dest_domain_id,
+				deposit_nonce,
+				sender: record.sender,
+				asset_id: record.asset_id,
+				amount: record.amount,
+			});
+			Ok(())
+		}
+
+		/// Initiates a transfer to `final_dest` through `via_domain`. The proposal is addressed
+		/// to `via_domain`, which forwards the transfer to the final domain and charges the fee
+		/// of the second leg from the bridged amount.
+		#[transactional]
+		#[pallet::call_index(21)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit_with_route())]
+		pub fn deposit_with_route(
+			origin: OriginFor<T>,
+			asset: Box<MultiAsset>,
+			final_dest: Box<MultiLocation>,
+			via_domain: DomainID,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::deposit_internal(sender, *asset, *final_dest, None, Some(via_domain), None)
+		}
+
+		/// Set the account allowed to report dest domain health, or remove it with `None`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_health_oracle())]
+		pub fn set_health_oracle(
+			origin: OriginFor<T>,
+			oracle: Option<T::AccountId>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_health_oracle".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			HealthOracle::<T>::set(oracle.clone());
+
+			// Emit HealthOracleSet event
+			Self::deposit_event(Event::HealthOracleSet { oracle });
+			Ok(())
+		}
+
+		/// Report the health of a dest domain, only the health oracle can do so. Deposits to
+		/// the domain are paused while it is unhealthy and resume once it is healthy again.
+		#[pallet::call_index(23)]
+		#[pallet::weight(< T as Config >::WeightInfo::report_domain_health())]
+		pub fn report_domain_health(
+			origin: OriginFor<T>,
+			domain: DomainID,
+			healthy: bool,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			ensure!(HealthOracle::<T>::get() == Some(reporter), Error::<T>::NotHealthOracle);
+			Self::ensure_registered(domain)?;
+
+			// Domain is healthy until reported otherwise
+			let was_healthy = DomainHealth::<T>::get(domain).unwrap_or(true);
+			DomainHealth::<T>::insert(domain, healthy);
+
+			if was_healthy != healthy {
+				// Emit DomainHealthChanged event
+				Self::deposit_event(Event::DomainHealthChanged { domain, healthy });
+			}
+			Ok(())
+		}
+
+		/// Set the inbound limit of a resource, or remove it with `None`. The current window is
+		/// kept, use `clear_inbound_window` to resume an exhausted resource.
+		#[pallet::call_index(24)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_inbound_limit())]
+		pub fn set_inbound_limit(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			limit: Option<InboundLimit>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_inbound_limit".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			if let Some(limit) = limit {
+				Self::ensure_valid_inbound_limit(&limit)?;
+			}
+
+			InboundLimits::<T>::set(resource_id, limit);
+
+			// Emit InboundLimitSet event
+			Self::deposit_event(Event::InboundLimitSet { resource_id, limit });
+			Ok(())
+		}
+
+		/// Reset the inbound window of a resource, proposals of an exhausted resource are
+		/// executed again.
+		#[pallet::call_index(25)]
+		#[pallet::weight(< T as Config >::WeightInfo::clear_inbound_window())]
+		pub fn clear_inbound_window(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let operator_origin_hash = Self::origin_hash(&origin);
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"clear_inbound_window".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			InboundWindows::<T>::remove(resource_id);
+			Self::record_intervention(
+				operator_origin_hash,
+				InterventionOperation::ClearInboundWindow,
+				InterventionSubject::Resource { resource_id },
+				None,
+			);
+
+			// Emit InboundWindowCleared event
+			Self::deposit_event(Event::InboundWindowCleared { resource_id });
+			Ok(())
+		}
+
+		/// Set the share of the deposit fee waived for an account, `Permill::one()` exempts it
+		/// from the fee and `None` removes the discount.
+		#[pallet::call_index(27)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fee_discount())]
+		pub fn set_fee_discount(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			discount: Option<Permill>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_discount".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			FeeDiscounts::<T>::set(&account, discount);
+
+			// Emit FeeDiscountSet event
+			Self::deposit_event(Event::FeeDiscountSet { account, discount });
+			Ok(())
+		}
+
+		/// Pause bridge for `reason`, with an optional note for incident response. Requires the
+		/// `pause_bridge` access. A `CircuitBreaker` pause can only be cleared by the bridge
+		/// committee origin.
+		#[pallet::call_index(28)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_bridge_with_reason())]
+		pub fn pause_bridge_with_reason(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			reason: PauseReason,
+			note: Option<PauseNote>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(dest_domain_id)?;
+
+			Self::pause_domain(dest_domain_id, PauseInfo { reason, note });
+			Ok(())
+		}
+
+		/// Prefund deposit fees in the asset, later deposits of the asset pay their fee from the
+		/// prefund balance before taking it from the deposited amount.
+		#[transactional]
+		#[pallet::call_index(29)]
+		#[pallet::weight(< T as Config >::WeightInfo::prefund_fees())]
+		pub fn prefund_fees(
+			origin: OriginFor<T>,
+			asset_id: Box<AssetId>,
+			amount: u128,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			// Key the balance by the registered asset id, the one deposits carry on with
+			let (asset_id, _) = Self::resolve_asset(&asset_id).ok_or(Error::<T>::AssetNotBound)?;
+			T::AssetTransactor::withdraw_asset(
+				&(asset_id, Fungible(amount)).into(),
+				&Junction::AccountId32 { network: None, id: account.clone().into() }.into(),
+				None,
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			T::AssetTransactor::deposit_asset(
+				&(asset_id, Fungible(amount)).into(),
+				&Junction::AccountId32 { network: None, id: Self::fee_prefund_account().into() }
+					.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			FeePrefunds::<T>::mutate(&account, asset_id, |balance| {
+				*balance = balance.saturating_add(amount)
+			});
+
+			// Emit FeesPrefunded event
+			Self::deposit_event(Event::FeesPrefunded { account, asset_id, amount });
+			Ok(())
+		}
+
+		/// Withdraw `amount` of the unused prefund balance in the asset.
+		#[transactional]
+		#[pallet::call_index(30)]
+		#[pallet::weight(< T as Config >::WeightInfo::withdraw_prefund())]
+		pub fn withdraw_prefund(
+			origin: OriginFor<T>,
+			asset_id: Box<AssetId>,
+			amount: u128,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			let (asset_id, _) = Self::resolve_asset(&asset_id).ok_or(Error::<T>::AssetNotBound)?;
+			Self::take_prefund(&account, asset_id, amount)?;
+			T::AssetTransactor::deposit_asset(
+				&(asset_id, Fungible(amount)).into(),
+				&Junction::AccountId32 { network: None, id: account.clone().into() }.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+
+			// Emit PrefundWithdrawn event
+			Self::deposit_event(Event::PrefundWithdrawn { account, asset_id, amount });
+			Ok(())
+		}
+
+		/// Rebind `resource_id` to `new_asset` from block `effective_at_block`. Proposals and
+		/// deposits keep using the current binding until then.
+		#[pallet::call_index(31)]
+		#[pallet::weight(< T as Config >::WeightInfo::rebind_resource())]
+		pub fn rebind_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			new_asset: Box<AssetId>,
+			effective_at_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(effective_at_block >= now, Error::<T>::RebindInThePast);
+			if let Some(rebind) = ResourceRebinds::<T>::get(resource_id) {
+				ensure!(rebind.effective_at <= now, Error::<T>::RebindPending);
+			}
+			let old_asset = Self::rid_to_assetid(&resource_id).ok_or(Error::<T>::AssetNotBound)?;
+			ensure!(
+				!Self::bindings().iter().any(|(asset, _)| asset == new_asset.as_ref()),
+				Error::<T>::AssetAlreadyBound
+			);
+			ensure!(
+				FailedProposalCounts::<T>::get(resource_id) == 0,
+				Error::<T>::FailedProposalsPending
+			);
+
+			ResourceRebinds::<T>::insert(
+				resource_id,
+				ResourceRebind {
+					old_asset,
+					new_asset: *new_asset,
+					effective_at: effective_at_block,
+				},
+			);
+
+			// Emit ResourceRebound event
+			Self::deposit_event(Event::ResourceRebound {
+				resource_id,
+				old_asset,
+				new_asset: *new_asset,
+				effective_at: effective_at_block,
+			});
+			Ok(())
+		}
+
+		/// Set the format of the deposit data exchanged with a domain
+		#[pallet::call_index(32)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_data_format())]
+		pub fn set_data_format(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			format: DataFormat,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_data_format".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(domain_id)?;
+
+			DataFormats::<T>::insert(domain_id, format);
+
+			// Emit DataFormatSet event
+			Self::deposit_event(Event::DataFormatSet { domain_id, format });
+			Ok(())
+		}
+
+		/// Enable or disable the retry of the queued failed proposals of a resource with the
+		/// leftover weight of blocks, e.g. once its reserve is topped up after an incident
+		#[pallet::call_index(33)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_auto_retry())]
+		pub fn set_auto_retry(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			enabled: bool,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			if enabled {
+				AutoRetryEnabled::<T>::insert(resource_id, true);
+			} else {
+				AutoRetryEnabled::<T>::remove(resource_id);
+			}
+
+			// Emit AutoRetrySet event
+			Self::deposit_event(Event::AutoRetrySet { resource_id, enabled });
+			Ok(())
+		}
+
+		/// Set the cap of the value locked by the bridge, in the 18 decimals of deposit data.
+		/// `None` removes the cap. A cap below the current TVL only rejects new deposits.
+		#[pallet::call_index(34)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_global_tvl_cap())]
+		pub fn set_global_tvl_cap(origin: OriginFor<T>, cap: Option<u128>) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			GlobalTvlCap::<T>::set(cap);
+
+			// Emit GlobalTvlCapSet event
+			Self::deposit_event(Event::GlobalTvlCapSet { cap });
+			Ok(())
+		}
+
+		/// Onboard a resource in one go: set its fees and inbound limit, create its asset if it's
+		/// minted by the bridge and bind it. Nothing is onboarded if any step fails.
+		#[transactional]
+		#[pallet::call_index(35)]
+		#[pallet::weight(< T as Config >::WeightInfo::onboard_resource(spec.fees.len() as u32))]
+		pub fn onboard_resource(origin: OriginFor<T>, spec: Box<OnboardingSpec>) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let OnboardingSpec { asset, resource_id, fees, inbound_limit, mintable, decimals } =
+				*spec;
+
+			let bindings = Self::bindings();
+			ensure!(
+				!bindings.iter().any(|(_, id)| *id == resource_id),
+				Error::<T>::ResourceAlreadyBound
+			);
+			ensure!(
+				!bindings.iter().any(|(bound_asset, _)| *bound_asset == asset),
+				Error::<T>::AssetAlreadyBound
+			);
+
+			for (domain_id, fee) in fees {
+				Self::ensure_registered(domain_id)?;
+				T::ResourceOnboarder::set_fee(domain_id, &asset, fee)?;
+			}
+
+			if let Some(limit) = inbound_limit {
+				Self::ensure_valid_inbound_limit(&limit)?;
+				InboundLimits::<T>::insert(resource_id, limit);
+
+				// Emit InboundLimitSet event
+				Self::deposit_event(Event::InboundLimitSet { resource_id, limit: Some(limit) });
+			}
+
+			if mintable {
+				T::ResourceOnboarder::create_asset(&asset, decimals)?;
+			}
+
+			OnboardedResources::<T>::insert(
+				resource_id,
+				OnboardedResource { asset, decimals, mintable },
+			);
+			for account in Self::resource_accounts(&resource_id) {
+				ProtectedAccounts::<T>::insert(account, resource_id);
+			}
+			Self::ensure_protocol_accounts_distinct()?;
+
+			// Emit ResourceOnboarded event
+			Self::deposit_event(Event::ResourceOnboarded {
+				resource_id,
+				asset,
+				decimals,
+				mintable,
+			});
+			Ok(())
+		}
+
+		/// Offboard a resource onboarded by `onboard_resource`, it's unbound along with its
+		/// inbound limit and auto retry setting. Its failed proposals must be retried first, and
+		/// its reserve must be drained unless `acknowledge_reserve` is set.
+		#[transactional]
+		#[pallet::call_index(36)]
+		#[pallet::weight(< T as Config >::WeightInfo::offboard_resource())]
+		pub fn offboard_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			acknowledge_reserve: bool,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				OnboardedResources::<T>::contains_key(resource_id),
+				Error::<T>::ResourceNotOnboarded
+			);
+			let asset = Self::rid_to_assetid(&resource_id).ok_or(Error::<T>::AssetNotBound)?;
+			ensure!(
+				FailedProposalCounts::<T>::get(resource_id) == 0,
+				Error::<T>::FailedProposalsPending
+			);
+			let reserve_balance = Self::reserve_balance(&asset);
+			ensure!(
+				acknowledge_reserve || reserve_balance == Some(0),
+				Error::<T>::ReserveNotDrained
+			);
+
+			OnboardedResources::<T>::remove(resource_id);
+			for account in Self::resource_accounts(&resource_id) {
+				ProtectedAccounts::<T>::remove(account);
+			}
+			ResourceRebinds::<T>::remove(resource_id);
+			InboundLimits::<T>::remove(resource_id);
+			InboundWindows::<T>::remove(resource_id);
+			AutoRetryEnabled::<T>::remove(resource_id);
+			MinReserveBalance::<T>::remove(resource_id);
+			TokenBucketConfigs::<T>::remove(resource_id);
+			TokenBucket::<T>::remove(resource_id);
+
+			// Emit ResourceOffboarded event
+			Self::deposit_event(Event::ResourceOffboarded { resource_id, asset, reserve_balance });
+			Ok(())
+		}
+
+		/// Set the minimum balance kept in the reserve account of a resource, `None` removes it
+		#[pallet::call_index(37)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_min_reserve_balance())]
+		pub fn set_min_reserve_balance(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			minimum: Option<u128>,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			MinReserveBalance::<T>::set(resource_id, minimum);
+
+			// Emit MinReserveBalanceSet event
+			Self::deposit_event(Event::MinReserveBalanceSet { resource_id, minimum });
+			Ok(())
+		}
+
+		/// Prune the data of the given deposits stored more than `DepositDataRetention` blocks
+		/// ago, the other keys are skipped.
+		#[pallet::call_index(38)]
+		#[pallet::weight(< T as Config >::WeightInfo::prune_deposit_data(keys.len() as u32))]
+		pub fn prune_deposit_data(
+			origin: OriginFor<T>,
+			keys: Vec<(DomainID, DepositNonce)>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut count = 0u32;
+			for (dest_domain_id, deposit_nonce) in keys {
+				if let Some(stored) = DepositDataOf::<T>::get(dest_domain_id, deposit_nonce) {
+					if now >= stored.stored_at.saturating_add(T::DepositDataRetention::get()) {
+						DepositDataOf::<T>::remove(dest_domain_id, deposit_nonce);
+						count += 1;
+					}
+				}
+			}
+			ensure!(count > 0, Error::<T>::NoDepositDataToPrune);
+
+			// Emit DepositDataPruned event
+			Self::deposit_event(Event::DepositDataPruned { count });
+			Ok(())
+		}
+
+		/// Set the token bucket throttling the deposits of a resource, `None` removes it
+		#[pallet::call_index(39)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_token_bucket())]
+		pub fn set_token_bucket(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			config: Option<TokenBucketConfig>,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			match config {
+				Some(config) => {
+					ensure!(config.capacity > 0, Error::<T>::InvalidTokenBucket);
+					TokenBucketConfigs::<T>::insert(resource_id, config);
+					TokenBucket::<T>::insert(
+						resource_id,
+						(config.capacity, frame_system::Pallet::<T>::block_number()),
+					);
+				},
+				None => {
+					TokenBucketConfigs::<T>::remove(resource_id);
+					TokenBucket::<T>::remove(resource_id);
+				},
+			}
+
+			// Emit TokenBucketSet event
+			Self::deposit_event(Event::TokenBucketSet { resource_id, config });
+			Ok(())
+		}
+
+		/// Set the balance of `FeeReserveAccount` in a fee asset below which `FeeReserveLow` is
+		/// emitted, `None` stops checking the asset
+		#[pallet::call_index(40)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fee_reserve_threshold())]
+		pub fn set_fee_reserve_threshold(
+			origin: OriginFor<T>,
+			asset_id: Box<AssetId>,
+			threshold: Option<u128>,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			// Key the threshold by the registered asset id, the one fees are collected in
+			let (asset_id, _) = Self::resolve_asset(&asset_id).ok_or(Error::<T>::AssetNotBound)?;
+			FeeReserveThresholds::<T>::set(asset_id, threshold);
+			// A reserve still below the new threshold is reported again
+			FeeReserveLowAssets::<T>::remove(asset_id);
+
+			// Emit FeeReserveThresholdSet event
+			Self::deposit_event(Event::FeeReserveThresholdSet { asset_id, threshold });
+			Ok(())
+		}
+
+		/// Enable or disable the acknowledgment of the proposals executed from a domain
+		#[pallet::call_index(41)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_ack_enabled())]
+		pub fn set_ack_enabled(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			enabled: bool,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			Self::ensure_registered(domain_id)?;
+
+			AckEnabled::<T>::set(domain_id, enabled);
+
+			// Emit AckEnabledSet event
+			Self::deposit_event(Event::AckEnabledSet { domain_id, enabled });
+			Ok(())
+		}
+
+		/// Halt or resume the acknowledgments of all domains, it takes effect from the next
+		/// executed proposal
+		#[pallet::call_index(42)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_acks_halted())]
+		pub fn set_acks_halted(origin: OriginFor<T>, halted: bool) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			AcksHalted::<T>::put(halted);
+
+			// Emit AcksHaltedSet event
+			Self::deposit_event(Event::AcksHaltedSet { halted });
+			Ok(())
+		}
+
+		/// Resolve the conflict between the bindings of `resource_id` by `T::ResourcePairs` and
+		/// by the registered resources, keeping the binding of `keep`:
+		/// - `Constant`: the registered resource and the rebind are removed
+		/// - `Registry`: the pair of `T::ResourcePairs` is overruled and the rebind is removed
+		/// - `Override`: the pair of `T::ResourcePairs` is overruled and the rebind is kept
+		#[pallet::call_index(43)]
+		#[pallet::weight(< T as Config >::WeightInfo::resolve_binding_conflict())]
+		pub fn resolve_binding_conflict(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			keep: BindingSource,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let (constant_asset, _) =
+				Self::binding_conflict(&resource_id).ok_or(Error::<T>::NoBindingConflict)?;
+
+			match keep {
+				BindingSource::Override => {
+					ensure!(
+						ResourceRebinds::<T>::contains_key(resource_id),
+						Error::<T>::BindingSourceMissing
+					);
+					OverruledResourcePairs::<T>::insert(resource_id, constant_asset);
+				},
+				BindingSource::Registry => {
+					OverruledResourcePairs::<T>::insert(resource_id, constant_asset);
+					ResourceRebinds::<T>::remove(resource_id);
+				},
+				BindingSource::Constant => {
+					OnboardedResources::<T>::remove(resource_id);
+					for account in Self::resource_accounts(&resource_id) {
+						ProtectedAccounts::<T>::remove(account);
+					}
+					ResourceRebinds::<T>::remove(resource_id);
+				},
+			}
+			let (asset, _) =
+				Self::resolve_binding(&resource_id).ok_or(Error::<T>::AssetNotBound)?;
+
+			// Emit BindingConflictResolved event
+			Self::deposit_event(Event::BindingConflictResolved { resource_id, kept: keep, asset });
+			Ok(())
+		}
+
+		/// Prune the receipts of the given proposals kept more than `ExecutionReceiptRetention`
+		/// blocks ago, the other keys are skipped.
+		#[pallet::call_index(44)]
+		#[pallet::weight(< T as Config >::WeightInfo::prune_execution_receipts(keys.len() as u32))]
+		pub fn prune_execution_receipts(
+			origin: OriginFor<T>,
+			keys: Vec<(DomainID, DepositNonce)>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut count = 0u32;
+			for (origin_domain_id, deposit_nonce) in keys {
+				if let Some(receipt) = ExecutionReceipts::<T>::get(origin_domain_id, deposit_nonce)
+				{
+					if now >= receipt.block.saturating_add(T::ExecutionReceiptRetention::get()) {
+						ExecutionReceipts::<T>::remove(origin_domain_id, deposit_nonce);
+						count += 1;
+					}
+				}
+			}
+			ensure!(count > 0, Error::<T>::NoExecutionReceiptToPrune);
+
+			// Emit ExecutionReceiptsPruned event
+			Self::deposit_event(Event::ExecutionReceiptsPruned { count });
+			Ok(())
+		}
+
+		/// Deny inbound transfers to `recipient`, the address as carried by deposit data.
+		/// Proposals to it fail with `RecipientDenied` and the funds stay in the reserve.
+		#[pallet::call_index(45)]
+		#[pallet::weight(< T as Config >::WeightInfo::add_denied_recipient())]
+		pub fn add_denied_recipient(origin: OriginFor<T>, recipient: Vec<u8>) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let key = DeniedRecipient::try_from(recipient.clone())
+				.map_err(|_| Error::<T>::RecipientTooLong)?;
+			RecipientDenylist::<T>::insert(key, ());
+
+			// Emit RecipientDenylisted event
+			Self::deposit_event(Event::RecipientDenylisted { recipient });
+			Ok(())
+		}
+
+		/// Allow inbound transfers to `recipient` again, its failed proposals can be retried
+		#[pallet::call_index(46)]
+		#[pallet::weight(< T as Config >::WeightInfo::remove_denied_recipient())]
+		pub fn remove_denied_recipient(origin: OriginFor<T>, recipient: Vec<u8>) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let key = DeniedRecipient::try_from(recipient.clone())
+				.map_err(|_| Error::<T>::RecipientNotDenied)?;
+			ensure!(RecipientDenylist::<T>::contains_key(&key), Error::<T>::RecipientNotDenied);
+			RecipientDenylist::<T>::remove(key);
+
+			// Emit RecipientDenylistRemoved event
+			Self::deposit_event(Event::RecipientDenylistRemoved { recipient });
+			Ok(())
+		}
+
+		/// Initiates a transfer on behalf of the owner of `permit`, withdrawing the asset and the
+		/// fee from the owner. `signature` is the owner's signature of `permit_payload(permit)`.
+		#[transactional]
+		#[pallet::call_index(47)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit_with_permit())]
+		pub fn deposit_with_permit(
+			origin: OriginFor<T>,
+			permit: Box<DepositPermit<T::AccountId, BlockNumberFor<T>>>,
+			signature: MultiSignature,
+		) -> DispatchResult {
+			let submitter = ensure_signed(origin)?;
+			ensure!(T::EnableDepositPermits::get(), Error::<T>::DepositPermitsDisabled);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= permit.deadline,
+				Error::<T>::PermitExpired
+			);
+			let owner: [u8; 32] = permit.owner.clone().into();
+			ensure!(
+				signature.verify(&Self::permit_payload(&permit)[..], &AccountId32::from(owner)),
+				Error::<T>::InvalidPermitSignature
+			);
+			PermitNonces::<T>::try_mutate(&permit.owner, |nonce| {
+				ensure!(*nonce == permit.nonce, Error::<T>::InvalidPermitNonce);
+				*nonce = nonce.saturating_add(1);
+				Ok::<(), DispatchError>(())
+			})?;
+
+			let DepositPermit { owner, asset, dest, nonce, .. } = *permit;
+			Self::deposit_internal(owner.clone(), asset, dest, None, None, None)?;
+
+			// Emit DepositPermitUsed event
+			Self::deposit_event(Event::DepositPermitUsed { owner, nonce, submitter });
+			Ok(())
+		}
+
+		/// Pause a single direction of a domain: `Inbound` stops executing the proposals from
+		/// the domain, `Outbound` stops the deposits to it.
+		#[pallet::call_index(48)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_route())]
+		pub fn pause_route(
+			origin: OriginFor<T>,
+			direction: RouteDirection,
+			domain_id: DomainID,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_route".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(domain_id)?;
+
+			// Mark the route as paused, the reason of a domain already paused is kept
+			PausedRoutes::<T>::insert((direction, domain_id), true);
+			if !PauseReasons::<T>::contains_key(domain_id) {
+				PauseReasons::<T>::insert(
+					domain_id,
+					PauseInfo { reason: PauseReason::Governance, note: None },
+				);
+			}
+			Self::note_pause_action(domain_id, true);
+
+			// Emit RoutePaused event
+			Self::deposit_event(Event::RoutePaused { direction, domain_id });
+			Ok(())
+		}
+
+		/// Unpause a single direction of a domain, the other direction is left as is
+		#[pallet::call_index(49)]
+		#[pallet::weight(< T as Config >::WeightInfo::unpause_route())]
+		pub fn unpause_route(
+			origin: OriginFor<T>,
+			direction: RouteDirection,
+			domain_id: DomainID,
+		) -> DispatchResult {
+			let is_committee = Self::is_committee(&origin);
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unpause_route".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(domain_id)?;
+
+			ensure!(Self::is_route_paused(direction, domain_id), Error::<T>::BridgeUnpaused);
+			ensure!(
+				is_committee || !Self::is_circuit_breaker_pause(domain_id),
+				Error::<T>::CommitteeOriginRequired
+			);
+
+			// Mark the route as unpaused
+			PausedRoutes::<T>::insert((direction, domain_id), false);
+			Self::end_mpc_key_grace();
+			if !Self::is_paused(domain_id) {
+				PauseReasons::<T>::remove(domain_id);
+			}
+			Self::note_pause_action(domain_id, Self::is_paused(domain_id));
+
+			// Emit RouteUnpaused event
+			Self::deposit_event(Event::RouteUnpaused { direction, domain_id });
+			Ok(())
+		}
+
+		/// Initiates a transfer of several assets to the same recipient under one deposit nonce,
+		/// each asset being a line item of the deposit data.
+		#[transactional]
+		#[pallet::call_index(50)]
+		#[pallet::weight(Pallet::<T>::deposit_multi_weight(assets))]
+		pub fn deposit_multi(
+			origin: OriginFor<T>,
+			assets: Vec<MultiAsset>,
+			dest: Box<MultiLocation>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::deposit_multi_internal(sender, assets, *dest)
+		}
+
+		/// Schedule the sunset of a domain: deposits to it are rejected from
+		/// `deposit_cutoff_block` on, so that users are warned ahead of its unregistration.
+		/// Proposals from the domain keep being executed.
+		#[pallet::call_index(51)]
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_domain_sunset())]
+		pub fn schedule_domain_sunset(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			deposit_cutoff_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"schedule_domain_sunset".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			Self::ensure_registered(domain_id)?;
+			// A sunset is final once its cutoff block is reached
+			Self::ensure_not_sunset(domain_id)?;
+			ensure!(
+				deposit_cutoff_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidSunsetBlock
+			);
+
+			DomainSunsets::<T>::insert(domain_id, deposit_cutoff_block);
+
+			// Emit DomainSunsetScheduled event
+			Self::deposit_event(Event::DomainSunsetScheduled { domain_id, deposit_cutoff_block });
+			Ok(())
+		}
+
+		/// Cancel a deposit within `CancellationWindow` blocks, e.g. after a mistyped recipient,
+		/// only the sender of the deposit can do so. The bridged amount is returned, the fee is
+		/// not. Relayers drop a cancelled deposit, one they processed already is left in
+		/// `CancelledDeposits` for reconciliation.
+		#[transactional]
+		#[pallet::call_index(52)]
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_deposit())]
+		pub fn cancel_deposit(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let record = DepositRecords::<T>::get((dest_domain_id, deposit_nonce))
+				.ok_or(Error::<T>::DepositRecordNotFound)?;
+			ensure!(record.sender == sender, Error::<T>::NotDepositSender);
+			ensure!(!record.cancelled, Error::<T>::DepositAlreadyCancelled);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now < record.recorded_at.saturating_add(T::CancellationWindow::get()),
+				Error::<T>::CancellationWindowElapsed
+			);
+
+			Self::return_deposit(&record)?;
+
+			// A pending refund is resolved by the cancellation, the record can be pruned
+			DepositRecords::<T>::insert(
+				(dest_domain_id, deposit_nonce),
+				DepositRecord { refund_requested: false, cancelled: true, ..record.clone() },
+			);
+			CancelledDeposits::<T>::insert(dest_domain_id, deposit_nonce, now);
+
+			// Emit DepositCancelled event
+			Self::deposit_event(Event::DepositCancelled {
+				dest_domain_id,
+				deposit_nonce,
+				sender,
+				asset_id: record.asset_id,
+				amount: record.amount,
+			});
+			Ok(())
+		}
+
+		/// Escape hatch moving `amount` of an asset out of a bridge account to `to`, for funds
+		/// stuck with no other way out. The first call schedules the withdrawal, the same call
+		/// executes it from `EmergencyWithdrawalDelay` blocks later and for as many blocks, after
+		/// which it has to be scheduled again. Without a delay the scheduled withdrawal does not
+		/// expire. The whole bridge must be paused both times.
+		#[transactional]
+		#[pallet::call_index(53)]
+		#[pallet::weight(< T as Config >::WeightInfo::emergency_withdraw())]
+		pub fn emergency_withdraw(
+			origin: OriginFor<T>,
+			from: BridgeAccount,
+			asset: Box<AssetId>,
+			amount: u128,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let operator_origin_hash = Self::origin_hash(&origin);
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_halted(), Error::<T>::BridgeNotHalted);
+
+			let withdrawal_id = blake2_256(&(from, asset.as_ref(), amount, &to).encode());
+			let now = frame_system::Pallet::<T>::block_number();
+			let delay = T::EmergencyWithdrawalDelay::get();
+			match EmergencyWithdrawals::<T>::get(withdrawal_id) {
+				Some(executable_at) if now < executable_at => {
+					return Err(Error::<T>::EmergencyWithdrawalTimelocked.into())
+				},
+				// without a delay a scheduled withdrawal stays executable until it is executed
+				Some(executable_at)
+					if delay.is_zero() || now < executable_at.saturating_add(delay) => {},
+				_ => {
+					let executable_at = now.saturating_add(delay);
+					EmergencyWithdrawals::<T>::insert(withdrawal_id, executable_at);
+
+					// Emit EmergencyWithdrawalScheduled event
+					Self::deposit_event(Event::EmergencyWithdrawalScheduled {
+						from,
+						asset_id: *asset,
+						amount,
+						to,
+						executable_at,
+					});
+					return Ok(());
+				},
+			}
+			EmergencyWithdrawals::<T>::remove(withdrawal_id);
+
+			let multi_asset: MultiAsset = (*asset, Fungible(amount)).into();
+			T::AssetTransactor::withdraw_asset(
+				&multi_asset,
+				&Junction::AccountId32 { network: None, id: Self::bridge_account(&from).into() }
+					.into(),
+				None,
+			)
+			.map_err(|_| Error::<T>::InsufficientReserve)?;
+			T::AssetTransactor::deposit_asset(
+				&multi_asset,
+				&Junction::AccountId32 { network: None, id: to.clone().into() }.into(),
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			Self::record_intervention(
+				operator_origin_hash,
+				InterventionOperation::EmergencyWithdraw,
+				InterventionSubject::Account { account: from },
+				Some(amount),
+			);
+
+			// Emit EmergencyWithdrawal event
+			Self::deposit_event(Event::EmergencyWithdrawal { from, asset_id: *asset, amount, to });
+			Ok(())
+		}
+
+		/// Set the decimals of the amounts of a resource exchanged with `domain_id`, or the
+		/// default decimals of the resource if `domain_id` is `None`. `None` decimals remove the
+		/// entry, the entry of a domain takes precedence over the default.
+		#[pallet::call_index(54)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_resource_decimals())]
+		pub fn set_resource_decimals(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			domain_id: Option<DomainID>,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_resource_decimals".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(Self::rid_to_assetid(&resource_id).is_some(), Error::<T>::AssetNotBound);
+			if let Some(domain_id) = domain_id {
+				Self::ensure_registered(domain_id)?;
+			}
+			if let Some(decimals) = decimals {
+				ensure!(
+					10u128.checked_pow(u32::from(decimals.abs_diff(18))).is_some(),
+					Error::<T>::InvalidDecimals
+				);
+			}
+
+			match domain_id {
+				Some(domain_id) => {
+					ResourceDomainDecimals::<T>::set(resource_id, domain_id, decimals)
+				},
+				None => DefaultResourceDecimals::<T>::set(resource_id, decimals),
+			}
+
+			// Emit ResourceDecimalsSet event
+			Self::deposit_event(Event::ResourceDecimalsSet { resource_id, domain_id, decimals });
+			Ok(())
+		}
+
+		/// Set the number of blocks the funds of the proposals of a resource are held before
+		/// they can be released by `finalize_proposal`, `None` removes the delay. Proposals
+		/// recorded before keep their release block.
+		#[pallet::call_index(55)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_settlement_delay())]
+		pub fn set_settlement_delay(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			delay: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_settlement_delay".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			RequiredSettlementDelay::<T>::set(resource_id, delay);
+
+			// Emit SettlementDelaySet event
+			Self::deposit_event(Event::SettlementDelaySet { resource_id, delay });
+			Ok(())
+		}
+
+		/// Release the funds of a proposal recorded for the settlement delay of its resource,
+		/// anyone can finalize it once its release block is reached. The proposal is executed
+		/// the way `execute_proposal` executes it, it's left pending if the execution fails.
+		#[transactional]
+		#[pallet::call_index(56)]
+		#[pallet::weight(< T as Config >::WeightInfo::finalize_proposal())]
+		pub fn finalize_proposal(
+			origin: OriginFor<T>,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let settlement = PendingSettlements::<T>::take(origin_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::SettlementNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= settlement.release_at,
+				Error::<T>::SettlementDelayNotElapsed
+			);
+
+			let proposal = Proposal {
+				origin_domain_id,
+				deposit_nonce,
+				resource_id: settlement.resource_id,
+				data: settlement.data.into_inner(),
+			};
+			Self::execute_proposal_internal(&proposal)?;
+			Self::note_proposal_executed(&proposal);
+			Self::acknowledge(&proposal);
+			Ok(())
+		}
+
+		/// Pause every route and capture in `FreezeSnapshot` the reserve balance and the failed
+		/// proposals of each resource and the nonces of each domain, for the post-mortem of an
+		/// incident. `resource_count` and `domain_count` are at least the number of resources
+		/// and domains, the snapshot of a previous freeze must be cleared first.
+		#[pallet::call_index(57)]
+		#[pallet::weight(< T as Config >::WeightInfo::emergency_freeze(*resource_count, *domain_count))]
+		pub fn emergency_freeze(
+			origin: OriginFor<T>,
+			resource_count: u32,
+			domain_count: u32,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"emergency_freeze".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(!FreezeSnapshot::<T>::exists(), Error::<T>::FreezeSnapshotExists);
+
+			let bindings = Self::bindings();
+			let domains = Self::known_domains();
+			ensure!(
+				bindings.len() <= resource_count as usize && domains.len() <= domain_count as usize,
+				Error::<T>::FreezeWitnessTooLow
+			);
+
+			let resources = bindings
+				.into_iter()
+				.map(|(asset, resource_id)| ResourceFreezeState {
+					resource_id,
+					reserve_balance: Self::reserve_balance(&asset),
+					failed_proposals: FailedProposalCounts::<T>::get(resource_id),
+				})
+				.collect::<Vec<_>>();
+			let domains = domains
+				.into_iter()
+				.map(|domain_id| DomainFreezeState {
+					domain_id,
+					deposit_nonce: DepositCounts::<T>::get(domain_id),
+					min_unpruned_nonce: nonces::floor::<T>(domain_id),
+				})
+				.collect::<Vec<_>>();
+			let snapshot = BridgeFreezeSnapshot {
+				frozen_at: frame_system::Pallet::<T>::block_number(),
+				resources: resources
+					.try_into()
+					.map_err(|_| Error::<T>::TooManyResourcesToSnapshot)?,
+				// at most 256 domains
+				domains: BoundedVec::truncate_from(domains),
+			};
+			let snapshot_hash = blake2_256(&snapshot.encode());
+			FreezeSnapshot::<T>::put(snapshot);
+
+			Self::pause_all_domains(&PauseInfo { reason: PauseReason::Governance, note: None });
+
+			// Emit BridgeFrozen event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::BridgeFrozen { sender, snapshot_hash });
+			Ok(())
+		}
+
+		/// Remove the snapshot of the last `emergency_freeze`, the bridge stays paused
+		#[pallet::call_index(58)]
+		#[pallet::weight(< T as Config >::WeightInfo::clear_freeze_snapshot())]
+		pub fn clear_freeze_snapshot(origin: OriginFor<T>) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+
+			ensure!(FreezeSnapshot::<T>::exists(), Error::<T>::FreezeSnapshotNotFound);
+			FreezeSnapshot::<T>::kill();
+
+			// Emit FreezeSnapshotCleared event
+			Self::deposit_event(Event::FreezeSnapshotCleared);
+			Ok(())
+		}
+
+		/// Cancel a settlement before its release, e.g. of a fraudulent proposal. Nothing is
+		/// refunded as the funds never moved, the nonce is consumed so that the proposal can
+		/// neither be finalized nor submitted again.
+		#[pallet::call_index(59)]
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_settlement())]
+		pub fn cancel_settlement(
+			origin: OriginFor<T>,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::EnableSettlementCancellation::get(),
+				Error::<T>::SettlementCancellationDisabled
+			);
+
+			let settlement = PendingSettlements::<T>::take(origin_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::SettlementNotFound)?;
+			nonces::consume::<T>(origin_domain_id, deposit_nonce);
+			Self::clear_failed_proposal(origin_domain_id, deposit_nonce, settlement.resource_id);
+
+			// Emit SettlementCancelled event
+			Self::deposit_event(Event::SettlementCancelled {
+				origin_domain_id,
+				deposit_nonce,
+				resource_id: settlement.resource_id,
+			});
+			Ok(())
+		}
+
+		/// Change the chainID of a registered or unregistered domain, `register_domain` keeps the
+		/// chainID a domain was known with
+		#[pallet::call_index(60)]
+		#[pallet::weight(< T as Config >::WeightInfo::update_domain_chain_id())]
+		pub fn update_domain_chain_id(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			chain_id: ChainID,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"update_domain_chain_id".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			let old_chain_id = match DestChainIds::<T>::get(domain_id) {
+				Some(old_chain_id) => {
+					DestChainIds::<T>::insert(domain_id, chain_id);
+					old_chain_id
+				},
+				None => {
+					let old_chain_id = UnregisteredChainIds::<T>::get(domain_id)
+						.ok_or(Error::<T>::DestDomainNotSupported)?;
+					UnregisteredChainIds::<T>::insert(domain_id, chain_id);
+					old_chain_id
+				},
+			};
+
+			// Emit DomainChainIdUpdated event
+			Self::deposit_event(Event::DomainChainIdUpdated { domain_id, old_chain_id, chain_id });
+			Ok(())
+		}
+
+		/// Executes a batch of deposit proposals as `execute_proposal`. `expected_fresh` is a
+		/// bitmap of the proposals the relayer expects to be unexecuted, bit `i % 8` of byte
+		/// `i / 8` for proposal `i`. If all of them are already executed the call is rejected
+		/// before verifying the signature, and the weight of executed proposals is refunded.
+		#[transactional]
+		#[pallet::call_index(61)]
+		#[pallet::weight(Pallet::<T>::execute_fresh_proposals_weight(proposals))]
+		pub fn execute_fresh_proposals(
+			origin: OriginFor<T>,
+			proposals: Vec<Proposal>,
+			signature: Vec<u8>,
+			expected_fresh: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				Self::is_fresh_bitmap_valid(&proposals, &expected_fresh),
+				Error::<T>::InvalidFreshBitmap
+			);
+			ensure!(
+				!Self::are_fresh_proposals_executed(&proposals, &expected_fresh),
+				Error::<T>::ProposalsAlreadyExecuted
+			);
+
+			let weight = Self::execute_fresh_proposals_weight(&proposals);
+			let executed = proposals
+				.iter()
+				.filter(|p| Self::is_proposal_executed(p.deposit_nonce, p.origin_domain_id))
+				.count() as u64;
+			Self::do_execute_proposal(origin, proposals, signature)?;
+
+			Ok(Some(weight.saturating_sub(Self::proposal_weight().saturating_mul(executed))).into())
+		}
+
+		/// Cap the amount of a resource `recipient`, the address as carried by deposit data,
+		/// receives from proposals overall, `None` removes the cap. Proposals that would exceed
+		/// it fail with `RecipientCapExceeded`. The amount received is counted from the first
+		/// cap and cleared with its removal.
+		#[pallet::call_index(62)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_recipient_inbound_cap())]
+		pub fn set_recipient_inbound_cap(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			recipient: Vec<u8>,
+			cap: Option<u128>,
+		) -> DispatchResult {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let key = (
+				resource_id,
+				DeniedRecipient::try_from(recipient.clone())
+					.map_err(|_| Error::<T>::RecipientTooLong)?,
+			);
+			match cap {
+				Some(cap) => RecipientInboundCap::<T>::insert(&key, cap),
+				None => {
+					RecipientInboundCap::<T>::remove(&key);
+					RecipientInbound::<T>::remove(&key);
+				},
+			}
+
+			// Emit RecipientInboundCapSet event
+			Self::deposit_event(Event::RecipientInboundCapSet { resource_id, recipient, cap });
+			Ok(())
+		}
+
+		/// Replace a mistaken MPC address within `KeySetGracePeriod` blocks of it being set.
+		/// Allowed once, before any proposal is executed, while every domain is paused and has
+		/// stayed paused since the address was set.
+		#[pallet::call_index(63)]
+		#[pallet::weight(< T as Config >::WeightInfo::correct_mpc_key())]
+		pub fn correct_mpc_key(origin: OriginFor<T>, addr: MpcAddress) -> DispatchResult {
+			let operator_origin_hash = Self::origin_hash(&origin);
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let grace_end = MpcKeyGraceEnd::<T>::get().ok_or(Error::<T>::MpcKeyNotCorrectable)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= grace_end,
+				Error::<T>::MpcKeyNotCorrectable
+			);
+			ensure!(!addr.is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(Self::is_halted(), Error::<T>::BridgeNotHalted);
+			ensure!(!nonces::any_consumed::<T>(), Error::<T>::MpcKeyInUse);
+
+			let old_mpc_addr = MpcAddr::<T>::get();
+			MpcAddr::<T>::set(addr);
+			MpcKeyGraceEnd::<T>::kill();
+			Self::record_intervention(
+				operator_origin_hash,
+				InterventionOperation::CorrectMpcKey,
+				InterventionSubject::MpcKey { old_mpc_addr, mpc_addr: addr },
+				None,
+			);
+
+			// Emit MpcKeyCorrected event
+			Self::deposit_event(Event::MpcKeyCorrected { old_mpc_addr, mpc_addr: addr });
+			Ok(())
+		}
+
+		/// Vest the fees of a resource to `beneficiary`, up to `per_block` of them each block,
+		/// instead of handing them over to `FeeDistributor`. Setting the schedule of a resource
+		/// again restarts the vesting of its unreleased fees from the current block, once the
+		/// resource is bound to another asset they have to be released first.
+		#[pallet::call_index(64)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fee_vesting())]
+		pub fn set_fee_vesting(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			beneficiary: T::AccountId,
+			per_block: u128,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_vesting".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(per_block > 0, Error::<T>::InvalidVestingRate);
+			let asset_id = Self::rid_to_assetid(&resource_id).ok_or(Error::<T>::AssetNotBound)?;
+
+			let unreleased = match FeeVestings::<T>::get(resource_id) {
+				Some(schedule) => {
+					let unreleased = schedule.accrued.saturating_sub(schedule.released);
+					ensure!(
+						unreleased == 0 || schedule.asset_id == asset_id,
+						Error::<T>::VestingAssetMismatch
+					);
+					unreleased
+				},
+				None => 0,
+			};
+			FeeVestings::<T>::insert(
+				resource_id,
+				FeeVestingSchedule {
+					beneficiary: beneficiary.clone(),
+					asset_id,
+					accrued: unreleased,
+					released: 0,
+					per_block,
+					start: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			// Emit FeeVestingSet event
+			Self::deposit_event(Event::FeeVestingSet { resource_id, beneficiary, per_block });
+			Ok(())
+		}
+
+		/// Release the fees of a resource vested since the last release to the beneficiary of its
+		/// schedule. Anyone can trigger the release.
+		#[transactional]
+		#[pallet::call_index(65)]
+		#[pallet::weight(< T as Config >::WeightInfo::release_vested_fees())]
+		pub fn release_vested_fees(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut schedule =
+				FeeVestings::<T>::get(resource_id).ok_or(Error::<T>::NoFeeVesting)?;
+			let amount =
+				Self::releasable_fees(&schedule, frame_system::Pallet::<T>::block_number());
+			ensure!(amount > 0, Error::<T>::NoVestedFees);
+
+			let asset: MultiAsset = (schedule.asset_id, Fungible(amount)).into();
+			T::AssetTransactor::withdraw_asset(
+				&asset,
+				&Junction::AccountId32 {
+					network: None,
+					id: Self::fee_vesting_account(&resource_id).into(),
+				}
+				.into(),
+				None,
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			T::AssetTransactor::deposit_asset(
+				&asset,
+				&Junction::AccountId32 { network: None, id: schedule.beneficiary.clone().into() }
+					.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			schedule.released = schedule.released.saturating_add(amount);
+			let beneficiary = schedule.beneficiary.clone();
+			FeeVestings::<T>::insert(resource_id, schedule);
+
+			// Emit VestedFeesReleased event
+			Self::deposit_event(Event::VestedFeesReleased { resource_id, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Initiates a transfer as `deposit`, with the recipient in `recipient_format` rather
+		/// than in the format told from the dest. Requires `EnableRecipientFormat`.
+		#[transactional]
+		#[pallet::call_index(66)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit())]
+		pub fn deposit_with_recipient_format(
+			origin: OriginFor<T>,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+			recipient_format: RecipientFormat,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::deposit_internal(sender, *asset, *dest, None, None, Some(recipient_format))
+		}
+	}
+
+	/// Fee distributor depositing the whole fee to `FeeReserveAccount`
+	pub struct FeeReserveDistributor<T>(PhantomData<T>);
+
+	impl<T: Config> DistributeFee<T::AccountId> for FeeReserveDistributor<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		fn distribute_fee(_payer: &T::AccountId, fee: MultiAsset) -> DispatchResult {
+			T::AssetTransactor::deposit_asset(
+				&fee,
+				&Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			Ok(())
+		}
+	}
+
+	/// Resource onboarder setting the fees with the basic and percentage fee handlers, through the
+	/// fee handler router. Assets of mintable resources are not created.
+	pub struct FeeHandlerOnboarder<T>(PhantomData<T>);
+
+	impl<T> ResourceOnboarder for FeeHandlerOnboarder<T>
+	where
+		T: Config
+			+ sygma_basic_feehandler::Config
+			+ sygma_percentage_feehandler::Config
+			+ sygma_fee_handler_router::Config,
+	{
+		fn set_fee(domain: DomainID, asset: &AssetId, fee: FeeConfig) -> DispatchResult {
+			let handler_type = match fee {
+				FeeConfig::Basic(amount) => {
+					sygma_basic_feehandler::Pallet::<T>::set_fee(
+						RawOrigin::Root.into(),
+						domain,
+						Box::new(*asset),
+						amount,
+					)?;
+					FeeHandlerType::BasicFeeHandler
+				},
+				FeeConfig::Percentage { rate_basis_point, lower_bound, upper_bound } => {
+					sygma_percentage_feehandler::Pallet::<T>::set_fee_rate(
+						RawOrigin::Root.into(),
+						domain,
+						Box::new(*asset),
+						rate_basis_point,
+						lower_bound,
+						upper_bound,
+					)?;
+					FeeHandlerType::PercentageFeeHandler
+				},
+			};
+			sygma_fee_handler_router::Pallet::<T>::set_fee_handler(
+				RawOrigin::Root.into(),
+				domain,
+				Box::new(*asset),
+				handler_type,
+			)
+		}
+
+		fn create_asset(_asset: &AssetId, _decimals: u8) -> DispatchResult {
+			Err(Error::<T>::MintableResourceNotSupported.into())
+		}
+	}
+
+	/// `Pairs` of assets and their decimals along with the onboarded resources, to be read by
+	/// the `DecimalConverter`
+	pub struct WithOnboardedDecimals<T, Pairs>(PhantomData<(T, Pairs)>);
+
+	impl<T: Config, Pairs: Get<Vec<(AssetId, u8)>>> Get<Vec<(AssetId, u8)>>
+		for WithOnboardedDecimals<T, Pairs>
+	{
+		fn get() -> Vec<(AssetId, u8)> {
+			Pairs::get()
+				.into_iter()
+				.chain(
+					OnboardedResources::<T>::iter_values()
+						.map(|resource| (resource.asset, resource.decimals)),
+				)
+				.collect()
+		}
+	}
+
+	/// Domain registry backed by the domains registered in this pallet
+	pub struct LocalDomainRegistry<T>(PhantomData<T>);
+
+	impl<T: Config> IsRegisteredDomain for LocalDomainRegistry<T> {
+		fn is_registered(domain: DomainID) -> bool {
+			DestDomainIds::<T>::get(domain)
+		}
+	}
+
+	impl<T: Config> BridgeEmergency for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		fn pause_everything() {
+			Pallet::<T>::pause_everything()
+		}
+
+		fn restore_from_snapshot() {
+			Pallet::<T>::restore_from_snapshot()
+		}
+	}
+
+	impl<T: Config> Bridge for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		fn transfer(
+			sender: [u8; 32],
+			asset: MultiAsset,
+			dest: MultiLocation,
+			_max_weight: Option<Weight>,
+		) -> DispatchResult {
+			let sender_origin = OriginFor::<T>::from(RawOrigin::Signed(sender.into()));
+			Pallet::<T>::deposit(sender_origin, Box::from(asset), Box::from(dest))?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		/// Verifies that EIP712 typed proposal data is signed by MPC address
+		#[allow(dead_code)]
+		pub(crate) fn verify_by_mpc_address(signing_message: [u8; 32], signature: Vec<u8>) -> bool {
+			let sig = match signature.try_into() {
+				Ok(_sig) => _sig,
+				Err(error) => return false,
+			};
+
+			// recover the signing address
+			if let Ok(pubkey) =
+				// recover the uncompressed pubkey
+				secp256k1_ecdsa_recover(&sig, &signing_message)
+			{
+				let address = Self::public_key_to_address(&pubkey);
+
+				address == MpcAddr::<T>::get().0
+			} else {
+				false
+			}
+		}
+
+		/// Verify the signature of a batch by `MpcAddr`, or the threshold signatures if
+		/// `RequireMpcThreshold` is set. With `EnableSignatureCache` the result is cached for the
+		/// rest of the block. The cache is keyed by everything the result depends on, including
+		/// the current signers, so it never changes the outcome of a verification.
+		pub(crate) fn verify_batch_signature(
+			signing_message: [u8; 32],
+			signature: Vec<u8>,
+		) -> bool {
+			let threshold = T::RequireMpcThreshold::get();
+			let verify = |signature: Vec<u8>| {
+				if threshold {
+					Self::verify_by_mpc_threshold(signing_message, signature)
+				} else {
+					Self::verify_by_mpc_address(signing_message, signature)
+				}
+			};
+			if !T::EnableSignatureCache::get() {
+				return verify(signature);
+			}
+
+			let key = if threshold {
+				blake2_256(
+					&(
+						signing_message,
+						&signature,
+						MpcSigners::<T>::get(),
+						MpcThreshold::<T>::get(),
+					)
+						.encode(),
+				)
+			} else {
+				blake2_256(&(signing_message, &signature, MpcAddr::<T>::get()).encode())
+			};
+			if let Some(verified) = VerifiedSignatures::<T>::get(key) {
+				log::trace!(
+					target: LOG_TARGET,
+					"verify: cached result for digest 0x{}..: {}",
+					Self::digest_prefix(&signing_message),
+					verified
+				);
+				return verified;
+			}
+			let verified = verify(signature);
+			VerifiedSignatures::<T>::insert(key, verified);
+			verified
+		}
+
+		/// Return true if at least `MpcThreshold` distinct `MpcSigners` signed the message,
+		/// `signatures` is the concatenation of 65-byte ECDSA signatures
+		pub fn verify_by_mpc_threshold(signing_message: [u8; 32], signatures: Vec<u8>) -> bool {
+			let signers = MpcSigners::<T>::get();
+			let threshold = MpcThreshold::<T>::get() as usize;
+			if threshold == 0 || signatures.len() % 65 != 0 || signatures.len() / 65 > signers.len()
+			{
+				return false;
+			}
+
+			let mut approvals: Vec<MpcAddress> = Vec::with_capacity(threshold);
+			for signature in signatures.chunks(65) {
+				let sig: [u8; 65] = match signature.try_into() {
+					Ok(_sig) => _sig,
+					Err(_) => return false,
+				};
+
+				// recover the signing address
+				if let Ok(pubkey) = secp256k1_ecdsa_recover(&sig, &signing_message) {
+					let address = MpcAddress(Self::public_key_to_address(&pubkey));
+					if signers.contains(&address) && !approvals.contains(&address) {
+						approvals.push(address);
+					}
+				}
+			}
+
+			approvals.len() >= threshold
+		}
+
+		/// Weight of `deposit_multi`, each asset is charged as a deposit of its own
+		pub fn deposit_multi_weight(assets: &[MultiAsset]) -> Weight {
+			let n = assets.len().clamp(1, T::MaxAssetsPerDeposit::get().max(1) as usize);
+			<T as Config>::WeightInfo::deposit().saturating_mul(n as u64)
+		}
+
+		/// Weight of `execute_proposal` for a batch, including the relayer check and the threshold
+		/// signatures verification if they are required. The signature is verified once per
+		/// batch, over a digest whose hashing scales with the encoded size of the batch.
+		pub fn execute_proposal_weight(proposals: &[Proposal]) -> Weight {
+			let n = proposals.len() as u32;
+			let batch_size = u32::try_from(proposals.encoded_size()).unwrap_or(u32::MAX);
+			let mut weight = <T as Config>::WeightInfo::verify_signature(batch_size)
+				.saturating_add(Self::proposal_weight().saturating_mul(n as u64));
+			if T::RequireRelayerAllowlist::get() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			}
+			if T::RequireMpcThreshold::get() {
+				weight = weight.saturating_add(<T as Config>::WeightInfo::verify_mpc_threshold(
+					T::MaxMpcSigners::get(),
+				));
+			}
+			// `VerifiedSignatures` entry and its removal in `on_finalize`
+			if T::EnableSignatureCache::get() {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+			}
+			if T::UsedNoncesPruneThreshold::get() > 0 {
+				// `UsedNoncesCount` and `MinUnprunedNonce` of each origin domain, and the pruned
+				// `UsedNonces` entries
+				let pruned = T::MaxUsedNoncesPrunedPerCall::get() as u64;
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+					(n as u64).saturating_mul(2).saturating_add(pruned),
+					(n as u64).saturating_add(pruned.saturating_mul(2)),
+				));
+			}
+			weight
+		}
+
+		/// Weight of `execute_fresh_proposals`: `execute_proposal` and the check of the
+		/// proposals expected fresh
+		pub fn execute_fresh_proposals_weight(proposals: &[Proposal]) -> Weight {
+			// `MinUnprunedNonce` and `UsedNonces` of each proposal
+			Self::execute_proposal_weight(proposals).saturating_add(
+				T::DbWeight::get().reads((proposals.len() as u64).saturating_mul(2)),
+			)
+		}
+
+		/// Weight of executing a single proposal, apart from the signature verification
+		pub fn proposal_weight() -> Weight {
+			let db_weight = T::DbWeight::get();
+			let mut weight = <T as Config>::WeightInfo::per_proposal_fungible()
+				// `InboundLimits` and `InboundWindows`
+				.saturating_add(db_weight.reads_writes(2, 1))
+				// `AutoRetryQueue` entry
+				.saturating_add(db_weight.reads_writes(1, 1))
+				// `RecipientDenylist` entry
+				.saturating_add(db_weight.reads(1))
+				// `RecipientInboundCap` and `RecipientInbound` entries
+				.saturating_add(db_weight.reads_writes(2, 1))
+				// `ProtectedAccounts` entry of the beneficiary
+				.saturating_add(db_weight.reads(1))
+				// `HighestExecutedNonce` of the origin domain
+				.saturating_add(db_weight.reads_writes(1, 1))
+				// `GlobalTvl`
+				.saturating_add(db_weight.reads_writes(1, 1));
+			// `BlockActivity`
+			if T::EnableOffchainMetrics::get() {
+				weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+			}
+			// `ExecutionHistory` of the beneficiary
+			if T::EnableAccountHistory::get() {
+				weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+			}
+			// `TransferShortfalls` if the proposal is credited short, the balances compared are
+			// the ones the transactor accesses
+			if T::AllowPartialTransfer::get() {
+				weight = weight.saturating_add(db_weight.writes(1));
+			}
+			// `ExecutionReceipts` entry, read back for the receipt hash
+			weight = weight.saturating_add(db_weight.reads_writes(3, 1));
+			// Leaf of the forwarded transfer or acknowledgment, taken by `on_finalize` for
+			// `DepositRoots`
+			if T::EnableDepositRoots::get() {
+				weight = weight.saturating_add(db_weight.reads_writes(3, 5));
+			}
+			// Acknowledgment: `AcksHalted`, `AckEnabled`, `AcksInBlock`, the fee, the fee pot
+			// and `DepositCounts`
+			weight.saturating_add(db_weight.reads_writes(6, 4))
+		}
+
+		/// Return the id of a deposit, `keccak256(local_domain_id || dest_domain_id || nonce)`
+		/// with the domain ids as 1 byte and the nonce as 8 big-endian bytes, as packed by
+		/// `abi.encodePacked(uint8, uint8, uint64)`. Both chains can compute it independently.
+		pub fn transfer_id(dest_domain_id: DomainID, deposit_nonce: DepositNonce) -> [u8; 32] {
+			keccak_256(
+				&[
+					&[T::LocalDomainId::get().into_inner(), dest_domain_id.into_inner()][..],
+					&deposit_nonce.to_be_bytes()[..],
+				]
+				.concat(),
+			)
+		}
+
+		/// Return the TokenReservedAccount address by the given token. Onboarded resources are
+		/// held in the reserve account of the tokens other than the native one.
+		pub fn get_token_reserved_account(token_id: &AssetId) -> Option<[u8; 32]> {
+			T::TransferReserveAccounts::get()
+				.get(token_id)
+				.map(|account| (*account).clone().into())
+				.or_else(|| {
+					OnboardedResources::<T>::iter_values()
+						.any(|resource| resource.asset == *token_id)
+						.then(|| {
+							accounts::token_reserve_account::<T::AccountId>(T::PalletId::get(), 1)
+								.into()
+						})
+				})
+		}
+
+		/// convert the ECDSA 64-byte uncompressed pubkey to H160 address
+		pub fn public_key_to_address(public_key: &[u8]) -> [u8; 20] {
+			let hash = keccak_256(public_key);
+			let final_hash = array_ref![&hash, 12, 20];
+			*final_hash
+		}
+
+		/// Parse proposals and construct the original signing message, see `proposal_digest`
+		pub fn construct_ecdsa_signing_proposals_data(proposals: &Vec<Proposal>) -> [u8; 32] {
+			Self::proposal_digest(proposals)
+		}
+
+		/// Return the digest the MPC signs for a batch of proposals, the EIP-712 hash of
+		/// `Proposals(Proposal[] proposals)` where each proposal is
+		/// `Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)`.
+		///
+		/// The digest is computed from the decoded fields in their canonical ABI form, never from
+		/// the submitted call bytes. Two submissions only share a digest if they decode to the
+		/// same proposals, and SCALE decoding of the call admits a single encoding of them: a
+		/// compact length of `data` not in its shortest form, or bytes left over after the call,
+		/// fail to decode rather than produce the same proposals.
+		pub fn proposal_digest(proposals: &Vec<Proposal>) -> [u8; 32] {
+			let proposals: Vec<ProposalRef> = proposals
+				.iter()
+				.map(|prop| ProposalRef {
+					origin_domain_id: prop.origin_domain_id.into_inner(),
+					deposit_nonce: prop.deposit_nonce,
+					resource_id: prop.resource_id,
+					data: &prop.data,
+				})
+				.collect();
+			sygma_codec::proposal_digest(
+				&proposals,
+				T::EIP712ChainID::get(),
+				T::DestVerifyingContractAddress::get(),
+			)
+		}
+
+		/// Extract the registered asset id, resource id and transfer amount from `MultiAsset`,
+		/// currently only fungible asset are supported.
+		fn extract_asset(asset: &MultiAsset) -> Option<(AssetId, ResourceId, u128, TransferType)> {
+			match (&asset.fun, &asset.id) {
+				(Fungible(amount), _) => {
+					Self::resolve_asset(&asset.id).map(|(asset_id, resource_id)| {
+						(asset_id, resource_id, *amount, TransferType::FungibleTransfer)
+					})
+				},
+				_ => None,
+			}
+		}
+
+		/// Resolve the registered `AssetId` and its `ResourceId` for the given asset id.
+		///
+		/// An exact match always wins, otherwise the first pair of `T::ResourcePairs` that
+		/// matches under `T::AssetMatchingMode` is returned. With a lenient mode the order of
+		/// `T::ResourcePairs` therefore decides between assets only differing in ignored fields.
+		pub fn resolve_asset(asset_id: &AssetId) -> Option<(AssetId, ResourceId)> {
+			let pairs = Self::bindings();
+			if let Some(pair) = pairs.iter().find(|(id, _)| id == asset_id) {
+				return Some(*pair);
+			}
+
+			let mode = T::AssetMatchingMode::get();
+			if mode == AssetMatchingMode::Strict {
+				return None;
+			}
+			let normalized = Self::normalize_asset_id(asset_id, mode);
+			pairs
+				.into_iter()
+				.find(|(id, _)| Self::normalize_asset_id(id, mode) == normalized)
+		}
+
+		/// Drop the parts of a concrete asset id that are ignored by the given matching mode
+		fn normalize_asset_id(asset_id: &AssetId, mode: AssetMatchingMode) -> AssetId {
+			let mut location = match asset_id {
+				Concrete(location) => *location,
+				_ => return *asset_id,
+			};
+
+			if matches!(mode, AssetMatchingMode::IgnoreParents | AssetMatchingMode::Lenient) {
+				location.parents = 0;
+			}
+			if matches!(mode, AssetMatchingMode::IgnoreNetworkId | AssetMatchingMode::Lenient) {
+				for i in 0..location.interior.len() {
+					match location.interior.at_mut(i) {
+						Some(Junction::AccountId32 { network, .. })
+						| Some(Junction::AccountIndex64 { network, .. })
+						| Some(Junction::AccountKey20 { network, .. }) => *network = None,
+						_ => {},
+					}
+				}
+			}
+
+			Concrete(location)
+		}
+
+		/// Fail with `DestDomainNotSupported` if the domain is not registered in `DomainRegistry`
+		pub fn ensure_registered(domain_id: DomainID) -> DispatchResult {
+			ensure!(
+				T::DomainRegistry::is_registered(domain_id),
+				Error::<T>::DestDomainNotSupported
+			);
+			Ok(())
+		}
+
+		/// Return error if the deposit cutoff block of the domain is reached
+		pub fn ensure_not_sunset(domain_id: DomainID) -> DispatchResult {
+			if let Some(cutoff) = DomainSunsets::<T>::get(domain_id) {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < cutoff,
+					Error::<T>::DomainSunset
+				);
+			}
+			Ok(())
+		}
+
+		/// Emit `DomainSunset` for the domains whose deposit cutoff block is `n`
+		fn announce_domain_sunsets(n: BlockNumberFor<T>) -> Weight {
+			let mut reads = 0u64;
+			for (domain_id, cutoff) in DomainSunsets::<T>::iter() {
+				reads = reads.saturating_add(1);
+				if cutoff == n {
+					// Emit DomainSunset event
+					Self::deposit_event(Event::DomainSunset { domain_id });
+				}
+			}
+			T::DbWeight::get().reads(reads.saturating_add(1))
+		}
+
+		/// Extract the recipient and dest domain of `dest`. The order of the interior junctions is
+		/// part of the route: a dest that only parses with two of its junctions swapped is
+		/// rejected as `InvalidDestination` rather than being misrouted.
+		pub fn extract_dest_data(
+			dest: &MultiLocation,
+		) -> Result<(Vec<u8>, DomainID), DispatchError> {
+			if let Some(extracted) = T::ExtractDestData::extract_dest(dest) {
+				return Ok(extracted);
+			}
+
+			let junctions: Vec<Junction> = dest.interior.iter().copied().collect();
+			for i in 0..junctions.len() {
+				for j in i + 1..junctions.len() {
+					let mut swapped = junctions.clone();
+					swapped.swap(i, j);
+					let reordered = swapped
+						.into_iter()
+						.try_fold(Junctions::Here, |interior, junction| {
+							interior.pushed_with(junction).ok()
+						})
+						.map(|interior| MultiLocation { parents: dest.parents, interior });
+					let misordered = reordered.map_or(false, |reordered| {
+						T::ExtractDestData::extract_dest(&reordered).is_some()
+					});
+					ensure!(!misordered, Error::<T>::InvalidDestination);
+				}
+			}
+			Err(Error::<T>::ExtractDestDataFailed.into())
+		}
+
+		/// Deposit data format of a domain, `UnsupportedDataFormat` if it's not configured
+		pub fn data_format(domain_id: DomainID) -> Result<DataFormat, DispatchError> {
+			DataFormats::<T>::get(domain_id).ok_or(Error::<T>::UnsupportedDataFormat.into())
+		}
+
+		/// Decimals of the amounts of a resource exchanged with a domain: the entry of the domain
+		/// in `ResourceDomainDecimals`, else the `DefaultResourceDecimals` of the resource, else 18
+		pub fn resource_decimals(resource_id: &ResourceId, domain_id: DomainID) -> u8 {
+			ResourceDomainDecimals::<T>::get(resource_id, domain_id)
+				.or_else(|| DefaultResourceDecimals::<T>::get(resource_id))
+				.unwrap_or(18)
+		}
+
+		/// Convert an amount in 18 decimals, the decimals of the `DecimalConverter`, to the
+		/// decimals of the resource on `domain_id`. The digits below the precision of the domain
+		/// are dropped, a non zero amount can not be dropped entirely.
+		pub fn to_domain_decimals(
+			resource_id: &ResourceId,
+			domain_id: DomainID,
+			amount: u128,
+		) -> Result<u128, DispatchError> {
+			let decimals = Self::resource_decimals(resource_id, domain_id);
+			let converted = if decimals >= 18 {
+				10u128
+					.checked_pow(u32::from(decimals - 18))
+					.and_then(|factor| amount.checked_mul(factor))
+			} else {
+				10u128
+					.checked_pow(u32::from(18 - decimals))
+					.map(|factor| amount / factor)
+					.filter(|converted| *converted > 0 || amount == 0)
+			};
+			converted.ok_or(Error::<T>::DecimalConversionFail.into())
+		}
+
+		/// Convert an amount in the decimals of the resource on `domain_id` to 18 decimals
+		pub fn from_domain_decimals(
+			resource_id: &ResourceId,
+			domain_id: DomainID,
+			amount: u128,
+		) -> Result<u128, DispatchError> {
+			let decimals = Self::resource_decimals(resource_id, domain_id);
+			let converted = if decimals >= 18 {
+				10u128
+					.checked_pow(u32::from(decimals - 18))
+					.map(|factor| amount / factor)
+					.filter(|converted| *converted > 0 || amount == 0)
+			} else {
+				10u128
+					.checked_pow(u32::from(18 - decimals))
+					.and_then(|factor| amount.checked_mul(factor))
+			};
+			converted.ok_or(Error::<T>::DecimalConversionFail.into())
+		}
+
+		/// Amount carried by the deposit data of a deposit of `asset` to `dest_domain_id`, before
+		/// the fee. `None` if the asset is not bound to a resource or can not be converted.
+		pub fn dest_amount(asset: MultiAsset, dest_domain_id: DomainID) -> Option<u128> {
+			let (asset_id, resource_id) = Self::resolve_asset(&asset.id)?;
+			let amount = T::DecimalConverter::convert_to(&(asset_id, asset.fun).into())?;
+			Self::to_domain_decimals(&resource_id, dest_domain_id, amount).ok()
+		}
+
+		pub fn create_deposit_data(
+			format: DataFormat,
+			amount: u128,
+			recipient: Vec<u8>,
+		) -> Vec<u8> {
+			sygma_codec::deposit_data(format, amount, &recipient)
+		}
+
+		/// Fail with `DepositDataTooLarge` if `size` bytes of deposit data exceed
+		/// `MaxDepositDataSize`
+		fn ensure_deposit_data_size(size: usize) -> DispatchResult {
+			ensure!(size <= T::MaxDepositDataSize::get() as usize, Error::<T>::DepositDataTooLarge);
+			Ok(())
+		}
+
+		/// Split deposit data into the amount, the recipient data and the extra section, see
+		/// `sygma_codec::split_deposit_data`. With `CompressDepositData` the data version is
+		/// stripped first, decompressing the data if needed.
+		fn split_deposit_data(
+			format: DataFormat,
+			data: &[u8],
+		) -> Result<(u128, Vec<u8>, Vec<u8>), DispatchError> {
+			Self::ensure_deposit_data_size(data.len())?;
+			let data = if T::CompressDepositData::get() {
+				sygma_codec::decompress_deposit_data(data, T::MaxDepositDataSize::get() as usize)
+					.ok_or(Error::<T>::InvalidDepositData)?
+			} else {
+				data.to_vec()
+			};
+			let (amount, recipient, extra) = sygma_codec::split_deposit_data(format, &data)
+				.ok_or(Error::<T>::InvalidDepositData)?;
+			Ok((amount, recipient.to_vec(), extra.to_vec()))
+		}
+
+		/// Extract transfer amount and recipient location from deposit data without route
+		/// entry, the entries of other extensions are skipped.
+		///
+		/// Only fungible transfer is supported so far.
+		fn extract_deposit_data(
+			format: DataFormat,
+			data: &[u8],
+		) -> Result<(u128, MultiLocation), DispatchError> {
+			let (amount, recipient, extra) = Self::split_deposit_data(format, data)?;
+			if Self::final_domain_of(&extra)?.is_some() {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+			if T::EnableRecipientFormat::get() {
+				return Ok((amount, Self::recipient_location(&recipient)?));
+			}
+
+			if let Ok(location) = <MultiLocation>::decode(&mut &recipient[..]) {
+				Ok((amount, location))
+			} else {
+				Err(Error::<T>::InvalidDepositData.into())
+			}
+		}
+
+		/// Prefix `recipient` with its format if `EnableRecipientFormat` is set. The format is
+		/// `explicit_format` if given, else told from the recipient, and it must be handled by
+		/// the domain the recipient is credited on.
+		fn format_recipient(
+			recipient: Vec<u8>,
+			explicit_format: Option<RecipientFormat>,
+			domain_id: DomainID,
+		) -> Result<Vec<u8>, DispatchError> {
+			if !T::EnableRecipientFormat::get() {
+				ensure!(explicit_format.is_none(), Error::<T>::RecipientFormatDisabled);
+				return Ok(recipient);
+			}
+
+			let recipient_format = match explicit_format {
+				Some(recipient_format) => recipient_format,
+				None => Self::detect_recipient_format(&recipient)
+					.ok_or(Error::<T>::UnknownRecipientFormat)?,
+			};
+			ensure!(
+				recipient_format.is_supported_by(Self::data_format(domain_id)?),
+				Error::<T>::RecipientFormatMismatch
+			);
+			sygma_codec::tagged_recipient(recipient_format, &recipient)
+				.ok_or(Error::<T>::RecipientFormatMismatch.into())
+		}
+
+		/// Format of a recipient extracted from a dest: an EVM address or an account by its
+		/// length, else a SCALE encoded `MultiLocation`
+		fn detect_recipient_format(recipient: &[u8]) -> Option<RecipientFormat> {
+			match recipient.len() {
+				20 => Some(RecipientFormat::RawEvm20),
+				32 => Some(RecipientFormat::RawAccount32),
+				_ => {
+					let mut input = recipient;
+					(MultiLocation::decode(&mut input).is_ok() && input.is_empty())
+						.then_some(RecipientFormat::ScaleMultiLocation)
+				},
+			}
+		}
+
+		/// Location of a recipient prefixed with its format, credited on this chain. An EVM
+		/// address is not handled by this chain.
+		fn recipient_location(recipient: &[u8]) -> Result<MultiLocation, DispatchError> {
+			match sygma_codec::split_tagged_recipient(recipient)
+				.ok_or(Error::<T>::InvalidDepositData)?
+			{
+				(RecipientFormat::RawAccount32, account) => {
+					let mut id = [0u8; 32];
+					id.copy_from_slice(account);
+					Ok(Junction::AccountId32 { network: None, id }.into())
+				},
+				(RecipientFormat::ScaleMultiLocation, mut location) => {
+					<MultiLocation>::decode(&mut location)
+						.map_err(|_| Error::<T>::InvalidDepositData.into())
+				},
+				(RecipientFormat::RawEvm20, _) => Err(Error::<T>::RecipientFormatMismatch.into()),
+			}
+		}
+
+		/// Extract transfer amount, recipient data and final dest domain from the deposit data
+		/// of a routed transfer, whose extra section has a route entry.
+		///
+		/// Return `None` if there is no route entry in the extra section.
+		fn extract_routed_deposit_data(
+			format: DataFormat,
+			data: &[u8],
+		) -> Result<Option<(u128, Vec<u8>, DomainID)>, DispatchError> {
+			let (amount, recipient, extra) = Self::split_deposit_data(format, data)?;
+			Ok(Self::final_domain_of(&extra)?
+				.map(|final_domain_id| (amount, recipient, final_domain_id)))
+		}
+
+		/// Final dest domain carried by the route entry of an extra section, a single byte
+		/// domain id. `None` if there is no route entry, the entries of other tags are skipped.
+		fn final_domain_of(extra: &[u8]) -> Result<Option<DomainID>, DispatchError> {
+			let entries = sygma_codec::decode_extra(extra, &[EXTRA_TAG_ROUTE])
+				.ok_or(Error::<T>::InvalidDepositData)?;
+			match entries.first() {
+				None => Ok(None),
+				Some((_, [final_domain_id])) => Ok(Some(DomainID::new(*final_domain_id))),
+				Some(_) => Err(Error::<T>::InvalidDepositData.into()),
+			}
+		}
+
+		/// Source amount hint carried by an extra section, the amount locked on the origin chain
+		/// and its decimals. `None` if there is no hint, the entries of other tags are skipped.
+		fn source_amount_hint_of(extra: &[u8]) -> Result<Option<(u128, u8)>, DispatchError> {
+			let entries = sygma_codec::decode_extra(extra, &[EXTRA_TAG_SOURCE_AMOUNT])
+				.ok_or(Error::<T>::InvalidDepositData)?;
+			match entries.first() {
+				None => Ok(None),
+				Some((_, hint)) if hint.len() == 17 => {
+					let mut amount = [0u8; 16];
+					amount.copy_from_slice(&hint[..16]);
+					Ok(Some((u128::from_be_bytes(amount), hint[16])))
+				},
+				Some(_) => Err(Error::<T>::InvalidDepositData.into()),
+			}
+		}
+
+		/// Check that the source amount hint of a proposal, converted to the local decimals of
+		/// the asset, is exactly the amount about to be credited. A mismatch is reported by
+		/// `DecimalMismatch` rather than crediting a wrong amount.
+		fn check_source_amount_hint(
+			proposal: &Proposal,
+			(hinted_amount, hinted_decimals): (u128, u8),
+			credited: &MultiAsset,
+		) -> DispatchResult {
+			let credited_amount = match credited.fun {
+				Fungible(amount) => amount,
+				_ => return Err(Error::<T>::InvalidDepositData.into()),
+			};
+			// Scaled to the 18 decimals of the `DecimalConverter`, digits can't be dropped
+			let scaled = if hinted_decimals <= 18 {
+				10u128
+					.checked_pow(u32::from(18 - hinted_decimals))
+					.and_then(|factor| hinted_amount.checked_mul(factor))
+			} else {
+				10u128
+					.checked_pow(u32::from(hinted_decimals - 18))
+					.filter(|factor| hinted_amount % factor == 0)
+					.map(|factor| hinted_amount / factor)
+			};
+			let expected_amount = scaled
+				.and_then(|amount| T::DecimalConverter::convert_from(&(credited.id, amount).into()))
+				.and_then(|asset| match asset.fun {
+					Fungible(amount) => Some(amount),
+					_ => None,
+				});
+			if expected_amount == Some(credited_amount) {
+				return Ok(());
+			}
+
+			// Emit DecimalMismatch event
+			Self::deposit_event(Event::DecimalMismatch {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				hinted_amount,
+				hinted_decimals,
+				expected_amount,
+				credited_amount,
+			});
+			Err(Error::<T>::DecimalMismatch.into())
+		}
+
+		/// Forward an inbound routed transfer to its final domain, the fee of the second leg is
+		/// charged from the bridged amount. The forwarded transfer carries no route, so a
+		/// transfer is routed at most once.
+		fn forward_routed_transfer(
+			proposal: &Proposal,
+			asset_id: AssetId,
+			inbound_amount: u128,
+			recipient: Vec<u8>,
+			dest_domain_id: DomainID,
+		) -> DispatchResult {
+			ensure!(dest_domain_id != proposal.origin_domain_id, Error::<T>::InvalidRoute);
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+			Self::ensure_registered(dest_domain_id)?;
+			ensure!(
+				DomainHealth::<T>::get(dest_domain_id).unwrap_or(true),
+				Error::<T>::DomainUnhealthy
+			);
+			Self::ensure_not_sunset(dest_domain_id)?;
+			let format = Self::data_format(dest_domain_id)?;
+			if T::EnableRecipientFormat::get() {
+				let (recipient_format, _) = sygma_codec::split_tagged_recipient(&recipient)
+					.ok_or(Error::<T>::InvalidDepositData)?;
+				ensure!(
+					recipient_format.is_supported_by(format),
+					Error::<T>::RecipientFormatMismatch
+				);
+			}
+			// Header of the dest domain format may be longer than the inbound one
+			Self::ensure_deposit_data_size(sygma_codec::deposit_data_len(format, recipient.len()))?;
+
+			// convert the asset decimal
+			let asset = T::DecimalConverter::convert_from(&(asset_id, inbound_amount).into())
+				.ok_or(Error::<T>::DecimalConversionFail)?;
+			let amount = match asset.fun {
+				Fungible(amount) => amount,
+				_ => return Err(Error::<T>::InvalidDepositData.into()),
+			};
+
+			let fee = T::FeeHandler::get_fee(dest_domain_id, asset.clone())
+				.ok_or(Error::<T>::MissingFeeConfig)?;
+			ensure!(amount > fee, Error::<T>::FeeTooExpensive);
+			let fee_asset: MultiAsset = (asset_id, Fungible(fee)).into();
+
+			// The bridged amount stays in the reserve account if asset is reserved in local
+			// chain, only the fee is taken out of it
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+					.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+				Self::check_reserve(&proposal.resource_id, token_reserved_account, &fee_asset)?;
+				T::AssetTransactor::withdraw_asset(
+					&fee_asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::InsufficientReserve)?;
+			}
+			Self::distribute_fee(&Self::account_id(), &proposal.resource_id, fee_asset)?;
+
+			let bridge_amount = amount - fee;
+			let (deposit_nonce, nonce_events) = Self::next_deposit_nonce(dest_domain_id)?;
+			let decimal_converted_amount =
+				T::DecimalConverter::convert_to(&(asset_id, bridge_amount).into())
+					.ok_or(Error::<T>::DecimalConversionFail)?;
+			let dest_amount = Self::to_domain_decimals(
+				&proposal.resource_id,
+				dest_domain_id,
+				decimal_converted_amount,
+			)?;
+			// Only the fee leaves the bridge, the forwarded amount stays locked
+			Self::unlock_value(
+				&proposal.resource_id,
+				inbound_amount.saturating_sub(decimal_converted_amount),
+			);
+			let sender = Self::account_id();
+
+			// Emit FeeCollected, Deposit and TransferForwarded events
+			let fee_collected = Event::FeeCollected {
+				fee_payer: sender.clone(),
+				dest_domain_id,
+				resource_id: proposal.resource_id,
+				fee_amount: fee,
+				fee_asset_id: asset_id,
+			};
+			let deposit = Event::Deposit {
+				dest_domain_id,
+				resource_id: proposal.resource_id,
+				deposit_nonce,
+				transfer_id: Self::transfer_id(dest_domain_id, deposit_nonce),
+				sender,
+				transfer_type: TransferType::FungibleTransfer,
+				deposit_data: Self::event_deposit_data(
+					dest_domain_id,
+					deposit_nonce,
+					proposal.resource_id,
+					Self::create_deposit_data(format, dest_amount, recipient),
+				)?,
+				handler_response: vec![],
+			};
+			let forwarded = Event::TransferForwarded {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				dest_domain_id,
+				forwarded_deposit_nonce: deposit_nonce,
+			};
+			Self::emit_deposit_events(
+				vec![fee_collected],
+				deposit,
+				[vec![forwarded], nonce_events].concat(),
+			);
+
+			Ok(())
+		}
+
+		fn rid_to_assetid(rid: &ResourceId) -> Option<AssetId> {
+			Self::bindings().into_iter().find(|(_, id)| id == rid).map(|(asset, _)| asset)
+		}
+
+		/// Resources of `T::ResourcePairs` and of the registered resources, in this order, each
+		/// bound once as resolved by `resolve_binding`
+		fn bindings() -> Vec<(AssetId, ResourceId)> {
+			let mut resource_ids: Vec<ResourceId> = Vec::new();
+			for resource_id in T::ResourcePairs::get()
+				.into_iter()
+				.map(|(_, resource_id)| resource_id)
+				.chain(OnboardedResources::<T>::iter_keys())
+			{
+				if !resource_ids.contains(&resource_id) {
+					resource_ids.push(resource_id);
+				}
+			}
+			resource_ids
+				.into_iter()
+				.filter_map(|resource_id| {
+					Self::resolve_binding(&resource_id).map(|(asset, _)| (asset, resource_id))
+				})
+				.collect()
+		}
+
+		/// Asset `resource_id` is bound to and the source of the binding. A rebind overrides the
+		/// registered resources, which override `T::ResourcePairs`, whatever the lookup order.
+		/// A rebind binds the old asset until it takes effect.
+		pub fn resolve_binding(resource_id: &ResourceId) -> Option<(AssetId, BindingSource)> {
+			if let Some(rebind) = ResourceRebinds::<T>::get(resource_id) {
+				let now = frame_system::Pallet::<T>::block_number();
+				let asset =
+					if now >= rebind.effective_at { rebind.new_asset } else { rebind.old_asset };
+				return Some((asset, BindingSource::Override));
+			}
+			if let Some(resource) = OnboardedResources::<T>::get(resource_id) {
+				return Some((resource.asset, BindingSource::Registry));
+			}
+			Self::constant_binding(resource_id).map(|asset| (asset, BindingSource::Constant))
+		}
+
+		/// Asset `T::ResourcePairs` binds `resource_id` to, unless the pair is overruled
+		fn constant_binding(resource_id: &ResourceId) -> Option<AssetId> {
+			T::ResourcePairs::get()
+				.into_iter()
+				.find(|(_, id)| id == resource_id)
+				.map(|(asset, _)| asset)
+				.filter(|asset| OverruledResourcePairs::<T>::get(resource_id) != Some(*asset))
+		}
+
+		/// Assets `T::ResourcePairs` and the registered resources bind `resource_id` to, if they
+		/// disagree. A conflict arises when a runtime upgrade adds a pair for a resource already
+		/// registered in storage.
+		fn binding_conflict(resource_id: &ResourceId) -> Option<(AssetId, AssetId)> {
+			let registered = OnboardedResources::<T>::get(resource_id)?.asset;
+			let constant = Self::constant_binding(resource_id)?;
+			(constant != registered).then_some((constant, registered))
+		}
+
+		/// Resources whose bindings by `T::ResourcePairs` and by the registered resources
+		/// conflict, to be cleared by `resolve_binding_conflict`
+		pub fn binding_conflicts() -> Vec<ResourceId> {
+			OnboardedResources::<T>::iter_keys()
+				.filter(|resource_id| Self::binding_conflict(resource_id).is_some())
+				.collect()
+		}
+
+		/// Data of a deposit whose `Deposit` event carries its hash, `None` if it's not stored or
+		/// already pruned
+		pub fn deposit_data(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<Vec<u8>> {
+			DepositDataOf::<T>::get(dest_domain_id, deposit_nonce)
+				.map(|stored| stored.data.into_inner())
+		}
+
+		/// Deposits to `dest_domain_id` of the nonces `from_nonce..to_nonce`, for relayers to
+		/// rebuild their state without replaying the blocks. A call covers up to
+		/// `MAX_DEPOSIT_RANGE` nonces below the next nonce of the domain, the rest of the range
+		/// starts from `next_nonce`.
+		pub fn deposits_in_range(
+			dest_domain_id: DomainID,
+			from_nonce: DepositNonce,
+			to_nonce: DepositNonce,
+		) -> DepositRange<BlockNumberFor<T>> {
+			let to_nonce = to_nonce.min(DepositCounts::<T>::get(dest_domain_id));
+			let end = to_nonce.min(from_nonce.saturating_add(MAX_DEPOSIT_RANGE));
+			let entries = (from_nonce..end)
+				.map(|deposit_nonce| {
+					let key = (dest_domain_id, deposit_nonce);
+					match DepositRecords::<T>::get(key) {
+						Some(record) => DepositRangeEntry::Recorded(DepositRecordSummary {
+							deposit_nonce,
+							sender: record.sender.into(),
+							asset_id: record.asset_id,
+							amount: record.amount,
+							recorded_at: record.recorded_at,
+							refund_requested: record.refund_requested,
+							cancelled: record.cancelled,
+							deposit_data_hash: DepositDataHashes::<T>::get(key),
+						}),
+						None => DepositRangeEntry::NotRetained(deposit_nonce),
+					}
+				})
+				.collect();
+			DepositRange { entries, next_nonce: Some(end).filter(|end| *end < to_nonce) }
+		}
+
+		/// Receipt of an executed proposal, `None` if it credited nothing or is already pruned
+		pub fn execution_receipt(
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<ExecutionReceipt<BlockNumberFor<T>>> {
+			ExecutionReceipts::<T>::get(origin_domain_id, deposit_nonce)
+		}
+
+		/// Proposals from `origin_domain_id` awaiting the release block of their settlement, by
+		/// nonce
+		pub fn pending_settlements(
+			origin_domain_id: DomainID,
+		) -> Vec<(DepositNonce, BlockNumberFor<T>)> {
+			let mut pending: Vec<_> = PendingSettlements::<T>::iter_prefix(origin_domain_id)
+				.map(|(deposit_nonce, settlement)| (deposit_nonce, settlement.release_at))
+				.collect();
+			pending.sort_by_key(|(deposit_nonce, _)| *deposit_nonce);
+			pending
+		}
+
+		/// Addresses whose signatures verify proposals and the number of them required: the
+		/// `MpcSigners` and `MpcThreshold` if `RequireMpcThreshold` is enabled, otherwise the
+		/// single MPC address with a threshold of 1, if set
+		pub fn mpc_config() -> (Vec<MpcAddress>, u32) {
+			if T::RequireMpcThreshold::get() {
+				(MpcSigners::<T>::get().into_inner(), MpcThreshold::<T>::get())
+			} else if MpcAddr::<T>::get().is_clear() {
+				(Vec::new(), 0)
+			} else {
+				(vec![MpcAddr::<T>::get()], 1)
+			}
+		}
+
+		/// Deposit data as carried by the `Deposit` event: the data itself, or its blake2_256 hash
+		/// with the data stored in `DepositDataOf` if `HashDepositData` is set. With
+		/// `CompressDepositData` the data is prefixed by its data version and compressed first.
+		/// The deposit is added to the root of the block with the data as relayed.
+		fn event_deposit_data(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			resource_id: ResourceId,
+			data: Vec<u8>,
+		) -> Result<Vec<u8>, DispatchError> {
+			let data = if T::CompressDepositData::get() {
+				sygma_codec::compress_deposit_data(&data)
+			} else {
+				data
+			};
+			if T::EnableDepositRoots::get() {
+				let index = BlockDepositLeafCount::<T>::mutate(|count| {
+					*count = count.saturating_add(1);
+					*count - 1
+				});
+				BlockDepositLeaves::<T>::insert(
+					index,
+					sygma_codec::deposit_leaf(
+						dest_domain_id.into_inner(),
+						deposit_nonce,
+						resource_id,
+						&data,
+					),
+				);
+			}
+			if !T::HashDepositData::get() {
+				return Ok(data);
+			}
+			let hash = blake2_256(&data);
+			DepositDataOf::<T>::insert(
+				dest_domain_id,
+				deposit_nonce,
+				StoredDepositData {
+					stored_at: frame_system::Pallet::<T>::block_number(),
+					data: BoundedVec::try_from(data)
+						.map_err(|_| Error::<T>::DepositDataTooLarge)?,
+				},
+			);
+			Ok(hash.to_vec())
+		}
+
+		/// Store the Merkle root of the deposits of the block `n` in `DepositRoots`, if any
+		fn store_deposit_root(n: BlockNumberFor<T>) {
+			let count = BlockDepositLeafCount::<T>::take();
+			if count == 0 {
+				return;
+			}
+			let leaves: Vec<[u8; 32]> =
+				(0..count).filter_map(BlockDepositLeaves::<T>::take).collect();
+			DepositRoots::<T>::insert(n, sygma_codec::merkle_root(&leaves));
+		}
+
+		/// First bytes of a digest, enough to tell it apart in the logs
+		fn digest_prefix(digest: &[u8; 32]) -> HexDisplay<'_> {
+			HexDisplay::from(array_ref![digest, 0, 4])
+		}
+
+		/// Return true if deposit nonce has been used
+		pub fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
+			nonces::is_consumed::<T>(domain_id, nonce)
+		}
+
+		/// Verify and execute a batch of deposit proposals
+		fn do_execute_proposal(
+			origin: OriginFor<T>,
+			proposals: Vec<Proposal>,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			// Check MPC address and bridge status
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(!ExecutionLock::<T>::get(), Error::<T>::ReentrancyDetected);
+
+			ensure!(!proposals.is_empty(), Error::<T>::EmptyProposalList);
+
+			// Check relayer if only allowed relayers can submit proposals
+			if T::RequireRelayerAllowlist::get() {
+				let relayer = ensure_signed(origin).map_err(|_| Error::<T>::RelayerNotAllowed)?;
+				ensure!(Relayers::<T>::get(relayer), Error::<T>::RelayerNotAllowed);
+			}
+
+			// parse proposals and construct signing message to meet EIP712 typed data
+			let final_message = Self::proposal_digest(&proposals);
+
+			// Verify MPC signature
+			let verified = Self::verify_batch_signature(final_message, signature);
+			log::debug!(
+				target: LOG_TARGET,
+				"verify: {} proposals, digest 0x{}.., threshold {}, verified {}",
+				proposals.len(),
+				Self::digest_prefix(&final_message),
+				T::RequireMpcThreshold::get(),
+				verified
+			);
+			ensure!(verified, Error::<T>::BadMpcSignature);
+
+			// Execute proposals one by one.
+			// Note if one proposal failed to execute, we emit `FailedHandlerExecution` rather
+			// than revert whole transaction. The lock is held meanwhile, a reverted call rolls it
+			// back along with the rest.
+			ExecutionLock::<T>::put(true);
+			let dedup = T::EnableBatchDeduplication::get();
+			let mut processed: BTreeSet<(DomainID, DepositNonce)> = BTreeSet::new();
+			for proposal in proposals.iter() {
+				if dedup && !processed.insert((proposal.origin_domain_id, proposal.deposit_nonce)) {
+					// Emit DuplicateProposalSkipped event
+					Self::deposit_event(Event::DuplicateProposalSkipped {
+						origin_domain_id: proposal.origin_domain_id,
+						deposit_nonce: proposal.deposit_nonce,
+					});
+					continue;
+				}
+				let _ = Self::process_proposal(proposal);
+			}
+			ExecutionLock::<T>::kill();
+
+			// Prune replay protection entries of the origin domains if they grow too large
+			let origin_domain_ids: BTreeSet<DomainID> =
+				proposals.iter().map(|p| p.origin_domain_id).collect();
+			let threshold = T::UsedNoncesPruneThreshold::get();
+			let mut budget = T::MaxUsedNoncesPrunedPerCall::get();
+			for origin_domain_id in origin_domain_ids {
+				budget =
+					budget.saturating_sub(nonces::prune::<T>(origin_domain_id, threshold, budget));
+			}
+
+			Ok(())
+		}
+
+		/// Whether the bitmap of proposals expected fresh has a bit for each proposal of the batch
+		pub fn is_fresh_bitmap_valid(proposals: &[Proposal], expected_fresh: &[u8]) -> bool {
+			expected_fresh.len() == (proposals.len() + 7) / 8
+		}
+
+		/// Proposals flagged in the `expected_fresh` bitmap
+		pub fn expected_fresh_proposals<'a>(
+			proposals: &'a [Proposal],
+			expected_fresh: &'a [u8],
+		) -> impl Iterator<Item = &'a Proposal> {
+			proposals.iter().enumerate().filter_map(move |(i, proposal)| {
+				match expected_fresh.get(i / 8) {
+					Some(flags) if flags & (1 << (i % 8)) != 0 => Some(proposal),
+					_ => None,
+				}
+			})
+		}
+
+		/// Whether some proposals are expected fresh and all of them are already executed
+		pub fn are_fresh_proposals_executed(proposals: &[Proposal], expected_fresh: &[u8]) -> bool {
+			let mut flagged = Self::expected_fresh_proposals(proposals, expected_fresh).peekable();
+			flagged.peek().is_some()
+				&& flagged.all(|p| Self::is_proposal_executed(p.deposit_nonce, p.origin_domain_id))
+		}
+
+		/// Check the balance of `FeeReserveAccount` in the fee assets following
+		/// `FeeReserveCheckCursor`, up to `MaxFeeReserveChecksPerBlock` of them. `FeeReserveLow`
+		/// is emitted when a balance drops below its threshold, the checks start over from the
+		/// first asset once the last one is checked.
+		fn check_fee_reserves() -> Weight {
+			let limit = T::MaxFeeReserveChecksPerBlock::get();
+			let mut thresholds = match FeeReserveCheckCursor::<T>::get() {
+				Some(last) => FeeReserveThresholds::<T>::iter_from(
+					FeeReserveThresholds::<T>::hashed_key_for(last),
+				),
+				None => FeeReserveThresholds::<T>::iter(),
+			};
+			let reserve: MultiLocation =
+				Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into();
+
+			let mut checked = 0u32;
+			let mut last = None;
+			while checked < limit {
+				let (asset_id, threshold) = match thresholds.next() {
+					Some(entry) => entry,
+					None => break,
+				};
+				checked += 1;
+				last = Some(asset_id);
+
+				let balance = match T::BalanceInspector::balance_of(&asset_id, &reserve) {
+					Some(balance) => balance,
+					None => continue,
+				};
+				let reported = FeeReserveLowAssets::<T>::get(asset_id);
+				if balance < threshold && !reported {
+					FeeReserveLowAssets::<T>::insert(asset_id, true);
+
+					// Emit FeeReserveLow event
+					Self::deposit_event(Event::FeeReserveLow { asset_id, balance });
+				} else if balance >= threshold && reported {
+					FeeReserveLowAssets::<T>::remove(asset_id);
+				}
+			}
+
+			if checked < limit {
+				FeeReserveCheckCursor::<T>::kill();
+			} else {
+				FeeReserveCheckCursor::<T>::set(last);
+			}
+
+			// Cursor, then the threshold, balance and report flag of each asset
+			T::DbWeight::get().reads_writes(1 + 3 * checked as u64, 1 + checked as u64)
+		}
+
+		/// Retry queued proposals while `limit` covers the weight of one more, up to
+		/// `MaxAutoRetriesPerBlock` of them. Each retry is accounted as a whole
+		/// `execute_proposal` call, the signature check included, plus the queue update.
+		fn process_auto_retries(limit: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let per_item = Self::proposal_weight().saturating_add(db_weight.writes(1));
+			// Read of the next `AutoRetryEnabled` entry
+			let per_resource = db_weight.reads(1);
+
+			let mut used = Weight::zero();
+			let mut budget = T::MaxAutoRetriesPerBlock::get();
+			let mut resources = AutoRetryEnabled::<T>::iter_keys();
+			while budget > 0 {
+				// Read of the first queued proposal of the resource comes along
+				if !limit.all_gte(used.saturating_add(per_resource).saturating_add(per_item)) {
+					break;
+				}
+				used = used.saturating_add(per_resource);
+				let resource_id = match resources.next() {
+					Some(resource_id) => resource_id,
+					None => break,
+				};
+
+				// Collect the entries first, the retries update the queue
+				let mut affordable = 0u32;
+				while affordable < budget
+					&& limit.all_gte(
+						used.saturating_add(per_item.saturating_mul(affordable as u64 + 1)),
+					) {
+					affordable += 1;
+				}
+				let queued: Vec<_> = AutoRetryQueue::<T>::iter_prefix(resource_id)
+					.take(affordable as usize)
+					.collect();
+				for ((origin_domain_id, deposit_nonce), entry) in queued {
+					used = used.saturating_add(per_item);
+					budget -= 1;
+					Self::auto_retry(resource_id, origin_domain_id, deposit_nonce, entry);
+				}
+			}
+			used
+		}
+
+		/// Retry a queued proposal, it's dropped from the queue once executed or after
+		/// `MaxAutoRetryAttempts` failures
+		fn auto_retry(
+			resource_id: ResourceId,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			queued: QueuedProposal<BoundedVec<u8, T::MaxDepositDataSize>>,
+		) {
+			let key = (origin_domain_id, deposit_nonce);
+			// Executed by relayers in the meantime
+			if Self::is_proposal_executed(deposit_nonce, origin_domain_id) {
+				AutoRetryQueue::<T>::remove(resource_id, key);
+				return;
+			}
+
+			let proposal = Proposal {
+				origin_domain_id,
+				deposit_nonce,
+				resource_id,
+				data: queued.data.clone().into_inner(),
+			};
+			if Self::process_proposal(&proposal).is_err() {
+				let attempts = queued.attempts.saturating_add(1);
+				if attempts >= T::MaxAutoRetryAttempts::get() {
+					AutoRetryQueue::<T>::remove(resource_id, key);
+
+					// Emit AutoRetryAbandoned event
+					Self::deposit_event(Event::AutoRetryAbandoned {
+						resource_id,
+						origin_domain_id,
+						deposit_nonce,
+					});
+				} else {
+					AutoRetryQueue::<T>::insert(
+						resource_id,
+						key,
+						QueuedProposal { attempts, ..queued },
+					);
+				}
+			}
+		}
+
+		/// Execute a proposal and record its outcome. Its events are emitted in the order indexers
+		/// rely on: the events of the execution, e.g. the ones of a forwarded transfer, then
+		/// `ProposalExecution` or `FailedHandlerExecution`, then the ones of the acknowledgment.
+		/// The events of the proposals of a batch never interleave.
+		fn process_proposal(proposal: &Proposal) -> DispatchResult {
+			// Proposals of a resource with a settlement delay are only recorded, they are
+			// executed by `finalize_proposal`
+			if let Some(delay) = RequiredSettlementDelay::<T>::get(proposal.resource_id) {
+				return Self::schedule_settlement(proposal, delay).map_err(|e| {
+					Self::note_proposal_failed(proposal, e);
+					e
+				});
+			}
+			match Self::execute_proposal_internal(proposal) {
+				Ok(()) => {
+					log::debug!(
+						target: LOG_TARGET,
+						"execute_proposal: origin domain {}, nonce {}, resource 0x{} executed",
+						proposal.origin_domain_id.into_inner(),
+						proposal.deposit_nonce,
+						HexDisplay::from(&proposal.resource_id)
+					);
+					Self::note_proposal_executed(proposal);
+					Self::acknowledge(proposal);
+					Ok(())
+				},
+				Err(e) => {
+					log::debug!(
+						target: LOG_TARGET,
+						"execute_proposal: origin domain {}, nonce {}, resource 0x{} failed: {:?}",
+						proposal.origin_domain_id.into_inner(),
+						proposal.deposit_nonce,
+						HexDisplay::from(&proposal.resource_id),
+						e
+					);
+					Self::note_proposal_failed(proposal, e);
+					Err(e)
+				},
+			}
+		}
+
+		/// Record a proposal in `PendingSettlements` to be released `delay` blocks later. It
+		/// leaves the auto retry queue, the settlement supersedes it.
+		fn schedule_settlement(proposal: &Proposal, delay: BlockNumberFor<T>) -> DispatchResult {
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Inbound, proposal.origin_domain_id),
+				Error::<T>::BridgePaused
+			);
+			Self::ensure_registered(proposal.origin_domain_id)?;
+			ensure!(
+				!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id),
+				Error::<T>::ProposalAlreadyComplete
+			);
+			ensure!(
+				!PendingSettlements::<T>::contains_key(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce
+				),
+				Error::<T>::SettlementPending
+			);
+			let data = BoundedVec::try_from(proposal.data.clone())
+				.map_err(|_| Error::<T>::DepositDataTooLarge)?;
+
+			let release_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+			PendingSettlements::<T>::insert(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				PendingSettlement { resource_id: proposal.resource_id, data, release_at },
+			);
+			AutoRetryQueue::<T>::remove(
+				proposal.resource_id,
+				(proposal.origin_domain_id, proposal.deposit_nonce),
+			);
+
+			// Emit SettlementScheduled event
+			Self::deposit_event(Event::SettlementScheduled {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				resource_id: proposal.resource_id,
+				release_at,
+			});
+			Ok(())
+		}
+
+		/// Record the execution of a proposal and clear its failure, if any
+		pub(crate) fn note_proposal_executed(proposal: &Proposal) {
+			// Update proposal status
+			nonces::consume::<T>(proposal.origin_domain_id, proposal.deposit_nonce);
+			Self::note_activity(|activity| {
+				activity.executions = activity.executions.saturating_add(1)
+			});
+			Self::clear_failed_proposal(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				proposal.resource_id,
+			);
+
+			// Emit ProposalExecution
+			Self::deposit_event(Event::ProposalExecution {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				data_hash: keccak_256(
+					&[proposal.data.clone(), T::PalletId::get().into_account_truncating()].concat(),
+				),
+				receipt_hash: ExecutionReceipts::<T>::get(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce,
+				)
+				.map(|receipt| blake2_256(&receipt.encode())),
+			});
+		}
+
+		/// Drop the failure record and the auto retry entry of a proposal whose nonce is consumed
+		fn clear_failed_proposal(
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			resource_id: ResourceId,
+		) {
+			if FailedProposals::<T>::take(origin_domain_id, deposit_nonce).is_some() {
+				FailedProposalCounts::<T>::mutate(resource_id, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+			AutoRetryQueue::<T>::remove(resource_id, (origin_domain_id, deposit_nonce));
+		}
+
+		/// Send the acknowledgment of an executed proposal if its origin domain expects one
+		fn acknowledge(proposal: &Proposal) {
+			if !AcksHalted::<T>::get() && AckEnabled::<T>::get(proposal.origin_domain_id) {
+				if let Err(e) = Self::send_ack(proposal) {
+					let err_msg: &'static str = e.into();
+					// Emit AckFailed event
+					Self::deposit_event(Event::AckFailed {
+						origin_domain_id: proposal.origin_domain_id,
+						deposit_nonce: proposal.deposit_nonce,
+						error: err_msg.as_bytes().to_vec(),
+					});
+				}
+			}
+		}
+
+		/// Deposit the acknowledgment of an executed proposal back to its origin domain, as a
+		/// generic message of `AckResourceId` whose fee is paid from `ack_fee_pot_account`
+		#[transactional]
+		fn send_ack(proposal: &Proposal) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let sent = AcksInBlock::<T>::get()
+				.filter(|(block, _)| *block == now)
+				.map_or(0, |(_, sent)| sent);
+			ensure!(sent < T::MaxAcksPerBlock::get(), Error::<T>::AckRateLimited);
+			AcksInBlock::<T>::put((now, sent.saturating_add(1)));
+
+			let dest_domain_id = proposal.origin_domain_id;
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+			let format = Self::data_format(dest_domain_id)?;
+			let (status, delivered_amount) = Self::ack_status(format, proposal)?;
+
+			let fee_asset_id = T::AckFeeAsset::get();
+			let fee = T::FeeHandler::get_fee(dest_domain_id, (fee_asset_id, 0u128).into())
+				.ok_or(Error::<T>::MissingFeeConfig)?;
+			let sender = Self::ack_fee_pot_account();
+			if fee > 0 {
+				let fee_asset: MultiAsset = (fee_asset_id, Fungible(fee)).into();
+				T::AssetTransactor::withdraw_asset(
+					&fee_asset,
+					&Junction::AccountId32 { network: None, id: sender.clone().into() }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+				Self::distribute_fee(&sender, &T::AckResourceId::get(), fee_asset)?;
+			}
+
+			let resource_id = T::AckResourceId::get();
+			let (ack_nonce, nonce_events) = Self::next_deposit_nonce(dest_domain_id)?;
+
+			// Emit FeeCollected, Deposit and AckSent events
+			let fee_collected = Event::FeeCollected {
+				fee_payer: sender.clone(),
+				dest_domain_id,
+				resource_id,
+				fee_amount: fee,
+				fee_asset_id,
+			};
+			let deposit = Event::Deposit {
+				dest_domain_id,
+				resource_id,
+				deposit_nonce: ack_nonce,
+				transfer_id: Self::transfer_id(dest_domain_id, ack_nonce),
+				sender,
+				transfer_type: TransferType::GenericTransfer,
+				deposit_data: Self::event_deposit_data(
+					dest_domain_id,
+					ack_nonce,
+					resource_id,
+					sygma_codec::ack_data(format, proposal.deposit_nonce, status, delivered_amount),
+				)?,
+				handler_response: vec![],
+			};
+			let ack_sent = Event::AckSent {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				ack_nonce,
+				status,
+			};
+			Self::emit_deposit_events(
+				vec![fee_collected],
+				deposit,
+				[vec![ack_sent], nonce_events].concat(),
+			);
+			Ok(())
+		}
+
+		/// Outcome of an executed proposal and the amount delivered, in the 18 decimals of
+		/// deposit data
+		fn ack_status(
+			format: DataFormat,
+			proposal: &Proposal,
+		) -> Result<(AckStatus, u128), DispatchError> {
+			let (amount, _, extra) = Self::split_deposit_data(format, &proposal.data)?;
+			if Self::final_domain_of(&extra)?.is_some() {
+				return Ok((AckStatus::Forwarded, amount));
+			}
+			if UnclaimedTransfers::<T>::contains_key(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+			) {
+				return Ok((AckStatus::Escrowed, amount));
+			}
+			match TransferShortfalls::<T>::get(proposal.origin_domain_id, proposal.deposit_nonce) {
+				Some(shortfall) => {
+					let asset_id = Self::rid_to_assetid(&proposal.resource_id)
+						.ok_or(Error::<T>::AssetNotBound)?;
+					let shortfall = T::DecimalConverter::convert_to(&(asset_id, shortfall).into())
+						.ok_or(Error::<T>::DecimalConversionFail)?;
+					let shortfall = Self::to_domain_decimals(
+						&proposal.resource_id,
+						proposal.origin_domain_id,
+						shortfall,
+					)?;
+					Ok((AckStatus::PartiallyDelivered, amount.saturating_sub(shortfall)))
+				},
+				None => Ok((AckStatus::Delivered, amount)),
+			}
+		}
+
+		/// Count the activity of the current block in `BlockActivity`, the counters of a previous
+		/// block are reset. Nothing is counted unless `EnableOffchainMetrics` is set.
+		fn note_activity(count: impl FnOnce(&mut ActivityCounters<BlockNumberFor<T>>)) {
+			if !T::EnableOffchainMetrics::get() {
+				return;
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut activity = BlockActivity::<T>::get()
+				.filter(|activity| activity.block == now)
+				.unwrap_or(ActivityCounters { block: now, ..Default::default() });
+			count(&mut activity);
+			BlockActivity::<T>::put(activity);
+		}
+
+		/// Record the failure of a proposal, it's queued for the auto retry unless it can't be
+		/// executed anymore
+		fn note_proposal_failed(proposal: &Proposal, e: DispatchError) {
+			Self::note_activity(|activity| {
+				activity.failed_executions = activity.failed_executions.saturating_add(1)
+			});
+			// Proposal above the single cap is never executed, consume its nonce so
+			// that relayers stop retrying it
+			if e == Error::<T>::InboundSingleCapExceeded.into() {
+				nonces::consume::<T>(proposal.origin_domain_id, proposal.deposit_nonce);
+			}
+			let reason = Self::execution_failure_reason(&e);
+			let err_msg: &'static str = e.into();
+			if Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id) {
+				// A consumed nonce can't be retried, e.g. the replay of an executed batch,
+				// so the failure is not recorded and any previous one is dropped
+				Self::clear_failed_proposal(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce,
+					proposal.resource_id,
+				);
+			} else {
+				if !FailedProposals::<T>::contains_key(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce,
+				) {
+					FailedProposalCounts::<T>::mutate(proposal.resource_id, |count| {
+						*count = count.saturating_add(1)
+					});
+				}
+				FailedProposals::<T>::insert(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce,
+					FailedProposal {
+						reason,
+						detail: BoundedVec::truncate_from(err_msg.as_bytes().to_vec()),
+					},
+				);
+				// The data was verified against the MPC signature, it's kept for the auto
+				// retry. Data longer than `MaxDepositDataSize` is not queued.
+				let key = (proposal.origin_domain_id, proposal.deposit_nonce);
+				if !AutoRetryQueue::<T>::contains_key(proposal.resource_id, key) {
+					if let Ok(data) = BoundedVec::try_from(proposal.data.clone()) {
+						AutoRetryQueue::<T>::insert(
+							proposal.resource_id,
+							key,
+							QueuedProposal { data, attempts: 0 },
+						);
+					}
+				}
+			}
+			// Any error during proposal list execution will emit FailedHandlerExecution
+			Self::deposit_event(Event::FailedHandlerExecution {
+				error: err_msg.as_bytes().to_vec(),
+				reason,
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+			});
+		}
+
+		/// Execute a single proposal
+		pub(crate) fn execute_proposal_internal(proposal: &Proposal) -> DispatchResult {
+			// Check if dest domain bridge is paused
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Inbound, proposal.origin_domain_id),
+				Error::<T>::BridgePaused
+			);
+			// Check if domain is supported
+			Self::ensure_registered(proposal.origin_domain_id)?;
+			// Check if proposal has executed
+			ensure!(
+				!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id),
+				Error::<T>::ProposalAlreadyComplete
+			);
+			// Extract ResourceId from proposal data to get corresponding asset (MultiAsset)
+			let asset_id =
+				Self::rid_to_assetid(&proposal.resource_id).ok_or(Error::<T>::AssetNotBound)?;
+			let format = Self::data_format(proposal.origin_domain_id)?;
+			// Transfer to a denied recipient is held, whether it's credited locally or forwarded
+			let (_, recipient, extra) = Self::split_deposit_data(format, &proposal.data)?;
+			ensure!(!Self::is_recipient_denied(&recipient), Error::<T>::RecipientDenied);
+			let recipient_key = DeniedRecipient::try_from(recipient).ok();
+			// Transfer routed through this chain is forwarded to its final domain rather than
+			// credited locally
+			if let Some((amount, recipient, final_domain_id)) =
+				Self::extract_routed_deposit_data(format, &proposal.data)?
+			{
+				let amount = Self::from_domain_decimals(
+					&proposal.resource_id,
+					proposal.origin_domain_id,
+					amount,
+				)?;
+				log::trace!(
+					target: LOG_TARGET,
+					"execute_proposal: origin domain {}, nonce {}, amount {} routed to domain {}",
+					proposal.origin_domain_id.into_inner(),
+					proposal.deposit_nonce,
+					amount,
+					final_domain_id.into_inner()
+				);
+				let window = Self::check_inbound_limit(&proposal.resource_id, amount)?;
+				let received =
+					Self::check_recipient_cap(&proposal.resource_id, &recipient_key, amount)?;
+				Self::forward_routed_transfer(
+					proposal,
+					asset_id,
+					amount,
+					recipient,
+					final_domain_id,
+				)?;
+				Self::note_inbound_transfer(&proposal.resource_id, window, amount);
+				Self::note_recipient_inbound(&proposal.resource_id, recipient_key, received);
+				return Ok(());
+			}
+			// Extract Receipt from proposal data to get corresponding location (MultiLocation)
+			let (amount, location) = Self::extract_deposit_data(format, &proposal.data)?;
+			if let (0, Junctions::X1(Junction::AccountId32 { id, .. })) =
+				(location.parents, location.interior)
+			{
+				ensure!(
+					!Self::is_protected_account(&T::AccountId::from(id)),
+					Error::<T>::ProtectedAccountRecipient
+				);
+			}
+			let amount = Self::from_domain_decimals(
+				&proposal.resource_id,
+				proposal.origin_domain_id,
+				amount,
+			)?;
+			log::trace!(
+				target: LOG_TARGET,
+				"execute_proposal: origin domain {}, nonce {}, amount {}",
+				proposal.origin_domain_id.into_inner(),
+				proposal.deposit_nonce,
+				amount
+			);
+			let window = Self::check_inbound_limit(&proposal.resource_id, amount)?;
+			let received =
+				Self::check_recipient_cap(&proposal.resource_id, &recipient_key, amount)?;
+
+			// convert the asset decimal
+			let decimal_converted_asset =
+				T::DecimalConverter::convert_from(&(asset_id, amount).into())
+					.ok_or(Error::<T>::DecimalConversionFail)?;
+			if let Some(hint) = Self::source_amount_hint_of(&extra)? {
+				Self::check_source_amount_hint(proposal, hint, &decimal_converted_asset)?;
+			}
+
+			let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+			if T::IsReserve::contains(&decimal_converted_asset, &MultiLocation::here()) {
+				Self::check_reserve(
+					&proposal.resource_id,
+					token_reserved_account,
+					&decimal_converted_asset,
+				)?;
+			}
+
+			Self::transfer_to_beneficiary(
+				proposal,
+				location,
+				decimal_converted_asset.clone(),
+				token_reserved_account,
+			)?;
+
+			Self::note_inbound_transfer(&proposal.resource_id, window, amount);
+			Self::note_recipient_inbound(&proposal.resource_id, recipient_key, received);
+			Self::unlock_value(&proposal.resource_id, amount);
+			Self::keep_execution_receipt(proposal, location, &decimal_converted_asset);
+			// Index the execution by the local account credited
+			if let (
+				true,
+				(0, Junctions::X1(Junction::AccountId32 { id, .. })),
+				Fungible(credited_amount),
+			) = (
+				T::EnableAccountHistory::get(),
+				(location.parents, location.interior),
+				decimal_converted_asset.fun,
+			) {
+				ExecutionHistory::<T>::mutate(T::AccountId::from(id), |history| {
+					Self::push_history(
+						history,
+						proposal.origin_domain_id,
+						proposal.deposit_nonce,
+						proposal.resource_id,
+						credited_amount,
+					)
+				});
+			}
+			Ok(())
+		}
+
+		/// Message the owner of a deposit permit signs. It's bound to the chain by its genesis
+		/// hash and to the bridge instance by its pallet id.
+		pub fn permit_payload(permit: &DepositPermit<T::AccountId, BlockNumberFor<T>>) -> Vec<u8> {
+			(
+				b"sygma-deposit-permit",
+				frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+				T::PalletId::get(),
+				permit,
+			)
+				.encode()
+		}
+
+		/// Return true if `recipient` is in `RecipientDenylist`. A recipient longer than
+		/// `MAX_RECIPIENT_LEN` can not be denied.
+		pub fn is_recipient_denied(recipient: &[u8]) -> bool {
+			DeniedRecipient::try_from(recipient.to_vec())
+				.map_or(false, RecipientDenylist::<T>::contains_key)
+		}
+
+		/// Keep the receipt of the amount credited to the recipient, unless the transfer is
+		/// escrowed
+		fn keep_execution_receipt(
+			proposal: &Proposal,
+			recipient: MultiLocation,
+			asset: &MultiAsset,
+		) {
+			let requested = match asset.fun {
+				Fungible(amount) => amount,
+				_ => return,
+			};
+			if UnclaimedTransfers::<T>::contains_key(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+			) {
+				return;
+			}
+			let shortfall =
+				TransferShortfalls::<T>::get(proposal.origin_domain_id, proposal.deposit_nonce)
+					.unwrap_or_default();
+			ExecutionReceipts::<T>::insert(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				ExecutionReceipt {
+					recipient,
+					asset: asset.id,
+					amount: requested.saturating_sub(shortfall),
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+		}
+
+		/// Balance of the reserve account of `asset`, `None` if the asset is not held in reserve
+		/// or its balance can not be read
+		fn reserve_balance(asset: &AssetId) -> Option<u128> {
+			Self::get_token_reserved_account(asset).and_then(|account| {
+				T::BalanceInspector::balance_of(
+					asset,
+					&Junction::AccountId32 { network: None, id: account }.into(),
+				)
+			})
+		}
+
+		/// Check the reserve account holds `asset` before it is withdrawn, emit `ReserveShortfall`
+		/// with the required and available amounts if it does not, and that the withdrawal keeps
+		/// `MinReserveBalance` in it. Nothing is checked if the balance can not be read, the
+		/// withdrawal fails on its own then.
+		fn check_reserve(
+			resource_id: &ResourceId,
+			token_reserved_account: [u8; 32],
+			asset: &MultiAsset,
+		) -> DispatchResult {
+			let required = match asset.fun {
+				Fungible(amount) => amount,
+				_ => return Ok(()),
+			};
+			let available = match T::BalanceInspector::balance_of(
+				&asset.id,
+				&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+			) {
+				Some(available) => available,
+				None => return Ok(()),
+			};
+			if available < required {
+				// Emit ReserveShortfall event
+				Self::deposit_event(Event::ReserveShortfall {
+					resource_id: *resource_id,
+					required,
+					available,
+				});
+				return Err(Error::<T>::InsufficientReserve.into());
+			}
+			ensure!(
+				available - required
+					>= MinReserveBalance::<T>::get(resource_id).unwrap_or_default(),
+				Error::<T>::ReserveBelowMinimum
+			);
+			Ok(())
+		}
+
+		/// Release the asset of a proposal from the reserve account and credit it to the
+		/// beneficiary. Nothing is moved if the beneficiary is credited less than the asset amount
+		/// and `AllowPartialTransfer` is not set.
+		#[transactional]
+		fn transfer_to_beneficiary(
+			proposal: &Proposal,
+			location: MultiLocation,
+			asset: MultiAsset,
+			token_reserved_account: [u8; 32],
+		) -> DispatchResult {
+			// Withdraw `asset` from reserve account
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				T::AssetTransactor::withdraw_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::InsufficientReserve)?;
+			}
+
+			let balance_before = T::BalanceInspector::balance_of(&asset.id, &location);
+			// Deposit `asset` to dest location
+			match T::AssetTransactor::deposit_asset(
+				&asset,
+				&location,
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			) {
+				Ok(_) => Self::check_credited_amount(proposal, &location, &asset, balance_before),
+				// Local account can not be credited for a recoverable reason, e.g. amount below
+				// the existential deposit, keep the funds in escrow rather than have relayers
+				// retry the proposal
+				Err(XcmError::FailedToTransactAsset(_)) if Self::is_local_account(&location) => {
+					Self::escrow_transfer(proposal, location, asset)
+				},
+				Err(_) => Err(Error::<T>::InvalidRecipient.into()),
+			}
+		}
+
+		/// Compare the amount credited to the beneficiary with the asset amount, some transactors
+		/// credit less than requested (e.g. rounding). The amount is not checked if a balance can
+		/// not be read.
+		fn check_credited_amount(
+			proposal: &Proposal,
+			location: &MultiLocation,
+			asset: &MultiAsset,
+			balance_before: Option<u128>,
+		) -> DispatchResult {
+			let requested = match asset.fun {
+				Fungible(amount) => amount,
+				_ => return Ok(()),
+			};
+			let credited =
+				match (balance_before, T::BalanceInspector::balance_of(&asset.id, location)) {
+					(Some(before), Some(after)) => after.saturating_sub(before),
+					_ => return Ok(()),
+				};
+			if credited >= requested {
+				return Ok(());
+			}
+			ensure!(T::AllowPartialTransfer::get(), Error::<T>::PartialTransferNotAllowed);
+
+			TransferShortfalls::<T>::insert(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				requested - credited,
+			);
+
+			// Emit PartialTransferCredited event
+			Self::deposit_event(Event::PartialTransferCredited {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				requested,
+				credited,
+			});
+			Ok(())
+		}
+
+		/// Check the inbound limit of a resource and return its current window, rolling it if
+		/// `InboundLimitWindow` has passed. `None` if the resource has no limit.
+		fn check_inbound_limit(
+			resource_id: &ResourceId,
+			amount: u128,
+		) -> Result<Option<InboundWindow<BlockNumberFor<T>>>, DispatchError> {
+			let limit = match InboundLimits::<T>::get(resource_id) {
+				Some(limit) => limit,
+				None => return Ok(None),
+			};
+			ensure!(amount <= limit.max_single, Error::<T>::InboundSingleCapExceeded);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut window = match InboundWindows::<T>::get(resource_id) {
+				Some(window)
+					if now < window.started_at.saturating_add(T::InboundLimitWindow::get()) =>
+				{
+					window
+				},
+				_ => InboundWindow { started_at: now, amount: 0, exhausted: false },
+			};
+			ensure!(!window.exhausted, Error::<T>::InboundWindowExhausted);
+
+			if window.amount.saturating_add(amount) > limit.max_per_window {
+				window.exhausted = true;
+				InboundWindows::<T>::insert(resource_id, &window);
+
+				// Emit InboundWindowExhausted event
+				Self::deposit_event(Event::InboundWindowExhausted {
+					resource_id: *resource_id,
+					window_started_at: window.started_at,
+				});
+				return Err(Error::<T>::InboundWindowExhausted.into());
+			}
+
+			Ok(Some(window))
+		}
+
+		/// Account an executed amount to the inbound window returned by `check_inbound_limit`
+		fn note_inbound_transfer(
+			resource_id: &ResourceId,
+			window: Option<InboundWindow<BlockNumberFor<T>>>,
+			amount: u128,
+		) {
+			if let Some(mut window) = window {
+				window.amount = window.amount.saturating_add(amount);
+				InboundWindows::<T>::insert(resource_id, window);
+			}
+		}
+
+		/// Check `RecipientInboundCap` of the recipient of a resource and return the amount it
+		/// received with `amount`. `None` if the recipient has no cap.
+		fn check_recipient_cap(
+			resource_id: &ResourceId,
+			recipient: &Option<DeniedRecipient>,
+			amount: u128,
+		) -> Result<Option<u128>, DispatchError> {
+			let key = match recipient {
+				Some(recipient) => (*resource_id, recipient.clone()),
+				None => return Ok(None),
+			};
+			let cap = match RecipientInboundCap::<T>::get(&key) {
+				Some(cap) => cap,
+				None => return Ok(None),
+			};
+			let received = RecipientInbound::<T>::get(&key).saturating_add(amount);
+			ensure!(received <= cap, Error::<T>::RecipientCapExceeded);
+			Ok(Some(received))
+		}
+
+		/// Account the amount returned by `check_recipient_cap` to the recipient
+		fn note_recipient_inbound(
+			resource_id: &ResourceId,
+			recipient: Option<DeniedRecipient>,
+			received: Option<u128>,
+		) {
+			if let (Some(recipient), Some(received)) = (recipient, received) {
+				RecipientInbound::<T>::insert((*resource_id, recipient), received);
+			}
+		}
+
+		/// Fail with `InvalidInboundLimit` unless `0 < max_single <= max_per_window`
+		fn ensure_valid_inbound_limit(limit: &InboundLimit) -> DispatchResult {
+			ensure!(
+				limit.max_single > 0 && limit.max_single <= limit.max_per_window,
+				Error::<T>::InvalidInboundLimit
+			);
+			Ok(())
+		}
+
+		/// Tokens the bucket of a resource holds once refilled for the blocks passed since its
+		/// last refill, `None` if the resource has no bucket
+		fn refilled_tokens(resource_id: &ResourceId) -> Option<u128> {
+			let config = TokenBucketConfigs::<T>::get(resource_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let (tokens, last_refill) =
+				TokenBucket::<T>::get(resource_id).unwrap_or((config.capacity, now));
+			let elapsed: u128 = now.saturating_sub(last_refill).saturated_into();
+			Some(
+				tokens
+					.saturating_add(config.refill_per_block.saturating_mul(elapsed))
+					.min(config.capacity),
+			)
+		}
+
+		/// Refill the token bucket of a resource for the blocks passed since its last refill and
+		/// take `amount` of tokens out of it, `RateLimitExceeded` if it holds fewer
+		fn take_tokens(resource_id: &ResourceId, amount: u128) -> DispatchResult {
+			let tokens = match Self::refilled_tokens(resource_id) {
+				Some(tokens) => tokens,
+				None => return Ok(()),
+			};
+			ensure!(amount <= tokens, Error::<T>::RateLimitExceeded);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			TokenBucket::<T>::insert(resource_id, (tokens - amount, now));
+			Ok(())
+		}
+
+		/// Return true if a deposit of `amount` of the resource, in the 18 decimals of deposit
+		/// data, would be rejected right now by its token bucket or `GlobalTvlCap`, or if the
+		/// resource tripped a circuit breaker still in effect on a domain. Nothing is mutated.
+		pub fn would_rate_limit(resource_id: ResourceId, amount: u128) -> bool {
+			let bucket_exceeded =
+				Self::refilled_tokens(&resource_id).map_or(false, |tokens| amount > tokens);
+			let value = T::AmountNormalizer::normalize(&resource_id, amount);
+			let cap_exceeded = GlobalTvlCap::<T>::get()
+				.map_or(false, |cap| GlobalTvl::<T>::get().saturating_add(value) > cap);
+			let circuit_broken = PauseReasons::<T>::iter().any(|(domain_id, info)| {
+				info.reason == PauseReason::CircuitBreaker { resource: Some(resource_id) }
+					&& Self::is_route_paused(RouteDirection::Outbound, domain_id)
+			});
+			bucket_exceeded || cap_exceeded || circuit_broken
+		}
+
+		/// Add a deposited amount of the resource to `GlobalTvl`, `TvlCapExceeded` if it would
+		/// exceed the cap
+		fn lock_value(resource_id: &ResourceId, amount: u128) -> DispatchResult {
+			let value = T::AmountNormalizer::normalize(resource_id, amount);
+			let tvl = GlobalTvl::<T>::get().saturating_add(value);
+			if let Some(cap) = GlobalTvlCap::<T>::get() {
+				ensure!(tvl <= cap, Error::<T>::TvlCapExceeded);
+			}
+			GlobalTvl::<T>::put(tvl);
+			Ok(())
+		}
+
+		/// Remove an amount of the resource released by the bridge from `GlobalTvl`
+		fn unlock_value(resource_id: &ResourceId, amount: u128) {
+			let value = T::AmountNormalizer::normalize(resource_id, amount);
+			GlobalTvl::<T>::mutate(|tvl| *tvl = tvl.saturating_sub(value));
+		}
+
+		/// Classify the error of a failed proposal for relayers
+		pub fn execution_failure_reason(error: &DispatchError) -> ExecutionFailureReason {
+			let is_any = |errors: Vec<Error<T>>| {
+				errors.into_iter().any(|e| DispatchError::from(e) == *error)
+			};
+
+			if is_any(vec![Error::<T>::AssetNotBound, Error::<T>::NoLiquidityHolderAccountBound]) {
+				ExecutionFailureReason::ResourceNotRegistered
+			} else if is_any(vec![Error::<T>::InsufficientReserve, Error::<T>::ReserveBelowMinimum])
+			{
+				ExecutionFailureReason::InsufficientReserve
+			} else if is_any(vec![
+				Error::<T>::InvalidDepositData,
+				Error::<T>::DecimalConversionFail,
+				Error::<T>::DepositDataTooLarge,
+			]) {
+				ExecutionFailureReason::DecodeError
+			} else if is_any(vec![
+				Error::<T>::InvalidRecipient,
+				Error::<T>::RecipientFormatMismatch,
+			]) {
+				ExecutionFailureReason::RecipientInvalid
+			} else if is_any(vec![Error::<T>::BelowExistentialDeposit]) {
+				ExecutionFailureReason::EdBelowMinimum
+			} else if is_any(vec![
+				Error::<T>::BridgePaused,
+				Error::<T>::DestDomainNotSupported,
+				Error::<T>::DomainUnhealthy,
+				Error::<T>::InvalidRoute,
+				Error::<T>::UnsupportedDataFormat,
+				Error::<T>::RecipientDenied,
+			]) {
+				ExecutionFailureReason::Filtered
+			} else if is_any(vec![
+				Error::<T>::InboundSingleCapExceeded,
+				Error::<T>::InboundWindowExhausted,
+				Error::<T>::RecipientCapExceeded,
+			]) {
+				ExecutionFailureReason::InboundLimitExceeded
+			} else if is_any(vec![Error::<T>::DecimalMismatch]) {
+				ExecutionFailureReason::DecimalMismatch
+			} else if is_any(vec![Error::<T>::ProtectedAccountRecipient]) {
+				ExecutionFailureReason::ProtectedAccountRecipient
+			} else {
+				match error {
+					DispatchError::Module(ModuleError { error, .. }) => {
+						ExecutionFailureReason::Other(error[0])
+					},
+					_ => ExecutionFailureReason::Other(u8::MAX),
+				}
+			}
+		}
+
+		/// Classify the error of a failed deposit for indexers
+		pub fn deposit_failure_reason(error: &DispatchError) -> DepositFailureReason {
+			let is_any = |errors: Vec<Error<T>>| {
+				errors.into_iter().any(|e| DispatchError::from(e) == *error)
+			};
+
+			if is_any(vec![
+				Error::<T>::ExtractDestDataFailed,
+				Error::<T>::InvalidDestination,
+				Error::<T>::InvalidRoute,
+			]) {
+				DepositFailureReason::InvalidDestination
+			} else if is_any(vec![
+				Error::<T>::MissingMpcAddress,
+				Error::<T>::BridgePaused,
+				Error::<T>::DestDomainNotSupported,
+				Error::<T>::DomainUnhealthy,
+				Error::<T>::UnsupportedDataFormat,
+			]) {
+				DepositFailureReason::DomainUnavailable
+			} else if is_any(vec![
+				Error::<T>::AssetNotBound,
+				Error::<T>::NoLiquidityHolderAccountBound,
+				Error::<T>::DecimalConversionFail,
+			]) {
+				DepositFailureReason::AssetNotSupported
+			} else if is_any(vec![
+				Error::<T>::MissingFeeConfig,
+				Error::<T>::FeeTooExpensive,
+				Error::<T>::InsufficientPrefund,
+			]) {
+				DepositFailureReason::FeeNotPayable
+			} else if is_any(vec![Error::<T>::TransactFailed]) {
+				DepositFailureReason::WithdrawFailed
+			} else if is_any(vec![
+				Error::<T>::TvlCapExceeded,
+				Error::<T>::DepositDataTooLarge,
+				Error::<T>::RateLimitExceeded,
+			]) {
+				DepositFailureReason::LimitExceeded
+			} else {
+				match error {
+					DispatchError::Module(ModuleError { error, .. }) => {
+						DepositFailureReason::Other(error[0])
+					},
+					_ => DepositFailureReason::Other(u8::MAX),
+				}
+			}
+		}
+
+		/// Return the account of the pallet
+		pub fn account_id() -> T::AccountId {
+			accounts::pallet_account(T::PalletId::get())
+		}
+
+		/// Return the account holding the vesting fees of a resource
+		pub fn fee_vesting_account(resource_id: &ResourceId) -> T::AccountId {
+			accounts::fee_vesting_account(T::PalletId::get(), resource_id)
+		}
+
+		/// Fees of `schedule` vested by block `now` and not released yet
+		pub fn releasable_fees(
+			schedule: &FeeVestingSchedule<T::AccountId, BlockNumberFor<T>>,
+			now: BlockNumberFor<T>,
+		) -> u128 {
+			let elapsed: u128 = now.saturating_sub(schedule.start).saturated_into();
+			schedule
+				.accrued
+				.min(schedule.per_block.saturating_mul(elapsed))
+				.saturating_sub(schedule.released)
+		}
+
+		/// Hand a fee collected for `resource_id` over to `FeeDistributor`, unless the resource
+		/// has a fee vesting schedule in the asset of the fee, the fee then accrues to it
+		fn distribute_fee(
+			payer: &T::AccountId,
+			resource_id: &ResourceId,
+			fee: MultiAsset,
+		) -> DispatchResult {
+			let schedule = FeeVestings::<T>::get(resource_id);
+			match (schedule, &fee.fun) {
+				(Some(mut schedule), Fungible(amount)) if schedule.asset_id == fee.id => {
+					T::AssetTransactor::deposit_asset(
+						&fee,
+						&Junction::AccountId32 {
+							network: None,
+							id: Self::fee_vesting_account(resource_id).into(),
+						}
+						.into(),
+						// Put empty message hash here because we are not sending XCM message
+						&XcmContext::with_message_id([0; 32]),
+					)
+					.map_err(|_| Error::<T>::TransactFailed)?;
+					schedule.accrued = schedule.accrued.saturating_add(*amount);
+					FeeVestings::<T>::insert(resource_id, schedule);
+					Ok(())
+				},
+				_ => T::FeeDistributor::distribute_fee(payer, fee),
+			}
+		}
+
+		/// Return the holding account escrowing undeliverable inbound transfers of a resource
+		pub fn escrow_account(resource_id: &ResourceId) -> T::AccountId {
+			accounts::escrow_account(T::PalletId::get(), resource_id)
+		}
+
+		/// Return page `page` of the deposits of `account`, newest first
+		pub fn deposits_of(
+			account: &T::AccountId,
+			page: u32,
+			page_size: u32,
+		) -> Vec<TransferSummary<BlockNumberFor<T>>> {
+			Self::history_page(DepositHistory::<T>::get(account), page, page_size)
+		}
+
+		/// Return page `page` of the executed proposals crediting `account`, newest first
+		pub fn executions_to(
+			account: &T::AccountId,
+			page: u32,
+			page_size: u32,
+		) -> Vec<TransferSummary<BlockNumberFor<T>>> {
+			Self::history_page(ExecutionHistory::<T>::get(account), page, page_size)
+		}
+
+		fn history_page(
+			history: BoundedVec<TransferSummary<BlockNumberFor<T>>, T::MaxHistoryPerAccount>,
+			page: u32,
+			page_size: u32,
+		) -> Vec<TransferSummary<BlockNumberFor<T>>> {
+			history
+				.into_iter()
+				.rev()
+				.skip((page as usize).saturating_mul(page_size as usize))
+				.take(page_size as usize)
+				.collect()
+		}
+
+		/// Return the bridge account `account` stands for
+		pub fn bridge_account(account: &BridgeAccount) -> T::AccountId {
+			match account {
+				BridgeAccount::Pallet => Self::account_id(),
+				BridgeAccount::TokenReserve => {
+					accounts::token_reserve_account(T::PalletId::get(), 1)
+				},
+				BridgeAccount::Escrow { resource_id } => Self::escrow_account(resource_id),
+				BridgeAccount::FeePrefund => Self::fee_prefund_account(),
+				BridgeAccount::AckFeePot => Self::ack_fee_pot_account(),
+				BridgeAccount::FeeVesting { resource_id } => Self::fee_vesting_account(resource_id),
+			}
+		}
+
+		/// Return true if `who` is the fee reserve, a transfer reserve or a bridge account of
+		/// the pallet or of a resource. The accounts of the onboarded resources are looked up in
+		/// `ProtectedAccounts`, so the cost doesn't grow with the resources.
+		pub fn is_protected_account(who: &T::AccountId) -> bool {
+			Self::configured_protocol_accounts().contains(who)
+				|| [
+					BridgeAccount::Pallet,
+					BridgeAccount::TokenReserve,
+					BridgeAccount::FeePrefund,
+					BridgeAccount::AckFeePot,
+				]
+				.into_iter()
+				.any(|account| Self::bridge_account(&account) == *who)
+				|| ProtectedAccounts::<T>::contains_key(who)
+				|| T::ResourcePairs::get()
+					.iter()
+					.any(|(_, resource_id)| Self::resource_accounts(resource_id).contains(who))
+		}
+
+		/// Bridge accounts derived for a resource
+		fn resource_accounts(resource_id: &ResourceId) -> [T::AccountId; 2] {
+			[Self::escrow_account(resource_id), Self::fee_vesting_account(resource_id)]
+		}
+
+		/// `FeeReserveAccount` and the accounts of `TransferReserveAccounts`
+		fn configured_protocol_accounts() -> Vec<T::AccountId> {
+			let mut accounts: Vec<T::AccountId> =
+				T::TransferReserveAccounts::get().into_values().collect();
+			accounts.push(T::FeeReserveAccount::get());
+			accounts
+		}
+
+		/// Bridge accounts not shared with the configured reserves, those of the bound resources
+		/// included, so the set grows as they are bound
+		fn derived_protocol_accounts() -> Vec<BridgeAccount> {
+			let mut accounts = vec![BridgeAccount::FeePrefund, BridgeAccount::AckFeePot];
+			for (_, resource_id) in Self::bindings() {
+				accounts.push(BridgeAccount::Escrow { resource_id });
+				accounts.push(BridgeAccount::FeeVesting { resource_id });
+			}
+			accounts
+		}
+
+		/// Ensure none of the configured reserves is an account derived for the bound resources,
+		/// as funds credited to it would be accounted twice
+		fn ensure_protocol_accounts_distinct() -> DispatchResult {
+			let configured = Self::configured_protocol_accounts();
+			ensure!(
+				!Self::derived_protocol_accounts()
+					.iter()
+					.any(|account| configured.contains(&Self::bridge_account(account))),
+				Error::<T>::ProtocolAccountConflict
+			);
+			Ok(())
+		}
+
+		/// Return the account holding the prefunded deposit fees
+		pub fn fee_prefund_account() -> T::AccountId {
+			accounts::fee_prefund_account(T::PalletId::get())
+		}
+
+		/// Return the account paying the fees of the acknowledgments, funded by the committee
+		pub fn ack_fee_pot_account() -> T::AccountId {
+			accounts::ack_fee_pot_account(T::PalletId::get())
+		}
+
+		/// Return the account acting on behalf of `sender` of a remote domain
+		pub fn remote_sender_account(domain_id: DomainID, sender: &[u8]) -> T::AccountId {
+			accounts::remote_sender_account(T::PalletId::get(), domain_id, sender)
+		}
+
+		/// Resource id the asset at `location` is bridged as, resolved the same way as `deposit`
+		/// resolves the deposited asset
+		pub fn resource_for_location(location: MultiLocation) -> Option<ResourceId> {
+			Self::resolve_asset(&location.into()).map(|(_, resource_id)| resource_id)
+		}
+
+		/// Gather in one call whether the bridge is usable for the asset at `asset_location` to
+		/// the dest domain. Fee and reserve are checked for `probe_amount` of the asset, in its
+		/// local decimals.
+		pub fn bridge_status(
+			dest_domain_id: DomainID,
+			asset_location: MultiLocation,
+			probe_amount: Option<u128>,
+		) -> BridgeStatus {
+			let resolved = Self::resolve_asset(&asset_location.into());
+			// asset is probed in its registered form if it's bound to a resource
+			let asset_id = resolved.map_or(asset_location.into(), |(asset_id, _)| asset_id);
+			let fee = T::FeeHandler::get_fee(
+				dest_domain_id,
+				(asset_id, probe_amount.unwrap_or(0)).into(),
+			);
+			let reserve_sufficient = probe_amount.map(|amount| {
+				let asset: MultiAsset = (asset_id, amount).into();
+				if !T::IsReserve::contains(&asset, &MultiLocation::here()) {
+					return true;
+				}
+				Self::get_token_reserved_account(&asset_id)
+					.map_or(false, |reserve_account| Self::can_withdraw(&asset, reserve_account))
+			});
+
+			let domain_paused = Self::is_route_paused(RouteDirection::Outbound, dest_domain_id);
+			let pause_info = PauseReasons::<T>::get(dest_domain_id).filter(|_| domain_paused);
+			BridgeStatus {
+				domain_registered: T::DomainRegistry::is_registered(dest_domain_id),
+				domain_paused,
+				pause_reason: pause_info.as_ref().map(|info| info.reason),
+				pause_note: pause_info.and_then(|info| info.note).map(|note| note.into_inner()),
+				domain_healthy: DomainHealth::<T>::get(dest_domain_id).unwrap_or(true),
+				mpc_address_set: !MpcAddr::<T>::get().is_clear(),
+				resource_id: resolved.map(|(_, resource_id)| resource_id),
+				fee,
+				reserve_sufficient,
+				min_transfer: fee.map(|fee| fee.saturating_add(1)),
+				max_transfer: None,
+			}
+		}
+
+		/// Gather the config and live state of the route of a resource to a domain, see
+		/// `RouteInfo`. Nothing is mutated, the token bucket is read as refilled up to now.
+		pub fn route_info(
+			resource_id: ResourceId,
+			domain_id: DomainID,
+		) -> RouteInfo<BlockNumberFor<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let asset_id = Self::rid_to_assetid(&resource_id);
+			let domain_registered = T::DomainRegistry::is_registered(domain_id);
+			let data_format = DataFormats::<T>::get(domain_id);
+			let fee = asset_id
+				.and_then(|asset_id| T::FeeHandler::get_fee(domain_id, (asset_id, 0).into()));
+
+			let outbound_paused = Self::is_route_paused(RouteDirection::Outbound, domain_id);
+			let inbound_paused = Self::is_route_paused(RouteDirection::Inbound, domain_id);
+			let pause_reason = PauseReasons::<T>::get(domain_id)
+				.filter(|_| outbound_paused || inbound_paused)
+				.map(|info| info.reason);
+			let circuit_broken = outbound_paused
+				&& pause_reason
+					== Some(PauseReason::CircuitBreaker { resource: Some(resource_id) });
+			let domain_healthy = DomainHealth::<T>::get(domain_id).unwrap_or(true);
+			let sunset_at = DomainSunsets::<T>::get(domain_id);
+			let inbound_window = InboundWindows::<T>::get(resource_id).filter(|window| {
+				now < window.started_at.saturating_add(T::InboundLimitWindow::get())
+			});
+
+			RouteInfo {
+				enabled: asset_id.is_some() && domain_registered && data_format.is_some(),
+				asset_id,
+				domain_registered,
+				data_format,
+				decimals: Self::resource_decimals(&resource_id, domain_id),
+				outbound_paused,
+				inbound_paused,
+				pause_reason,
+				blocked: !domain_healthy || sunset_at.map_or(false, |cutoff| now >= cutoff),
+				domain_healthy,
+				sunset_at,
+				circuit_broken,
+				fee,
+				min_transfer: fee.map(|fee| fee.saturating_add(1)),
+				available_tokens: Self::refilled_tokens(&resource_id),
+				inbound_limit: InboundLimits::<T>::get(resource_id),
+				inbound_window,
+				settlement_delay: RequiredSettlementDelay::<T>::get(resource_id),
+			}
+		}
+
+		/// Return the hash identifying the operator of a break-glass call
+		fn origin_hash(origin: &OriginFor<T>) -> [u8; 32] {
+			blake2_256(&origin.caller().encode())
+		}
+
+		/// Append a break-glass operation to `ManualInterventions`, evicting the oldest entry if
+		/// it is full
+		fn record_intervention(
+			operator_origin_hash: [u8; 32],
+			operation: InterventionOperation,
+			subject: InterventionSubject,
+			amount: Option<u128>,
+		) {
+			ManualInterventions::<T>::mutate(|interventions| {
+				if interventions.len() >= T::MaxManualInterventions::get() as usize {
+					interventions.remove(0);
+				}
+				// can only fail with a zero bound, then nothing is kept
+				let _ = interventions.try_push(ManualIntervention {
+					block: frame_system::Pallet::<T>::block_number(),
+					operation,
+					operator_origin_hash,
+					subject,
+					amount,
+				});
+			});
+		}
+
+		/// Dry-run a deposit of `asset` to `dest` by `sender` as `deposit` runs it, and explain
+		/// why it would be rejected. Nothing the dry-run does is kept, its events included.
+		pub fn validate_deposit(
+			sender: &T::AccountId,
+			asset: &MultiAsset,
+			dest: &MultiLocation,
+		) -> Result<(), DepositValidationError> {
+			frame_support::storage::with_transaction(|| {
+				let result =
+					Self::deposit_internal(sender.clone(), asset.clone(), *dest, None, None, None);
+				sp_runtime::TransactionOutcome::Rollback(Ok::<DispatchResult, DispatchError>(
+					result,
+				))
+			})
+			.and_then(|result| result)
+			.map_err(|error| Self::deposit_validation_error(&error, sender, asset, dest))
+		}
+
+		/// Explain the failure of a deposit with the parameters involved, read from the state
+		/// the deposit failed in
+		fn deposit_validation_error(
+			error: &DispatchError,
+			sender: &T::AccountId,
+			asset: &MultiAsset,
+			dest: &MultiLocation,
+		) -> DepositValidationError {
+			let is_any = |errors: Vec<Error<T>>| {
+				errors.into_iter().any(|e| DispatchError::from(e) == *error)
+			};
+			let dest_domain_id = || {
+				Self::extract_dest_data(dest)
+					.map(|(_, domain_id)| domain_id)
+					.unwrap_or_default()
+			};
+			let resource_id = || {
+				Self::extract_asset(asset)
+					.map(|(_, resource_id, ..)| resource_id)
+					.unwrap_or_default()
+			};
+
+			if is_any(vec![Error::<T>::MissingMpcAddress]) {
+				DepositValidationError::MpcAddressMissing
+			} else if is_any(vec![
+				Error::<T>::ExtractDestDataFailed,
+				Error::<T>::InvalidDestination,
+				Error::<T>::InvalidRoute,
+				Error::<T>::UnknownRecipientFormat,
+				Error::<T>::RecipientFormatMismatch,
+			]) {
+				DepositValidationError::InvalidDestination
+			} else if is_any(vec![
+				Error::<T>::DestDomainNotSupported,
+				Error::<T>::DomainSunset,
+				Error::<T>::UnsupportedDataFormat,
+			]) {
+				DepositValidationError::DomainNotSupported { domain_id: dest_domain_id() }
+			} else if is_any(vec![Error::<T>::BridgePaused, Error::<T>::DomainUnhealthy]) {
+				DepositValidationError::DomainPaused { domain_id: dest_domain_id() }
+			} else if is_any(vec![Error::<T>::AssetNotBound]) {
+				DepositValidationError::AssetNotBound { asset_id: asset.id.encode() }
+			} else if is_any(vec![Error::<T>::MissingFeeConfig]) {
+				DepositValidationError::MissingFeeConfig {
+					resource_id: resource_id(),
+					domain_id: dest_domain_id(),
+				}
+			} else if is_any(vec![Error::<T>::FeeTooExpensive]) {
+				// The prefund pays the fee first, the amount has to exceed the rest
+				let (fee, prefunded) = match Self::extract_asset(asset) {
+					Some((asset_id, _, amount, _)) => {
+						let fee = Self::sender_fee(
+							sender,
+							dest_domain_id(),
+							(asset_id, Fungible(amount)).into(),
+						)
+						.unwrap_or_default();
+						(fee, FeePrefunds::<T>::get(sender, asset_id).min(fee))
+					},
+					None => (0, 0),
+				};
+				DepositValidationError::FeeTooExpensive {
+					fee,
+					min_amount: fee.saturating_sub(prefunded).saturating_add(1),
+				}
+			} else if is_any(vec![Error::<T>::TransactFailed]) {
+				DepositValidationError::WithdrawFailed
+			} else if is_any(vec![Error::<T>::TvlCapExceeded]) {
+				DepositValidationError::TvlCapExceeded {
+					cap: GlobalTvlCap::<T>::get().unwrap_or_default(),
+					locked: GlobalTvl::<T>::get(),
+				}
+			} else if is_any(vec![Error::<T>::RateLimitExceeded]) {
+				let resource_id = resource_id();
+				DepositValidationError::RateLimitExceeded {
+					resource_id,
+					available: Self::refilled_tokens(&resource_id).unwrap_or_default(),
+				}
+			} else if is_any(vec![Error::<T>::DepositDataTooLarge]) {
+				DepositValidationError::DepositDataTooLarge {
+					max_size: T::MaxDepositDataSize::get(),
+				}
+			} else {
+				match error {
+					DispatchError::Module(ModuleError { error, .. }) => {
+						DepositValidationError::Other(error[0])
+					},
+					_ => DepositValidationError::Other(u8::MAX),
+				}
+			}
+		}
+
+		/// Return the fee `sender` pays for a deposit of `asset` to `dest`, the fee `deposit`
+		/// charges. `None` if the deposit would be rejected for an unknown asset or dest, or a
+		/// missing fee.
+		pub fn effective_fee(
+			sender: &T::AccountId,
+			asset: &MultiAsset,
+			dest: &MultiLocation,
+		) -> Option<u128> {
+			if dest.parents != T::ExpectedDestParents::get() {
+				return None;
+			}
+			let (_, dest_domain_id) = T::ExtractDestData::extract_dest(dest)?;
+			let (asset_id, _, amount, _) = Self::extract_asset(asset)?;
+			Self::sender_fee(sender, dest_domain_id, (asset_id, Fungible(amount)).into())
+		}
+
+		/// Return the fee handler quote reduced by the fee discount of `sender`, the waived share
+		/// is rounded down
+		fn sender_fee(
+			sender: &T::AccountId,
+			dest_domain_id: DomainID,
+			asset: MultiAsset,
+		) -> Option<u128> {
+			let fee = T::FeeHandler::get_fee(dest_domain_id, asset)?;
+			Some(match FeeDiscounts::<T>::get(sender) {
+				Some(discount) => fee.saturating_sub(discount.mul_floor(fee)),
+				None => fee,
+			})
+		}
+
+		/// Return true if `asset` can be withdrawn from `who`, the withdrawal is reverted
+		fn can_withdraw(asset: &MultiAsset, who: [u8; 32]) -> bool {
+			frame_support::storage::with_transaction(|| {
+				let withdrawn = T::AssetTransactor::withdraw_asset(
+					asset,
+					&Junction::AccountId32 { network: None, id: who }.into(),
+					None,
+				)
+				.is_ok();
+				sp_runtime::TransactionOutcome::Rollback(Ok::<bool, DispatchError>(withdrawn))
+			})
+			.unwrap_or(false)
+		}
+
+		fn is_local_account(location: &MultiLocation) -> bool {
+			matches!(
+				(location.parents, &location.interior),
+				(0, Junctions::X1(Junction::AccountId32 { .. }))
+			)
+		}
+
+		/// Credit the asset to the escrow holding account of the proposal's resource and record
+		/// it in `UnclaimedTransfers`
+		fn escrow_transfer(
+			proposal: &Proposal,
+			beneficiary: MultiLocation,
+			asset: MultiAsset,
+		) -> DispatchResult {
+			let amount = match asset.fun {
+				Fungible(amount) => amount,
+				_ => return Err(Error::<T>::TransactFailed.into()),
+			};
+
+			T::AssetTransactor::deposit_asset(
+				&asset,
+				&Junction::AccountId32 {
+					network: None,
+					id: Self::escrow_account(&proposal.resource_id).into(),
+				}
+				.into(),
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::BelowExistentialDeposit)?;
+
+			UnclaimedTransfers::<T>::insert(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				EscrowedTransfer {
+					resource_id: proposal.resource_id,
+					beneficiary,
+					asset_id: asset.id,
+					amount,
+					escrowed_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			// Emit TransferEscrowed
+			Self::deposit_event(Event::TransferEscrowed {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				beneficiary,
+				asset_id: asset.id,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Move an escrowed transfer from the escrow holding account to `dest`
+		fn release_escrowed_transfer(
+			escrowed: &EscrowedTransfer<BlockNumberFor<T>>,
+			dest: &MultiLocation,
+		) -> DispatchResult {
+			let asset: MultiAsset = (escrowed.asset_id, Fungible(escrowed.amount)).into();
+
+			T::AssetTransactor::withdraw_asset(
+				&asset,
+				&Junction::AccountId32 {
+					network: None,
+					id: Self::escrow_account(&escrowed.resource_id).into(),
+				}
+				.into(),
+				None,
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			T::AssetTransactor::deposit_asset(&asset, dest, &XcmContext::with_message_id([0; 32]))
+				.map_err(|_| Error::<T>::TransactFailed)?;
+
+			Ok(())
+		}
+
+		/// Initiates a transfer on behalf of `sender`, a nonce reserved by `reserve_nonce` is used
+		/// instead of bumping `DepositCounts` if given. If `via_domain` is given, the transfer is
+		/// addressed to it and routed to the domain of `dest` from there. `recipient_format`
+		/// overrides the format of the recipient told from `dest`.
+		fn deposit_internal(
+			sender: T::AccountId,
+			asset: MultiAsset,
+			dest: MultiLocation,
+			reserved_nonce: Option<DepositNonce>,
+			via_domain: Option<DomainID>,
+			recipient_format: Option<RecipientFormat>,
+		) -> DispatchResult {
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+
+			ensure!(dest.parents == T::ExpectedDestParents::get(), Error::<T>::InvalidDestination);
+
+			// Extract dest (MultiLocation) to get corresponding dest domainID and Ethereum
+			// recipient address
+			let (recipient, final_domain_id) = Self::extract_dest_data(&dest)?;
+
+			// A routed transfer carries its final domain in the extra section of deposit data
+			let (dest_domain_id, extra_data) = match via_domain {
+				Some(via_domain_id) => {
+					ensure!(via_domain_id != final_domain_id, Error::<T>::InvalidRoute);
+					let route = [final_domain_id.into_inner()];
+					let extra_data = sygma_codec::encode_extra(
+						&[(EXTRA_TAG_ROUTE, &route[..])],
+						T::MaxDepositDataSize::get() as usize,
+					)
+					.map_err(|_| Error::<T>::DepositDataTooLarge)?;
+					(via_domain_id, extra_data)
+				},
+				None => (final_domain_id, vec![]),
+			};
+			let format = Self::data_format(dest_domain_id)?;
+			let recipient = Self::format_recipient(recipient, recipient_format, final_domain_id)?;
+			// Only the length of the recipient is logged
+			log::trace!(
+				target: LOG_TARGET,
+				"deposit: dest domain {}, final domain {}, recipient of {} bytes",
+				dest_domain_id.into_inner(),
+				final_domain_id.into_inner(),
+				recipient.len()
+			);
+			// Deposit data is the amount and the recipient in the format of the dest domain,
+			// followed by the extra section
+			Self::ensure_deposit_data_size(
+				sygma_codec::deposit_data_len(format, recipient.len())
+					.saturating_add(extra_data.len()),
+			)?;
+
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+
+			Self::ensure_registered(dest_domain_id)?;
+
+			ensure!(
+				DomainHealth::<T>::get(dest_domain_id).unwrap_or(true),
+				Error::<T>::DomainUnhealthy
+			);
+			Self::ensure_not_sunset(dest_domain_id)?;
+
+			// Extract asset (MultiAsset) to get corresponding ResourceId, transfer amount and the
+			// transfer type
+			let (asset_id, resource_id, amount, transfer_type) =
+				Self::extract_asset(&asset).ok_or(Error::<T>::AssetNotBound)?;
+			// Carry on with the registered asset id, it differs from the given one when the
+			// asset only matched under a lenient `AssetMatchingMode`
+			let asset: MultiAsset = (asset_id, Fungible(amount)).into();
+			let extra_data = [extra_data, Self::asset_metadata_extra(&asset_id)?].concat();
+			Self::ensure_deposit_data_size(
+				sygma_codec::deposit_data_len(format, recipient.len())
+					.saturating_add(extra_data.len()),
+			)?;
+			// Return error if no fee handler set
+			let fee = Self::sender_fee(&sender, dest_domain_id, asset.clone())
+				.ok_or(Error::<T>::MissingFeeConfig)?;
+			// Pay the fee from the prefund balance first, the rest is taken from `amount`
+			let prefunded = FeePrefunds::<T>::get(&sender, asset.id).min(fee);
+			let fee_from_amount = fee - prefunded;
+			log::trace!(
+				target: LOG_TARGET,
+				"deposit: resource 0x{}, amount {}, fee {}, prefunded {}",
+				HexDisplay::from(&resource_id),
+				amount,
+				fee,
+				prefunded
+			);
+
+			ensure!(amount > fee_from_amount, Error::<T>::FeeTooExpensive);
+
+			// Fee events are emitted ahead of `Deposit`, see `emit_deposit_events`
+			let mut fee_events = vec![];
+			if prefunded > 0 {
+				Self::take_prefund(&sender, asset.id, prefunded)?;
+
+				// PrefundConsumed event
+				fee_events.push(Event::PrefundConsumed {
+					account: sender.clone(),
+					asset_id: asset.id,
+					amount: prefunded,
+				});
+			}
+
+			// Withdraw `amount` of asset from sender, in its own storage layer so that a failed
+			// withdrawal leaves nothing behind when the prefunded fee is kept
+			let withdrawn = frame_support::storage::with_storage_layer(|| {
+				T::AssetTransactor::withdraw_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: sender.clone().into() }.into(),
+					None,
+				)
+				.map_err(|_| DispatchError::from(Error::<T>::TransactFailed))
+			});
+			if let Err(error) = withdrawn {
+				log::debug!(
+					target: LOG_TARGET,
+					"deposit: dest domain {}, resource 0x{} withdrawal failed: {:?}",
+					dest_domain_id.into_inner(),
+					HexDisplay::from(&resource_id),
+					error
+				);
+				if !T::KeepFeeOnFailedDeposit::get() || prefunded == 0 {
+					return Err(error);
+				}
+				Self::distribute_fee(
+					&sender,
+					&resource_id,
+					(asset.id, Fungible(prefunded)).into(),
+				)?;
+
+				// Emit PrefundConsumed and FeeRetainedOnFailure events
+				fee_events.push(Event::FeeRetainedOnFailure {
+					account: sender,
+					dest_domain_id,
+					asset_id: asset.id,
+					fee: prefunded,
+				});
+				fee_events.into_iter().for_each(Self::deposit_event);
+				return Ok(());
+			}
+
+			// Hand `fee` of asset to the fee distributor, nothing to distribute for an exempt
+			// sender
+			if fee > 0 {
+				Self::distribute_fee(&sender, &resource_id, (asset.id, Fungible(fee)).into())?;
+			}
+
+			let bridge_amount = amount - fee_from_amount;
+
+			let decimal_converted_amount =
+				Self::lock_deposited_asset(&sender, asset.id, resource_id, bridge_amount)?;
+			let dest_amount =
+				Self::to_domain_decimals(&resource_id, dest_domain_id, decimal_converted_amount)?;
+
+			// Bump deposit nonce unless one was reserved beforehand
+			let (deposit_nonce, nonce_events) = match reserved_nonce {
+				Some(deposit_nonce) => (deposit_nonce, vec![]),
+				None => Self::next_deposit_nonce(dest_domain_id)?,
+			};
+
+			Self::record_deposit(dest_domain_id, deposit_nonce, &sender, asset.id, bridge_amount);
+			Self::note_activity(|activity| activity.deposits = activity.deposits.saturating_add(1));
+			if T::EnableAccountHistory::get() {
+				DepositHistory::<T>::mutate(&sender, |history| {
+					Self::push_history(
+						history,
+						dest_domain_id,
+						deposit_nonce,
+						resource_id,
+						bridge_amount,
+					)
+				});
+			}
+			log::debug!(
+				target: LOG_TARGET,
+				"deposit: dest domain {}, nonce {}, resource 0x{}, amount {}, dest amount {}, fee {}",
+				dest_domain_id.into_inner(),
+				deposit_nonce,
+				HexDisplay::from(&resource_id),
+				bridge_amount,
+				dest_amount,
+				fee
+			);
+
+			// FeeCollected event
+			fee_events.push(Event::FeeCollected {
+				fee_payer: sender.clone(),
+				dest_domain_id,
+				resource_id,
+				fee_amount: fee,
+				fee_asset_id: asset.id,
+			});
+
+			let deposit_data = Self::event_deposit_data(
+				dest_domain_id,
+				deposit_nonce,
+				resource_id,
+				[Self::create_deposit_data(format, dest_amount, recipient), extra_data].concat(),
+			)?;
+			DepositDataHashes::<T>::insert(
+				(dest_domain_id, deposit_nonce),
+				blake2_256(&deposit_data),
+			);
+
+			// Emit fee events, Deposit event and the nonce anomaly events, if any
+			let deposit = Event::Deposit {
+				dest_domain_id,
+				resource_id,
+				deposit_nonce,
+				transfer_id: Self::transfer_id(dest_domain_id, deposit_nonce),
+				sender,
+				transfer_type,
+				deposit_data,
+				handler_response: vec![],
+			};
+			Self::emit_deposit_events(fee_events, deposit, nonce_events);
+			Self::notify_deposit(dest_domain_id, deposit_nonce);
+
+			Ok(())
+		}
+
+		/// Send the XCM notifying `DepositNotificationTarget` of a deposit, single or multi-asset,
+		/// if `EnableDepositNotification` is set. A send error is reported by an event only.
+		fn notify_deposit(dest_domain_id: DomainID, deposit_nonce: DepositNonce) {
+			if !T::EnableDepositNotification::get() {
+				return;
+			}
+			let target = T::DepositNotificationTarget::get();
+			let message = Self::deposit_notification(&target, dest_domain_id, deposit_nonce);
+			if let Err(error) = send_xcm::<T::DepositNotifier>(target.dest, message) {
+				// Emit DepositNotificationFailed event
+				Self::deposit_event(Event::DepositNotificationFailed {
+					dest_domain_id,
+					deposit_nonce,
+					error,
+				});
+			}
+		}
+
+		/// XCM notifying `target` of a deposit, the transfer id is the topic of the message
+		pub fn deposit_notification(
+			target: &NotificationTarget,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Xcm<()> {
+			let transfer_id = Self::transfer_id(dest_domain_id, deposit_nonce);
+			let call =
+				[target.call_index.to_vec(), (dest_domain_id, deposit_nonce, transfer_id).encode()]
+					.concat();
+			Xcm(vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most: target.weight,
+					call: call.into(),
+				},
+				SetTopic(transfer_id),
+			])
+		}
+
+		/// Extra entry of the metadata of a deposited asset, empty unless `AttachAssetMetadata` is
+		/// set and the asset has metadata
+		fn asset_metadata_extra(asset_id: &AssetId) -> Result<Vec<u8>, DispatchError> {
+			if !T::AttachAssetMetadata::get() {
+				return Ok(vec![]);
+			}
+			let metadata = match T::AssetMetadataProvider::metadata(asset_id) {
+				Some(metadata) => metadata,
+				None => return Ok(vec![]),
+			};
+			let value =
+				sygma_codec::asset_metadata(metadata.decimals, &metadata.name, &metadata.symbol)
+					.ok_or(Error::<T>::AssetMetadataTooLarge)?;
+			sygma_codec::encode_extra(
+				&[(EXTRA_TAG_ASSET_METADATA, &value[..])],
+				T::MaxDepositDataSize::get() as usize,
+			)
+			.map_err(|_| Error::<T>::DepositDataTooLarge.into())
+		}
+
+		/// Lock `bridge_amount` of a deposited asset, already withdrawn from `sender`, and count it
+		/// against the limits of the bridge. Return the amount in the 18 decimals of deposit data.
+		fn lock_deposited_asset(
+			sender: &T::AccountId,
+			asset_id: AssetId,
+			resource_id: ResourceId,
+			bridge_amount: u128,
+		) -> Result<u128, DispatchError> {
+			let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+
+			// Deposit `bridge_amount` of asset to reserve account if asset is reserved in local
+			// chain.
+			let asset: MultiAsset = (asset_id, Fungible(bridge_amount)).into();
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				T::AssetTransactor::deposit_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			}
+
+			// convert the asset decimal
+			let decimal_converted_amount =
+				T::DecimalConverter::convert_to(&asset).ok_or(Error::<T>::DecimalConversionFail)?;
+			Self::take_tokens(&resource_id, decimal_converted_amount)?;
+			Self::lock_value(&resource_id, decimal_converted_amount)?;
+			let value = T::AmountNormalizer::normalize(&resource_id, decimal_converted_amount);
+			AccountVolume::<T>::mutate(sender, |volume| *volume = volume.saturating_add(value));
+			Ok(decimal_converted_amount)
+		}
+
+		/// Initiates a transfer of several assets on behalf of `sender` under one deposit nonce.
+		/// Every asset is resolved before anything moves, the fee of each asset is then taken
+		/// from its amount. Prefunded fees are not used and the deposit is not refundable.
+		fn deposit_multi_internal(
+			sender: T::AccountId,
+			assets: Vec<MultiAsset>,
+			dest: MultiLocation,
+		) -> DispatchResult {
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(!assets.is_empty(), Error::<T>::InvalidAssetList);
+			ensure!(
+				assets.len() <= T::MaxAssetsPerDeposit::get() as usize,
+				Error::<T>::TooManyAssets
+			);
+			ensure!(dest.parents == T::ExpectedDestParents::get(), Error::<T>::InvalidDestination);
+
+			let (recipient, dest_domain_id) = Self::extract_dest_data(&dest)?;
+			let format = Self::data_format(dest_domain_id)?;
+			let recipient = Self::format_recipient(recipient, None, dest_domain_id)?;
+			Self::ensure_deposit_data_size(sygma_codec::multi_asset_deposit_data_len(
+				format,
+				assets.len(),
+				recipient.len(),
+			))?;
+			ensure!(
+				!Self::is_route_paused(RouteDirection::Outbound, dest_domain_id),
+				Error::<T>::BridgePaused
+			);
+			Self::ensure_registered(dest_domain_id)?;
+			ensure!(
+				DomainHealth::<T>::get(dest_domain_id).unwrap_or(true),
+				Error::<T>::DomainUnhealthy
+			);
+			Self::ensure_not_sunset(dest_domain_id)?;
+
+			let mut fee_events = vec![];
+			let mut items = vec![];
+			for (asset_id, resource_id, amount) in Self::resolve_deposit_assets(&assets)? {
+				let asset: MultiAsset = (asset_id, Fungible(amount)).into();
+				let fee = Self::sender_fee(&sender, dest_domain_id, asset.clone())
+					.ok_or(Error::<T>::MissingFeeConfig)?;
+				ensure!(amount > fee, Error::<T>::FeeTooExpensive);
+
+				T::AssetTransactor::withdraw_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: sender.clone().into() }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+				if fee > 0 {
+					Self::distribute_fee(&sender, &resource_id, (asset_id, Fungible(fee)).into())?;
+				}
+				let decimal_converted_amount =
+					Self::lock_deposited_asset(&sender, asset_id, resource_id, amount - fee)?;
+				items.push((
+					resource_id,
+					Self::to_domain_decimals(
+						&resource_id,
+						dest_domain_id,
+						decimal_converted_amount,
+					)?,
+				));
+
+				// FeeCollected event
+				fee_events.push(Event::FeeCollected {
+					fee_payer: sender.clone(),
+					dest_domain_id,
+					resource_id,
+					fee_amount: fee,
+					fee_asset_id: asset_id,
+				});
+			}
+
+			let (deposit_nonce, nonce_events) = Self::next_deposit_nonce(dest_domain_id)?;
+			Self::note_activity(|activity| activity.deposits = activity.deposits.saturating_add(1));
+
+			// Emit fee events, Deposit event and the nonce anomaly events, if any
+			let resource_id = T::MultiAssetResourceId::get();
+			let deposit = Event::Deposit {
+				dest_domain_id,
+				resource_id,
+				deposit_nonce,
+				transfer_id: Self::transfer_id(dest_domain_id, deposit_nonce),
+				sender,
+				transfer_type: TransferType::FungibleTransfer,
+				deposit_data: Self::event_deposit_data(
+					dest_domain_id,
+					deposit_nonce,
+					resource_id,
+					sygma_codec::multi_asset_deposit_data(format, &items, &recipient),
+				)?,
+				handler_response: vec![],
+			};
+			Self::emit_deposit_events(fee_events, deposit, nonce_events);
+			Self::notify_deposit(dest_domain_id, deposit_nonce);
+
+			Ok(())
+		}
+
+		/// Resolve the assets of a multi-asset deposit to their registered asset id, resource id
+		/// and amount, in order. Fail if an asset is not bound or a resource appears twice.
+		pub fn resolve_deposit_assets(
+			assets: &[MultiAsset],
+		) -> Result<Vec<(AssetId, ResourceId, u128)>, DispatchError> {
+			let mut resolved: Vec<(AssetId, ResourceId, u128)> = Vec::with_capacity(assets.len());
+			for asset in assets {
+				let (asset_id, resource_id, amount, _) =
+					Self::extract_asset(asset).ok_or(Error::<T>::AssetNotBound)?;
+				ensure!(
+					!resolved.iter().any(|(_, other, _)| *other == resource_id),
+					Error::<T>::InvalidAssetList
+				);
+				resolved.push((asset_id, resource_id, amount));
+			}
+			Ok(resolved)
+		}
+
+		/// Emit the events of an outbound transfer in the order indexers rely on: the fee events,
+		/// then `Deposit`, then the events following from the deposit, e.g. the forwarding of a
+		/// routed transfer or a nonce anomaly. Deposits, forwarded transfers and acknowledgments
+		/// all emit their events here.
+		fn emit_deposit_events(
+			fee_events: Vec<Event<T>>,
+			deposit: Event<T>,
+			follow_ups: Vec<Event<T>>,
+		) {
+			for event in fee_events.into_iter().chain([deposit]).chain(follow_ups) {
+				Self::deposit_event(event);
+			}
+		}
+
+		/// Withdraw `amount` of the prefund balance of `account` from the prefund account, the
+		/// caller deposits it where it goes
+		fn take_prefund(account: &T::AccountId, asset_id: AssetId, amount: u128) -> DispatchResult {
+			FeePrefunds::<T>::try_mutate_exists(account, asset_id, |balance| {
+				let remaining = balance
+					.unwrap_or_default()
+					.checked_sub(amount)
+					.ok_or(Error::<T>::InsufficientPrefund)?;
+				*balance = Some(remaining).filter(|remaining| *remaining > 0);
+				Ok::<(), DispatchError>(())
+			})?;
+			T::AssetTransactor::withdraw_asset(
+				&(asset_id, Fungible(amount)).into(),
+				&Junction::AccountId32 { network: None, id: Self::fee_prefund_account().into() }
+					.into(),
+				None,
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			Ok(())
+		}
+
+		/// Append a transfer to an account history, evicting the oldest entry if it is full
+		fn push_history(
+			history: &mut BoundedVec<TransferSummary<BlockNumberFor<T>>, T::MaxHistoryPerAccount>,
+			domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			resource_id: ResourceId,
+			amount: u128,
+		) {
+			if !history.is_empty() && history.len() >= T::MaxHistoryPerAccount::get() as usize {
+				history.remove(0);
+			}
+			// can only fail with a zero bound, then nothing is kept
+			let _ = history.try_push(TransferSummary {
+				domain_id,
+				deposit_nonce,
+				resource_id,
+				amount,
+				block: frame_system::Pallet::<T>::block_number(),
+			});
+		}
+
+		/// Keep the deposit for refunding and queue it for pruning
+		fn record_deposit(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: &T::AccountId,
+			asset_id: AssetId,
+			amount: u128,
+		) {
+			DepositRecords::<T>::insert(
+				(dest_domain_id, deposit_nonce),
+				DepositRecord {
+					sender: sender.clone(),
+					asset_id,
+					amount,
+					recorded_at: frame_system::Pallet::<T>::block_number(),
+					refund_requested: false,
+					cancelled: false,
+				},
+			);
+			let tail = DepositRecordQueueTail::<T>::get();
+			DepositRecordQueue::<T>::insert(tail, (dest_domain_id, deposit_nonce));
+			DepositRecordQueueTail::<T>::put(tail.saturating_add(1));
+		}
+
+		/// Return the bridged amount of a deposit to its sender, releasing it from the reserve
+		/// account if the asset is reserved in local chain, otherwise it was burned on deposit
+		fn return_deposit(
+			record: &DepositRecord<T::AccountId, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let asset: MultiAsset = (record.asset_id, Fungible(record.amount)).into();
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				let token_reserved_account = Self::get_token_reserved_account(&record.asset_id)
+					.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+				if let Some((_, resource_id)) = Self::resolve_asset(&record.asset_id) {
+					Self::check_reserve(&resource_id, token_reserved_account, &asset)?;
+				}
+				T::AssetTransactor::withdraw_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			}
+			T::AssetTransactor::deposit_asset(
+				&asset,
+				&Junction::AccountId32 { network: None, id: record.sender.clone().into() }.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			if let (Some(amount), Some((_, resource_id))) = (
+				T::DecimalConverter::convert_to(&(record.asset_id, record.amount).into()),
+				Self::resolve_asset(&record.asset_id),
+			) {
+				Self::unlock_value(&resource_id, amount);
+			}
+			Ok(())
+		}
+
+		/// Assign the current nonce of dest domain and bump `DepositCounts`. The events of a
+		/// nonce anomaly are returned along with the nonce, to be emitted after the `Deposit`.
+		fn next_deposit_nonce(
+			dest_domain_id: DomainID,
+		) -> Result<(DepositNonce, Vec<Event<T>>), DispatchError> {
+			let deposit_nonce = DepositCounts::<T>::get(dest_domain_id);
+			let nonce_events = Self::check_deposit_nonce(dest_domain_id, deposit_nonce);
+			DepositCounts::<T>::insert(
+				dest_domain_id,
+				deposit_nonce.checked_add(1).ok_or(Error::<T>::DepositNonceOverflow)?,
+			);
+			Ok((deposit_nonce, nonce_events))
+		}
+
+		/// Return `DepositNonceAnomaly` if the nonce about to be assigned does not follow the last
+		/// assigned one, e.g. `DepositCounts` was restored from a bad snapshot. The deposit still
+		/// proceeds with the stored counter, optionally the domain is paused to stop further
+		/// deposits until the operators step in, `BridgePaused` is returned as well then.
+		fn check_deposit_nonce(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Vec<Event<T>> {
+			let mut events = vec![];
+			if let Some(last_nonce) = LastDepositNonces::<T>::get(dest_domain_id) {
+				let expected = last_nonce.saturating_add(1);
+				if deposit_nonce != expected {
+					events.push(Event::DepositNonceAnomaly {
+						domain: dest_domain_id,
+						expected,
+						actual: deposit_nonce,
+					});
+
+					if T::PauseOnDepositNonceAnomaly::get() {
+						let PauseInfo { reason, note } = Self::set_paused(
+							dest_domain_id,
+							PauseInfo {
+								reason: PauseReason::CircuitBreaker { resource: None },
+								note: None,
+							},
+						);
+						events.push(Event::BridgePaused { dest_domain_id, reason, note });
+					}
+				}
+			}
+			LastDepositNonces::<T>::insert(dest_domain_id, deposit_nonce);
+			events
+		}
+
+		/// unpause all registered domains in the storage
+		fn unpause_all_domains() {
+			Self::known_domains()
+				.into_iter()
+				.for_each(|d| Self::set_domain_paused(d, false));
+			let _ = PauseReasons::<T>::clear(u32::MAX, None);
+		}
+
+		/// pause all registered domains in the storage
+		fn pause_all_domains(info: &PauseInfo) {
+			Self::known_domains().into_iter().for_each(|d| {
+				Self::set_paused(d, info.clone());
+			});
+		}
+
+		/// Registered domains along with the domains having a pause entry
+		fn known_domains() -> BTreeSet<DomainID> {
+			DestDomainIds::<T>::iter_keys()
+				.chain(PausedRoutes::<T>::iter_keys().map(|(_, d)| d))
+				.collect()
+		}
+
+		/// Return true if the route of the domain in `direction` is paused
+		pub fn is_route_paused(direction: RouteDirection, domain_id: DomainID) -> bool {
+			PausedRoutes::<T>::get((direction, domain_id))
+		}
+
+		/// Return true if the domain is paused in any direction
+		pub fn is_paused(domain_id: DomainID) -> bool {
+			RouteDirection::ALL
+				.into_iter()
+				.any(|direction| Self::is_route_paused(direction, domain_id))
+		}
+
+		/// Return true if every known domain is paused in both directions
+		pub fn is_halted() -> bool {
+			Self::known_domains().into_iter().all(|domain_id| {
+				RouteDirection::ALL
+					.into_iter()
+					.all(|direction| Self::is_route_paused(direction, domain_id))
+			})
+		}
+
+		/// Pause or unpause both directions of a domain
+		fn set_domain_paused(domain_id: DomainID, paused: bool) {
+			for direction in RouteDirection::ALL {
+				PausedRoutes::<T>::insert((direction, domain_id), paused);
+			}
+			if !paused {
+				Self::end_mpc_key_grace();
+			}
+			Self::note_pause_action(domain_id, paused);
+		}
+
+		/// End the grace period of the MPC address once a route is unpaused, the address may
+		/// have signed proposals and been deposited to since. Pausing the bridge again does not
+		/// make the address correctable again.
+		fn end_mpc_key_grace() {
+			MpcKeyGraceEnd::<T>::kill();
+		}
+
+		/// Append a pause or unpause action to the `PauseHistory` of a domain, evicting the
+		/// oldest entry if it is full. `paused` is whether the domain is paused afterwards.
+		fn note_pause_action(domain_id: DomainID, paused: bool) {
+			PauseHistory::<T>::mutate(domain_id, |history| {
+				if history.len() >= T::MaxPauseHistory::get() as usize {
+					history.remove(0);
+				}
+				// can only fail with a zero bound, then nothing is kept
+				let _ = history.try_push((paused, frame_system::Pallet::<T>::block_number()));
+			});
+		}
+
+		/// Pause a domain and emit `BridgePaused`
+		fn pause_domain(dest_domain_id: DomainID, info: PauseInfo) {
+			let PauseInfo { reason, note } = Self::set_paused(dest_domain_id, info);
+			Self::deposit_event(Event::BridgePaused { dest_domain_id, reason, note });
+		}
+
+		/// Mark a domain paused for `info`. A circuit breaker pause is kept over a pause for
+		/// another reason, so that it can not be cleared without the committee origin. Return the
+		/// pause info in effect.
+		fn set_paused(dest_domain_id: DomainID, info: PauseInfo) -> PauseInfo {
+			if Self::is_circuit_breaker_pause(dest_domain_id)
+				&& !matches!(info.reason, PauseReason::CircuitBreaker { .. })
+			{
+				if let Some(current) = PauseReasons::<T>::get(dest_domain_id) {
+					return current;
+				}
+			}
+			Self::set_domain_paused(dest_domain_id, true);
+			PauseReasons::<T>::insert(dest_domain_id, info.clone());
+			info
+		}
+
+		/// Return true if the domain is paused by a circuit breaker
+		fn is_circuit_breaker_pause(dest_domain_id: DomainID) -> bool {
+			Self::is_paused(dest_domain_id)
+				&& matches!(
+					PauseReasons::<T>::get(dest_domain_id),
+					Some(PauseInfo { reason: PauseReason::CircuitBreaker { .. }, .. })
+				)
+		}
+
+		/// Pause all domains for the chain-wide emergency stop, remembering the domains paused
+		/// before in `PrePauseSnapshot`. Nothing is done if the bridge is already stopped, so the
+		/// snapshot is never overwritten by the emergency pause itself.
+		pub fn pause_everything() {
+			if PrePauseSnapshot::<T>::exists() {
+				return;
+			}
+			let mut snapshot: Vec<((RouteDirection, DomainID), PauseInfo)> = Vec::new();
+			let mut paused_before = 0u32;
+			for domain in Self::known_domains().into_iter().filter(|d| Self::is_paused(*d)) {
+				let info = PauseReasons::<T>::get(domain)
+					.unwrap_or(PauseInfo { reason: PauseReason::Governance, note: None });
+				for direction in RouteDirection::ALL {
+					if Self::is_route_paused(direction, domain) {
+						snapshot.push(((direction, domain), info.clone()));
+					}
+				}
+				paused_before += 1;
+			}
+			// at most 256 domains in both directions
+			PrePauseSnapshot::<T>::put(BoundedVec::truncate_from(snapshot));
+
+			Self::pause_all_domains(&PauseInfo { reason: PauseReason::SafeMode, note: None });
+
+			// Emit EmergencyPauseEntered event
+			Self::deposit_event(Event::EmergencyPauseEntered { paused_before });
+		}
+
+		/// Lift the chain-wide emergency stop: the domains of `PrePauseSnapshot` get their pause
+		/// back and the others are unpaused. A circuit breaker tripped during the stop is kept.
+		pub fn restore_from_snapshot() {
+			let snapshot = match PrePauseSnapshot::<T>::take() {
+				Some(snapshot) => snapshot,
+				None => return,
+			};
+			for domain in Self::known_domains() {
+				if Self::is_circuit_breaker_pause(domain) {
+					continue;
+				}
+				let mut pause_info = None;
+				for direction in RouteDirection::ALL {
+					let paused = snapshot.iter().find(|(route, _)| *route == (direction, domain));
+					PausedRoutes::<T>::insert((direction, domain), paused.is_some());
+					if paused.is_none() {
+						Self::end_mpc_key_grace();
+					}
+					if let Some((_, info)) = paused {
+						pause_info = Some(info.clone());
+					}
+				}
+				match pause_info {
+					Some(info) => PauseReasons::<T>::insert(domain, info),
+					None => PauseReasons::<T>::remove(domain),
+				}
+				Self::note_pause_action(domain, Self::is_paused(domain));
+			}
+
+			// Emit EmergencyPauseExited event
+			Self::deposit_event(Event::EmergencyPauseExited);
+		}
+
+		/// Return true if `origin` is the bridge committee origin, the origin allowed to clear a
+		/// circuit breaker pause
+		fn is_committee(origin: &OriginFor<T>) -> bool {
+			<T as sygma_access_segregator::Config>::BridgeCommitteeOrigin::ensure_origin(
+				origin.clone(),
+			)
+			.is_ok()
+		}
+	}
+
+	#[cfg(test)]
+	mod test {
+		use codec::{self, Decode, DecodeAll, Encode};
+		use frame_support::{
+			assert_noop, assert_ok,
+			crypto::ecdsa::ECDSAExt,
+			dispatch::{DispatchResult, GetDispatchInfo},
+			traits::{
+				tokens::fungibles::Create as FungibleCerate, BuildGenesisConfig, Get, Hooks,
+				OriginTrait,
+			},
+			weights::Weight,
+		};
+		use parachains_common::AccountId;
+		use primitive_types::U256;
+		use sp_core::{
+			ecdsa,
+			offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
+			sr25519, ByteArray, Pair,
+		};
+		use sp_io::hashing::blake2_256;
+		use sp_runtime::{
+			offchain::storage::StorageValueRef,
+			traits::{Dispatchable, SignedExtension},
+			transaction_validity::InvalidTransaction,
+			DispatchError, MultiSignature, Permill,
+		};
+		use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec};
+		use xcm::latest::{prelude::*, AssetId as XcmAssetId};
+
+		use bridge::mock::{
+			assert_events, new_test_ext, slice_to_generalkey, AccessSegregator, AckResourceId,
+			AllowPartialTransfer, AmountScales, AssetMatching, Assets, AttachAssetMetadata,
+			Balances, BridgeAccountNative, BridgeAccountOtherTokens, BridgePalletIndex,
+			CancellationWindow, CompressDepositData, DepositDataRetention,
+			DepositNotificationTarget, DepositRecordRetention, DepositShortfall,
+			DestVerifyingContractAddress, EIP712ChainID, EmergencyWithdrawalDelay,
+			EnableAccountHistory, EnableBatchDeduplication, EnableDepositNotification,
+			EnableDepositPermits, EnableDepositRoots, EnableOffchainMetrics, EnableRecipientFormat,
+			EnableSettlementCancellation, EnableSignatureCache, ExecutionReceiptRetention,
+			ExpectedDestParents, FailAssetCreation, FeePartnerAccount, FeeStakersAccount,
+			HashDepositData, KeepFeeOnFailedDeposit, KeySetGracePeriod, MaxDepositDataSize,
+			MultiAssetResourceId, NativeLocation, NativeResourceId, PauseOnDepositNonceAnomaly,
+			ReentrantCall, ReentrantCallResult, RejectedDomain, RequireMpcThreshold,
+			RequireRelayerAllowlist, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin as Origin,
+			SentXcm, SplitFees, SygmaBasicFeeHandler, SygmaBridge, SygmaFeeHandlerRouter,
+			SygmaPercentageFeeHandler, System, TreasuryAccount, UsdcAssetId, UsdcLocation,
+			UsdtAssetId, UsdtLocation, UsdtResourceId, UsedNoncesPruneThreshold, XcmSendFailure,
+			ALICE, ASSET_OWNER, BOB, DEST_DOMAIN_ID, ENDOWED_BALANCE,
+		};
+		use sygma_codec::vectors::{DEPOSIT_DATA, PROPOSAL_DIGESTS, TAGGED_RECIPIENTS};
+		use sygma_fee_handler_router::FeeHandlerType;
+		use sygma_traits::{
+			AssetMatchingMode, Bridge, BridgeEmergency, DepositFailureReason, DepositNonce,
+			DepositValidationError, DomainID, ExecutionFailureReason, FeeConfig, MpcAddress,
+			ResourceId, TransferType, EXTRA_TAG_ASSET_METADATA, EXTRA_TAG_MEMO, EXTRA_TAG_ROUTE,
+			EXTRA_TAG_SOURCE_AMOUNT,
+		};
+
+		use crate as bridge;
+		use crate::{
+			extension::CheckFreshProposals,
+			extension::ReportDepositFailure,
+			metrics,
+			mock::{AstrAssetId, AstrLocation, AstrResourceId},
+			nonces,
+			scenario::{native, test_key, usdc, usdt, weth, ScenarioAsset, ScenarioBuilder},
+			AckStatus, ActivityCounters, AutoRetryQueue, BindingSource, BlockActivity,
+			BlockDepositLeafCount, BlockDepositLeaves, BridgeAccount, BridgeStatus, DataFormat,
+			DataFormats, DeniedRecipient, DepositCounts, DepositDataHashes, DepositHistory,
+			DepositPermit, DepositRange, DepositRangeEntry, DepositRecord, DepositRecordQueueHead,
+			DepositRecordQueueTail, DepositRecordSummary, DepositRecords, DestChainIds,
+			DestDomainIds, DomainFreezeState, DomainHealth, Error, Event as SygmaBridgeEvent,
+			ExecutionHistory, ExecutionLock, ExecutionReceipt, FailedProposal,
+			FailedProposalCounts, FailedProposals, FeeDiscounts, FeePrefunds,
+			FeeReserveCheckCursor, FeeReserveLowAssets, FeeReserveThresholds, GlobalTvl,
+			InboundLimit, InboundLimits, InboundWindow, InboundWindows, InterventionOperation,
+			InterventionSubject, ManualIntervention, MinUnprunedNonce, MpcAddr, MpcKeyGraceEnd,
+			NonceReservations, OnboardedResource, OnboardedResources, OnboardingSpec,
+			OverruledResourcePairs, PauseInfo, PauseNote, PauseReason, PauseReasons, PausedRoutes,
+			PermitNonces, Proposal, ProtectedAccounts, RecipientDenylist, RecipientFormat,
+			Relayers, ResourceFreezeState, RouteDirection, RouteInfo, TokenBucketConfig,
+			TransferSummary, UnclaimedTransfers, UsedNonces, UsedNoncesCount, VerifiedSignatures,
+			MAX_DEPOSIT_RANGE, MAX_RECIPIENT_LEN,
+		};
+
+		#[test]
+		fn get_token_reserved_account_test() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
+					BridgeAccountOtherTokens::get().as_slice()
+				);
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
+					BridgeAccountNative::get().as_slice()
+				);
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&AstrLocation::get().into()).unwrap(),
+					BridgeAccountOtherTokens::get().as_slice()
+				);
+
+				// unknown token should return None
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(
+						&MultiLocation::new(
+							2,
+							X3(
+								Parachain(1000),
+								slice_to_generalkey(b"sygma"),
+								slice_to_generalkey(b"unknown"),
+							),
+						)
+						.into()
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn set_mpc_address() {
+			new_test_ext().execute_with(|| {
+				let default_addr: MpcAddress = MpcAddress::default();
+				let test_mpc_addr_a: MpcAddress = MpcAddress([1u8; 20]);
+				let test_mpc_addr_b: MpcAddress = MpcAddress([2u8; 20]);
+
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// set to test_mpc_addr_a, registered domain gets paused
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_a));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::MpcAddressSet {
+						mpc_addr: test_mpc_addr_a,
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+						dest_domain_id: DEST_DOMAIN_ID,
+						reason: PauseReason::Governance,
+						note: None,
+					}),
+				]);
+
+				// replaying the same mpc address is a no-op and does not pause a live bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_a));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeUnpaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// set to test_mpc_addr_b: should be MpcAddrNotUpdatable error
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_b),
+					bridge::Error::<Runtime>::MpcAddrNotUpdatable
+				);
+
+				// permission test: unauthorized account should not be able to set mpc address
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::set_mpc_address(unauthorized_account, test_mpc_addr_a),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+			})
+		}
+
+		#[test]
+		fn pause_bridge() {
+			new_test_ext().execute_with(|| {
+				let default_addr = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge, should be ok
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+					reason: PauseReason::Governance,
+					note: None,
+				})]);
+
+				// pause bridge again after paused, should be ok
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+					reason: PauseReason::Governance,
+					note: None,
+				})]);
+
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::pause_bridge(unauthorized_account, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn unpause_bridge() {
+			new_test_ext().execute_with(|| {
+				let default_addr: MpcAddress = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+					reason: PauseReason::Governance,
+					note: None,
+				})]);
+
+				// bridge should be paused here
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// ready to unpause bridge, should be ok
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeUnpaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// try to unpause it again, should be error
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::BridgeUnpaused
+				);
+
+				// permission test: unauthorized account should not be able to unpause a recognized
+				// bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::unpause_bridge(unauthorized_account, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_invalid_signature() {
+			new_test_ext().execute_with(|| {
+				let signature = vec![1u8];
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: DomainID::new(2),
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// should be false
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_invalid_message() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let public = pair.public();
+				let message = b"Something important";
+				let signature = pair.sign(&message[..]);
+
+				// make sure generated keypair, message and signature are all good
+				assert!(ecdsa::Pair::verify(&signature, &message[..], &public));
+				assert!(!ecdsa::Pair::verify(&signature, b"Something else", &public));
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: DomainID::new(2),
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// verify non matched signature against proposal list, should be false
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_valid_message_unmatched_mpc() {
+			new_test_ext().execute_with(|| {
+				// generate the signing keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+
+				// set mpc address to another random key
+				let test_mpc_addr: MpcAddress = MpcAddress([7u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: DomainID::new(2),
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// sign final message using generated prikey
+				let signature = pair.sign_prehashed(&final_message);
+
+				// verify signature, should be false because the signing address != mpc address
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_valid_message_valid_signature() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+
+				// set mpc address to generated keypair's address
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: DomainID::new(2),
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// sign final message using generated mpc prikey
+				// `pari.sign` will hash the final message into blake2_256 then sign it, so use
+				// sign_prehashed here
+				let signature = pair.sign_prehashed(&final_message);
+
+				// verify signature, should be true
+				assert!(SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn deposit_native_asset_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							final_amount_in_deposit_event,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn transfer_wrap_deposit_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest: MultiLocation = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				// Call transfer instead of deposit
+				assert_ok!(SygmaBridge::transfer(ALICE.into(), asset.clone(), dest, None));
+
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							final_amount_in_deposit_event,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn hex_zero_padding_32_test() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(
+					sygma_codec::hex_zero_padding_32(100).to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 100,
+					]
+				);
+				let recipient = String::from("0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4");
+				assert_eq!(
+					sygma_codec::hex_zero_padding_32(recipient.len() as u128).to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 42,
+					]
+				);
+			})
+		}
+
+		#[test]
+		fn create_deposit_data_test() {
+			new_test_ext().execute_with(|| {
+				let recipient = b"0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4".to_vec();
+				let data = SygmaBridge::create_deposit_data(DataFormat::EvmAbi, 100, recipient);
+				// 32 + 32 + 42
+				assert_eq!(data.len(), 106);
+				assert_eq!(
+					data.to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 48, 120, 57, 53, 69, 67, 70,
+						53, 97, 101, 48, 48, 48, 101, 48, 102, 101, 48, 101, 48, 100, 69, 54, 51,
+						97, 68, 69, 57, 98, 55, 68, 56, 50, 97, 51, 55, 50, 48, 51, 56, 98, 52,
+					]
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_foreign_asset_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// Register foreign asset (USDT) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some USDT to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
+				// USDT in the mock runtime has been configured as the reserved token, so the corresponding account should hold the deposit balance
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					amount - fee
+				);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount - fee,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn deposit_unbounded_asset_should_fail() {
+			new_test_ext().execute_with(|| {
+				let unbounded_asset_location = MultiLocation::new(1, X1(GeneralIndex(123)));
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(unbounded_asset_location.into()),
+					fee
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(unbounded_asset_location), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_to_unrecognized_dest_should_fail() {
+			new_test_ext().execute_with(|| {
+				let invalid_dest = MultiLocation::new(
+					0,
+					X2(GeneralIndex(0), slice_to_generalkey(b"ethereum recipient")),
+				);
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(invalid_dest),
+					),
+					bridge::Error::<Runtime>::ExtractDestDataFailed
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_without_fee_set_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200u128;
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_less_than_fee_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 200u128;
+				let amount = 100u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::FeeTooExpensive
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_when_bridge_paused_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				// set mpc address will also unpause all bridges
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// Pause bridge again
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// Should failed
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+				// Unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// Should success
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+			})
+		}
+
+		#[test]
+		fn deposit_without_mpc_set_should_fail() {
+			new_test_ext().execute_with(|| {
+				let fee = 200u128;
+				let amount = 100u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+			})
+		}
+
+		#[test]
+		fn retry_bridge() {
+			new_test_ext().execute_with(|| {
+				// the deposit to retry was made at the current block
+				System::set_block_number(1234567);
+
+				// should be access denied SINCE Alice does not have permission to retry
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// Grant ALICE the access of `retry`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"retry".to_vec(),
+					ALICE
+				));
+
+				// mpc address is missing, should fail
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge after set mpc address and retry, should fail
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+
+				// unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// retry again, should work
+				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
+					deposit_on_block_height: 1234567u128,
+					dest_domain_id: DEST_DOMAIN_ID,
+					sender: ALICE,
+				})]);
+			})
+		}
+
+		#[test]
+		fn proposal_execution_should_work() {
+			new_test_ext().execute_with(|| {
+				// mpc address is missing, should fail
+				assert_noop!(
+					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
+					bridge::Error::<Runtime>::MissingMpcAddress,
+				);
+				// set mpc address to generated keypair's address
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// Generate an evil key
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
+
+				// Deposit some native asset in advance
+				let fee = 1_000_000_000_000u128;
+				let amount: u128 = 200_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+
+				// Register foreign asset (USDT) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint 400 USDT to liquidity holder for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap()
+					),
+					400_000_000_000_000,
+				));
+				// alice deposit 200 - 1 token fee native token, so the native token holder should have 199 tokens
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_000_000_000_000
+				);
+				// USDT liquidity holder should have 400 USDT at this moment
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					400_000_000_000_000
+				);
+
+				// Generate proposals
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
+				// 0.000200000000
+				let valid_native_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 18 decimal
+				// 0.000200000000000000
+				let valid_usdt_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_depositnonce_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_domainid_proposal = Proposal {
+					origin_domain_id: DomainID::new(2),
+					deposit_nonce: 3,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_resourceid_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: [2u8; 32],
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_recipient_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						b"invalid recipient".to_vec(),
+					),
+				};
+				let empty_data_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: UsdtResourceId::get(),
+					data: vec![],
+				};
+
+				let proposals = vec![
+					valid_native_transfer_proposal,
+					valid_usdt_transfer_proposal,
+					invalid_depositnonce_proposal,
+					invalid_domainid_proposal,
+					invalid_resourceid_proposal,
+					invalid_recipient_proposal,
+					empty_data_proposal,
+				];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
+				let proposals_with_bad_signature = evil_pair.sign_prehashed(&final_message);
+
+				// Should failed if dest domain 1 bridge paused
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals.clone(),
+					proposals_with_valid_signature.encode()
+				));
+				// should emit FailedHandlerExecution event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: vec![66, 114, 105, 100, 103, 101, 80, 97, 117, 115, 101, 100],
+						reason: ExecutionFailureReason::Filtered,
+						origin_domain_id: DomainID::new(1),
+						deposit_nonce: 3,
+					},
+				)]);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals.clone(),
+						proposals_with_bad_signature.encode(),
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 0);
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					proposals_with_valid_signature.encode(),
+				));
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					proposals_with_valid_signature.encode(),
+				));
+				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
+				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
+				// usdt is defined in 18 decimal so that converted amount is the same as in proposal
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), amount);
+
+				// liquidity holder accounts balance after proposals execution
+				// 199 - 0.0002 native token is 198.999800000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_000_000_000_000 - 200_000_000
+				);
+				// 400 USDT after transferring out the USDT proposal, should remain 200 USDT
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					200_000_000_000_000
+				);
+			})
+		}
+
+		#[test]
+		fn get_bridge_pause_status() {
+			new_test_ext().execute_with(|| {
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn access_control() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				assert_noop!(
+					SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// Grant ALICE the access of `set_mpc_address`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"set_mpc_address".to_vec(),
+					ALICE
+				));
+				// Grant BOB the access of `pause_bridge` and `unpause_bridge`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					BOB
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_bridge".to_vec(),
+					BOB
+				));
+
+				// BOB set mpc address should still failed
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Some(BOB).into(), test_mpc_addr),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// ALICE set mpc address should work
+				assert_ok!(SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// ALICE pause&unpause bridge should still failed
+				assert_noop!(
+					SygmaBridge::pause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// BOB pause&unpause bridge should work
+				assert_ok!(SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn multi_domain_test() {
+			new_test_ext().execute_with(|| {
+				// root register domainID 1 with chainID 0, should be ok
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DomainID::new(1),
+					U256::from(0)
+				));
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// alice register domainID 1 with chainID 1, should raise error AccessDenied
+				assert_noop!(
+					SygmaBridge::register_domain(
+						Origin::from(Some(ALICE)),
+						DomainID::new(1),
+						U256::from(1)
+					),
+					Error::<Runtime>::AccessDenied
+				);
+				// Grant ALICE the access of `register_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				// alice register domainID 1 with chainID 1, should be ok
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DomainID::new(1),
+					U256::from(1)
+				));
+				// should emit RegisterDestDomain event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::RegisterDestDomain {
+						sender: ALICE,
+						domain_id: DomainID::new(1),
+						chain_id: U256::from(1),
+					},
+				)]);
+				// storage check
+				assert!(DestDomainIds::<Runtime>::get(DomainID::new(1)));
+				assert_eq!(DestChainIds::<Runtime>::get(DomainID::new(1)).unwrap(), U256::from(1));
+
+				// alice unregister domainID 1 with chainID 0, should raise error AccessDenied
+				assert_noop!(
+					SygmaBridge::unregister_domain(
+						Origin::from(Some(ALICE)),
+						DomainID::new(1),
+						U256::from(0),
+						false
+					),
+					Error::<Runtime>::AccessDenied
+				);
+				// Grant ALICE the access of `unregister_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unregister_domain".to_vec(),
+					ALICE
+				));
+				// alice unregister domainID 1 with chainID 2, should raise error
+				// DestChainIDNotMatch
+				assert_noop!(
+					SygmaBridge::unregister_domain(
+						Origin::from(Some(ALICE)),
+						DomainID::new(1),
+						U256::from(2),
+						false
+					),
+					Error::<Runtime>::DestChainIDNotMatch
+				);
+				// alice unregister domainID 2 with chainID 2, should raise error
+				// DestDomainNotSupported
+				assert_noop!(
+					SygmaBridge::unregister_domain(
+						Origin::from(Some(ALICE)),
+						DomainID::new(2),
+						U256::from(2),
+						false
+					),
+					Error::<Runtime>::DestDomainNotSupported
+				);
+				// alice unregister domainID 1 with chainID 1, should success
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::from(Some(ALICE)),
+					DomainID::new(1),
+					U256::from(1),
+					false
+				));
+				// should emit UnregisterDestDomain event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::UnregisterDestDomain {
+						sender: ALICE,
+						domain_id: DomainID::new(1),
+						chain_id: U256::from(1),
+					},
+				)]);
+
+				// storage check
+				// DomainID 1 should not support anymore
+				assert!(!DestDomainIds::<Runtime>::get(DomainID::new(1)));
+				// corresponding chainID should be None since kv not exist anymore
+				assert!(DestChainIds::<Runtime>::get(DomainID::new(1)).is_none());
+			})
+		}
+
+		#[test]
+		fn deposit_with_decimal_converter() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// native asset with 12 decimal
+				let fee_native_asset = 1_000_000_000_000u128; // 1.0 native asset
+				let amount_native_asset = 123_456_789_123_456u128; // 123.456_789_123_456
+				let adjusted_amount_native_asset = 122_456_789_123_456_000_000u128; // amount_native_asset - fee_native_asset then adjust it to 18 decimals
+
+				// usdt asset with 18 decimal
+				let fee_usdt_asset = 1_000_000_000_000_000_000u128; // 1.0 usdt asset
+				let amount_usdt_asset = 123_456_789_123_456_789_123u128; // 123.456_789_123_456_789_123
+				let adjusted_amount_usdt_asset = 122_456_789_123_456_789_123u128; // amount_usdt_asset - fee_usdt_asset then adjust it to 18 decimals
+
+				// astr asset with 24 decimal
+				let fee_astr_asset = 1_000_000_000_000_000_000_000_000u128; // 1.0 astr asset
+				let amount_astr_asset = 123_456_789_123_456_789_123_456_789u128; // 123.456_789_123_456_789_123_456_789
+				let adjusted_amount_astr_asset = 122_456_789_123_456_789_123u128; // amount_astr_asset - fee_astr_asset then adjust it to 18 decimals
+
+				// set fees
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_native_asset
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					fee_usdt_asset
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					fee_astr_asset
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// deposit native asset which has 12 decimal
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(amount_native_asset)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount_native_asset);
+				// native asset should be reserved so that BridgeAccount should hold it
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount_native_asset - fee_native_asset
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee_native_asset);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee_native_asset,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							adjusted_amount_native_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+
+				// deposit usdt asset which has 18 decimal
+				// Register foreign asset (usdt) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some usdt to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				)); // make sure Alice owns enough funds here
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				// deposit
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(amount_usdt_asset)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), &ALICE),
+					ENDOWED_BALANCE - amount_usdt_asset
+				);
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					122_456_789_123_456_789_123
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()),
+					fee_usdt_asset
+				);
+
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee_usdt_asset,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						deposit_nonce: 1,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 1),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							adjusted_amount_usdt_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+
+				// deposit astr asset which has 24 decimal
+				// Register foreign asset (astr) with asset id 1
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some astr to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(1),
+					ALICE,
+					ENDOWED_BALANCE,
+				)); // make sure Alice owns enough funds here
+				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				// deposit
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(AstrLocation::get()), Fungible(amount_astr_asset)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), &ALICE),
+					ENDOWED_BALANCE - amount_astr_asset
+				);
+				// astr asset should be reserved so that BridgeAccount should hold it(Astr is not
+				// defined in ConcrateSygmaAsset)
+				assert_eq!(
+					Assets::balance(
+						AstrAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+								.unwrap()
+						),
+					),
+					amount_astr_asset - fee_astr_asset
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), TreasuryAccount::get()),
+					fee_astr_asset
+				);
+
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: AstrResourceId::get(),
+						fee_amount: fee_astr_asset,
+						fee_asset_id: AstrLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: AstrResourceId::get(),
+						deposit_nonce: 2,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 2),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							adjusted_amount_astr_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+
+				// deposit astr asset which has 24 decimal, extreme small amount edge case
+				let amount_astr_asset_extreme_small_amount = 100_000; // 0.000000000000000000100000 astr
+				let fee_astr_asset_extreme_small_amount = 1;
+				// 0.000000000000000000000001 astr
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					fee_astr_asset_extreme_small_amount
+				));
+				// after decimal conversion from 24 to 18, the final amount will be 0 so that
+				// decimal conversion will raise error deposit should not work
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(
+							(
+								Concrete(AstrLocation::get()),
+								Fungible(amount_astr_asset_extreme_small_amount)
+							)
+								.into()
+						),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::DecimalConversionFail
+				);
+			})
+		}
+
+		#[test]
+		fn proposal_execution_with_decimal_converter() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				// set mpc address
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				let fee = 1_000_000_000_000u128; // 1 token in 12 decimals
+				let init_deposit = 10_000_000_000_000u128;
+				// 12 token in 12 decimal
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				// deposit in advance to make sure the native asset has enough funds in
+				// TransferReserveAccount by doing this, Alice will deposit (half of her native
+				// asset - fee) into TransferReserveAccount
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(ENDOWED_BALANCE / 2)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// BridgeAccount should have half of alice native asset - fee
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					ENDOWED_BALANCE / 2 - fee
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				let bridge_amount = 100_000_000_000_000_000_000; // 100 native with 18 decimals
+
+				// proposal for bridging native asset to alice(native asset is 12 decimal)
+				let p_native = Proposal {
+					origin_domain_id: DomainID::new(1),
+					resource_id: NativeResourceId::get(),
+					deposit_nonce: 1,
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals = vec![p_native];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+
+				// check Alice balance of native asset before executing, should have half of the
+				// init native asset
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE / 2);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+				// check Alice balance of native asset after executing, should have half of the init
+				// native asset + 100_000_000_000_000(12 decimal)
+				assert_eq!(
+					Balances::free_balance(ALICE),
+					ENDOWED_BALANCE / 2 + 100_000_000_000_000
+				);
+
+				// proposal for bridging usdt asset to alice(usdt asset is 18 decimal)
+				// Register foreign asset (usdt) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some USDT to liquidity holder for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap()
+					),
+					ENDOWED_BALANCE,
+				));
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					ENDOWED_BALANCE
+				);
+
+				let p_usdt = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 2,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_usdt = vec![p_usdt];
+				let final_message_usdt =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_usdt);
+				let signature_usdt = pair.sign_prehashed(&final_message_usdt);
+
+				// alice does not have any usdt at this moment
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), 0);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_usdt,
+					signature_usdt.encode()
+				));
+				// alice should have 100 usdt at this moment (100 usdt with 18 decimals)
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), &ALICE),
+					100_000_000_000_000_000_000
+				);
+
+				// proposal for bridging astr asset to alice(astr asset is 24 decimal)
+				// Register foreign asset (astr) with asset id 1
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
+				// Mint some astr to BridgeAccount for test because astr is reserved asset for
+				// testing
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(1),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+							.unwrap()
+					),
+					ENDOWED_BALANCE
+				));
+				assert_eq!(
+					Assets::balance(
+						AstrAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+								.unwrap()
+						),
+					),
+					ENDOWED_BALANCE
+				);
+
+				let p_astr = Proposal {
+					origin_domain_id: DomainID::new(1),
+					deposit_nonce: 3,
+					resource_id: AstrResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_astr = vec![p_astr];
+				let final_message_astr =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_astr);
+				let signature_astr = pair.sign_prehashed(&final_message_astr);
+
+				// alice does not have any astr at this moment
+				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), 0);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_astr,
+					signature_astr.encode()
+				));
+				// alice should have 100 astr at this moment (100 astr with 24 decimals)
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), &ALICE),
+					100_000_000_000_000_000_000_000_000
+				);
+
+				// extreme small amount edge case
+				let extreme_small_bridge_amount = 100_000; // 0.000000000000100000 native asset with 18 decimals
+										   // proposal for bridging native asset to alice(native asset is 12 decimal)
+				let p_native_extreme = Proposal {
+					origin_domain_id: DomainID::new(1),
+					resource_id: NativeResourceId::get(),
+					deposit_nonce: 4,
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						extreme_small_bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_extreme = vec![p_native_extreme];
+				let final_message_extreme =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_extreme);
+				let signature_extreme = pair.sign_prehashed(&final_message_extreme);
+
+				// execute_proposal extrinsic should work but it will actually failed at decimal
+				// conversion step because 0.000000000000100000 in 18 decimal converts to 12 decimal
+				// would be 0.000000000000 which is 0
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_extreme,
+					signature_extreme.encode()
+				));
+				// should emit FailedHandlerExecution event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: vec![
+							68, 101, 99, 105, 109, 97, 108, 67, 111, 110, 118, 101, 114, 115, 105,
+							111, 110, 70, 97, 105, 108,
+						],
+						reason: ExecutionFailureReason::DecodeError,
+						origin_domain_id: DomainID::new(1),
+						deposit_nonce: 4,
+					},
+				)]);
+			})
+		}
+
+		#[test]
+		fn unpause_all_domains_test() {
+			new_test_ext().execute_with(|| {
+				// Grant ALICE the access of `register_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					ALICE
+				));
+				// alice register some domains
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DomainID::new(1),
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DomainID::new(2),
+					U256::from(2)
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DomainID::new(3),
+					U256::from(3)
+				));
+
+				// pause all
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), DomainID::new(1)));
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), DomainID::new(2)));
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), DomainID::new(3)));
+
+				// double check if they are all paused
+				assert!(SygmaBridge::is_paused(DomainID::new(1)));
+				assert!(SygmaBridge::is_paused(DomainID::new(2)));
+				assert!(SygmaBridge::is_paused(DomainID::new(3)));
+
+				SygmaBridge::unpause_all_domains();
+
+				// all domains should be unpaused now
+				assert!(!SygmaBridge::is_paused(DomainID::new(1)));
+				assert!(!SygmaBridge::is_paused(DomainID::new(2)));
+				assert!(!SygmaBridge::is_paused(DomainID::new(3)));
+			})
+		}
+
+		#[test]
+		fn setup_order_test() {
+			new_test_ext().execute_with(|| {
+				// Make sure mpc address is not set
+				let default_addr: MpcAddress = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// Grant ALICE the access admin extrinsics
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unregister_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_bridge".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"retry".to_vec(),
+					ALICE
+				));
+
+				// the deposit to retry is made at the current block
+				System::set_block_number(1234567);
+
+				// alice setup bridges without mpc address setup
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1),
+					false
+				));
+				// register it back
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), DomainID::new(1)));
+				assert_ok!(SygmaBridge::unpause_bridge(
+					Origin::from(Some(ALICE)),
+					DomainID::new(1)
+				));
+				// pause domain 2 again to see if mpc address setup will unpause it
+				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), DomainID::new(1)));
+
+				// double check if it's paused
+				assert!(SygmaBridge::is_paused(DomainID::new(1)));
+
+				// retry should not work here, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+				// deposit should not work, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(AstrLocation::get()), Fungible(100)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+				// proposal execution should not work either, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
+					bridge::Error::<Runtime>::MissingMpcAddress,
+				);
+
+				// set mpc address to generated keypair's address
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// setting mpc address keeps the bridge paused until relayers are ready
+				assert!(SygmaBridge::is_paused(DomainID::new(1)));
+				assert_ok!(SygmaBridge::unpause_bridge(
+					Origin::from(Some(ALICE)),
+					DomainID::new(1)
+				));
+				assert!(!SygmaBridge::is_paused(DomainID::new(1)));
+
+				// retry again, should work
+				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
+					deposit_on_block_height: 1234567u128,
+					dest_domain_id: DEST_DOMAIN_ID,
+					sender: ALICE,
+				})]);
+
+				// deposit should work now
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				// proposal execution should work
+				let valid_native_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let proposals = vec![valid_native_transfer_proposal];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					proposals_with_valid_signature.encode(),
+				));
+				// check native asset balance
+				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
+				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
+			})
+		}
+
+		#[test]
+		fn deposit_native_asset_with_percentage_fee() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+
+				// test cases
+				let fee_rate_1 = 500u32; // 5%
+				let fee_rate_2 = 10000u32; // 100%
+				let fee_rate_3 = 9999u32; // 99.99%
+				let fee_rate_4 = 0u32; // 0%
+				let fee_rate_5 = 15000u32; // 150%
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::PercentageFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// test 5%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_1,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances of Alice after deposit 200 native token
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				// Check reserved native token
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					190_000_000_000_000u128
+				);
+				// Check fee collected
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10_000_000_000_000u128);
+				// Check event
+				let final_amount_in_deposit_event_1 = 190_000_000_000_000_000_000; // 200 cut 5% then adjust to 18 decimals
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: 10_000_000_000_000u128,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							final_amount_in_deposit_event_1,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+
+				// test 100%
+				// should not work because 100% is out of fee rate
+				assert_noop!(
+					SygmaPercentageFeeHandler::set_fee_rate(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(NativeLocation::get().into()),
+						fee_rate_2,
+						0u128,
+						1_000_000_000_000_000u128
+					),
+					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+				);
+
+				// test 99.99%
+				// override 5% to 99.99%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_3,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 0.02 to 190.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					190_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 199.98 to 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test 0%
+				// override 99.99% to 0%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_4,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 200 to 390.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					390_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 0 to 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test 150%
+				// should not work because 150% is out of fee rate
+				assert_noop!(
+					SygmaPercentageFeeHandler::set_fee_rate(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(NativeLocation::get().into()),
+						fee_rate_5,
+						0u128,
+						1_000_000_000_000_000u128
+					),
+					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+				);
+
+				// Check reserved native token, should remain as 390.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					390_020_000_000_000u128
+				);
+				// Check fee collected, should remain as 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test fee bound: fee rate 5%
+				let fee_lower_bound = 100_000_000_000_000u128; // 100
+				let fee_upper_bound = 1_000_000_000_000_000u128;
+				// 1000
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_1,
+					fee_lower_bound,
+					fee_upper_bound
+				));
+
+				// with higher fee lower bound
+				// 5% fee of 200 token should be 10 but fee lower bound is 100, so fee is 100 now
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 100 to 490.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					490_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 100 to 309.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 309_980_000_000_000u128);
+
+				// with lower fee upper bound
+				// 5% fee of 200000 token should be 10000 but fee upper bound is 1000, so fee is
+				// 1000 now
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(200_000_000_000_000_000)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 199000 to 199490.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_490_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 1000 to 1309.980000000000
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					1_309_980_000_000_000u128
+				);
+			})
+		}
+
+		#[test]
+		fn percentage_fee_rate_not_set_for_domain_and_asset() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// only set fee handler but not set fee rate for domain and asset
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::PercentageFeeHandler,
+				));
+
+				// deposit should not go through because fee rate is not set in storage, so when
+				// get_fee, it returns None
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_native_asset_with_percentage_fee_override_basic_fee_handler() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// set fee handler with basic fee handler and fixed fee
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				// Override Basic fee handler to Percentage fee handler with 5% fee rate
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::PercentageFeeHandler,
+				));
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					500u32,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
+				// Check reserved native token, should increase by 190
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee + 190_000_000_000_000u128
+				);
+				// Check fee collected, should increase by 10
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					fee + 10_000_000_000_000u128
+				);
+			})
+		}
+
+		#[test]
+		fn pause_all_bridges_test() {
+			new_test_ext().execute_with(|| {
+				let domain_1: DomainID = DomainID::new(1);
+				let domain_2: DomainID = DomainID::new(2);
+				let domain_3: DomainID = DomainID::new(3);
+
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
+
+				// all registered domains should be unpaused now
+				assert!(!SygmaBridge::is_paused(domain_1));
+				assert!(!SygmaBridge::is_paused(domain_2));
+				assert!(!SygmaBridge::is_paused(domain_3));
+
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::pause_all_bridges(unauthorized_account),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// Grant ALICE the access
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_all_bridges".to_vec(),
+					ALICE
+				));
+
+				assert_ok!(SygmaBridge::pause_all_bridges(Origin::signed(ALICE)));
+
+				// all registered domains should be paused now
+				assert!(SygmaBridge::is_paused(domain_1));
+				assert!(SygmaBridge::is_paused(domain_2));
+				assert!(SygmaBridge::is_paused(domain_3));
+
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AllBridgePaused {
+					sender: ALICE,
+				})]);
+			})
+		}
+
+		#[test]
+		fn unpause_all_bridges_test() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+
+				let domain_1: DomainID = DomainID::new(1);
+				let domain_2: DomainID = DomainID::new(2);
+				let domain_3: DomainID = DomainID::new(3);
+
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
+
+				// mpc address not setup, should be error
+				assert_noop!(
+					SygmaBridge::unpause_all_bridges(Origin::root()),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::unpause_all_bridges(unauthorized_account),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// Grant ALICE the access
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_all_bridges".to_vec(),
+					ALICE
+				));
+
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_1));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_2));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_3));
+
+				// all registered domains should be paused now
+				assert!(SygmaBridge::is_paused(domain_1));
+				assert!(SygmaBridge::is_paused(domain_2));
+				assert!(SygmaBridge::is_paused(domain_3));
+
+				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::signed(ALICE)));
+
+				// all registered domains should be unpaused now
+				assert!(!SygmaBridge::is_paused(domain_1));
+				assert!(!SygmaBridge::is_paused(domain_2));
+				assert!(!SygmaBridge::is_paused(domain_3));
+
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::AllBridgeUnpaused { sender: ALICE },
+				)]);
+			})
+		}
+
+		#[test]
+		fn deposit_nonce_fix_should_work() {
+			new_test_ext().execute_with(|| {
+				// Nonce from source chain start from 1, set first batch of nonce under [1, 63]
+				for nonce in 1..64u64 {
+					SygmaBridge::set_proposal_executed(nonce, DomainID::new(0));
+				}
+				// Nonce 0 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(0, DomainID::new(0)));
+				// Nonce 1 should be set
+				assert!(SygmaBridge::is_proposal_executed(1, DomainID::new(0)));
+				// Nonce 63 should be set
+				assert!(SygmaBridge::is_proposal_executed(63, DomainID::new(0)));
+
+				// set second batch of nonce under [64, 127]
+				for nonce in 64..128u64 {
+					SygmaBridge::set_proposal_executed(nonce, DomainID::new(0));
+				}
+				// Nonce 64 should be set
+				assert!(SygmaBridge::is_proposal_executed(64, DomainID::new(0)));
+				// Nonce 127 should be set
+				assert!(SygmaBridge::is_proposal_executed(127, DomainID::new(0)));
+				// Nonce 128 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(128, DomainID::new(0)));
+
+				// set future batch of nonce under [256, 300]
+				for nonce in 256..301u64 {
+					SygmaBridge::set_proposal_executed(nonce, DomainID::new(0));
+				}
+				// Nonce 256 should be set
+				assert!(SygmaBridge::is_proposal_executed(256, DomainID::new(0)));
+				// Nonce 300 should be set
+				assert!(SygmaBridge::is_proposal_executed(300, DomainID::new(0)));
+				// Nonce 301 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(301, DomainID::new(0)));
+			})
+		}
+
+		#[test]
+		fn resolve_asset_with_matching_mode() {
+			new_test_ext().execute_with(|| {
+				// USDT referred from the local chain instead of relative to its parent
+				let usdt_from_local: XcmAssetId =
+					MultiLocation::new(0, UsdtLocation::get().interior).into();
+
+				// exact match works under any mode
+				assert_eq!(
+					SygmaBridge::resolve_asset(&UsdtLocation::get().into()),
+					Some((UsdtLocation::get().into(), UsdtResourceId::get()))
+				);
+
+				// strict mode rejects different parents
+				AssetMatching::set(&AssetMatchingMode::Strict);
+				assert_eq!(SygmaBridge::resolve_asset(&usdt_from_local), None);
+
+				// ignoring network id does not help with different parents
+				AssetMatching::set(&AssetMatchingMode::IgnoreNetworkId);
+				assert_eq!(SygmaBridge::resolve_asset(&usdt_from_local), None);
+
+				// lenient modes resolve to the registered asset id
+				AssetMatching::set(&AssetMatchingMode::IgnoreParents);
+				assert_eq!(
+					SygmaBridge::resolve_asset(&usdt_from_local),
+					Some((UsdtLocation::get().into(), UsdtResourceId::get()))
+				);
+				AssetMatching::set(&AssetMatchingMode::Lenient);
+				assert_eq!(
+					SygmaBridge::resolve_asset(&usdt_from_local),
+					Some((UsdtLocation::get().into(), UsdtResourceId::get()))
+				);
+
+				// unknown interior is never matched
+				assert_eq!(
+					SygmaBridge::resolve_asset(
+						&MultiLocation::new(0, X1(GeneralIndex(123))).into()
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_with_lenient_asset_matching_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+				let usdt_from_local = MultiLocation::new(0, UsdtLocation::get().interior);
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+
+				// strict mode rejects the asset
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(usdt_from_local), Fungible(amount)).into()),
+						Box::new(dest),
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+
+				// ignoring parents the deposit goes through with the registered USDT
+				AssetMatching::set(&AssetMatchingMode::IgnoreParents);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(usdt_from_local), Fungible(amount)).into()),
+					Box::new(dest),
+				));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), fee);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount - fee,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn deposit_nonce_anomaly_should_be_detected() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// first deposits have no anomaly
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert!(!System::events().iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositNonceAnomaly { .. })
+				)));
+
+				// tamper the deposit counter, deposit still proceeds with the stored nonce
+				DepositCounts::<Runtime>::insert(DEST_DOMAIN_ID, 5);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositNonceAnomaly {
+						domain: DEST_DOMAIN_ID,
+						expected: 2,
+						actual: 5,
+					})));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 6);
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+
+				// with auto pause enabled, the domain is paused after the anomalous deposit
+				PauseOnDepositNonceAnomaly::set(&true);
+				DepositCounts::<Runtime>::insert(DEST_DOMAIN_ID, 3);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositNonceAnomaly {
+						domain: DEST_DOMAIN_ID,
+						expected: 6,
+						actual: 3,
+					})));
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_eq!(
+					PauseReasons::<Runtime>::get(DEST_DOMAIN_ID).map(|info| info.reason),
+					Some(PauseReason::CircuitBreaker { resource: None })
+				);
+				assert_noop!(
+					SygmaBridge::deposit(Origin::signed(ALICE), Box::new(asset), Box::new(dest)),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+			})
+		}
+
+		#[test]
+		fn fee_collected_event_should_carry_fee_asset_id() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let native_fee = 1_000_000_000_000u128;
+				let usdt_fee = 100u128;
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// fee of each resource is paid in its own asset
+				for (location, fee) in
+					[(NativeLocation::get(), native_fee), (UsdtLocation::get(), usdt_fee)]
+				{
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
+
+				// Register foreign asset (USDT) with asset id 0 and mint some to ALICE
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(200u128)).into()),
+					Box::new(dest),
+				));
+				let usdt_fee_asset_id: XcmAssetId = UsdtLocation::get().into();
+				assert_ne!(usdt_fee_asset_id, NativeLocation::get().into());
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: usdt_fee,
+						fee_asset_id: usdt_fee_asset_id,
+					})));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(2 * native_fee)).into()),
+					Box::new(dest),
+				));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: native_fee,
+						fee_asset_id: NativeLocation::get().into(),
+					})));
+			})
+		}
+
+		#[test]
+		fn undeliverable_proposal_should_be_escrowed_and_claimed() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// Register foreign asset (USDT) with asset id 0 and min balance 100
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 100,));
+				let reserve_account = AccountId::new(
+					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
+				);
+				let escrow_account = SygmaBridge::escrow_account(&UsdtResourceId::get());
+				// liquidity holder and escrow holding account are kept alive
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					reserve_account.clone(),
+					1000,
+				));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					escrow_account.clone(),
+					100,
+				));
+
+				// recipient does not hold any USDT, 50 is below the min balance
+				let recipient = AccountId::new([9u8; 32]);
+				let beneficiary =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: [9u8; 32] }));
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						50,
+						beneficiary.encode(),
+					),
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::TransferEscrowed {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						beneficiary,
+						asset_id: UsdtLocation::get().into(),
+						amount: 50,
+					})));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_some());
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &reserve_account), 950);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &escrow_account), 150);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &recipient), 0);
+
+				// recipient still can not be credited
+				assert_noop!(
+					SygmaBridge::claim_escrowed_transfer(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::TransactFailed
+				);
+
+				// claim on behalf of the recipient after it gets funded
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					recipient.clone(),
+					100,
+				));
+				assert_ok!(SygmaBridge::claim_escrowed_transfer(
+					Origin::signed(ALICE),
+					DEST_DOMAIN_ID,
+					1
+				));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &recipient), 150);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &escrow_account), 100);
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EscrowedTransferClaimed {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						beneficiary,
+					},
+				)]);
+
+				assert_noop!(
+					SygmaBridge::claim_escrowed_transfer(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::EscrowedTransferNotFound
+				);
+			})
+		}
+
+		#[test]
+		fn expired_escrowed_transfer_should_be_swept() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// Register foreign asset (USDT) with asset id 0 and min balance 100
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 100,));
+				for account in [
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap(),
+					),
+					SygmaBridge::escrow_account(&UsdtResourceId::get()),
+					TreasuryAccount::get(),
+				] {
+					assert_ok!(Assets::mint(
+						Origin::signed(ASSET_OWNER),
+						codec::Compact(0),
+						account,
+						1000,
+					));
+				}
+
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						50,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: [9u8; 32] }))
+							.encode(),
+					),
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_some());
+
+				assert_noop!(
+					SygmaBridge::sweep_escrowed_transfer(Origin::root(), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::EscrowRetentionNotExpired
+				);
+				System::set_block_number(System::block_number() + 100);
+				assert_noop!(
+					SygmaBridge::sweep_escrowed_transfer(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::sweep_escrowed_transfer(Origin::root(), DEST_DOMAIN_ID, 1));
+
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), 1050);
+				assert_eq!(
+					SygmaBridge::manual_interventions().into_inner(),
+					vec![ManualIntervention {
+						block: System::block_number(),
+						operation: InterventionOperation::SweepEscrowedTransfer,
+						operator_origin_hash: blake2_256(&Origin::root().caller().encode()),
+						subject: InterventionSubject::Transfer {
+							domain_id: DEST_DOMAIN_ID,
+							deposit_nonce: 1,
+						},
+						amount: Some(50),
+					}]
+				);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EscrowedTransferSwept {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+			})
+		}
+
+		#[test]
+		fn deposit_nonce_reservation_lifecycle() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// reserve nonce 0, the following deposit takes nonce 1
+				assert_ok!(SygmaBridge::reserve_nonce(Origin::signed(ALICE), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositNonceReserved {
+						dest_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 0,
+						owner: ALICE,
+						expires_at: 11,
+					},
+				)]);
+				assert_eq!(NonceReservations::<Runtime>::get(DEST_DOMAIN_ID, 0), Some((ALICE, 11)));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(BOB),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 2);
+
+				// only the owner can commit the reserved nonce
+				assert_noop!(
+					SygmaBridge::commit_deposit(
+						Origin::signed(BOB),
+						0,
+						Box::new(asset.clone()),
+						Box::new(dest)
+					),
+					bridge::Error::<Runtime>::NonceNotReserved
+				);
+				assert_noop!(
+					SygmaBridge::commit_deposit(
+						Origin::signed(ALICE),
+						1,
+						Box::new(asset.clone()),
+						Box::new(dest)
+					),
+					bridge::Error::<Runtime>::NonceNotReserved
+				);
+				assert_ok!(SygmaBridge::commit_deposit(
+					Origin::signed(ALICE),
+					0,
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							(amount - fee) * 1_000_000,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+				]);
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 2);
+				assert!(NonceReservations::<Runtime>::get(DEST_DOMAIN_ID, 0).is_none());
+				// committed nonce can not be used again
+				assert_noop!(
+					SygmaBridge::commit_deposit(
+						Origin::signed(ALICE),
+						0,
+						Box::new(asset.clone()),
+						Box::new(dest)
+					),
+					bridge::Error::<Runtime>::NonceNotReserved
+				);
+
+				// reservation of nonce 2 expires
+				assert_ok!(SygmaBridge::reserve_nonce(Origin::signed(ALICE), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::reclaim_nonce_reservation(Origin::signed(BOB), DEST_DOMAIN_ID, 2),
+					bridge::Error::<Runtime>::NonceReservationNotExpired
+				);
+				System::set_block_number(12);
+				assert_noop!(
+					SygmaBridge::commit_deposit(
+						Origin::signed(ALICE),
+						2,
+						Box::new(asset),
+						Box::new(dest)
+					),
+					bridge::Error::<Runtime>::NonceReservationExpired
+				);
+				assert_ok!(SygmaBridge::reclaim_nonce_reservation(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					2
+				));
+				assert!(NonceReservations::<Runtime>::get(DEST_DOMAIN_ID, 2).is_none());
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::NonceReservationReclaimed {
+						dest_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					},
+				)]);
+				// reclaimed nonce is never reassigned
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 3);
+			})
+		}
+
+		#[test]
+		fn execute_proposal_relayer_and_mpc_threshold_combinations() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				// 2 of 3 MPC signers
+				let signer_pairs: Vec<ecdsa::Pair> = (0..3).map(|_| Pair::generate().0).collect();
+				let signers: Vec<MpcAddress> = signer_pairs
+					.iter()
+					.map(|p| MpcAddress(p.public().to_eth_address().unwrap()))
+					.collect();
+				assert_noop!(
+					SygmaBridge::set_mpc_signers(Origin::root(), signers.clone(), 4),
+					bridge::Error::<Runtime>::InvalidMpcThreshold,
+				);
+				assert_noop!(
+					SygmaBridge::set_mpc_signers(Origin::root(), vec![signers[0], signers[0]], 1),
+					bridge::Error::<Runtime>::InvalidMpcThreshold,
+				);
+				assert_noop!(
+					SygmaBridge::set_mpc_signers(Origin::signed(BOB), signers.clone(), 2),
+					bridge::Error::<Runtime>::AccessDenied,
+				);
+				assert_ok!(SygmaBridge::set_mpc_signers(Origin::root(), signers.clone(), 2));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::MpcSignersSet {
+					signers: signers.clone(),
+					threshold: 2,
+				})]);
+
+				let proposal = |deposit_nonce| {
+					vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							200_000_000_000_000u128,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}]
+				};
+				let mpc_signature = |proposals: &Vec<Proposal>| {
+					let message = SygmaBridge::construct_ecdsa_signing_proposals_data(proposals);
+					pair.sign_prehashed(&message).encode()
+				};
+				let threshold_signature = |proposals: &Vec<Proposal>, n: usize| {
+					let message = SygmaBridge::construct_ecdsa_signing_proposals_data(proposals);
+					signer_pairs[..n]
+						.iter()
+						.flat_map(|p| p.sign_prehashed(&message).encode())
+						.collect::<Vec<u8>>()
+				};
+
+				// both checks disabled, any origin with the MPC signature is accepted
+				let proposals = proposal(1);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(BOB),
+						proposals.clone(),
+						threshold_signature(&proposals, 2)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(BOB),
+					proposals.clone(),
+					mpc_signature(&proposals)
+				));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// only relayer allowlist enabled
+				RequireRelayerAllowlist::set(&true);
+				let proposals = proposal(2);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(BOB),
+						proposals.clone(),
+						mpc_signature(&proposals)
+					),
+					bridge::Error::<Runtime>::RelayerNotAllowed,
+				);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::root(),
+						proposals.clone(),
+						mpc_signature(&proposals)
+					),
+					bridge::Error::<Runtime>::RelayerNotAllowed,
+				);
+				assert_noop!(
+					SygmaBridge::add_relayer(Origin::signed(BOB), BOB),
+					bridge::Error::<Runtime>::AccessDenied,
+				);
+				assert_ok!(SygmaBridge::add_relayer(Origin::root(), BOB));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::RelayerAdded {
+					relayer: BOB,
+				})]);
+				assert!(Relayers::<Runtime>::get(BOB));
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(BOB),
+					proposals.clone(),
+					mpc_signature(&proposals)
+				));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+
+				// both checks enabled
+				RequireMpcThreshold::set(&true);
+				let proposals = proposal(3);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(BOB),
+						proposals.clone(),
+						mpc_signature(&proposals)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(BOB),
+						proposals.clone(),
+						threshold_signature(&proposals, 1)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals.clone(),
+						threshold_signature(&proposals, 2)
+					),
+					bridge::Error::<Runtime>::RelayerNotAllowed,
+				);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(BOB),
+					proposals.clone(),
+					threshold_signature(&proposals, 2)
+				));
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+
+				// only MPC threshold enabled
+				RequireRelayerAllowlist::set(&false);
+				assert_ok!(SygmaBridge::remove_relayer(Origin::root(), BOB));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::RelayerRemoved {
+					relayer: BOB,
+				})]);
+				assert!(!Relayers::<Runtime>::get(BOB));
+				let proposals = proposal(4);
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(BOB),
+						proposals.clone(),
+						threshold_signature(&proposals, 1)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(BOB),
+					proposals.clone(),
+					threshold_signature(&proposals, 3)
+				));
+				assert!(SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn deposit_records_should_be_pruned_in_pages() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// 6 deposits fill 3 pages of 2 records
+				System::set_block_number(1);
+				for _ in 0..6 {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest)
+					));
+				}
+				assert_eq!(DepositRecords::<Runtime>::count(), 6);
+				assert_eq!(
+					DepositRecords::<Runtime>::get((DEST_DOMAIN_ID, 0)).unwrap(),
+					DepositRecord {
+						sender: ALICE,
+						asset_id: NativeLocation::get().into(),
+						amount: amount - fee,
+						recorded_at: 1,
+						refund_requested: false,
+						cancelled: false,
+					}
+				);
+
+				// only the sender can request a refund, and only once
+				assert_noop!(
+					SygmaBridge::request_refund(Origin::signed(BOB), DEST_DOMAIN_ID, 4),
+					bridge::Error::<Runtime>::NotDepositSender,
+				);
+				assert_ok!(SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 4));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::RefundRequested {
+					dest_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 4,
+					sender: ALICE,
+				})]);
+				assert_noop!(
+					SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 4),
+					bridge::Error::<Runtime>::RefundAlreadyRequested,
+				);
+
+				// records within the retention window are not pruned
+				System::set_block_number(10);
+				assert_noop!(
+					SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2),
+					bridge::Error::<Runtime>::NoDepositRecordsToPrune,
+				);
+
+				// prune the first 2 pages
+				System::set_block_number(11);
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositRecordsPruned { count: 2 },
+				)]);
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2));
+				assert_eq!(DepositRecords::<Runtime>::count(), 2);
+				assert!(DepositRecords::<Runtime>::get((DEST_DOMAIN_ID, 3)).is_none());
+
+				// the pending refund is moved to the back of the queue, the record behind it is
+				// pruned
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositRecordsPruned { count: 1 },
+				)]);
+				assert_eq!(DepositRecords::<Runtime>::count(), 1);
+				assert!(DepositRecords::<Runtime>::get((DEST_DOMAIN_ID, 4)).is_some());
+				assert_eq!(DepositRecordQueueHead::<Runtime>::get(), 6);
+				assert_eq!(DepositRecordQueueTail::<Runtime>::get(), 7);
+
+				// refund of a pruned record fails
+				assert_noop!(
+					SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 0),
+					bridge::Error::<Runtime>::DepositRecordNotFound,
+				);
+
+				// refund the pending one, the bridged amount goes back to the sender
+				assert_noop!(
+					SygmaBridge::refund_deposit(Origin::signed(BOB), DEST_DOMAIN_ID, 4),
+					bridge::Error::<Runtime>::AccessDenied,
+				);
+				assert_noop!(
+					SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 5),
+					bridge::Error::<Runtime>::RefundNotRequested,
+				);
+				let alice_balance = Balances::free_balance(ALICE);
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+				assert_ok!(SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 4));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositRefunded {
+					dest_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 4,
+					sender: ALICE,
+					asset_id: NativeLocation::get().into(),
+					amount: amount - fee,
+				})]);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance + amount - fee);
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					reserve_balance - (amount - fee)
+				);
+				assert_eq!(
+					SygmaBridge::manual_interventions().into_inner(),
+					vec![ManualIntervention {
+						block: 11,
+						operation: InterventionOperation::RefundDeposit,
+						operator_origin_hash: blake2_256(&Origin::root().caller().encode()),
+						subject: InterventionSubject::Transfer {
+							domain_id: DEST_DOMAIN_ID,
+							deposit_nonce: 4,
+						},
+						amount: Some(amount - fee),
+					}]
+				);
+				assert_noop!(
+					SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 4),
+					bridge::Error::<Runtime>::DepositRecordNotFound,
+				);
+
+				// the refunded record is gone, nothing is left to prune
+				assert_eq!(DepositRecords::<Runtime>::count(), 0);
+				assert_noop!(
+					SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2),
+					bridge::Error::<Runtime>::NoDepositRecordsToPrune,
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_should_validate_dest_parents() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = |parents: u8| MultiLocation {
+					parents,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// parents = 0 is the expected topology
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest(0))
+				));
+				// parents = 1 and parents = 2 route to the relay chain level and above
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest(1))
+					),
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest(2))
+					),
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+
+				// expect parents = 1, dest is then left to the dest data parser
+				ExpectedDestParents::set(&1);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest(0))
+					),
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest(1))
+					),
+					bridge::Error::<Runtime>::ExtractDestDataFailed
+				);
+				assert_noop!(
+					SygmaBridge::deposit(Origin::signed(ALICE), Box::new(asset), Box::new(dest(2))),
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+			})
+		}
+
+		#[test]
+		fn routed_transfer_should_be_forwarded_to_final_domain() {
+			new_test_ext().execute_with(|| {
+				let final_domain_id: DomainID = DomainID::new(2);
+				let fee = 1_000_000_000_000u128;
+				let second_leg_fee = 2_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let final_dest = |domain_id: DomainID| MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[domain_id.into_inner()]),
+					),
+				};
+
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				for (domain_id, domain_fee) in
+					[(DEST_DOMAIN_ID, fee), (final_domain_id, second_leg_fee)]
+				{
+					assert_ok!(SygmaBridge::register_domain(
+						Origin::root(),
+						domain_id,
+						U256::from(domain_id.into_inner())
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						domain_fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), final_domain_id));
+
+				// a route can not end at its intermediate domain
+				assert_noop!(
+					SygmaBridge::deposit_with_route(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(final_dest(DEST_DOMAIN_ID)),
+						DEST_DOMAIN_ID,
+					),
+					bridge::Error::<Runtime>::InvalidRoute
+				);
+
+				// first leg, the proposal is addressed to the intermediate domain and carries
+				// the final domain in the route extension
+				assert_ok!(SygmaBridge::deposit_with_route(
+					Origin::signed(ALICE),
+					Box::new(asset),
+					Box::new(final_dest(final_domain_id)),
+					DEST_DOMAIN_ID,
+				));
+				// 12 decimals to 18 decimals
+				let first_leg_data = [
+					SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						(amount - fee) * 1_000_000,
+						b"ethereum recipient".to_vec(),
+					),
+					vec![EXTRA_TAG_ROUTE, 1, final_domain_id.into_inner()],
+				]
+				.concat();
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: first_leg_data.clone(),
+						handler_response: vec![],
+					}),
+				]);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), amount - fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				// second leg, this chain acts as the intermediate domain and forwards the
+				// transfer to the final domain
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 0,
+					resource_id: NativeResourceId::get(),
+					data: first_leg_data,
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
+				));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: final_domain_id,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(final_domain_id, 0),
+						sender: BridgeAccountNative::get(),
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							(amount - fee - second_leg_fee) * 1_000_000,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					})));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::TransferForwarded {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 0,
+						dest_domain_id: final_domain_id,
+						forwarded_deposit_nonce: 0,
+					})));
+				assert!(SygmaBridge::is_proposal_executed(0, DEST_DOMAIN_ID));
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					amount - fee - second_leg_fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee + second_leg_fee);
+
+				// a routed transfer bouncing back to its origin domain is not forwarded
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: [
+						SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount * 1_000_000,
+							b"ethereum recipient".to_vec(),
+						),
+						vec![EXTRA_TAG_ROUTE, 1, DEST_DOMAIN_ID.into_inner()],
+					]
+					.concat(),
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InvalidRoute".to_vec(),
+						reason: ExecutionFailureReason::Filtered,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn deposit_should_be_gated_on_domain_health() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// only the allowlisted oracle can report
+				assert_noop!(
+					SygmaBridge::report_domain_health(Origin::signed(BOB), DEST_DOMAIN_ID, false),
+					bridge::Error::<Runtime>::NotHealthOracle,
+				);
+				assert_noop!(
+					SygmaBridge::set_health_oracle(Origin::signed(BOB), Some(BOB)),
+					bridge::Error::<Runtime>::AccessDenied,
+				);
+				assert_ok!(SygmaBridge::set_health_oracle(Origin::root(), Some(BOB)));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::HealthOracleSet {
+					oracle: Some(BOB),
+				})]);
+				assert_noop!(
+					SygmaBridge::report_domain_health(Origin::signed(ALICE), DEST_DOMAIN_ID, false),
+					bridge::Error::<Runtime>::NotHealthOracle,
+				);
+				assert_noop!(
+					SygmaBridge::report_domain_health(Origin::signed(BOB), DomainID::new(2), false),
+					bridge::Error::<Runtime>::DestDomainNotSupported,
+				);
+
+				// unhealthy domain rejects deposits
+				assert_ok!(SygmaBridge::report_domain_health(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					false
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DomainHealthChanged {
+						domain: DEST_DOMAIN_ID,
+						healthy: false,
+					},
+				)]);
+				assert_eq!(DomainHealth::<Runtime>::get(DEST_DOMAIN_ID), Some(false));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest)
+					),
+					bridge::Error::<Runtime>::DomainUnhealthy,
+				);
+				assert_noop!(
+					SygmaBridge::reserve_nonce(Origin::signed(ALICE), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DomainUnhealthy,
+				);
+
+				// reporting the same health again does not emit another event
+				System::reset_events();
+				assert_ok!(SygmaBridge::report_domain_health(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					false
+				));
+				assert!(System::events().is_empty());
+
+				// healthy domain accepts deposits again
+				assert_ok!(SygmaBridge::report_domain_health(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					true
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DomainHealthChanged { domain: DEST_DOMAIN_ID, healthy: true },
+				)]);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+
+				// removed oracle can no longer report
+				assert_ok!(SygmaBridge::set_health_oracle(Origin::root(), None));
+				assert_noop!(
+					SygmaBridge::report_domain_health(Origin::signed(BOB), DEST_DOMAIN_ID, false),
+					bridge::Error::<Runtime>::NotHealthOracle,
+				);
+			})
+		}
+
+		#[test]
+		fn execution_failure_reason_encoding_should_be_stable() {
+			assert_eq!(ExecutionFailureReason::ResourceNotRegistered.encode(), vec![0]);
+			assert_eq!(ExecutionFailureReason::InsufficientReserve.encode(), vec![1]);
+			assert_eq!(ExecutionFailureReason::DecodeError.encode(), vec![2]);
+			assert_eq!(ExecutionFailureReason::RecipientInvalid.encode(), vec![3]);
+			assert_eq!(ExecutionFailureReason::EdBelowMinimum.encode(), vec![4]);
+			assert_eq!(ExecutionFailureReason::Filtered.encode(), vec![5]);
+			assert_eq!(ExecutionFailureReason::Other(7).encode(), vec![6, 7]);
+			assert_eq!(ExecutionFailureReason::InboundLimitExceeded.encode(), vec![7]);
+			assert_eq!(ExecutionFailureReason::DecimalMismatch.encode(), vec![8]);
+			assert_eq!(ExecutionFailureReason::ProtectedAccountRecipient.encode(), vec![9]);
+		}
+
+		#[test]
+		fn failed_proposals_should_carry_failure_reason() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				// Register foreign asset (USDT) with asset id 0 and min balance 100
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 100,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap()
+					),
+					1000,
+				));
+
+				let bob_location =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+				let proposal = |origin_domain_id, deposit_nonce, resource_id, data| Proposal {
+					origin_domain_id,
+					deposit_nonce,
+					resource_id,
+					data,
+				};
+				let execute = |proposals: Vec<Proposal>| {
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let assert_failed = |origin_domain_id, deposit_nonce, reason, detail: &[u8]| {
+					assert_eq!(
+						FailedProposals::<Runtime>::get(origin_domain_id, deposit_nonce),
+						Some(FailedProposal {
+							reason,
+							detail: detail.to_vec().try_into().unwrap(),
+						})
+					);
+					assert!(System::events().iter().any(|r| r.event
+						== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+							error: detail.to_vec(),
+							reason,
+							origin_domain_id,
+							deposit_nonce,
+						})));
+				};
+
+				execute(vec![
+					// origin domain not registered
+					proposal(
+						DomainID::new(2),
+						1,
+						NativeResourceId::get(),
+						SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							1_000_000,
+							bob_location.encode(),
+						),
+					),
+					// resource not bound to an asset
+					proposal(
+						DEST_DOMAIN_ID,
+						2,
+						[2u8; 32],
+						SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							1_000_000,
+							bob_location.encode(),
+						),
+					),
+					// deposit data can not be decoded
+					proposal(DEST_DOMAIN_ID, 3, NativeResourceId::get(), b"garbage".to_vec()),
+					// native reserve account is empty
+					proposal(
+						DEST_DOMAIN_ID,
+						4,
+						NativeResourceId::get(),
+						SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							1_000_000,
+							bob_location.encode(),
+						),
+					),
+					// 50 USDT is below the min balance of both recipient and escrow account
+					proposal(
+						DEST_DOMAIN_ID,
+						5,
+						UsdtResourceId::get(),
+						SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							50,
+							MultiLocation::new(0, X1(AccountId32 { network: None, id: [9u8; 32] }))
+								.encode(),
+						),
+					),
+				]);
+				assert_failed(
+					DomainID::new(2),
+					1,
+					ExecutionFailureReason::Filtered,
+					b"DestDomainNotSupported",
+				);
+				assert_failed(
+					DEST_DOMAIN_ID,
+					2,
+					ExecutionFailureReason::ResourceNotRegistered,
+					b"AssetNotBound",
+				);
+				assert_failed(
+					DEST_DOMAIN_ID,
+					3,
+					ExecutionFailureReason::DecodeError,
+					b"InvalidDepositData",
+				);
+				assert_failed(
+					DEST_DOMAIN_ID,
+					4,
+					ExecutionFailureReason::InsufficientReserve,
+					b"InsufficientReserve",
+				);
+				assert_failed(
+					DEST_DOMAIN_ID,
+					5,
+					ExecutionFailureReason::EdBelowMinimum,
+					b"BelowExistentialDeposit",
+				);
+
+				// recipient is not an account
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				execute(vec![proposal(
+					DEST_DOMAIN_ID,
+					6,
+					NativeResourceId::get(),
+					SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						1_000_000,
+						MultiLocation::new(0, X1(GeneralIndex(1))).encode(),
+					),
+				)]);
+				assert_failed(
+					DEST_DOMAIN_ID,
+					6,
+					ExecutionFailureReason::RecipientInvalid,
+					b"InvalidRecipient",
+				);
+
+				// failed proposal is cleared once executed
+				let funded_proposal = proposal(
+					DEST_DOMAIN_ID,
+					4,
+					NativeResourceId::get(),
+					SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						1_000_000,
+						bob_location.encode(),
+					),
+				);
+				execute(vec![funded_proposal.clone()]);
+				assert!(SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 4).is_none());
+
+				// the replay of an executed proposal is not recorded
+				execute(vec![funded_proposal]);
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 4).is_none());
+				assert_eq!(FailedProposalCounts::<Runtime>::get(NativeResourceId::get()), 0);
+
+				// any other failure carries the index of the pallet error
+				assert_eq!(
+					SygmaBridge::execution_failure_reason(
+						&bridge::Error::<Runtime>::ProposalAlreadyComplete.into()
+					),
+					ExecutionFailureReason::Other(
+						bridge::Error::<Runtime>::ProposalAlreadyComplete.encode()[0]
+					)
+				);
+			})
+		}
+
+		#[test]
+		fn replay_protection_should_hold_across_used_nonces_pruning() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				UsedNoncesPruneThreshold::set(&1);
+
+				let execute = |nonces: Vec<DepositNonce>| {
+					let proposals: Vec<Proposal> = nonces
+						.into_iter()
+						.map(|deposit_nonce| Proposal {
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+							resource_id: NativeResourceId::get(),
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								1_000_000,
+								MultiLocation::new(
+									0,
+									X1(AccountId32 { network: None, id: BOB.into() }),
+								)
+								.encode(),
+							),
+						})
+						.collect();
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+
+				// nonce 5 is left unused in the first entry, nothing can be pruned
+				execute((0..5).chain(6..130).collect());
+				assert_eq!(UsedNoncesCount::<Runtime>::get(DEST_DOMAIN_ID), 3);
+				assert_eq!(MinUnprunedNonce::<Runtime>::get(DEST_DOMAIN_ID), 0);
+				assert!(!SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+
+				// the first 2 entries are full, at most 2 entries are pruned per call and the
+				// partially used third entry is kept
+				execute(vec![5]);
+				assert_eq!(UsedNoncesCount::<Runtime>::get(DEST_DOMAIN_ID), 1);
+				assert_eq!(MinUnprunedNonce::<Runtime>::get(DEST_DOMAIN_ID), 128);
+				assert_eq!(UsedNonces::<Runtime>::get(DEST_DOMAIN_ID, 0), 0);
+				assert_eq!(UsedNonces::<Runtime>::get(DEST_DOMAIN_ID, 1), 0);
+				assert_eq!(UsedNonces::<Runtime>::get(DEST_DOMAIN_ID, 2), 0b11);
+
+				// pruned nonces are still executed and can not be replayed
+				for nonce in [0, 5, 63, 64, 127, 128, 129] {
+					assert!(SygmaBridge::is_proposal_executed(nonce, DEST_DOMAIN_ID));
+				}
+				assert!(!SygmaBridge::is_proposal_executed(130, DEST_DOMAIN_ID));
+				let bob_balance = Balances::free_balance(BOB);
+				execute(vec![5, 100]);
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 5).is_none());
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 100).is_none());
+
+				// unpruned nonces still execute
+				execute(vec![130]);
+				assert!(SygmaBridge::is_proposal_executed(130, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 1);
+			})
+		}
+
+		#[test]
+		fn inbound_limits_should_cap_proposals_per_window() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				let resource_id = NativeResourceId::get();
+				let limit = InboundLimit { max_single: 3_000_000, max_per_window: 5_000_000 };
+				assert_noop!(
+					SygmaBridge::set_inbound_limit(Origin::signed(BOB), resource_id, Some(limit)),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::set_inbound_limit(
+						Origin::root(),
+						resource_id,
+						Some(InboundLimit { max_single: 6_000_000, max_per_window: 5_000_000 })
+					),
+					bridge::Error::<Runtime>::InvalidInboundLimit
+				);
+				assert_ok!(SygmaBridge::set_inbound_limit(
+					Origin::root(),
+					resource_id,
+					Some(limit)
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::InboundLimitSet {
+					resource_id,
+					limit: Some(limit),
+				})]);
+
+				// amounts are in 18 decimals, 1_000_000 credits 1 to BOB
+				let execute = |transfers: Vec<(DepositNonce, u128)>| {
+					let proposals: Vec<Proposal> = transfers
+						.into_iter()
+						.map(|(deposit_nonce, amount)| Proposal {
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+							resource_id,
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								amount,
+								MultiLocation::new(
+									0,
+									X1(AccountId32 { network: None, id: BOB.into() }),
+								)
+								.encode(),
+							),
+						})
+						.collect();
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let bob_balance = Balances::free_balance(BOB);
+
+				// proposal above the single cap is failed and its nonce consumed
+				execute(vec![(1, 4_000_000)]);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InboundSingleCapExceeded".to_vec(),
+						reason: ExecutionFailureReason::InboundLimitExceeded,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				execute(vec![(1, 1_000_000)]);
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+
+				// window of 5 starts at block 1, the third proposal would exceed it
+				execute(vec![(2, 2_000_000), (3, 2_000_000), (4, 2_000_000)]);
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 4);
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::InboundWindowExhausted {
+						resource_id,
+						window_started_at: 1,
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InboundWindowExhausted".to_vec(),
+						reason: ExecutionFailureReason::InboundLimitExceeded,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 4,
+					}),
+				]);
+				assert_eq!(
+					InboundWindows::<Runtime>::get(resource_id),
+					Some(InboundWindow { started_at: 1, amount: 4_000_000, exhausted: true })
+				);
+
+				// exhausted window pauses the resource even for an amount that would fit, until
+				// the last block of the window
+				execute(vec![(5, 1_000_000)]);
+				System::set_block_number(10);
+				execute(vec![(5, 1_000_000)]);
+				assert!(!SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 4);
+
+				// window rolls at block 11
+				System::set_block_number(11);
+				execute(vec![(4, 2_000_000), (5, 3_000_000)]);
+				assert!(SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 9);
+				assert_eq!(
+					InboundWindows::<Runtime>::get(resource_id),
+					Some(InboundWindow { started_at: 11, amount: 5_000_000, exhausted: false })
+				);
+
+				// governance clears an exhausted window
+				execute(vec![(6, 1_000_000)]);
+				assert!(!SygmaBridge::is_proposal_executed(6, DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::clear_inbound_window(Origin::signed(BOB), resource_id),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::clear_inbound_window(Origin::root(), resource_id));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::InboundWindowCleared { resource_id },
+				)]);
+				let interventions = SygmaBridge::manual_interventions();
+				assert_eq!(interventions.len(), 1);
+				assert_eq!(interventions[0].operation, InterventionOperation::ClearInboundWindow);
+				assert_eq!(interventions[0].subject, InterventionSubject::Resource { resource_id });
+				assert_eq!(interventions[0].amount, None);
+				execute(vec![(6, 1_000_000)]);
+				assert!(SygmaBridge::is_proposal_executed(6, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 10);
+
+				// resource without limit is not capped
+				assert_ok!(SygmaBridge::set_inbound_limit(Origin::root(), resource_id, None));
+				execute(vec![(7, 10_000_000)]);
+				assert!(SygmaBridge::is_proposal_executed(7, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 20);
+			})
+		}
+
+		#[test]
+		fn derived_accounts_should_be_pinned() {
+			new_test_ext().execute_with(|| {
+				// derivations for PalletId(*b"sygma/01"), funds are held by these accounts and they
+				// must never change
+				assert_eq!(
+					SygmaBridge::account_id(),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f30310000000000000000000000000000000000000000"
+					))
+				);
+				assert_eq!(SygmaBridge::account_id(), BridgeAccountNative::get());
+				assert_eq!(
+					BridgeAccountOtherTokens::get(),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f30310100000000000000000000000000000000000000"
+					))
+				);
+				assert_eq!(
+					SygmaBridge::escrow_account(&NativeResourceId::get()),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f3031f8107ad00a1dea5405f21b5ae63c1be80e22f2b1"
+					))
+				);
+				assert_eq!(
+					SygmaBridge::remote_sender_account(
+						DomainID::new(1),
+						&hex_literal::hex!("5C1F5961696BaD2e73f73417f07EF55C62a2dC5b")
+					),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f30313d5f9ec27f11c996b3da7035bb705081c749bfde"
+					))
+				);
+
+				assert_eq!(
+					SygmaBridge::fee_prefund_account(),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f30318bd475489b84377b259e2db8465465b7cf8ac463"
+					))
+				);
+				assert_eq!(
+					SygmaBridge::ack_fee_pot_account(),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f303177266d8c9494030371de30ec9493f5e510f2788c"
+					))
+				);
+				assert_eq!(
+					SygmaBridge::fee_vesting_account(&NativeResourceId::get()),
+					AccountId::new(hex_literal::hex!(
+						"6d6f646c7379676d612f3031bb42469682fd59bfb4ed960fd0ad834973eb74bb"
+					))
+				);
+
+				// resources and domains get distinct accounts although the sub account seed is
+				// truncated
+				assert_ne!(
+					SygmaBridge::escrow_account(&UsdtResourceId::get()),
+					SygmaBridge::escrow_account(&AstrResourceId::get())
+				);
+				assert_ne!(
+					SygmaBridge::remote_sender_account(DomainID::new(1), &[1u8; 20]),
+					SygmaBridge::remote_sender_account(DomainID::new(2), &[1u8; 20])
+				);
+			})
+		}
+
+		#[test]
+		fn transfer_id_should_match_golden_vectors() {
+			new_test_ext().execute_with(|| {
+				// keccak256(abi.encodePacked(uint8(3), uint8(1), uint64(nonce)))
+				assert_eq!(
+					SygmaBridge::transfer_id(DomainID::new(1), 0),
+					hex_literal::hex!(
+						"f2c2cd8858c0c2720e80de10d04ad3ba6a72f01777877df6430134c46b2457dc"
+					)
+				);
+				assert_eq!(
+					SygmaBridge::transfer_id(DomainID::new(1), 1),
+					hex_literal::hex!(
+						"8893f52ad052708b7b246081786b5b2a02ef8de02bb8f20391e98f23b3469442"
+					)
+				);
+				assert_eq!(
+					SygmaBridge::transfer_id(DomainID::new(1), 1234),
+					hex_literal::hex!(
+						"7c44dc474b3c7ce7c4e3222ede70cc8fb122fd9bedce38b85a3422833d34fd9c"
+					)
+				);
+				assert_ne!(
+					SygmaBridge::transfer_id(DomainID::new(1), 0),
+					SygmaBridge::transfer_id(DomainID::new(2), 0)
+				);
+			})
+		}
+
+		#[test]
+		fn bridge_status_should_reflect_bridge_state() {
+			new_test_ext().execute_with(|| {
+				let status =
+					|| SygmaBridge::bridge_status(DEST_DOMAIN_ID, NativeLocation::get(), Some(100));
+				let native_asset: XcmAssetId = NativeLocation::get().into();
+
+				// nothing set up yet
+				assert_eq!(
+					status(),
+					BridgeStatus {
+						domain_registered: false,
+						domain_paused: false,
+						pause_reason: None,
+						pause_note: None,
+						domain_healthy: true,
+						mpc_address_set: false,
+						resource_id: Some(NativeResourceId::get()),
+						fee: None,
+						reserve_sufficient: Some(false),
+						min_transfer: None,
+						max_transfer: None,
+					}
+				);
+				assert!(!status().is_usable());
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset),
+					10
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					100
+				));
+				assert_eq!(
+					status(),
+					BridgeStatus {
+						domain_registered: true,
+						domain_paused: false,
+						pause_reason: None,
+						pause_note: None,
+						domain_healthy: true,
+						mpc_address_set: true,
+						resource_id: Some(NativeResourceId::get()),
+						fee: Some(10),
+						reserve_sufficient: Some(true),
+						min_transfer: Some(11),
+						max_transfer: None,
+					}
+				);
+				assert!(status().is_usable());
+				// reserve can not release more than it holds
+				assert_eq!(
+					SygmaBridge::bridge_status(DEST_DOMAIN_ID, NativeLocation::get(), Some(101))
+						.reserve_sufficient,
+					Some(false)
+				);
+				assert_eq!(
+					SygmaBridge::bridge_status(DEST_DOMAIN_ID, NativeLocation::get(), None)
+						.reserve_sufficient,
+					None
+				);
+				// the probe is not withdrawn
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), 100);
+
+				// flips with the pause flag
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(status().domain_paused);
+				assert_eq!(status().pause_reason, Some(PauseReason::Governance));
+				assert!(!status().is_usable());
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(status().is_usable());
+				assert_eq!(status().pause_reason, None);
+
+				// flips when the fee entry is removed
+				sygma_basic_feehandler::AssetFees::<Runtime>::remove((
+					DEST_DOMAIN_ID,
+					&native_asset,
+				));
+				assert_eq!(status().fee, None);
+				assert_eq!(status().min_transfer, None);
+				assert!(!status().is_usable());
+
+				// asset not bound to a resource
+				assert_eq!(
+					SygmaBridge::bridge_status(
+						DEST_DOMAIN_ID,
+						MultiLocation::new(1, X1(Parachain(9999))),
+						Some(100)
+					)
+					.resource_id,
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn retry_should_reject_deposits_older_than_max_age() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// MaxRetryAge is 50 blocks
+				System::set_block_number(100);
+				assert_ok!(SygmaBridge::retry(Origin::root(), 50, DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::retry(Origin::root(), 100, DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::retry(Origin::root(), 49, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DepositTooOld
+				);
+				assert_noop!(
+					SygmaBridge::retry(Origin::root(), 0, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DepositTooOld
+				);
+				// no deposit is made above the current block
+				assert_noop!(
+					SygmaBridge::retry(Origin::root(), 101, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::InvalidRetryHeight
+				);
+				assert_noop!(
+					SygmaBridge::retry(Origin::root(), u128::MAX, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::InvalidRetryHeight
+				);
+
+				// the boundary moves with the current block
+				System::set_block_number(101);
+				assert_noop!(
+					SygmaBridge::retry(Origin::root(), 50, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DepositTooOld
+				);
+				assert_ok!(SygmaBridge::retry(Origin::root(), 51, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
+					deposit_on_block_height: 51,
+					dest_domain_id: DEST_DOMAIN_ID,
+					sender: [0u8; 32].into(),
+				})]);
+			})
+		}
+
+		#[test]
+		fn manual_interventions_should_evict_oldest_entry() {
+			new_test_ext().execute_with(|| {
+				let resource_id = NativeResourceId::get();
+
+				// MaxManualInterventions is 3
+				for block in 1..=5 {
+					System::set_block_number(block);
+					assert_ok!(SygmaBridge::clear_inbound_window(Origin::root(), resource_id));
+					assert_eq!(SygmaBridge::manual_interventions().len(), (block as usize).min(3));
+				}
+
+				let interventions = SygmaBridge::manual_interventions();
+				assert_eq!(
+					interventions.iter().map(|i| i.block).collect::<Vec<_>>(),
+					vec![3, 4, 5]
+				);
+				assert!(interventions.iter().all(|i| i.operation
+					== InterventionOperation::ClearInboundWindow
+					&& i.subject == InterventionSubject::Resource { resource_id }
+					&& i.amount.is_none()
+					&& i.operator_origin_hash == blake2_256(&Origin::root().caller().encode())));
+
+				// failed calls are not recorded
+				assert_noop!(
+					SygmaBridge::clear_inbound_window(Origin::signed(ALICE), resource_id),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// a different operator is told apart
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"clear_inbound_window".to_vec(),
+					ALICE
+				));
+				assert_ok!(SygmaBridge::clear_inbound_window(Origin::root(), resource_id));
+				assert_ok!(SygmaBridge::clear_inbound_window(Origin::signed(ALICE), resource_id));
+				let interventions = SygmaBridge::manual_interventions();
+				assert_eq!(interventions[0].block, 5);
+				assert_ne!(
+					interventions[1].operator_origin_hash,
+					interventions[2].operator_origin_hash
+				);
+			})
+		}
+
+		#[test]
+		fn under_delivered_proposal_should_be_reverted_or_recorded() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				// amounts are in 18 decimals, 100_000_000 credits 100 to BOB
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							100_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let bob_balance = Balances::free_balance(BOB);
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+
+				// transactor credits 10 less, the release is reverted
+				DepositShortfall::set(&10);
+				execute(1);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"PartialTransferNotAllowed".to_vec(),
+						reason: ExecutionFailureReason::Other(
+							bridge::Error::<Runtime>::PartialTransferNotAllowed.encode()[0],
+						),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), reserve_balance);
+
+				// partial transfers allowed, the shortfall is recorded
+				AllowPartialTransfer::set(&true);
+				execute(1);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 90);
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					reserve_balance - 100
+				);
+				assert_eq!(SygmaBridge::transfer_shortfalls(DEST_DOMAIN_ID, 1), Some(10));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::PartialTransferCredited {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						requested: 100,
+						credited: 90,
+					})));
+
+				// full delivery records nothing
+				DepositShortfall::set(&0);
+				execute(2);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 190);
+				assert_eq!(SygmaBridge::transfer_shortfalls(DEST_DOMAIN_ID, 2), None);
+			})
+		}
+
+		#[test]
+		fn mutated_proposal_call_should_be_rejected() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						100_000_000,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				}];
+				let data_len = proposals[0].data.len() as u32;
+				let signature =
+					pair.sign_prehashed(&SygmaBridge::proposal_digest(&proposals)).encode();
+				let call = bridge::Call::<Runtime>::execute_proposal { proposals, signature };
+				let encoded = call.encode();
+
+				let submit = |bytes: &[u8]| -> Result<DispatchResult, codec::Error> {
+					match bridge::Call::<Runtime>::decode_all(&mut &bytes[..])? {
+						bridge::Call::execute_proposal { proposals, signature } => {
+							Ok(SygmaBridge::execute_proposal(
+								Origin::signed(ALICE),
+								proposals,
+								signature,
+							))
+						},
+						_ => unreachable!(),
+					}
+				};
+
+				// the encoding of the decoded call is the submitted one
+				assert_eq!(bridge::Call::<Runtime>::decode(&mut &encoded[..]).unwrap(), call);
+
+				// appended bytes are rejected
+				assert!(submit(&[encoded.clone(), vec![0u8]].concat()).is_err());
+
+				// a non canonical compact length of `data` is rejected, it follows the call index,
+				// the proposals length and the fixed size fields of the proposal
+				let data_len_at = 1 + 1 + 1 + 8 + 32;
+				assert_eq!(
+					encoded[data_len_at..data_len_at + 2],
+					codec::Compact(data_len).encode()[..]
+				);
+				let non_canonical = [
+					&encoded[..data_len_at],
+					&((data_len << 2) | 0b10).to_le_bytes()[..],
+					&encoded[data_len_at + 2..],
+				]
+				.concat();
+				assert!(submit(&non_canonical).is_err());
+
+				// any flipped byte of the proposal or the signature fails verification
+				for at in [2, 3, 11, 12, 43, data_len_at + 2, encoded.len() - 10] {
+					let mut mutated = encoded.clone();
+					mutated[at] ^= 0x01;
+					if let Ok(result) = submit(&mutated) {
+						assert_eq!(result, Err(bridge::Error::<Runtime>::BadMpcSignature.into()));
+					}
+				}
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// the untouched call executes
+				assert_ok!(submit(&encoded).unwrap());
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn effective_fee_should_match_fee_charged_by_deposit() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::effective_fee(&ALICE, &asset, &dest), None);
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+
+				// BOB gets a quarter of the fee waived, ASSET_OWNER is exempt
+				assert_noop!(
+					SygmaBridge::set_fee_discount(
+						Origin::signed(BOB),
+						BOB,
+						Some(Permill::from_percent(25))
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::set_fee_discount(
+					Origin::root(),
+					BOB,
+					Some(Permill::from_percent(25))
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeDiscountSet {
+					account: BOB,
+					discount: Some(Permill::from_percent(25)),
+				})]);
+				assert_ok!(SygmaBridge::set_fee_discount(
+					Origin::root(),
+					ASSET_OWNER,
+					Some(Permill::one())
+				));
+
+				for (sender, expected_fee) in [(ALICE, fee), (BOB, fee * 3 / 4), (ASSET_OWNER, 0)] {
+					let preview = SygmaBridge::effective_fee(&sender, &asset, &dest);
+					assert_eq!(preview, Some(expected_fee));
+
+					let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+					let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(sender.clone()),
+						Box::new(asset.clone()),
+						Box::new(dest),
+					));
+					assert_eq!(
+						Balances::free_balance(TreasuryAccount::get()),
+						treasury_balance + expected_fee
+					);
+					assert_eq!(
+						Balances::free_balance(BridgeAccountNative::get()),
+						reserve_balance + amount - expected_fee
+					);
+					assert!(System::events().iter().any(|r| r.event
+						== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+							fee_payer: sender.clone(),
+							dest_domain_id: DEST_DOMAIN_ID,
+							resource_id: NativeResourceId::get(),
+							fee_amount: expected_fee,
+							fee_asset_id: NativeLocation::get().into(),
+						})));
+				}
+
+				// removing the discount restores the standard fee
+				assert_ok!(SygmaBridge::set_fee_discount(Origin::root(), BOB, None));
+				assert!(FeeDiscounts::<Runtime>::get(BOB).is_none());
+				assert_eq!(SygmaBridge::effective_fee(&BOB, &asset, &dest), Some(fee));
+
+				// deposits that would be rejected have no fee
+				let unbound: MultiAsset =
+					(Concrete(MultiLocation::new(0, X1(GeneralIndex(9)))), Fungible(amount)).into();
+				assert_eq!(SygmaBridge::effective_fee(&ALICE, &unbound, &dest), None);
+				assert_eq!(
+					SygmaBridge::effective_fee(
+						&ALICE,
+						&asset,
+						&MultiLocation { parents: 1, interior: dest.interior }
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn pause_reason_should_gate_who_can_unpause() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// ALICE only holds the pauser role
+				for name in [&b"pause_bridge"[..], b"unpause_bridge", b"unpause_all_bridges"] {
+					assert_ok!(AccessSegregator::grant_access(
+						Origin::root(),
+						BridgePalletIndex::get(),
+						name.to_vec(),
+						ALICE
+					));
+				}
+				let note: PauseNote = b"relayer upgrade".to_vec().try_into().unwrap();
+				let status =
+					|| SygmaBridge::bridge_status(DEST_DOMAIN_ID, NativeLocation::get(), None);
+
+				// pauser can set and clear the pauses other than the circuit breaker
+				for reason in
+					[PauseReason::Governance, PauseReason::MpcSignal, PauseReason::Scheduled]
+				{
+					assert_ok!(SygmaBridge::pause_bridge_with_reason(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						reason,
+						Some(note.clone())
+					));
+					assert_events(vec![RuntimeEvent::SygmaBridge(
+						SygmaBridgeEvent::BridgePaused {
+							dest_domain_id: DEST_DOMAIN_ID,
+							reason,
+							note: Some(note.clone()),
+						},
+					)]);
+					assert_eq!(
+						PauseReasons::<Runtime>::get(DEST_DOMAIN_ID),
+						Some(PauseInfo { reason, note: Some(note.clone()) })
+					);
+					assert_eq!(status().pause_reason, Some(reason));
+					assert_eq!(status().pause_note, Some(b"relayer upgrade".to_vec()));
+
+					assert_ok!(SygmaBridge::unpause_bridge(Origin::signed(ALICE), DEST_DOMAIN_ID));
+					assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+					assert_eq!(PauseReasons::<Runtime>::get(DEST_DOMAIN_ID), None);
+					assert_eq!(status().pause_reason, None);
+				}
+
+				// pauser can trip the circuit breaker but not clear it
+				let circuit_breaker =
+					PauseReason::CircuitBreaker { resource: Some(NativeResourceId::get()) };
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
+					Origin::signed(ALICE),
+					DEST_DOMAIN_ID,
+					circuit_breaker,
+					None
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+					reason: circuit_breaker,
+					note: None,
+				})]);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Origin::signed(ALICE), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::CommitteeOriginRequired
+				);
+				assert_noop!(
+					SygmaBridge::unpause_all_bridges(Origin::signed(ALICE)),
+					bridge::Error::<Runtime>::CommitteeOriginRequired
+				);
+				// a later pause for another reason does not downgrade the circuit breaker
+				assert_ok!(SygmaBridge::pause_bridge(Origin::signed(ALICE), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+					reason: circuit_breaker,
+					note: None,
+				})]);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Origin::signed(ALICE), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::CommitteeOriginRequired
+				);
+				assert_eq!(status().pause_reason, Some(circuit_breaker));
+
+				// committee origin clears it
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_eq!(PauseReasons::<Runtime>::get(DEST_DOMAIN_ID), None);
+
+				// and can clear it along with all bridges
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
+					Origin::signed(ALICE),
+					DEST_DOMAIN_ID,
+					circuit_breaker,
+					None
+				));
+				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::root()));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_eq!(PauseReasons::<Runtime>::get(DEST_DOMAIN_ID), None);
+			})
+		}
+
+		#[test]
+		fn genesis_used_nonce_ranges_should_reject_proposals_as_complete() {
+			new_test_ext().execute_with(|| {
+				bridge::GenesisConfig::<Runtime> {
+					used_nonce_ranges: vec![
+						(DEST_DOMAIN_ID, 200, 260),
+						(DEST_DOMAIN_ID, 50, 130),
+						(DEST_DOMAIN_ID, 0, 100),
+						(DomainID::new(2), 10, 20),
+					],
+					phantom: Default::default(),
+				}
+				.build();
+
+				// ranges from nonce 0 move the high-water mark, the others are marked in
+				// `UsedNonces`
+				assert_eq!(MinUnprunedNonce::<Runtime>::get(DEST_DOMAIN_ID), 130);
+				assert_eq!(UsedNoncesCount::<Runtime>::get(DEST_DOMAIN_ID), 2);
+				assert_eq!(UsedNonces::<Runtime>::get(DEST_DOMAIN_ID, 3), u64::MAX << 8);
+				assert_eq!(UsedNonces::<Runtime>::get(DEST_DOMAIN_ID, 4), 0b1111);
+				assert_eq!(MinUnprunedNonce::<Runtime>::get(DomainID::new(2)), 0);
+				assert_eq!(UsedNonces::<Runtime>::get(DomainID::new(2), 0), 0b11_1111_1111 << 10);
+				for nonce in [0, 99, 129, 200, 259] {
+					assert!(SygmaBridge::is_proposal_executed(nonce, DEST_DOMAIN_ID));
+				}
+				for nonce in [130, 199, 260] {
+					assert!(!SygmaBridge::is_proposal_executed(nonce, DEST_DOMAIN_ID));
+				}
+				assert!(!SygmaBridge::is_proposal_executed(9, DomainID::new(2)));
+				assert!(SygmaBridge::is_proposal_executed(10, DomainID::new(2)));
+
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				let execute = |nonces: Vec<DepositNonce>| {
+					let proposals: Vec<Proposal> = nonces
+						.into_iter()
+						.map(|deposit_nonce| Proposal {
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+							resource_id: NativeResourceId::get(),
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								1_000_000,
+								MultiLocation::new(
+									0,
+									X1(AccountId32 { network: None, id: BOB.into() }),
+								)
+								.encode(),
+							),
+						})
+						.collect();
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+
+				// pre-marked nonces are rejected as complete
+				let bob_balance = Balances::free_balance(BOB);
+				execute(vec![0, 129, 200, 259]);
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				for nonce in [0, 129, 200, 259] {
+					assert!(System::events().iter().any(|r| r.event
+						== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+							error: b"ProposalAlreadyComplete".to_vec(),
+							reason: ExecutionFailureReason::Other(
+								bridge::Error::<Runtime>::ProposalAlreadyComplete.encode()[0]
+							),
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce: nonce,
+						})));
+					assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, nonce).is_none());
+				}
+
+				// nonces out of the ranges execute
+				execute(vec![130, 199, 260]);
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 3);
+				for nonce in [130, 199, 260] {
+					assert!(SygmaBridge::is_proposal_executed(nonce, DEST_DOMAIN_ID));
+				}
+			})
+		}
+
+		#[test]
+		fn prefunded_fees_should_be_consumed_before_the_deposited_amount() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let native_asset_id: XcmAssetId = NativeLocation::get().into();
+				let asset: MultiAsset = (native_asset_id, Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				let prefund_account = SygmaBridge::fee_prefund_account();
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+
+				// only bound assets can be prefunded
+				assert_noop!(
+					SygmaBridge::prefund_fees(
+						Origin::signed(ALICE),
+						Box::new(Concrete(MultiLocation::new(2, Here))),
+						fee
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+
+				// prefund two and a half fees
+				let alice_balance = Balances::free_balance(ALICE);
+				assert_ok!(SygmaBridge::prefund_fees(
+					Origin::signed(ALICE),
+					Box::new(native_asset_id),
+					fee * 5 / 2
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeesPrefunded {
+					account: ALICE,
+					asset_id: native_asset_id,
+					amount: fee * 5 / 2,
+				})]);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance - fee * 5 / 2);
+				assert_eq!(Balances::free_balance(&prefund_account), fee * 5 / 2);
+				assert_eq!(FeePrefunds::<Runtime>::get(ALICE, native_asset_id), fee * 5 / 2);
+
+				// the first two deposits are bridged in full, the third takes the half fee left
+				// in the prefund and the rest from the deposited amount
+				for (prefunded, remaining) in [(fee, fee * 3 / 2), (fee, fee / 2), (fee / 2, 0)] {
+					let alice_balance = Balances::free_balance(ALICE);
+					let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+					let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest),
+					));
+					assert!(System::events().iter().any(|r| r.event
+						== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::PrefundConsumed {
+							account: ALICE,
+							asset_id: native_asset_id,
+							amount: prefunded,
+						})));
+					assert_eq!(Balances::free_balance(ALICE), alice_balance - amount);
+					assert_eq!(
+						Balances::free_balance(TreasuryAccount::get()),
+						treasury_balance + fee
+					);
+					assert_eq!(
+						Balances::free_balance(BridgeAccountNative::get()),
+						reserve_balance + amount - (fee - prefunded)
+					);
+					assert_eq!(FeePrefunds::<Runtime>::get(ALICE, native_asset_id), remaining);
+				}
+				assert!(!FeePrefunds::<Runtime>::contains_key(ALICE, native_asset_id));
+
+				// without prefund the fee is taken from the deposited amount again
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest),
+				));
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					reserve_balance + amount - fee
+				);
+
+				// reclaim what is left after a deposit
+				assert_ok!(SygmaBridge::prefund_fees(
+					Origin::signed(ALICE),
+					Box::new(native_asset_id),
+					fee * 3
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset),
+					Box::new(dest),
+				));
+				assert_eq!(FeePrefunds::<Runtime>::get(ALICE, native_asset_id), fee * 2);
+				assert_noop!(
+					SygmaBridge::withdraw_prefund(
+						Origin::signed(BOB),
+						Box::new(native_asset_id),
+						fee
+					),
+					bridge::Error::<Runtime>::InsufficientPrefund
+				);
+				assert_noop!(
+					SygmaBridge::withdraw_prefund(
+						Origin::signed(ALICE),
+						Box::new(native_asset_id),
+						fee * 2 + 1
+					),
+					bridge::Error::<Runtime>::InsufficientPrefund
+				);
+				let alice_balance = Balances::free_balance(ALICE);
+				assert_ok!(SygmaBridge::withdraw_prefund(
+					Origin::signed(ALICE),
+					Box::new(native_asset_id),
+					fee * 2
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::PrefundWithdrawn {
+						account: ALICE,
+						asset_id: native_asset_id,
+						amount: fee * 2,
+					},
+				)]);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance + fee * 2);
+				assert_eq!(FeePrefunds::<Runtime>::get(ALICE, native_asset_id), 0);
+				assert_eq!(Balances::free_balance(&prefund_account), 0);
+			})
+		}
+
+		#[test]
+		fn oversized_deposit_data_should_be_rejected() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = |domain_id: DomainID| MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[domain_id.into_inner()]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// 32 bytes amount, 32 bytes recipient length and 18 bytes recipient fit, the 2
+				// bytes route extension pushes the payload over the limit
+				MaxDepositDataSize::set(&83);
+				let alice_balance = Balances::free_balance(ALICE);
+				assert_noop!(
+					SygmaBridge::deposit_with_route(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest(DomainID::new(2))),
+						DEST_DOMAIN_ID,
+					),
+					bridge::Error::<Runtime>::DepositDataTooLarge
+				);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest(DEST_DOMAIN_ID)),
+				));
+				assert_eq!(Balances::free_balance(ALICE), alice_balance - amount);
+
+				// the limit is inclusive
+				MaxDepositDataSize::set(&84);
+				assert_ok!(SygmaBridge::deposit_with_route(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest(DomainID::new(2))),
+					DEST_DOMAIN_ID,
+				));
+
+				// nothing is withdrawn from a rejected deposit
+				MaxDepositDataSize::set(&81);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset),
+						Box::new(dest(DEST_DOMAIN_ID)),
+					),
+					bridge::Error::<Runtime>::DepositDataTooLarge
+				);
+			})
+		}
+
+		#[test]
+		fn deposits_of_should_page_newest_first_and_evict_oldest() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+					),
+				};
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// MaxHistoryPerAccount is 3, the first deposit is evicted by the fourth
+				for block in 1..=4 {
+					System::set_block_number(block);
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest),
+					));
+				}
+				let summary = |deposit_nonce: DepositNonce| TransferSummary {
+					domain_id: DEST_DOMAIN_ID,
+					deposit_nonce,
+					resource_id: NativeResourceId::get(),
+					amount: amount - fee,
+					block: deposit_nonce + 1,
+				};
+				assert_eq!(DepositHistory::<Runtime>::get(ALICE).len(), 3);
+				assert_eq!(
+					SygmaBridge::deposits_of(&ALICE, 0, 10),
+					vec![summary(3), summary(2), summary(1)]
+				);
+
+				// pages are counted from the newest deposit, the last page may be partial
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 0, 2), vec![summary(3), summary(2)]);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 1, 2), vec![summary(1)]);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 2, 2), vec![]);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 2, 1), vec![summary(1)]);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 3, 1), vec![]);
+				assert_eq!(
+					SygmaBridge::deposits_of(&ALICE, 0, 3),
+					vec![summary(3), summary(2), summary(1)]
+				);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 0, 0), vec![]);
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, u32::MAX, u32::MAX), vec![]);
+				assert_eq!(SygmaBridge::deposits_of(&BOB, 0, 10), vec![]);
+
+				// nothing is indexed with the history disabled
+				EnableAccountHistory::set(&false);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(BOB),
+					Box::new(asset),
+					Box::new(dest),
+				));
+				assert!(!DepositHistory::<Runtime>::contains_key(BOB));
+				assert_eq!(SygmaBridge::deposits_of(&ALICE, 0, 1), vec![summary(3)]);
+			})
+		}
+
+		#[test]
+		fn executions_to_should_page_newest_first_and_evict_oldest() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				let execute = |nonces: Vec<DepositNonce>, beneficiary: AccountId| {
+					let proposals: Vec<Proposal> = nonces
+						.into_iter()
+						.map(|deposit_nonce| Proposal {
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+							resource_id: NativeResourceId::get(),
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								1_000_000 * (deposit_nonce as u128 + 1),
+								MultiLocation::new(
+									0,
+									X1(AccountId32 {
+										network: None,
+										id: beneficiary.clone().into(),
+									}),
+								)
+								.encode(),
+							),
+						})
+						.collect();
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				// 18 decimals to the 12 decimals of the native asset
+				let summary = |deposit_nonce: DepositNonce| TransferSummary {
+					domain_id: DEST_DOMAIN_ID,
+					deposit_nonce,
+					resource_id: NativeResourceId::get(),
+					amount: deposit_nonce as u128 + 1,
+					block: 1,
+				};
+
+				// MaxHistoryPerAccount is 3, the first two executions are evicted
+				execute(vec![0, 1, 2, 3, 4], BOB);
+				assert_eq!(ExecutionHistory::<Runtime>::get(&BOB).len(), 3);
+				assert_eq!(SygmaBridge::executions_to(&BOB, 0, 2), vec![summary(4), summary(3)]);
+				assert_eq!(SygmaBridge::executions_to(&BOB, 1, 2), vec![summary(2)]);
+				assert_eq!(SygmaBridge::executions_to(&BOB, 2, 2), vec![]);
+				assert_eq!(SygmaBridge::executions_to(&BOB, 0, 0), vec![]);
+				assert_eq!(SygmaBridge::executions_to(&ASSET_OWNER, 0, 10), vec![]);
+				// deposits are indexed apart from executions
+				assert_eq!(SygmaBridge::deposits_of(&BOB, 0, 10), vec![]);
+
+				// failed proposals are not indexed
+				execute(vec![4], BOB);
+				assert_eq!(SygmaBridge::executions_to(&BOB, 0, 1), vec![summary(4)]);
+				assert_eq!(ExecutionHistory::<Runtime>::get(&BOB).len(), 3);
+
+				// nothing is indexed with the history disabled
+				EnableAccountHistory::set(&false);
+				execute(vec![5], ASSET_OWNER);
+				assert!(SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+				assert!(!ExecutionHistory::<Runtime>::contains_key(&ASSET_OWNER));
+			})
+		}
+
+		#[test]
+		fn reserve_shortfall_should_be_reported_before_proposal_fails() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					25
+				));
+				// 10 of the native asset, in 18 decimals
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							10_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+
+				// drain the reserve
+				let bob_balance = Balances::free_balance(BOB);
+				execute(0);
+				execute(1);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), 5);
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 20);
+				assert!(!System::events().iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ReserveShortfall { .. })
+				)));
+
+				// the shortfall is reported ahead of the failure
+				execute(2);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ReserveShortfall {
+						resource_id: NativeResourceId::get(),
+						required: 10,
+						available: 5,
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InsufficientReserve".to_vec(),
+						reason: ExecutionFailureReason::InsufficientReserve,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					}),
+				]);
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), 5);
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 20);
+
+				// the proposal goes through once the reserve is topped up
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					15
+				));
+				execute(2);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 30);
+			})
+		}
+
+		#[test]
+		fn rebound_resource_should_switch_asset_at_effective_block() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				for location in [UsdtLocation::get(), UsdcLocation::get()] {
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						1
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
+				for asset_id in [UsdtAssetId::get(), UsdcAssetId::get()] {
+					assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+						<Runtime as frame_system::Config>::AccountId,
+					>>::create(asset_id, ASSET_OWNER, true, 1,));
+					assert_ok!(Assets::mint(
+						Origin::signed(ASSET_OWNER),
+						codec::Compact(asset_id),
+						ALICE,
+						ENDOWED_BALANCE,
+					));
+				}
+				let reserve = BridgeAccountOtherTokens::get();
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: UsdtResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							200,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let deposit = |location: MultiLocation| {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(location), Fungible(200u128)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							),
+						}),
+					)
+				};
+				let usdc: XcmAssetId = UsdcLocation::get().into();
+
+				// the reserve is empty, the proposal fails and blocks the rebind
+				execute(0);
+				assert!(!SygmaBridge::is_proposal_executed(0, DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::rebind_resource(
+						Origin::root(),
+						UsdtResourceId::get(),
+						Box::new(usdc),
+						10
+					),
+					bridge::Error::<Runtime>::FailedProposalsPending
+				);
+				for asset_id in [UsdtAssetId::get(), UsdcAssetId::get()] {
+					assert_ok!(Assets::mint(
+						Origin::signed(ASSET_OWNER),
+						codec::Compact(asset_id),
+						reserve.clone(),
+						1_000,
+					));
+				}
+				execute(0);
+				assert!(SygmaBridge::is_proposal_executed(0, DEST_DOMAIN_ID));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 200);
+
+				System::set_block_number(5);
+				assert_noop!(
+					SygmaBridge::rebind_resource(
+						Origin::signed(ALICE),
+						UsdtResourceId::get(),
+						Box::new(usdc),
+						10
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::rebind_resource(
+						Origin::root(),
+						UsdtResourceId::get(),
+						Box::new(usdc),
+						4
+					),
+					bridge::Error::<Runtime>::RebindInThePast
+				);
+				assert_noop!(
+					SygmaBridge::rebind_resource(Origin::root(), [9u8; 32], Box::new(usdc), 10),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+				assert_noop!(
+					SygmaBridge::rebind_resource(
+						Origin::root(),
+						UsdtResourceId::get(),
+						Box::new(AstrLocation::get().into()),
+						10
+					),
+					bridge::Error::<Runtime>::AssetAlreadyBound
+				);
+				assert_ok!(SygmaBridge::rebind_resource(
+					Origin::root(),
+					UsdtResourceId::get(),
+					Box::new(usdc),
+					10
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ResourceRebound {
+					resource_id: UsdtResourceId::get(),
+					old_asset: UsdtLocation::get().into(),
+					new_asset: usdc,
+					effective_at: 10,
+				})]);
+				assert_noop!(
+					SygmaBridge::rebind_resource(
+						Origin::root(),
+						UsdtResourceId::get(),
+						Box::new(NativeLocation::get().into()),
+						20
+					),
+					bridge::Error::<Runtime>::RebindPending
+				);
+
+				// before the effective block the old binding is used
+				execute(1);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 400);
+				assert_eq!(Assets::balance(UsdcAssetId::get(), &BOB), 0);
+				assert_ok!(deposit(UsdtLocation::get()));
+				assert_noop!(deposit(UsdcLocation::get()), bridge::Error::<Runtime>::AssetNotBound);
+
+				// from the effective block on the new binding is used
+				System::set_block_number(10);
+				execute(2);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 400);
+				assert_eq!(Assets::balance(UsdcAssetId::get(), &BOB), 200);
+				assert_noop!(deposit(UsdtLocation::get()), bridge::Error::<Runtime>::AssetNotBound);
+				assert_ok!(deposit(UsdcLocation::get()));
+				assert_eq!(SygmaBridge::resolve_asset(&usdc), Some((usdc, UsdtResourceId::get())));
+				assert_eq!(SygmaBridge::resolve_asset(&UsdtLocation::get().into()), None);
+			})
+		}
+
+		#[test]
+		fn deposit_data_should_round_trip_in_every_format() {
+			new_test_ext().execute_with(|| {
+				let amount = 200_000_000_000_000_000u128;
+				let recipient =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+
+				for format in [DataFormat::EvmAbi, DataFormat::Scale] {
+					let data = SygmaBridge::create_deposit_data(format, amount, recipient.encode());
+					assert_eq!(
+						data.len(),
+						sygma_codec::deposit_data_len(format, recipient.encode().len())
+					);
+					assert_eq!(
+						SygmaBridge::extract_deposit_data(format, &data),
+						Ok((amount, recipient))
+					);
+					assert_eq!(SygmaBridge::extract_routed_deposit_data(format, &data), Ok(None));
+
+					// the route extension follows the recipient in both formats
+					let routed = [data.clone(), vec![EXTRA_TAG_ROUTE, 1, 2]].concat();
+					assert_eq!(
+						SygmaBridge::extract_routed_deposit_data(format, &routed),
+						Ok(Some((amount, recipient.encode(), DomainID::new(2))))
+					);
+					assert_eq!(
+						SygmaBridge::extract_deposit_data(format, &routed),
+						Err(bridge::Error::<Runtime>::InvalidDepositData.into())
+					);
+
+					// entries of unknown tags are skipped, around and between the known ones
+					let unknown = vec![0x90, 2, 0xab, 0xcd];
+					let interleaved = [
+						data.clone(),
+						unknown.clone(),
+						vec![EXTRA_TAG_ROUTE, 1, 2],
+						vec![7, 0],
+						vec![EXTRA_TAG_MEMO, 2, b'h', b'i'],
+					]
+					.concat();
+					assert_eq!(
+						SygmaBridge::extract_routed_deposit_data(format, &interleaved),
+						Ok(Some((amount, recipient.encode(), DomainID::new(2))))
+					);
+					let unrouted =
+						[data.clone(), unknown, vec![EXTRA_TAG_MEMO, 2, b'h', b'i']].concat();
+					assert_eq!(
+						SygmaBridge::extract_routed_deposit_data(format, &unrouted),
+						Ok(None)
+					);
+					assert_eq!(
+						SygmaBridge::extract_deposit_data(format, &unrouted),
+						Ok((amount, recipient))
+					);
+					// malformed route entry, repeated tag and truncated entry
+					for extra in [
+						vec![EXTRA_TAG_ROUTE, 2, 2, 3],
+						vec![EXTRA_TAG_ROUTE, 1, 2, EXTRA_TAG_ROUTE, 1, 3],
+						vec![EXTRA_TAG_MEMO, 4, b'h', b'i'],
+					] {
+						assert_eq!(
+							SygmaBridge::extract_routed_deposit_data(
+								format,
+								&[data.clone(), extra].concat()
+							),
+							Err(bridge::Error::<Runtime>::InvalidDepositData.into())
+						);
+					}
+					assert_eq!(
+						SygmaBridge::extract_deposit_data(format, &data[..data.len() - 1]),
+						Err(bridge::Error::<Runtime>::InvalidDepositData.into())
+					);
+				}
+
+				assert_eq!(
+					SygmaBridge::create_deposit_data(DataFormat::Scale, amount, recipient.encode()),
+					(amount, recipient.encode()).encode()
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_data_should_follow_domain_data_format() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+				let bob_location =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+				let execute = |deposit_nonce: DepositNonce, data: Vec<u8>| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data,
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+
+				// format of an unregistered domain can not be set
+				assert_noop!(
+					SygmaBridge::set_data_format(Origin::root(), DEST_DOMAIN_ID, DataFormat::Scale),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+
+				// registration defaults to the EVM-ABI format and keeps a format set before
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_eq!(DataFormats::<Runtime>::get(DEST_DOMAIN_ID), Some(DataFormat::EvmAbi));
+				assert_noop!(
+					SygmaBridge::set_data_format(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						DataFormat::Scale
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::set_data_format(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					DataFormat::Scale
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DataFormatSet {
+					domain_id: DEST_DOMAIN_ID,
+					format: DataFormat::Scale,
+				})]);
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_eq!(DataFormats::<Runtime>::get(DEST_DOMAIN_ID), Some(DataFormat::Scale));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// outbound deposit data is SCALE encoded
+				assert_ok!(deposit());
+				let deposit_data = System::events()
+					.into_iter()
+					.find_map(|r| match r.event {
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+							deposit_data,
+							..
+						}) => Some(deposit_data),
+						_ => None,
+					})
+					.unwrap();
+				assert_eq!(
+					<(u128, Vec<u8>)>::decode_all(&mut deposit_data.as_slice()),
+					Ok((199_000_000_000_000_000_000, b"ethereum recipient".to_vec()))
+				);
+
+				// inbound deposit data is decoded as SCALE, EVM-ABI data is rejected
+				let bob_balance = Balances::free_balance(BOB);
+				execute(
+					0,
+					SygmaBridge::create_deposit_data(
+						DataFormat::Scale,
+						10_000_000,
+						bob_location.encode(),
+					),
+				);
+				assert!(SygmaBridge::is_proposal_executed(0, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 10);
+				execute(
+					1,
+					SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						10_000_000,
+						bob_location.encode(),
+					),
+				);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InvalidDepositData".to_vec(),
+						reason: ExecutionFailureReason::DecodeError,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+
+				// a domain without format rejects transfers both ways
+				DataFormats::<Runtime>::remove(DEST_DOMAIN_ID);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::UnsupportedDataFormat);
+				execute(
+					2,
+					SygmaBridge::create_deposit_data(
+						DataFormat::Scale,
+						10_000_000,
+						bob_location.encode(),
+					),
+				);
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"UnsupportedDataFormat".to_vec(),
+						reason: ExecutionFailureReason::Filtered,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					},
+				)]);
+			})
+		}
+
+		#[test]
+		fn idle_blocks_should_drain_auto_retry_queue() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					5
+				));
+				// 10 of the native asset, in 18 decimals
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							10_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let queue_len =
+					|| AutoRetryQueue::<Runtime>::iter_prefix(NativeResourceId::get()).count();
+				let idle = || {
+					System::set_block_number(System::block_number() + 1);
+					SygmaBridge::on_idle(System::block_number(), Weight::MAX)
+				};
+
+				// the reserve can't cover the proposals, they are all queued
+				for deposit_nonce in 0..5 {
+					execute(deposit_nonce);
+				}
+				assert_eq!(queue_len(), 5);
+				assert_eq!(FailedProposals::<Runtime>::iter().count(), 5);
+
+				// nothing is retried until the committee enables the auto retry
+				assert_eq!(idle(), Weight::zero());
+				assert_eq!(queue_len(), 5);
+				assert_noop!(
+					SygmaBridge::set_auto_retry(
+						Origin::signed(ALICE),
+						NativeResourceId::get(),
+						true
+					),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(SygmaBridge::set_auto_retry(
+					Origin::root(),
+					NativeResourceId::get(),
+					true
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AutoRetrySet {
+					resource_id: NativeResourceId::get(),
+					enabled: true,
+				})]);
+
+				// once the reserve is topped up idle blocks retry up to 2 proposals each
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					55
+				));
+				let bob_balance = Balances::free_balance(BOB);
+				for expected_len in [3, 1, 0] {
+					assert!(idle().any_gt(Weight::zero()));
+					assert_eq!(queue_len(), expected_len);
+				}
+				for deposit_nonce in 0..5 {
+					assert!(SygmaBridge::is_proposal_executed(deposit_nonce, DEST_DOMAIN_ID));
+				}
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 50);
+				assert_eq!(FailedProposals::<Runtime>::iter().count(), 0);
+				assert_eq!(FailedProposalCounts::<Runtime>::get(NativeResourceId::get()), 0);
+
+				// the weight handed to `on_idle` bounds the retries
+				execute(5);
+				execute(6);
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					15
+				));
+				let per_item = SygmaBridge::proposal_weight()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1));
+				let limit = per_item
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+				System::set_block_number(System::block_number() + 1);
+				assert_eq!(
+					SygmaBridge::on_idle(
+						System::block_number(),
+						per_item.saturating_sub(Weight::from_parts(1, 0))
+					),
+					Weight::zero()
+				);
+				assert_eq!(queue_len(), 2);
+				assert!(limit.all_gte(SygmaBridge::on_idle(System::block_number(), limit)));
+				assert_eq!(queue_len(), 1);
+
+				// a proposal failing `MaxAutoRetryAttempts` times is dropped from the queue
+				let (_, deposit_nonce) =
+					AutoRetryQueue::<Runtime>::iter_key_prefix(NativeResourceId::get())
+						.next()
+						.unwrap();
+				assert!(!SygmaBridge::is_proposal_executed(deposit_nonce, DEST_DOMAIN_ID));
+				for attempts in 1..3 {
+					idle();
+					assert_eq!(
+						AutoRetryQueue::<Runtime>::get(
+							NativeResourceId::get(),
+							(DEST_DOMAIN_ID, deposit_nonce)
+						)
+						.map(|queued| queued.attempts),
+						Some(attempts)
+					);
+				}
+				idle();
+				assert_eq!(queue_len(), 0);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::AutoRetryAbandoned {
+						resource_id: NativeResourceId::get(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+					},
+				)]);
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, deposit_nonce).is_some());
+			})
+		}
+
+		#[test]
+		fn fee_distributor_should_receive_collected_fees() {
+			new_test_ext().execute_with(|| {
+				let native_fee = 2_000_000_000_000u128;
+				let usdt_fee = 101u128;
+				let amount = 200_000_000_000_000u128;
+				for (location, fee) in
+					[(NativeLocation::get(), native_fee), (UsdtLocation::get(), usdt_fee)]
+				{
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+				let deposit = |location: MultiLocation| {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(location), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+
+				// the whole fee goes to the fee reserve account by default
+				let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+				assert_ok!(deposit(NativeLocation::get()));
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					treasury_balance + native_fee
+				);
+
+				// a distributor splitting the fee 50/50, the odd unit goes to the stakers
+				SplitFees::set(&true);
+				assert_ok!(deposit(NativeLocation::get()));
+				assert_ok!(deposit(UsdtLocation::get()));
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					treasury_balance + native_fee
+				);
+				assert_eq!(Balances::free_balance(FeePartnerAccount::get()), native_fee / 2);
+				assert_eq!(Balances::free_balance(FeeStakersAccount::get()), native_fee / 2);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), 0);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), FeePartnerAccount::get()), 50);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), FeeStakersAccount::get()), 51);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
+			})
+		}
+
+		#[test]
+		fn global_tvl_cap_should_reject_deposits_over_the_cap() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				// 199 of the native asset bridged by each deposit, in 18 decimals
+				let locked = 199_000_000_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+
+				// deposits are not capped by default
+				assert_eq!(SygmaBridge::global_tvl_cap(), None);
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::global_tvl(), locked);
+
+				// only the committee sets the cap
+				assert_noop!(
+					SygmaBridge::set_global_tvl_cap(Origin::signed(ALICE), Some(locked * 2)),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(SygmaBridge::set_global_tvl_cap(Origin::root(), Some(locked * 2)));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::GlobalTvlCapSet {
+					cap: Some(locked * 2),
+				})]);
+
+				// TVL reaches the cap, the next deposit is rejected
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::global_tvl(), locked * 2);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::TvlCapExceeded);
+
+				// executed proposal releases value
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 0,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						locked,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
+				));
+				assert!(SygmaBridge::is_proposal_executed(0, DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::global_tvl(), locked);
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::global_tvl(), locked * 2);
+
+				// removing the cap lets deposits through again
+				assert_ok!(SygmaBridge::set_global_tvl_cap(Origin::root(), None));
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::global_tvl(), locked * 3);
+			})
+		}
+
+		#[test]
+		fn execute_proposal_weight_should_charge_signature_once_per_batch() {
+			new_test_ext().execute_with(|| {
+				let proposal = |data: Vec<u8>| Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data,
+				};
+				let single = SygmaBridge::execute_proposal_weight(&[proposal(vec![1u8; 100])]);
+				let batch =
+					SygmaBridge::execute_proposal_weight(&vec![proposal(vec![1u8; 100]); 10]);
+
+				// the signature is verified once, each proposal adds its execution
+				assert!(batch.ref_time() < single.ref_time() * 10);
+				assert!(
+					batch.ref_time()
+						>= single.ref_time() + SygmaBridge::proposal_weight().ref_time() * 9
+				);
+				// hashing the digest scales with the size of the batch
+				let large = SygmaBridge::execute_proposal_weight(&[proposal(vec![1u8; 10_000])]);
+				assert!(large.ref_time() > single.ref_time());
+			})
+		}
+
+		#[test]
+		fn deposit_should_fail_on_domain_rejected_by_registry() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+
+				// registry falls back to the domains registered in the bridge
+				assert_ok!(deposit());
+
+				// registry rejects the domain though it's still registered locally
+				RejectedDomain::set(&Some(DEST_DOMAIN_ID));
+				assert!(DestDomainIds::<Runtime>::get(DEST_DOMAIN_ID));
+				assert_noop!(deposit(), bridge::Error::<Runtime>::DestDomainNotSupported);
+
+				RejectedDomain::set(&None);
+				assert_ok!(deposit());
+			})
+		}
+
+		#[test]
+		fn deposit_data_size_should_bound_inbound_and_forwarded_data() {
+			new_test_ext().execute_with(|| {
+				let final_domain_id: DomainID = DomainID::new(2);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				for domain_id in [DEST_DOMAIN_ID, final_domain_id] {
+					assert_ok!(SygmaBridge::register_domain(
+						Origin::root(),
+						domain_id,
+						U256::from(domain_id.into_inner())
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+					assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), domain_id));
+				}
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// fund the native reserve
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+						),
+					}),
+				));
+				let execute = |deposit_nonce, data: Vec<u8>| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data,
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let assert_too_large = |deposit_nonce| {
+					assert_events(vec![RuntimeEvent::SygmaBridge(
+						SygmaBridgeEvent::FailedHandlerExecution {
+							error: b"DepositDataTooLarge".to_vec(),
+							reason: ExecutionFailureReason::DecodeError,
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+						},
+					)]);
+					assert!(!SygmaBridge::is_proposal_executed(deposit_nonce, DEST_DOMAIN_ID));
+				};
+
+				// 32 bytes amount, 32 bytes recipient length and 36 bytes recipient
+				let data = SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					10_000_000_000_000_000_000,
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+						.encode(),
+				);
+				assert_eq!(data.len(), 100);
+				MaxDepositDataSize::set(&99);
+				execute(1, data.clone());
+				assert_too_large(1);
+				// the limit is inclusive
+				MaxDepositDataSize::set(&100);
+				execute(1, data);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// 37 bytes of routed SCALE data fit, but the EVM ABI data forwarded to the final
+				// domain takes 64 bytes of header and 18 bytes of recipient
+				assert_ok!(SygmaBridge::set_data_format(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					DataFormat::Scale
+				));
+				let routed = [
+					SygmaBridge::create_deposit_data(
+						DataFormat::Scale,
+						10_000_000_000_000_000_000,
+						b"ethereum recipient".to_vec(),
+					),
+					vec![EXTRA_TAG_ROUTE, 1, final_domain_id.into_inner()],
+				]
+				.concat();
+				assert_eq!(routed.len(), 37);
+				MaxDepositDataSize::set(&81);
+				execute(2, routed.clone());
+				assert_too_large(2);
+				MaxDepositDataSize::set(&82);
+				execute(2, routed);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::TransferForwarded {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						dest_domain_id: final_domain_id,
+						forwarded_deposit_nonce: 0,
+					})));
+			})
+		}
+
+		#[test]
+		fn failed_deposit_should_be_reported_by_extension() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let deposit = || {
+					RuntimeCall::SygmaBridge(bridge::Call::deposit {
+						asset: Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						dest: Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					})
+				};
+				// apply the call as the executive does, the dispatch is reverted on failure
+				let apply = |call: RuntimeCall| {
+					let info = call.get_dispatch_info();
+					let pre = ReportDepositFailure::<Runtime>::new()
+						.pre_dispatch(&ALICE, &call, &info, 0)
+						.unwrap();
+					let (post_info, result) = match call.dispatch(Origin::signed(ALICE)) {
+						Ok(post_info) => (post_info, Ok(())),
+						Err(e) => (e.post_info, Err(e.error)),
+					};
+					assert_ok!(ReportDepositFailure::<Runtime>::post_dispatch(
+						Some(pre),
+						&info,
+						&post_info,
+						0,
+						&result
+					));
+					result
+				};
+				let deposit_failed = |reason| {
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositFailed {
+						sender: ALICE,
+						reason,
+					})
+				};
+
+				// MPC address is not set
+				assert_eq!(
+					apply(deposit()),
+					Err(bridge::Error::<Runtime>::MissingMpcAddress.into())
+				);
+				assert_events(vec![deposit_failed(DepositFailureReason::DomainUnavailable)]);
+
+				// fee is not configured
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let balance = Balances::free_balance(ALICE);
+				assert_eq!(
+					apply(deposit()),
+					Err(bridge::Error::<Runtime>::MissingFeeConfig.into())
+				);
+				assert_events(vec![deposit_failed(DepositFailureReason::FeeNotPayable)]);
+				assert_eq!(Balances::free_balance(ALICE), balance);
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 0);
+
+				// successful deposits and other calls are not reported
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				System::reset_events();
+				assert_ok!(apply(deposit()));
+				assert!(apply(RuntimeCall::SygmaBridge(bridge::Call::pause_bridge {
+					dest_domain_id: DEST_DOMAIN_ID
+				}))
+				.is_err());
+				assert!(!System::events().iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositFailed { .. })
+				)));
+			})
+		}
+
+		#[test]
+		fn deposit_failure_reason_encoding_should_be_stable() {
+			assert_eq!(DepositFailureReason::InvalidDestination.encode(), vec![0]);
+			assert_eq!(DepositFailureReason::DomainUnavailable.encode(), vec![1]);
+			assert_eq!(DepositFailureReason::AssetNotSupported.encode(), vec![2]);
+			assert_eq!(DepositFailureReason::FeeNotPayable.encode(), vec![3]);
+			assert_eq!(DepositFailureReason::WithdrawFailed.encode(), vec![4]);
+			assert_eq!(DepositFailureReason::LimitExceeded.encode(), vec![5]);
+			assert_eq!(DepositFailureReason::Other(7).encode(), vec![6, 7]);
+		}
+
+		#[test]
+		fn onboard_resource_should_roll_back_on_failed_step() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let resource_id: ResourceId = [5u8; 32];
+				let asset: XcmAssetId = UsdcLocation::get().into();
+				let limit = InboundLimit { max_single: 1_000, max_per_window: 10_000 };
+				let spec = |fees: Vec<(DomainID, FeeConfig)>| {
+					Box::new(OnboardingSpec {
+						asset,
+						resource_id,
+						fees,
+						inbound_limit: Some(limit),
+						mintable: true,
+						decimals: 18,
+					})
+				};
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// only the committee onboards resources
+				assert_noop!(
+					SygmaBridge::onboard_resource(
+						Origin::signed(ALICE),
+						spec(vec![(DEST_DOMAIN_ID, FeeConfig::Basic(fee))])
+					),
+					DispatchError::BadOrigin
+				);
+
+				// the fee of the first domain is set before the second domain is rejected
+				assert_noop!(
+					SygmaBridge::onboard_resource(
+						Origin::root(),
+						spec(vec![
+							(DEST_DOMAIN_ID, FeeConfig::Basic(fee)),
+							(DomainID::new(9), FeeConfig::Basic(fee)),
+						])
+					),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+
+				// fees and inbound limit are set before the asset creation fails
+				FailAssetCreation::set(&true);
+				assert_noop!(
+					SygmaBridge::onboard_resource(
+						Origin::root(),
+						spec(vec![(DEST_DOMAIN_ID, FeeConfig::Basic(fee))])
+					),
+					DispatchError::Other("asset creation failed")
+				);
+				assert_eq!(SygmaBasicFeeHandler::asset_fees((DEST_DOMAIN_ID, asset)), None);
+				assert_eq!(InboundLimits::<Runtime>::get(resource_id), None);
+				assert_eq!(SygmaBridge::rid_to_assetid(&resource_id), None);
+
+				FailAssetCreation::set(&false);
+				assert_ok!(SygmaBridge::onboard_resource(
+					Origin::root(),
+					spec(vec![(DEST_DOMAIN_ID, FeeConfig::Basic(fee))])
+				));
+				assert_events(vec![
+					RuntimeEvent::SygmaBasicFeeHandler(sygma_basic_feehandler::Event::FeeSet {
+						domain: DEST_DOMAIN_ID,
+						asset,
+						amount: fee,
+					}),
+					RuntimeEvent::SygmaFeeHandlerRouter(
+						sygma_fee_handler_router::Event::FeeHandlerSet {
+							domain: DEST_DOMAIN_ID,
+							asset,
+							handler_type: FeeHandlerType::BasicFeeHandler,
+						},
+					),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::InboundLimitSet {
+						resource_id,
+						limit: Some(limit),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ResourceOnboarded {
+						resource_id,
+						asset,
+						decimals: 18,
+						mintable: true,
+					}),
+				]);
+				assert_eq!(SygmaBridge::rid_to_assetid(&resource_id), Some(asset));
+				assert_eq!(SygmaBasicFeeHandler::asset_fees((DEST_DOMAIN_ID, asset)), Some(fee));
+				assert_eq!(InboundLimits::<Runtime>::get(resource_id), Some(limit));
+
+				// neither the resource nor the asset can be onboarded twice
+				assert_noop!(
+					SygmaBridge::onboard_resource(Origin::root(), spec(vec![])),
+					bridge::Error::<Runtime>::ResourceAlreadyBound
+				);
+				let mut other = spec(vec![]);
+				other.resource_id = [6u8; 32];
+				assert_noop!(
+					SygmaBridge::onboard_resource(Origin::root(), other),
+					bridge::Error::<Runtime>::AssetAlreadyBound
+				);
+			})
+		}
+
+		#[test]
+		fn offboard_resource_should_check_failed_proposals_and_reserve() {
+			new_test_ext().execute_with(|| {
+				let resource_id: ResourceId = [5u8; 32];
+				let asset: XcmAssetId = UsdcLocation::get().into();
+				assert_ok!(SygmaBridge::onboard_resource(
+					Origin::root(),
+					Box::new(OnboardingSpec {
+						asset,
+						resource_id,
+						fees: vec![],
+						inbound_limit: Some(InboundLimit {
+							max_single: 1_000,
+							max_per_window: 10_000
+						}),
+						mintable: false,
+						decimals: 18,
+					})
+				));
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdcAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(UsdcAssetId::get()),
+					BridgeAccountOtherTokens::get(),
+					100,
+				));
+
+				// resources of `ResourcePairs` can not be offboarded
+				assert_noop!(
+					SygmaBridge::offboard_resource(Origin::root(), NativeResourceId::get(), true),
+					bridge::Error::<Runtime>::ResourceNotOnboarded
+				);
+				assert_noop!(
+					SygmaBridge::offboard_resource(Origin::signed(ALICE), resource_id, true),
+					DispatchError::BadOrigin
+				);
+
+				FailedProposalCounts::<Runtime>::insert(resource_id, 1);
+				assert_noop!(
+					SygmaBridge::offboard_resource(Origin::root(), resource_id, true),
+					bridge::Error::<Runtime>::FailedProposalsPending
+				);
+				FailedProposalCounts::<Runtime>::remove(resource_id);
+
+				assert_noop!(
+					SygmaBridge::offboard_resource(Origin::root(), resource_id, false),
+					bridge::Error::<Runtime>::ReserveNotDrained
+				);
+				assert_ok!(SygmaBridge::offboard_resource(Origin::root(), resource_id, true));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::ResourceOffboarded {
+						resource_id,
+						asset,
+						reserve_balance: Some(100),
+					},
+				)]);
+				assert_eq!(SygmaBridge::rid_to_assetid(&resource_id), None);
+				assert_eq!(InboundLimits::<Runtime>::get(resource_id), None);
+				assert_noop!(
+					SygmaBridge::offboard_resource(Origin::root(), resource_id, true),
+					bridge::Error::<Runtime>::ResourceNotOnboarded
+				);
+			})
+		}
+
+		#[test]
+		fn withdrawals_should_keep_min_reserve_balance() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let resource_id = NativeResourceId::get();
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				// only the committee sets the minimum
+				assert_noop!(
+					SygmaBridge::set_min_reserve_balance(
+						Origin::signed(ALICE),
+						resource_id,
+						Some(ENDOWED_BALANCE)
+					),
+					DispatchError::BadOrigin
+				);
+
+				// amounts are in 18 decimals, 100_000_000 releases 100 from the reserve
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id,
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							100_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+
+				// one unit above the boundary is rejected
+				assert_ok!(SygmaBridge::set_min_reserve_balance(
+					Origin::root(),
+					resource_id,
+					Some(ENDOWED_BALANCE - 99)
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::MinReserveBalanceSet {
+						resource_id,
+						minimum: Some(ENDOWED_BALANCE - 99),
+					},
+				)]);
+				execute(1);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"ReserveBelowMinimum".to_vec(),
+						reason: ExecutionFailureReason::InsufficientReserve,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), ENDOWED_BALANCE);
+
+				// withdrawing down to the minimum is allowed
+				assert_ok!(SygmaBridge::set_min_reserve_balance(
+					Origin::root(),
+					resource_id,
+					Some(ENDOWED_BALANCE - 100)
+				));
+				execute(1);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					ENDOWED_BALANCE - 100
+				);
+
+				// the reserve is at its minimum, nothing more can be released
+				execute(2);
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+
+				// removing the minimum releases the reserve again
+				assert_ok!(SygmaBridge::set_min_reserve_balance(Origin::root(), resource_id, None));
+				assert_eq!(SygmaBridge::min_reserve_balance(resource_id), None);
+				execute(2);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn refund_should_keep_min_reserve_balance() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset),
+					Box::new(dest)
+				));
+				assert_ok!(SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 0));
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+				let refunded = amount - fee;
+
+				assert_ok!(SygmaBridge::set_min_reserve_balance(
+					Origin::root(),
+					NativeResourceId::get(),
+					Some(reserve_balance - refunded + 1)
+				));
+				assert_noop!(
+					SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 0),
+					bridge::Error::<Runtime>::ReserveBelowMinimum
+				);
+
+				assert_ok!(SygmaBridge::set_min_reserve_balance(
+					Origin::root(),
+					NativeResourceId::get(),
+					Some(reserve_balance - refunded)
+				));
+				assert_ok!(SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 0));
+				assert_eq!(
+					Balances::free_balance(BridgeAccountNative::get()),
+					reserve_balance - refunded
+				);
+			})
+		}
+
+		#[test]
+		fn hashed_deposit_data_should_match_stored_preimage() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let deposit = || {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					));
+				};
+				let emitted_deposit_data = || {
+					System::events()
+						.into_iter()
+						.rev()
+						.find_map(|r| match r.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_data,
+								..
+							}) => Some(deposit_data),
+							_ => None,
+						})
+						.unwrap()
+				};
+				let expected_data = SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					199_000_000_000_000_000_000,
+					b"ethereum recipient".to_vec(),
+				);
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+
+				// the data itself is emitted by default, nothing is stored
+				System::set_block_number(1);
+				deposit();
+				assert_eq!(emitted_deposit_data(), expected_data);
+				assert_eq!(SygmaBridge::deposit_data(DEST_DOMAIN_ID, 0), None);
+
+				// the hash is emitted, the stored data is its preimage
+				HashDepositData::set(&true);
+				deposit();
+				let hash = emitted_deposit_data();
+				assert_eq!(hash.len(), 32);
+				let stored = SygmaBridge::deposit_data(DEST_DOMAIN_ID, 1).unwrap();
+				assert_eq!(stored, expected_data);
+				assert_eq!(hash, blake2_256(&stored).to_vec());
+
+				// data is kept for `DepositDataRetention` blocks
+				System::set_block_number(2);
+				deposit();
+				System::set_block_number(1 + DepositDataRetention::get() - 1);
+				assert_noop!(
+					SygmaBridge::prune_deposit_data(
+						Origin::signed(BOB),
+						vec![(DEST_DOMAIN_ID, 0), (DEST_DOMAIN_ID, 1)]
+					),
+					bridge::Error::<Runtime>::NoDepositDataToPrune
+				);
+				System::set_block_number(1 + DepositDataRetention::get());
+				assert_ok!(SygmaBridge::prune_deposit_data(
+					Origin::signed(BOB),
+					vec![(DEST_DOMAIN_ID, 1), (DEST_DOMAIN_ID, 2)]
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositDataPruned { count: 1 },
+				)]);
+				assert_eq!(SygmaBridge::deposit_data(DEST_DOMAIN_ID, 1), None);
+				assert!(SygmaBridge::deposit_data(DEST_DOMAIN_ID, 2).is_some());
+			})
+		}
+
+		#[test]
+		fn resource_for_location_should_resolve_like_deposit() {
+			new_test_ext().execute_with(|| {
+				let usdt_from_local = MultiLocation::new(0, UsdtLocation::get().interior);
+
+				assert_eq!(
+					SygmaBridge::resource_for_location(NativeLocation::get()),
+					Some(NativeResourceId::get())
+				);
+				assert_eq!(
+					SygmaBridge::resource_for_location(UsdtLocation::get()),
+					Some(UsdtResourceId::get())
+				);
+
+				// follows the asset matching mode
+				AssetMatching::set(&AssetMatchingMode::Strict);
+				assert_eq!(SygmaBridge::resource_for_location(usdt_from_local), None);
+				AssetMatching::set(&AssetMatchingMode::IgnoreParents);
+				assert_eq!(
+					SygmaBridge::resource_for_location(usdt_from_local),
+					Some(UsdtResourceId::get())
+				);
+
+				// unbound locations are not resolved
+				assert_eq!(
+					SygmaBridge::resource_for_location(MultiLocation::new(
+						0,
+						X1(GeneralIndex(123))
+					)),
+					None
+				);
+				assert_eq!(SygmaBridge::resource_for_location(UsdcLocation::get()), None);
+
+				// onboarded resources are resolved as well
+				assert_ok!(SygmaBridge::onboard_resource(
+					Origin::root(),
+					Box::new(OnboardingSpec {
+						asset: UsdcLocation::get().into(),
+						resource_id: [5u8; 32],
+						fees: vec![],
+						inbound_limit: None,
+						mintable: false,
+						decimals: 18,
+					})
+				));
+				assert_eq!(
+					SygmaBridge::resource_for_location(UsdcLocation::get()),
+					Some([5u8; 32])
+				);
+			})
+		}
+
+		#[test]
+		fn safe_mode_cycle_should_restore_prior_pause_states() {
+			new_test_ext().execute_with(|| {
+				let (domain_a, domain_b, domain_c) =
+					(DEST_DOMAIN_ID, DomainID::new(2), DomainID::new(3));
+				for domain in [domain_a, domain_b, domain_c] {
+					assert_ok!(SygmaBridge::register_domain(
+						Origin::root(),
+						domain,
+						U256::from(domain.into_inner())
+					));
+				}
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::root()));
+				let scheduled = PauseInfo {
+					reason: PauseReason::Scheduled,
+					note: Some(b"maintenance".to_vec().try_into().unwrap()),
+				};
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
+					Origin::root(),
+					domain_b,
+					scheduled.reason,
+					scheduled.note.clone()
+				));
+
+				// entering the safe mode pauses everything and remembers domain_b's pause
+				<SygmaBridge as BridgeEmergency>::pause_everything();
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EmergencyPauseEntered { paused_before: 1 },
+				)]);
+				for domain in [domain_a, domain_b, domain_c] {
+					assert!(SygmaBridge::is_paused(domain));
+				}
+				assert_eq!(
+					SygmaBridge::pause_reasons(domain_a).map(|info| info.reason),
+					Some(PauseReason::SafeMode)
+				);
+				assert_eq!(
+					SygmaBridge::pre_pause_snapshot().unwrap().into_inner(),
+					vec![
+						((RouteDirection::Inbound, domain_b), scheduled.clone()),
+						((RouteDirection::Outbound, domain_b), scheduled.clone())
+					]
+				);
+
+				// entering again keeps the snapshot
+				SygmaBridge::pause_everything();
+				assert_eq!(
+					SygmaBridge::pre_pause_snapshot().unwrap().into_inner(),
+					vec![
+						((RouteDirection::Inbound, domain_b), scheduled.clone()),
+						((RouteDirection::Outbound, domain_b), scheduled.clone())
+					]
+				);
+
+				// a circuit breaker tripped during the safe mode
+				let tripped = PauseInfo {
+					reason: PauseReason::CircuitBreaker { resource: None },
+					note: None,
+				};
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
+					Origin::root(),
+					domain_c,
+					tripped.reason,
+					None
+				));
+
+				// exiting restores the prior states, the circuit breaker stays
+				<SygmaBridge as BridgeEmergency>::restore_from_snapshot();
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EmergencyPauseExited,
+				)]);
+				assert!(!SygmaBridge::is_paused(domain_a));
+				assert_eq!(SygmaBridge::pause_reasons(domain_a), None);
+				assert!(SygmaBridge::is_paused(domain_b));
+				assert_eq!(SygmaBridge::pause_reasons(domain_b), Some(scheduled));
+				assert!(SygmaBridge::is_paused(domain_c));
+				assert_eq!(SygmaBridge::pause_reasons(domain_c), Some(tripped));
+				assert_eq!(SygmaBridge::pre_pause_snapshot(), None);
+
+				// exiting without a snapshot does nothing
+				SygmaBridge::restore_from_snapshot();
+				assert!(SygmaBridge::is_paused(domain_b));
+			})
+		}
+
+		#[test]
+		fn token_bucket_should_throttle_deposits() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				// 200 - 1 in 18 decimals
+				let bridged = 199_000_000_000_000_000_000u128;
+				let resource_id = NativeResourceId::get();
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							),
+						}),
+					)
+				};
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				let config =
+					TokenBucketConfig { capacity: 2 * bridged, refill_per_block: bridged / 10 };
+				assert_noop!(
+					SygmaBridge::set_token_bucket(Origin::signed(ALICE), resource_id, Some(config)),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::set_token_bucket(
+						Origin::root(),
+						resource_id,
+						Some(TokenBucketConfig { capacity: 0, refill_per_block: 1 })
+					),
+					bridge::Error::<Runtime>::InvalidTokenBucket
+				);
+				System::set_block_number(1);
+				assert_ok!(SygmaBridge::set_token_bucket(
+					Origin::root(),
+					resource_id,
+					Some(config)
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::TokenBucketSet {
+					resource_id,
+					config: Some(config),
+				})]);
+
+				// the full bucket allows a burst of two deposits
+				assert_ok!(deposit());
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::token_bucket(resource_id), Some((0, 1)));
+				assert_noop!(deposit(), bridge::Error::<Runtime>::RateLimitExceeded);
+
+				// a deposit worth of tokens is refilled over 10 blocks
+				System::set_block_number(10);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::RateLimitExceeded);
+				System::set_block_number(11);
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::token_bucket(resource_id), Some((0, 11)));
+
+				// refill stops at the capacity
+				System::set_block_number(100);
+				assert_ok!(deposit());
+				assert_eq!(SygmaBridge::token_bucket(resource_id), Some((bridged, 100)));
+				assert_ok!(deposit());
+				assert_noop!(deposit(), bridge::Error::<Runtime>::RateLimitExceeded);
+
+				// deposits are not throttled once the bucket is removed
+				assert_ok!(SygmaBridge::set_token_bucket(Origin::root(), resource_id, None));
+				assert_eq!(SygmaBridge::token_bucket(resource_id), None);
+				assert_ok!(deposit());
+				assert_ok!(deposit());
+			})
+		}
+
+		#[test]
+		fn codec_vectors_should_match_pallet_encoding() {
+			new_test_ext().execute_with(|| {
+				for vector in DEPOSIT_DATA {
+					let data = SygmaBridge::create_deposit_data(
+						vector.format,
+						vector.amount,
+						vector.recipient.to_vec(),
+					);
+					assert_eq!([data, vector.extra.to_vec()].concat(), vector.data);
+					assert_eq!(
+						SygmaBridge::split_deposit_data(vector.format, vector.data),
+						Ok((vector.amount, vector.recipient.to_vec(), vector.extra.to_vec()))
+					);
+				}
+
+				// Only the digests signed for the mock chain id and bridge contract can be checked
+				let mut checked = 0;
+				for vector in PROPOSAL_DIGESTS.iter().filter(|vector| {
+					vector.chain_id == EIP712ChainID::get()
+						&& vector.verifying_contract == DestVerifyingContractAddress::get()
+				}) {
+					let proposals: Vec<Proposal> = vector
+						.proposals
+						.iter()
+						.map(|prop| Proposal {
+							origin_domain_id: DomainID::new(prop.origin_domain_id),
+							deposit_nonce: prop.deposit_nonce,
+							resource_id: prop.resource_id,
+							data: prop.data.to_vec(),
+						})
+						.collect();
+					assert_eq!(SygmaBridge::proposal_digest(&proposals), vector.digest);
+					checked += 1;
+				}
+				assert!(checked > 1);
+			})
+		}
+
+		#[test]
+		fn fee_reserve_low_should_be_reported_once_per_drop() {
+			new_test_ext().execute_with(|| {
+				let threshold = 10_000_000_000_000u128;
+				let native: XcmAssetId = NativeLocation::get().into();
+				let low_reports = || {
+					System::events()
+						.iter()
+						.filter(|record| {
+							matches!(
+								record.event,
+								RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeReserveLow { .. })
+							)
+						})
+						.count()
+				};
+
+				assert_noop!(
+					SygmaBridge::set_fee_reserve_threshold(
+						Origin::signed(ALICE),
+						Box::new(native),
+						Some(threshold)
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::set_fee_reserve_threshold(
+						Origin::root(),
+						Box::new(MultiLocation::new(1, X1(Parachain(9999))).into()),
+						Some(threshold)
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+				for location in [NativeLocation::get(), UsdtLocation::get(), AstrLocation::get()] {
+					assert_ok!(SygmaBridge::set_fee_reserve_threshold(
+						Origin::root(),
+						Box::new(location.into()),
+						Some(threshold)
+					));
+				}
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FeeReserveThresholdSet {
+						asset_id: AstrLocation::get().into(),
+						threshold: Some(threshold),
+					},
+				)]);
+				assert_eq!(SygmaBridge::fee_reserve_thresholds(native), Some(threshold));
+
+				// All reserves are empty, the checks are spread over two blocks
+				SygmaBridge::on_initialize(1);
+				assert_eq!(low_reports(), 2);
+				assert!(FeeReserveCheckCursor::<Runtime>::get().is_some());
+				SygmaBridge::on_initialize(2);
+				assert_eq!(low_reports(), 3);
+				assert!(FeeReserveCheckCursor::<Runtime>::get().is_none());
+
+				// Reserves still low are not reported again
+				SygmaBridge::on_initialize(3);
+				SygmaBridge::on_initialize(4);
+				assert_eq!(low_reports(), 3);
+
+				// Native reserve recovers, then drops below the threshold again
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					TreasuryAccount::get(),
+					threshold
+				));
+				SygmaBridge::on_initialize(5);
+				SygmaBridge::on_initialize(6);
+				assert!(!FeeReserveLowAssets::<Runtime>::get(native));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					TreasuryAccount::get(),
+					threshold / 2
+				));
+				SygmaBridge::on_initialize(7);
+				SygmaBridge::on_initialize(8);
+				assert_eq!(low_reports(), 4);
+				assert!(System::events().iter().any(|record| record.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeReserveLow {
+						asset_id: native,
+						balance: threshold / 2,
+					})));
+
+				// Assets without threshold are not checked
+				assert_ok!(SygmaBridge::set_fee_reserve_threshold(
+					Origin::root(),
+					Box::new(native),
+					None
+				));
+				assert!(!FeeReserveLowAssets::<Runtime>::get(native));
+				assert_ok!(Balances::force_set_balance(Origin::root(), TreasuryAccount::get(), 0));
+				SygmaBridge::on_initialize(9);
+				SygmaBridge::on_initialize(10);
+				assert_eq!(low_reports(), 4);
+			})
+		}
+
+		#[test]
+		fn ack_should_carry_executed_nonce_until_disabled() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 100_000_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					SygmaBridge::ack_fee_pot_account(),
+					ENDOWED_BALANCE
+				));
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let ack_sent = || {
+					System::events().iter().any(|r| {
+						matches!(
+							r.event,
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AckSent { .. })
+						)
+					})
+				};
+
+				// acks are opt-in, only the committee enables them
+				execute(3);
+				assert!(!ack_sent());
+				assert_noop!(
+					SygmaBridge::set_ack_enabled(Origin::signed(ALICE), DEST_DOMAIN_ID, true),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::set_ack_enabled(Origin::root(), DomainID::new(9), true),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+				assert_ok!(SygmaBridge::set_ack_enabled(Origin::root(), DEST_DOMAIN_ID, true));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AckEnabledSet {
+					domain_id: DEST_DOMAIN_ID,
+					enabled: true,
+				})]);
+
+				// ack is a generic deposit back to the origin domain, its data encodes the
+				// executed nonce, paid from the ack fee pot
+				execute(5);
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: AckResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: SygmaBridge::ack_fee_pot_account(),
+						transfer_type: TransferType::GenericTransfer,
+						deposit_data: sygma_codec::ack_data(
+							DataFormat::EvmAbi,
+							5,
+							AckStatus::Delivered,
+							amount
+						),
+						handler_response: vec![],
+					})));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AckSent {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 5,
+						ack_nonce: 0,
+						status: AckStatus::Delivered,
+					})));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 1);
+				assert_eq!(
+					Balances::free_balance(SygmaBridge::ack_fee_pot_account()),
+					ENDOWED_BALANCE - fee
+				);
+
+				// disabling the flag stops acks from the next proposal on
+				assert_ok!(SygmaBridge::set_ack_enabled(Origin::root(), DEST_DOMAIN_ID, false));
+				System::reset_events();
+				execute(6);
+				assert!(SygmaBridge::is_proposal_executed(6, DEST_DOMAIN_ID));
+				assert!(!ack_sent());
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 1);
+
+				// kill switch stops acks of all domains
+				assert_ok!(SygmaBridge::set_ack_enabled(Origin::root(), DEST_DOMAIN_ID, true));
+				assert_ok!(SygmaBridge::set_acks_halted(Origin::root(), true));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AcksHaltedSet {
+					halted: true,
+				})]);
+				System::reset_events();
+				execute(7);
+				assert!(!ack_sent());
+
+				// at most MaxAcksPerBlock acks are sent in a block, the proposal still executes
+				assert_ok!(SygmaBridge::set_acks_halted(Origin::root(), false));
+				execute(8);
+				System::reset_events();
+				execute(9);
+				assert!(SygmaBridge::is_proposal_executed(9, DEST_DOMAIN_ID));
+				assert!(!ack_sent());
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AckFailed {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 9,
+					error: b"AckRateLimited".to_vec(),
+				})]);
+			})
+		}
+
+		#[test]
+		fn compressed_deposit_data_should_round_trip() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				let deposit = || {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					));
+					System::events()
+						.into_iter()
+						.rev()
+						.find_map(|r| match r.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_data,
+								..
+							}) => Some(deposit_data),
+							_ => None,
+						})
+						.unwrap()
+				};
+				let execute = |deposit_nonce: DepositNonce, data: Vec<u8>| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data,
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let expected_data = SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					199_000_000_000_000_000_000,
+					b"ethereum recipient".to_vec(),
+				);
+				let inbound_data = SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					100_000_000,
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+						.encode(),
+				);
+
+				// deposit data carries no data version by default
+				assert_eq!(deposit(), expected_data);
+				execute(1, inbound_data.clone());
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// outbound data is versioned and compressed, the destination decompresses it
+				CompressDepositData::set(&true);
+				let data = deposit();
+				assert_eq!(data[0], sygma_codec::DATA_VERSION_LZ4);
+				assert!(data.len() < expected_data.len());
+				assert_eq!(
+					sygma_codec::decompress_deposit_data(&data, MaxDepositDataSize::get() as usize),
+					Some(expected_data)
+				);
+
+				// inbound data is decompressed, raw payloads carry the raw version
+				let compressed = sygma_codec::compress_deposit_data(&inbound_data);
+				assert_eq!(compressed[0], sygma_codec::DATA_VERSION_LZ4);
+				execute(2, compressed);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				execute(3, [&[sygma_codec::DATA_VERSION_RAW][..], &inbound_data[..]].concat());
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+
+				// unversioned data is rejected once compression is enabled
+				execute(4, inbound_data);
+				assert!(!SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InvalidDepositData".to_vec(),
+						reason: ExecutionFailureReason::DecodeError,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 4,
+					})));
+			})
+		}
+
+		#[test]
+		fn binding_conflicts_should_resolve_by_precedence() {
+			new_test_ext().execute_with(|| {
+				let usdt: XcmAssetId = UsdtLocation::get().into();
+				let registered: XcmAssetId =
+					MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"registered")))
+						.into();
+				let rebound: XcmAssetId =
+					MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"rebound")))
+						.into();
+				let register = |resource_id: ResourceId, asset: XcmAssetId| {
+					OnboardedResources::<Runtime>::insert(
+						resource_id,
+						OnboardedResource { asset, decimals: 18, mintable: false },
+					)
+				};
+
+				// bound by a single source
+				assert_eq!(
+					SygmaBridge::resolve_binding(&UsdtResourceId::get()),
+					Some((usdt, BindingSource::Constant))
+				);
+				register([7u8; 32], registered);
+				assert_eq!(
+					SygmaBridge::resolve_binding(&[7u8; 32]),
+					Some((registered, BindingSource::Registry))
+				);
+				OnboardedResources::<Runtime>::remove([7u8; 32]);
+				assert_eq!(SygmaBridge::resolve_binding(&[7u8; 32]), None);
+
+				// constant and registry agreeing is no conflict
+				register(UsdtResourceId::get(), usdt);
+				assert_eq!(
+					SygmaBridge::resolve_binding(&UsdtResourceId::get()),
+					Some((usdt, BindingSource::Registry))
+				);
+				assert!(SygmaBridge::binding_conflicts().is_empty());
+				assert_noop!(
+					SygmaBridge::resolve_binding_conflict(
+						Origin::root(),
+						UsdtResourceId::get(),
+						BindingSource::Registry
+					),
+					bridge::Error::<Runtime>::NoBindingConflict
+				);
+
+				// registry wins over the constant, for lookups by resource and by asset alike
+				register(UsdtResourceId::get(), registered);
+				assert_eq!(SygmaBridge::binding_conflicts(), vec![UsdtResourceId::get()]);
+				assert_eq!(
+					SygmaBridge::resolve_binding(&UsdtResourceId::get()),
+					Some((registered, BindingSource::Registry))
+				);
+				assert_eq!(SygmaBridge::rid_to_assetid(&UsdtResourceId::get()), Some(registered));
+				assert_eq!(
+					SygmaBridge::resolve_asset(&registered),
+					Some((registered, UsdtResourceId::get()))
+				);
+				assert_eq!(SygmaBridge::resolve_asset(&usdt), None);
+
+				// only the committee resolves conflicts, from a source binding the resource
+				assert_noop!(
+					SygmaBridge::resolve_binding_conflict(
+						Origin::signed(ALICE),
+						UsdtResourceId::get(),
+						BindingSource::Constant
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::resolve_binding_conflict(
+						Origin::root(),
+						UsdtResourceId::get(),
+						BindingSource::Override
+					),
+					bridge::Error::<Runtime>::BindingSourceMissing
+				);
+
+				// keeping the constant removes the registered resource
+				assert_ok!(SygmaBridge::resolve_binding_conflict(
+					Origin::root(),
+					UsdtResourceId::get(),
+					BindingSource::Constant
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::BindingConflictResolved {
+						resource_id: UsdtResourceId::get(),
+						kept: BindingSource::Constant,
+						asset: usdt,
+					},
+				)]);
+				assert!(OnboardedResources::<Runtime>::get(UsdtResourceId::get()).is_none());
+				assert_eq!(
+					SygmaBridge::resolve_binding(&UsdtResourceId::get()),
+					Some((usdt, BindingSource::Constant))
+				);
+				assert!(SygmaBridge::binding_conflicts().is_empty());
+
+				// keeping the registry overrules the constant pair
+				register(UsdtResourceId::get(), registered);
+				assert_ok!(SygmaBridge::resolve_binding_conflict(
+					Origin::root(),
+					UsdtResourceId::get(),
+					BindingSource::Registry
+				));
+				assert_eq!(
+					OverruledResourcePairs::<Runtime>::get(UsdtResourceId::get()),
+					Some(usdt)
+				);
+				assert_eq!(
+					SygmaBridge::resolve_binding(&UsdtResourceId::get()),
+					Some((registered, BindingSource::Registry))
+				);
+				assert!(SygmaBridge::binding_conflicts().is_empty());
+
+				// a rebind overrides both, keeping it overrules the constant pair
+				register(
+					AstrResourceId::get(),
+					MultiLocation::new(1, X2(Parachain(2005), slice_to_generalkey(b"astr"))).into(),
+				);
+				assert_ok!(SygmaBridge::rebind_resource(
+					Origin::root(),
+					AstrResourceId::get(),
+					Box::new(rebound),
+					1
+				));
+				assert_eq!(
+					SygmaBridge::resolve_binding(&AstrResourceId::get()),
+					Some((rebound, BindingSource::Override))
+				);
+				assert_eq!(SygmaBridge::binding_conflicts(), vec![AstrResourceId::get()]);
+				assert_ok!(SygmaBridge::resolve_binding_conflict(
+					Origin::root(),
+					AstrResourceId::get(),
+					BindingSource::Override
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::BindingConflictResolved {
+						resource_id: AstrResourceId::get(),
+						kept: BindingSource::Override,
+						asset: rebound,
+					},
+				)]);
+				assert_eq!(
+					SygmaBridge::resolve_binding(&AstrResourceId::get()),
+					Some((rebound, BindingSource::Override))
+				);
+				assert!(SygmaBridge::binding_conflicts().is_empty());
+			})
+		}
+
+		#[test]
+		fn account_volume_should_accumulate_across_deposits() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				// 199 of the native asset bridged by each deposit, in 18 decimals
+				let bridged = 199_000_000_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
 					fee
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				let deposit = |amount: u128| {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+
+				assert_eq!(SygmaBridge::account_volume(ALICE), 0);
+				assert_ok!(deposit(amount));
+				assert_eq!(SygmaBridge::account_volume(ALICE), bridged);
+				assert_ok!(deposit(amount));
+				assert_ok!(deposit(amount * 2));
+				assert_eq!(
+					SygmaBridge::account_volume(ALICE),
+					bridged * 2 + 399_000_000_000_000_000_000
+				);
+
+				// failed deposits and other accounts are not counted
+				assert_noop!(deposit(fee), bridge::Error::<Runtime>::FeeTooExpensive);
+				assert_eq!(
+					SygmaBridge::account_volume(ALICE),
+					bridged * 2 + 399_000_000_000_000_000_000
+				);
+				assert_eq!(SygmaBridge::account_volume(BOB), 0);
+			})
+		}
+
+		#[test]
+		fn execution_receipt_should_match_credited_amount() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let bob_location =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+
+				// amounts are in 18 decimals, 100_000_000 credits 100 to BOB
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							100_000_000,
+							bob_location.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+					System::events()
+						.into_iter()
+						.rev()
+						.find_map(|r| match r.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ProposalExecution {
+								receipt_hash,
+								..
+							}) => Some(receipt_hash),
+							_ => None,
+						})
+						.unwrap()
+				};
+
+				// receipt records the amount credited, its hash is emitted
+				let bob_balance = Balances::free_balance(BOB);
+				let receipt_hash = execute(1);
+				let receipt = SygmaBridge::execution_receipt(DEST_DOMAIN_ID, 1).unwrap();
+				assert_eq!(
+					receipt,
+					ExecutionReceipt {
+						recipient: bob_location,
+						asset: NativeLocation::get().into(),
+						amount: Balances::free_balance(BOB) - bob_balance,
+						block: 1,
+					}
+				);
+				assert_eq!(receipt.amount, 100);
+				assert_eq!(receipt_hash, Some(blake2_256(&receipt.encode())));
+
+				// transactor crediting less, the receipt holds what the recipient got
+				AllowPartialTransfer::set(&true);
+				DepositShortfall::set(&10);
+				System::set_block_number(2);
+				let bob_balance = Balances::free_balance(BOB);
+				let receipt_hash = execute(2);
+				let receipt = SygmaBridge::execution_receipt(DEST_DOMAIN_ID, 2).unwrap();
+				assert_eq!(receipt.amount, Balances::free_balance(BOB) - bob_balance);
+				assert_eq!(receipt.amount, 90);
+				assert_eq!(receipt.block, 2);
+				assert_eq!(receipt_hash, Some(blake2_256(&receipt.encode())));
+				assert_eq!(SygmaBridge::execution_receipt(DEST_DOMAIN_ID, 3), None);
+
+				// receipts are kept for `ExecutionReceiptRetention` blocks
+				System::set_block_number(1 + ExecutionReceiptRetention::get() - 1);
+				assert_noop!(
+					SygmaBridge::prune_execution_receipts(
+						Origin::signed(BOB),
+						vec![(DEST_DOMAIN_ID, 1), (DEST_DOMAIN_ID, 3)]
+					),
+					bridge::Error::<Runtime>::NoExecutionReceiptToPrune
+				);
+				System::set_block_number(1 + ExecutionReceiptRetention::get());
+				assert_ok!(SygmaBridge::prune_execution_receipts(
+					Origin::signed(BOB),
+					vec![(DEST_DOMAIN_ID, 1), (DEST_DOMAIN_ID, 2)]
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::ExecutionReceiptsPruned { count: 1 },
+				)]);
+				assert_eq!(SygmaBridge::execution_receipt(DEST_DOMAIN_ID, 1), None);
+				assert!(SygmaBridge::execution_receipt(DEST_DOMAIN_ID, 2).is_some());
+			})
+		}
+
+		#[test]
+		fn signature_cache_should_be_per_block_and_consistent() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let (other_pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: vec![1u8],
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message).encode();
+				let other_signature = other_pair.sign_prehashed(&final_message).encode();
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let cache_key = |signature: &Vec<u8>| {
+					blake2_256(&(final_message, signature, test_mpc_addr).encode())
+				};
+
+				// nothing is cached by default
+				assert!(SygmaBridge::verify_batch_signature(final_message, signature.clone()));
+				assert_eq!(VerifiedSignatures::<Runtime>::iter().count(), 0);
+
+				// the second verification in the block is served by the cache, with the same result
+				EnableSignatureCache::set(&true);
+				assert!(SygmaBridge::verify_batch_signature(final_message, signature.clone()));
+				assert_eq!(VerifiedSignatures::<Runtime>::get(cache_key(&signature)), Some(true));
+				assert!(SygmaBridge::verify_batch_signature(final_message, signature.clone()));
+				assert!(!SygmaBridge::verify_batch_signature(
+					final_message,
+					other_signature.clone()
+				));
+				assert!(!SygmaBridge::verify_batch_signature(
+					final_message,
+					other_signature.clone()
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(
+					VerifiedSignatures::<Runtime>::get(cache_key(&other_signature)),
+					Some(false)
+				);
+				assert_eq!(VerifiedSignatures::<Runtime>::iter().count(), 2);
 
-				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
-				let dest: MultiLocation = MultiLocation {
+				// a cached result does not outlive the signers it was verified against
+				MpcAddr::<Runtime>::put(MpcAddress(other_pair.public().to_eth_address().unwrap()));
+				assert!(!SygmaBridge::verify_batch_signature(final_message, signature.clone()));
+				assert!(SygmaBridge::verify_batch_signature(
+					final_message,
+					other_signature.clone()
+				));
+				assert_eq!(VerifiedSignatures::<Runtime>::iter().count(), 4);
+
+				// the cache is cleared at the end of the block
+				SygmaBridge::on_finalize(1);
+				assert_eq!(VerifiedSignatures::<Runtime>::iter().count(), 0);
+			})
+		}
+
+		#[test]
+		fn prefunded_fee_should_be_kept_on_failed_deposit_only_if_configured() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let native_asset_id: XcmAssetId = NativeLocation::get().into();
+				// more than ALICE holds
+				let asset: MultiAsset = (native_asset_id, Fungible(fee * 20)).into();
+				let dest = MultiLocation {
 					parents: 0,
 					interior: X2(
 						slice_to_generalkey(b"ethereum recipient"),
 						slice_to_generalkey(&[1]),
 					),
 				};
+				let prefund_account = SygmaBridge::fee_prefund_account();
 
-				// Call transfer instead of deposit
-				assert_ok!(SygmaBridge::transfer(ALICE.into(), asset.clone(), dest, None));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(Balances::force_set_balance(Origin::root(), ALICE, fee * 10));
+				assert_ok!(SygmaBridge::prefund_fees(
+					Origin::signed(ALICE),
+					Box::new(native_asset_id),
+					fee
+				));
+				let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
 
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
+				// by default the deposit is atomic, the prefunded fee is not taken either
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest),
+					),
+					bridge::Error::<Runtime>::TransactFailed
 				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
-				// Check event
+				assert_eq!(Balances::free_balance(ALICE), fee * 9);
+				assert_eq!(Balances::free_balance(&prefund_account), fee);
+				assert_eq!(FeePrefunds::<Runtime>::get(ALICE, native_asset_id), fee);
+
+				// the prefunded fee is kept, nothing else moves and no deposit is made
+				KeepFeeOnFailedDeposit::set(&true);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest),
+				));
 				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::PrefundConsumed {
+						account: ALICE,
+						asset_id: native_asset_id,
+						amount: fee,
 					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeRetainedOnFailure {
+						account: ALICE,
 						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: fee,
-						fee_asset_id: NativeLocation::get().into(),
+						asset_id: native_asset_id,
+						fee,
 					}),
 				]);
+				assert_eq!(Balances::free_balance(ALICE), fee * 9);
+				assert_eq!(Balances::free_balance(&prefund_account), 0);
+				assert!(!FeePrefunds::<Runtime>::contains_key(ALICE, native_asset_id));
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance + fee);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), reserve_balance);
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 0);
+				assert_eq!(GlobalTvl::<Runtime>::get(), 0);
+
+				// without prefund there is no fee to keep, the deposit fails as a whole
+				assert_noop!(
+					SygmaBridge::deposit(Origin::signed(ALICE), Box::new(asset), Box::new(dest)),
+					bridge::Error::<Runtime>::TransactFailed
+				);
 			})
 		}
 
 		#[test]
-		fn hex_zero_padding_32_test() {
+		fn denied_recipient_should_be_held_in_a_batch() {
 			new_test_ext().execute_with(|| {
-				assert_eq!(
-					SygmaBridge::hex_zero_padding_32(100).to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 100,
-					]
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
+				let recipient = |account: AccountId| {
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: account.into() }))
+						.encode()
+				};
+				let denied = recipient(BOB);
+
+				assert_noop!(
+					SygmaBridge::add_denied_recipient(Origin::signed(ALICE), denied.clone()),
+					DispatchError::BadOrigin
 				);
-				let recipient = String::from("0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4");
+				assert_noop!(
+					SygmaBridge::add_denied_recipient(
+						Origin::root(),
+						vec![1u8; MAX_RECIPIENT_LEN as usize + 1]
+					),
+					bridge::Error::<Runtime>::RecipientTooLong
+				);
+				assert_noop!(
+					SygmaBridge::remove_denied_recipient(Origin::root(), denied.clone()),
+					bridge::Error::<Runtime>::RecipientNotDenied
+				);
+				assert_ok!(SygmaBridge::add_denied_recipient(Origin::root(), denied.clone()));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::RecipientDenylisted { recipient: denied.clone() },
+				)]);
+				assert!(SygmaBridge::is_recipient_denied(&denied));
+				assert_eq!(RecipientDenylist::<Runtime>::iter().count(), 1);
+
+				// amounts are in 18 decimals, 1_000_000 credits 1
+				let execute = |transfers: Vec<(DepositNonce, AccountId)>| {
+					let proposals: Vec<Proposal> = transfers
+						.into_iter()
+						.map(|(deposit_nonce, account)| Proposal {
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+							resource_id: NativeResourceId::get(),
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								1_000_000,
+								recipient(account),
+							),
+						})
+						.collect();
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
+				let alice_balance = Balances::free_balance(ALICE);
+				let bob_balance = Balances::free_balance(BOB);
+				let reserve_balance = Balances::free_balance(BridgeAccountNative::get());
+
+				// the denied recipient is skipped and recorded, the rest of the batch executes
+				execute(vec![(1, BOB), (2, ALICE)]);
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"RecipientDenied".to_vec(),
+						reason: ExecutionFailureReason::Filtered,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					})));
 				assert_eq!(
-					SygmaBridge::hex_zero_padding_32(recipient.len() as u128).to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 42,
-					]
+					FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).unwrap().detail.to_vec(),
+					b"RecipientDenied".to_vec()
 				);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance + 1);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), reserve_balance - 1);
+
+				// the held transfer is delivered once the recipient is removed
+				assert_ok!(SygmaBridge::remove_denied_recipient(Origin::root(), denied.clone()));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::RecipientDenylistRemoved { recipient: denied.clone() },
+				)]);
+				assert!(!SygmaBridge::is_recipient_denied(&denied));
+				execute(vec![(1, BOB)]);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 1);
 			})
 		}
 
 		#[test]
-		fn create_deposit_data_test() {
-			new_test_ext().execute_with(|| {
-				let recipient = b"0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4".to_vec();
-				let data = SygmaBridge::create_deposit_data(100, recipient);
-				// 32 + 32 + 42
-				assert_eq!(data.len(), 106);
+		fn offchain_metrics_should_be_written_to_local_storage() {
+			let mut ext = new_test_ext();
+			let (offchain, _) = TestOffchainExt::new();
+			ext.register_extension(OffchainDbExt::new(offchain.clone()));
+			ext.register_extension(OffchainWorkerExt::new(offchain));
+			ext.execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let native_asset_id: XcmAssetId = NativeLocation::get().into();
+				let asset: MultiAsset = (native_asset_id, Fungible(fee * 10)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let paused_domain_id = DomainID::new(5);
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					paused_domain_id,
+					U256::from(5)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), paused_domain_id));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				let deposit = || {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest),
+					));
+				};
+				let metric = |key: &[u8]| StorageValueRef::persistent(key).get::<u64>().unwrap();
+
+				// nothing is counted unless enabled
+				deposit();
+				assert_eq!(BlockActivity::<Runtime>::get(), None);
+
+				// one deposit, one executed and one failed proposal in block 1
+				EnableOffchainMetrics::set(&true);
+				deposit();
+				let proposals = vec![
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							1_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					},
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						resource_id: NativeResourceId::get(),
+						data: vec![1u8],
+					},
+				];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
+				));
 				assert_eq!(
-					data.to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 48, 120, 57, 53, 69, 67, 70,
-						53, 97, 101, 48, 48, 48, 101, 48, 102, 101, 48, 101, 48, 100, 69, 54, 51,
-						97, 68, 69, 57, 98, 55, 68, 56, 50, 97, 51, 55, 50, 48, 51, 56, 98, 52,
-					]
+					BlockActivity::<Runtime>::get(),
+					Some(ActivityCounters {
+						block: 1,
+						deposits: 1,
+						executions: 1,
+						failed_executions: 1
+					})
+				);
+				assert_eq!(metric(metrics::DEPOSITS_PER_BLOCK_KEY), None);
+
+				metrics::write_metrics::<Runtime>(1);
+				assert_eq!(metric(metrics::BLOCK_KEY), Some(1));
+				assert_eq!(metric(metrics::DEPOSITS_PER_BLOCK_KEY), Some(1));
+				assert_eq!(metric(metrics::EXECUTIONS_PER_BLOCK_KEY), Some(1));
+				assert_eq!(metric(metrics::FAILED_EXECUTIONS_PER_BLOCK_KEY), Some(1));
+				assert_eq!(metric(metrics::PAUSED_DOMAINS_KEY), Some(1));
+
+				// counters of a block without activity are zero
+				System::set_block_number(2);
+				metrics::write_metrics::<Runtime>(2);
+				assert_eq!(metric(metrics::BLOCK_KEY), Some(2));
+				assert_eq!(metric(metrics::DEPOSITS_PER_BLOCK_KEY), Some(0));
+				assert_eq!(metric(metrics::EXECUTIONS_PER_BLOCK_KEY), Some(0));
+				assert_eq!(metric(metrics::FAILED_EXECUTIONS_PER_BLOCK_KEY), Some(0));
+
+				// the first activity of a block resets the counters
+				deposit();
+				assert_eq!(
+					BlockActivity::<Runtime>::get(),
+					Some(ActivityCounters { block: 2, deposits: 1, ..Default::default() })
 				);
+
+				// the offchain worker writes nothing once disabled
+				#[cfg(feature = "offchain-metrics")]
+				{
+					System::set_block_number(3);
+					SygmaBridge::offchain_worker(3);
+					assert_eq!(metric(metrics::BLOCK_KEY), Some(3));
+					EnableOffchainMetrics::set(&false);
+					SygmaBridge::offchain_worker(4);
+					assert_eq!(metric(metrics::BLOCK_KEY), Some(3));
+				}
 			})
 		}
 
 		#[test]
-		fn deposit_foreign_asset_should_work() {
+		fn deposit_with_permit_should_withdraw_from_the_signer_once() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let native_asset_id: XcmAssetId = NativeLocation::get().into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				let (owner_pair, _) = sr25519::Pair::generate();
+				let (evil_pair, _) = sr25519::Pair::generate();
+				let owner = AccountId::from(owner_pair.public());
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(Balances::force_set_balance(Origin::root(), owner.clone(), amount * 3));
+
+				let permit = |nonce: u64, deadline: u64| DepositPermit {
+					owner: owner.clone(),
+					asset: (native_asset_id, Fungible(amount)).into(),
+					dest,
+					nonce,
+					deadline,
+				};
+				let sign = |pair: &sr25519::Pair, permit: &DepositPermit<AccountId, u64>| {
+					MultiSignature::Sr25519(pair.sign(&SygmaBridge::permit_payload(permit)))
+				};
+				let alice_balance = Balances::free_balance(ALICE);
+
+				// valid permit, submitted by ALICE on behalf of the owner
+				let valid = permit(0, 10);
+				assert_ok!(SygmaBridge::deposit_with_permit(
+					Origin::signed(ALICE),
+					Box::new(valid.clone()),
+					sign(&owner_pair, &valid),
+				));
+				assert!(System::events().iter().any(|r| matches!(
+					&r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit { sender, .. })
+						if *sender == owner
+				)));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositPermitUsed {
+						owner: owner.clone(),
+						nonce: 0,
+						submitter: ALICE,
+					},
+				)]);
+				assert_eq!(Balances::free_balance(&owner), amount * 2);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), amount - fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				assert_eq!(PermitNonces::<Runtime>::get(&owner), 1);
+
+				// replayed permit
+				assert_noop!(
+					SygmaBridge::deposit_with_permit(
+						Origin::signed(BOB),
+						Box::new(valid.clone()),
+						sign(&owner_pair, &valid),
+					),
+					bridge::Error::<Runtime>::InvalidPermitNonce
+				);
+
+				// permit signed by someone else than its owner, or altered after signing
+				let next = permit(1, 10);
+				assert_noop!(
+					SygmaBridge::deposit_with_permit(
+						Origin::signed(ALICE),
+						Box::new(next.clone()),
+						sign(&evil_pair, &next),
+					),
+					bridge::Error::<Runtime>::InvalidPermitSignature
+				);
+				let mut altered = next.clone();
+				altered.asset = (native_asset_id, Fungible(amount * 2)).into();
+				assert_noop!(
+					SygmaBridge::deposit_with_permit(
+						Origin::signed(ALICE),
+						Box::new(altered),
+						sign(&owner_pair, &next),
+					),
+					bridge::Error::<Runtime>::InvalidPermitSignature
+				);
+
+				// expired permit
+				System::set_block_number(11);
+				assert_noop!(
+					SygmaBridge::deposit_with_permit(
+						Origin::signed(ALICE),
+						Box::new(next.clone()),
+						sign(&owner_pair, &next),
+					),
+					bridge::Error::<Runtime>::PermitExpired
+				);
+
+				// permits disabled
+				let next = permit(1, 20);
+				EnableDepositPermits::set(&false);
+				assert_noop!(
+					SygmaBridge::deposit_with_permit(
+						Origin::signed(ALICE),
+						Box::new(next.clone()),
+						sign(&owner_pair, &next),
+					),
+					bridge::Error::<Runtime>::DepositPermitsDisabled
+				);
+				EnableDepositPermits::set(&true);
+				assert_ok!(SygmaBridge::deposit_with_permit(
+					Origin::signed(ALICE),
+					Box::new(next.clone()),
+					sign(&owner_pair, &next),
+				));
+				assert_eq!(Balances::free_balance(&owner), amount);
+				assert_eq!(PermitNonces::<Runtime>::get(&owner), 2);
+			})
+		}
 
+		#[test]
+		fn route_pause_should_apply_per_direction() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					fee
+					Box::new(NativeLocation::get().into()),
+					100
 				));
 				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
+					Box::new(NativeLocation::get().into()),
 					FeeHandlerType::BasicFeeHandler,
 				));
 				assert_ok!(SygmaBridge::register_domain(
@@ -1472,221 +16597,694 @@ pub mod pallet {
 					U256::from(1)
 				));
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					BridgeAccountNative::get(),
+					ENDOWED_BALANCE
+				));
 
-				// Register foreign asset (USDT) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_noop!(
+					SygmaBridge::pause_route(
+						Origin::signed(ALICE),
+						RouteDirection::Inbound,
+						DEST_DOMAIN_ID
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::unpause_route(
+						Origin::root(),
+						RouteDirection::Inbound,
+						DEST_DOMAIN_ID
+					),
+					bridge::Error::<Runtime>::BridgeUnpaused
+				);
 
-				// Mint some USDT to ALICE for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					ALICE,
-					ENDOWED_BALANCE,
-				));
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(200)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]),
+							),
+						}),
+					)
+				};
+				// amounts are in 18 decimals, 1_000_000 credits 1
+				let execute = |deposit_nonce: DepositNonce| {
+					let proposals = vec![Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							1_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					}];
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					assert_ok!(SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode()
+					));
+				};
 
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				// (inbound paused, outbound paused)
+				let quadrants = [(false, false), (true, false), (false, true), (true, true)];
+				for (deposit_nonce, (inbound, outbound)) in (1..).zip(quadrants) {
+					for (direction, paused) in
+						[(RouteDirection::Inbound, inbound), (RouteDirection::Outbound, outbound)]
+					{
+						if paused {
+							assert_ok!(SygmaBridge::pause_route(
+								Origin::root(),
+								direction,
+								DEST_DOMAIN_ID
+							));
+							assert_events(vec![RuntimeEvent::SygmaBridge(
+								SygmaBridgeEvent::RoutePaused {
+									direction,
+									domain_id: DEST_DOMAIN_ID,
+								},
+							)]);
+						}
+					}
+					assert_eq!(SygmaBridge::is_paused(DEST_DOMAIN_ID), inbound || outbound);
+
+					if outbound {
+						assert_noop!(deposit(), bridge::Error::<Runtime>::BridgePaused);
+					} else {
+						assert_ok!(deposit());
+					}
+					let bridge_status =
+						SygmaBridge::bridge_status(DEST_DOMAIN_ID, NativeLocation::get(), None);
+					assert_eq!(bridge_status.domain_paused, outbound);
+
+					execute(deposit_nonce);
+					assert_eq!(
+						SygmaBridge::is_proposal_executed(deposit_nonce, DEST_DOMAIN_ID),
+						!inbound
+					);
+
+					for (direction, paused) in
+						[(RouteDirection::Inbound, inbound), (RouteDirection::Outbound, outbound)]
+					{
+						if paused {
+							assert_ok!(SygmaBridge::unpause_route(
+								Origin::root(),
+								direction,
+								DEST_DOMAIN_ID
+							));
+							assert_events(vec![RuntimeEvent::SygmaBridge(
+								SygmaBridgeEvent::RouteUnpaused {
+									direction,
+									domain_id: DEST_DOMAIN_ID,
+								},
+							)]);
+						}
+					}
+					assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+					assert_eq!(SygmaBridge::pause_reasons(DEST_DOMAIN_ID), None);
+				}
+
+				// the coarse calls set both directions
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(PausedRoutes::<Runtime>::get((RouteDirection::Inbound, DEST_DOMAIN_ID)));
+				assert!(PausedRoutes::<Runtime>::get((RouteDirection::Outbound, DEST_DOMAIN_ID)));
+				assert_ok!(SygmaBridge::unpause_route(
+					Origin::root(),
+					RouteDirection::Outbound,
+					DEST_DOMAIN_ID
 				));
-				// Check balances
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
-				// USDT in the mock runtime has been configured as the reserved token, so the corresponding account should hold the deposit balance
+				assert!(SygmaBridge::is_route_paused(RouteDirection::Inbound, DEST_DOMAIN_ID));
 				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					amount - fee
+					SygmaBridge::pause_reasons(DEST_DOMAIN_ID).map(|info| info.reason),
+					Some(PauseReason::Governance)
 				);
-				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), fee);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							amount - fee,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						fee_amount: fee,
-						fee_asset_id: UsdtLocation::get().into(),
-					}),
-				]);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_route_paused(RouteDirection::Inbound, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_route_paused(RouteDirection::Outbound, DEST_DOMAIN_ID));
 			})
 		}
 
 		#[test]
-		fn deposit_unbounded_asset_should_fail() {
+		fn deposit_with_swapped_dest_junctions_should_fail() {
 			new_test_ext().execute_with(|| {
-				let unbounded_asset_location = MultiLocation::new(1, X1(GeneralIndex(123)));
 				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(200u128)).into();
+				let recipient = slice_to_generalkey(b"ethereum recipient");
+				let domain = slice_to_generalkey(&[DEST_DOMAIN_ID.into_inner()]);
 
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(unbounded_asset_location.into()),
-					fee
+					Box::new(NativeLocation::get().into()),
+					100
 				));
-				assert_ok!(SygmaBridge::register_domain(
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					U256::from(1)
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 
+				// domain junction before the account junction
+				let swapped = MultiLocation::new(0, X2(domain, recipient));
+				assert_eq!(
+					SygmaBridge::extract_dest_data(&swapped),
+					Err(bridge::Error::<Runtime>::InvalidDestination.into())
+				);
 				assert_noop!(
 					SygmaBridge::deposit(
 						Origin::signed(ALICE),
-						Box::new((Concrete(unbounded_asset_location), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+						Box::new(asset.clone()),
+						Box::new(swapped)
 					),
-					bridge::Error::<Runtime>::AssetNotBound
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+				assert_noop!(
+					SygmaBridge::commit_deposit(
+						Origin::signed(ALICE),
+						0,
+						Box::new(asset.clone()),
+						Box::new(swapped)
+					),
+					bridge::Error::<Runtime>::InvalidDestination
+				);
+
+				// junctions no order can parse are still an extraction failure
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(MultiLocation::new(0, X2(GeneralIndex(0), recipient)))
+					),
+					bridge::Error::<Runtime>::ExtractDestDataFailed
 				);
+
+				// the expected order goes through
+				let dest = MultiLocation::new(0, X2(recipient, domain));
+				assert_eq!(
+					SygmaBridge::extract_dest_data(&dest),
+					Ok((b"ethereum recipient".to_vec(), DEST_DOMAIN_ID))
+				);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset),
+					Box::new(dest)
+				));
 			})
 		}
 
 		#[test]
-		fn deposit_to_unrecognized_dest_should_fail() {
+		fn deposit_root_should_commit_every_deposit_of_the_block() {
 			new_test_ext().execute_with(|| {
-				let invalid_dest = MultiLocation::new(
-					0,
-					X2(GeneralIndex(0), slice_to_generalkey(b"ethereum recipient")),
-				);
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
-
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
 					fee
 				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+					)
+				};
+
+				// nothing is committed by default
+				assert_ok!(deposit());
+				SygmaBridge::on_finalize(1);
+				assert_eq!(SygmaBridge::deposit_roots(1), None);
+
+				EnableDepositRoots::set(&true);
+				System::set_block_number(2);
+				System::reset_events();
+				for _ in 0..3 {
+					assert_ok!(deposit());
+				}
+				let leaves: Vec<[u8; 32]> = System::events()
+					.into_iter()
+					.filter_map(|record| match record.event {
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+							dest_domain_id,
+							resource_id,
+							deposit_nonce,
+							deposit_data,
+							..
+						}) => Some(sygma_codec::deposit_leaf(
+							dest_domain_id.into_inner(),
+							deposit_nonce,
+							resource_id,
+							&deposit_data,
+						)),
+						_ => None,
+					})
+					.collect();
+				assert_eq!(leaves.len(), 3);
+				SygmaBridge::on_finalize(2);
+				let root = SygmaBridge::deposit_roots(2).unwrap();
+				assert_eq!(root, sygma_codec::merkle_root(&leaves));
+				for (i, leaf) in leaves.iter().enumerate() {
+					let proof = sygma_codec::merkle_proof(&leaves, i).unwrap();
+					assert!(sygma_codec::verify_merkle_proof(root, *leaf, &proof));
+				}
+				assert_eq!(BlockDepositLeafCount::<Runtime>::get(), 0);
+				assert_eq!(BlockDepositLeaves::<Runtime>::iter().count(), 0);
+
+				// a block without deposits has no root
+				System::set_block_number(3);
+				SygmaBridge::on_finalize(3);
+				assert_eq!(SygmaBridge::deposit_roots(3), None);
+			})
+		}
+
+		#[test]
+		fn deposit_events_should_be_emitted_in_canonical_order() {
+			new_test_ext().execute_with(|| {
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let native_asset_id: XcmAssetId = NativeLocation::get().into();
+				let asset: MultiAsset = (native_asset_id, Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				SplitFees::set(&true);
+				PauseOnDepositNonceAnomaly::set(&true);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset.clone()),
+					Box::new(dest)
+				));
+
+				// half of the fee is prefunded, the fee is split between the partner and the
+				// stakers and the tampered nonce pauses the domain after the deposit
+				assert_ok!(SygmaBridge::prefund_fees(
+					Origin::signed(ALICE),
+					Box::new(native_asset_id),
+					fee / 2
+				));
+				DepositCounts::<Runtime>::insert(DEST_DOMAIN_ID, 5);
+				System::reset_events();
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(asset),
+					Box::new(dest)
+				));
+				assert_eq!(Balances::free_balance(FeePartnerAccount::get()), fee);
+
+				// the assets move first, then the fee events, `Deposit` and what follows it
+				let events: Vec<RuntimeEvent> =
+					System::events().into_iter().map(|r| r.event).collect();
+				let first_bridge_event = events
+					.iter()
+					.position(|event| matches!(event, RuntimeEvent::SygmaBridge(_)))
+					.unwrap();
+				assert!(first_bridge_event > 0);
+				assert_eq!(
+					events[first_bridge_event..].to_vec(),
+					vec![
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::PrefundConsumed {
+							account: ALICE,
+							asset_id: native_asset_id,
+							amount: fee / 2,
+						}),
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+							fee_payer: ALICE,
+							dest_domain_id: DEST_DOMAIN_ID,
+							resource_id: NativeResourceId::get(),
+							fee_amount: fee,
+							fee_asset_id: native_asset_id,
+						}),
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+							dest_domain_id: DEST_DOMAIN_ID,
+							resource_id: NativeResourceId::get(),
+							deposit_nonce: 5,
+							transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 5),
+							sender: ALICE,
+							transfer_type: TransferType::FungibleTransfer,
+							deposit_data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								(amount - fee / 2) * 1_000_000,
+								b"ethereum recipient".to_vec(),
+							),
+							handler_response: vec![],
+						}),
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositNonceAnomaly {
+							domain: DEST_DOMAIN_ID,
+							expected: 1,
+							actual: 5,
+						}),
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+							dest_domain_id: DEST_DOMAIN_ID,
+							reason: PauseReason::CircuitBreaker { resource: None },
+							note: None,
+						}),
+					]
+				);
+			})
+		}
+
+		#[test]
+		fn proposal_events_should_be_emitted_in_canonical_order() {
+			new_test_ext().execute_with(|| {
+				let final_domain_id: DomainID = DomainID::new(2);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				for domain_id in [DEST_DOMAIN_ID, final_domain_id] {
+					assert_ok!(SygmaBridge::register_domain(
+						Origin::root(),
+						domain_id,
+						U256::from(domain_id.into_inner())
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+					assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), domain_id));
+				}
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::set_ack_enabled(Origin::root(), DEST_DOMAIN_ID, true));
+				for account in [BridgeAccountNative::get(), SygmaBridge::ack_fee_pot_account()] {
+					assert_ok!(Balances::force_set_balance(
+						Origin::root(),
+						account,
+						ENDOWED_BALANCE
+					));
+				}
+
+				// a forwarded transfer, a malformed proposal and a transfer credited locally
+				let proposals = vec![
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 0,
+						resource_id: NativeResourceId::get(),
+						data: [
+							SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								amount * 1_000_000,
+								b"ethereum recipient".to_vec(),
+							),
+							vec![EXTRA_TAG_ROUTE, 1, final_domain_id.into_inner()],
+						]
+						.concat(),
+					},
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						resource_id: NativeResourceId::get(),
+						data: vec![1u8],
+					},
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount * 1_000_000,
+							MultiLocation::new(
+								0,
+								X1(AccountId32 { network: None, id: BOB.into() }),
+							)
+							.encode(),
+						),
+					},
+				];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				System::reset_events();
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
+				));
 
-				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(invalid_dest),
-					),
-					bridge::Error::<Runtime>::ExtractDestDataFailed
+				// each proposal emits the events of its execution, its outcome, then the events
+				// of its acknowledgment, before the next proposal
+				let bridge_events: Vec<String> = System::events()
+					.into_iter()
+					.filter_map(|r| match r.event {
+						RuntimeEvent::SygmaBridge(event) => {
+							format!("{event:?}").split_whitespace().next().map(String::from)
+						},
+						_ => None,
+					})
+					.collect();
+				assert_eq!(
+					bridge_events,
+					vec![
+						"FeeCollected",
+						"Deposit",
+						"TransferForwarded",
+						"ProposalExecution",
+						"FeeCollected",
+						"Deposit",
+						"AckSent",
+						"FailedHandlerExecution",
+						"ProposalExecution",
+						"FeeCollected",
+						"Deposit",
+						"AckSent",
+					]
 				);
 			})
 		}
 
 		#[test]
-		fn deposit_without_fee_set_should_fail() {
+		fn deposit_multi_should_move_every_asset_under_one_nonce() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200u128;
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let usdt_fee = 100u128;
+				let usdt_amount = 300u128;
+				let native: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let usdt: MultiAsset =
+					(Concrete(UsdtLocation::get()), Fungible(usdt_amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				for (location, asset_fee) in
+					[(NativeLocation::get(), fee), (UsdtLocation::get(), usdt_fee)]
+				{
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						asset_fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(location.into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+
+				// assets are resolved in order, each resource at most once
+				assert_eq!(
+					SygmaBridge::resolve_deposit_assets(&[native.clone(), usdt.clone()]),
+					Ok(vec![
+						(NativeLocation::get().into(), NativeResourceId::get(), amount),
+						(UsdtLocation::get().into(), UsdtResourceId::get(), usdt_amount),
+					])
+				);
+				assert_eq!(
+					SygmaBridge::resolve_deposit_assets(&[native.clone(), native.clone()]),
+					Err(bridge::Error::<Runtime>::InvalidAssetList.into())
+				);
+				let unbound: MultiAsset =
+					(Concrete(MultiLocation::new(2, Here)), Fungible(amount)).into();
+				assert_eq!(
+					SygmaBridge::resolve_deposit_assets(&[native.clone(), unbound.clone()]),
+					Err(bridge::Error::<Runtime>::AssetNotBound.into())
+				);
+
+				// the asset count is bounded, nothing moves unless every asset resolves
 				assert_noop!(
-					SygmaBridge::deposit(
+					SygmaBridge::deposit_multi(Origin::signed(ALICE), vec![], Box::new(dest)),
+					bridge::Error::<Runtime>::InvalidAssetList
+				);
+				assert_noop!(
+					SygmaBridge::deposit_multi(
 						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+						vec![native.clone(), usdt.clone(), unbound.clone()],
+						Box::new(dest)
 					),
-					bridge::Error::<Runtime>::MissingFeeConfig
+					bridge::Error::<Runtime>::TooManyAssets
 				);
-			})
-		}
-
-		#[test]
-		fn deposit_less_than_fee_should_fail() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 200u128;
-				let amount = 100u128;
-
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
 				assert_noop!(
-					SygmaBridge::deposit(
+					SygmaBridge::deposit_multi(
 						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+						vec![native.clone(), unbound],
+						Box::new(dest)
 					),
-					bridge::Error::<Runtime>::FeeTooExpensive
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+
+				assert_ok!(SygmaBridge::deposit_multi(
+					Origin::signed(ALICE),
+					vec![native, usdt],
+					Box::new(dest)
+				));
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), &ALICE),
+					ENDOWED_BALANCE - usdt_amount
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), usdt_fee);
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 1);
+
+				// one deposit carrying a line item per asset, in 18 decimals
+				let items = vec![
+					(NativeResourceId::get(), (amount - fee) * 1_000_000),
+					(UsdtResourceId::get(), usdt_amount - usdt_fee),
+				];
+				let deposit_data = sygma_codec::multi_asset_deposit_data(
+					DataFormat::EvmAbi,
+					&items,
+					b"ethereum recipient",
 				);
+				assert_eq!(
+					sygma_codec::split_multi_asset_deposit_data(DataFormat::EvmAbi, &deposit_data),
+					Some((items, &b"ethereum recipient"[..]))
+				);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: usdt_fee,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: MultiAssetResourceId::get(),
+						deposit_nonce: 0,
+						transfer_id: SygmaBridge::transfer_id(DEST_DOMAIN_ID, 0),
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data,
+						handler_response: vec![],
+					}),
+				]);
 			})
 		}
 
 		#[test]
-		fn deposit_when_bridge_paused_should_fail() {
+		fn domain_sunset_should_walk_through_its_lifecycle() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
-
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
@@ -1699,157 +17297,157 @@ pub mod pallet {
 					Box::new(NativeLocation::get().into()),
 					FeeHandlerType::BasicFeeHandler,
 				));
-				// register domain
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				// set mpc address will also unpause all bridges
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// Pause bridge again
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				// Should failed
-				assert_noop!(
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let deposit = || {
 					SygmaBridge::deposit(
 						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::BridgePaused
-				);
-				// Unpause bridge
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				// Should success
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-			})
-		}
-
-		#[test]
-		fn deposit_without_mpc_set_should_fail() {
-			new_test_ext().execute_with(|| {
-				let fee = 200u128;
-				let amount = 100u128;
+						Box::new(asset.clone()),
+						Box::new(dest),
+					)
+				};
 
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
+				System::set_block_number(1);
+				// only admin can schedule a sunset, of a registered domain in the future
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					SygmaBridge::schedule_domain_sunset(Origin::signed(ALICE), DEST_DOMAIN_ID, 5),
+					bridge::Error::<Runtime>::AccessDenied
 				);
-			})
-		}
+				assert_noop!(
+					SygmaBridge::schedule_domain_sunset(Origin::root(), DomainID::new(2), 5),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+				assert_noop!(
+					SygmaBridge::schedule_domain_sunset(Origin::root(), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::InvalidSunsetBlock
+				);
+				assert_ok!(SygmaBridge::schedule_domain_sunset(Origin::root(), DEST_DOMAIN_ID, 3));
+				// the cutoff can be moved until it's reached
+				assert_ok!(SygmaBridge::schedule_domain_sunset(Origin::root(), DEST_DOMAIN_ID, 5));
+				assert_eq!(SygmaBridge::domain_sunsets(DEST_DOMAIN_ID), Some(5));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DomainSunsetScheduled {
+						domain_id: DEST_DOMAIN_ID,
+						deposit_cutoff_block: 5,
+					},
+				)]);
 
-		#[test]
-		fn retry_bridge() {
-			new_test_ext().execute_with(|| {
-				// should be access denied SINCE Alice does not have permission to retry
+				// deposits are accepted until the cutoff block
+				System::set_block_number(4);
+				SygmaBridge::on_initialize(4);
+				for _ in 0..3 {
+					assert_ok!(deposit());
+				}
+				assert!(!System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DomainSunset {
+						domain_id: DEST_DOMAIN_ID
+					})));
+
+				// the sunset is announced at the cutoff block, from which deposits are rejected
+				System::set_block_number(5);
+				SygmaBridge::on_initialize(5);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DomainSunset {
+					domain_id: DEST_DOMAIN_ID,
+				})]);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::DomainSunset);
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::reserve_nonce(Origin::signed(ALICE), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DomainSunset
+				);
+				assert_noop!(
+					SygmaBridge::schedule_domain_sunset(Origin::root(), DEST_DOMAIN_ID, 10),
+					bridge::Error::<Runtime>::DomainSunset
 				);
 
-				// Grant ALICE the access of `retry`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"retry".to_vec(),
-					ALICE
+				// proposals from the domain are still executed, one of them fails
+				System::set_block_number(6);
+				SygmaBridge::on_initialize(6);
+				let bob_location =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+				let proposals = vec![
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						resource_id: NativeResourceId::get(),
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount - fee,
+							bob_location.encode(),
+						),
+					},
+					Proposal {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						resource_id: [2u8; 32],
+						data: SygmaBridge::create_deposit_data(
+							DataFormat::EvmAbi,
+							amount - fee,
+							bob_location.encode(),
+						),
+					},
+				];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					pair.sign_prehashed(&final_message).encode()
 				));
-
-				// mpc address is missing, should fail
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 2));
+				assert!(!System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DomainSunset {
+						domain_id: DEST_DOMAIN_ID
+					})));
+
+				// executions from the domain are behind the 3 deposits made to it, the domain
+				// can only be unregistered with its backlog by force
+				assert_eq!(SygmaBridge::highest_executed_nonce(DEST_DOMAIN_ID), Some(1));
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					SygmaBridge::unregister_domain(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						U256::from(1),
+						false
+					),
+					bridge::Error::<Runtime>::DomainBacklogNotDrained
 				);
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1),
+					true
+				));
+				assert_eq!(SygmaBridge::domain_sunsets(DEST_DOMAIN_ID), None);
 
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// a registered back domain accepts deposits again once unpaused
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-
-				// pause bridge after set mpc address and retry, should fail
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::BridgePaused
-				);
-
-				// unpause bridge
 				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(!IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-
-				// retry again, should work
-				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
-					deposit_on_block_height: 1234567u128,
-					dest_domain_id: DEST_DOMAIN_ID,
-					sender: ALICE,
-				})]);
+				assert_ok!(deposit());
 			})
 		}
 
 		#[test]
-		fn proposal_execution_should_work() {
+		fn deposit_should_be_cancelled_by_its_sender_within_the_window() {
 			new_test_ext().execute_with(|| {
-				// mpc address is missing, should fail
-				assert_noop!(
-					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
-					bridge::Error::<Runtime>::MissingMpcAddress,
-				);
-				// set mpc address to generated keypair's address
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-
-				// Generate an evil key
-				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
-
-				// Deposit some native asset in advance
 				let fee = 1_000_000_000_000u128;
-				let amount: u128 = 200_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
@@ -1862,1660 +17460,3342 @@ pub mod pallet {
 					Box::new(NativeLocation::get().into()),
 					FeeHandlerType::BasicFeeHandler,
 				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 
-				// Register foreign asset (USDT) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				System::set_block_number(1);
+				for _ in 0..2 {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset.clone()),
+						Box::new(dest)
+					));
+				}
 
-				// Mint 400 USDT to liquidity holder for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-							.unwrap()
-					),
-					400_000_000_000_000,
-				));
-				// alice deposit 200 - 1 token fee native token, so the native token holder should have 199 tokens
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_000_000_000_000
+				// only the sender of a recorded deposit can cancel it
+				assert_noop!(
+					SygmaBridge::cancel_deposit(Origin::signed(BOB), DEST_DOMAIN_ID, 0),
+					bridge::Error::<Runtime>::NotDepositSender
 				);
-				// USDT liquidity holder should have 400 USDT at this moment
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					400_000_000_000_000
+				assert_noop!(
+					SygmaBridge::cancel_deposit(Origin::signed(ALICE), DEST_DOMAIN_ID, 2),
+					bridge::Error::<Runtime>::DepositRecordNotFound
 				);
 
-				// Generate proposals
-				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
-				// 0.000200000000
-				let valid_native_transfer_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 1,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				// amount is in 18 decimal 0.000200000000000000, will be convert to 18 decimal
-				// 0.000200000000000000
-				let valid_usdt_transfer_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 2,
-					resource_id: UsdtResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let invalid_depositnonce_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 2,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let invalid_domainid_proposal = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 3,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let invalid_resourceid_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 3,
-					resource_id: [2u8; 32],
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let invalid_recipient_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 3,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(amount, b"invalid recipient".to_vec()),
-				};
-				let empty_data_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 3,
-					resource_id: UsdtResourceId::get(),
-					data: vec![],
-				};
-
-				let proposals = vec![
-					valid_native_transfer_proposal,
-					valid_usdt_transfer_proposal,
-					invalid_depositnonce_proposal,
-					invalid_domainid_proposal,
-					invalid_resourceid_proposal,
-					invalid_recipient_proposal,
-					empty_data_proposal,
-				];
-
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
-				let proposals_with_bad_signature = evil_pair.sign_prehashed(&final_message);
-
-				// Should failed if dest domain 1 bridge paused
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals.clone(),
-					proposals_with_valid_signature.encode()
-				));
-				// should emit FailedHandlerExecution event
+				// last block of the window, the cancellation resolves the pending refund
+				assert_ok!(SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 1));
+				System::set_block_number(1 + CancellationWindow::get() - 1);
+				let balance_before = Balances::free_balance(ALICE);
+				assert_ok!(SygmaBridge::cancel_deposit(Origin::signed(ALICE), DEST_DOMAIN_ID, 1));
+				// bridged amount is returned, the fee is not
+				assert_eq!(Balances::free_balance(ALICE), balance_before + amount - fee);
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::FailedHandlerExecution {
-						error: vec![66, 114, 105, 100, 103, 101, 80, 97, 117, 115, 101, 100],
-						origin_domain_id: 1,
-						deposit_nonce: 3,
+					SygmaBridgeEvent::DepositCancelled {
+						dest_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						sender: ALICE,
+						asset_id: NativeLocation::get().into(),
+						amount: amount - fee,
 					},
 				)]);
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-
-				assert_noop!(
-					SygmaBridge::execute_proposal(
-						Origin::signed(ALICE),
-						proposals.clone(),
-						proposals_with_bad_signature.encode(),
-					),
-					bridge::Error::<Runtime>::BadMpcSignature,
-				);
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE);
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 0);
-				assert!(SygmaBridge::verify_by_mpc_address(
-					final_message,
-					proposals_with_valid_signature.encode(),
-				));
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals,
-					proposals_with_valid_signature.encode(),
-				));
-				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
-				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
-				// usdt is defined in 18 decimal so that converted amount is the same as in proposal
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), amount);
+				let record = DepositRecords::<Runtime>::get((DEST_DOMAIN_ID, 1)).unwrap();
+				assert!(record.cancelled && !record.refund_requested);
+				assert_eq!(SygmaBridge::cancelled_deposits(DEST_DOMAIN_ID, 1), Some(5));
 
-				// liquidity holder accounts balance after proposals execution
-				// 199 - 0.0002 native token is 198.999800000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_000_000_000_000 - 200_000_000
-				);
-				// 400 USDT after transferring out the USDT proposal, should remain 200 USDT
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					200_000_000_000_000
+				// a cancelled deposit is neither cancelled again nor refunded
+				assert_noop!(
+					SygmaBridge::cancel_deposit(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::DepositAlreadyCancelled
+				);
+				assert_noop!(
+					SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::DepositAlreadyCancelled
+				);
+				assert_noop!(
+					SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::DepositAlreadyCancelled
+				);
+
+				// past the window the deposit can only be refunded
+				System::set_block_number(1 + CancellationWindow::get());
+				assert_noop!(
+					SygmaBridge::cancel_deposit(Origin::signed(ALICE), DEST_DOMAIN_ID, 0),
+					bridge::Error::<Runtime>::CancellationWindowElapsed
 				);
+				assert_ok!(SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 0));
+				assert_ok!(SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 0));
+
+				// the cancelled record is pruned, the cancelled nonce is kept for reconciliation
+				System::set_block_number(1 + DepositRecordRetention::get());
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositRecordsPruned { count: 1 },
+				)]);
+				assert_eq!(DepositRecords::<Runtime>::count(), 0);
+				assert_eq!(SygmaBridge::cancelled_deposits(DEST_DOMAIN_ID, 1), Some(5));
 			})
 		}
 
 		#[test]
-		fn get_bridge_pause_status() {
+		fn would_rate_limit_should_predict_throttled_deposits() {
 			new_test_ext().execute_with(|| {
-				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
-
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				// register domain
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				// 200 - 1 in 18 decimals
+				let bridged = 199_000_000_000_000_000_000u128;
+				let resource_id = NativeResourceId::get();
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							),
+						}),
+					)
+				};
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), MpcAddress([1u8; 20])));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 
-				// pause bridge
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				// nothing is limited without a bucket nor a cap
+				assert!(!SygmaBridge::would_rate_limit(resource_id, u128::MAX));
 
-				// unpause bridge
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				System::set_block_number(1);
+				assert_ok!(SygmaBridge::set_token_bucket(
+					Origin::root(),
+					resource_id,
+					Some(TokenBucketConfig {
+						capacity: 2 * bridged,
+						refill_per_block: bridged / 10
+					})
+				));
+				assert!(!SygmaBridge::would_rate_limit(resource_id, 2 * bridged));
+				assert!(SygmaBridge::would_rate_limit(resource_id, 2 * bridged + 1));
+				// other resources have their own bucket
+				assert!(!SygmaBridge::would_rate_limit(UsdtResourceId::get(), 2 * bridged + 1));
+
+				// at the limit the deposit goes through, below it bounces
+				assert_ok!(deposit());
+				assert!(!SygmaBridge::would_rate_limit(resource_id, bridged));
+				assert_ok!(deposit());
+				assert!(SygmaBridge::would_rate_limit(resource_id, bridged));
+				assert_noop!(deposit(), bridge::Error::<Runtime>::RateLimitExceeded);
+
+				// refill is accounted for, without being stored
+				System::set_block_number(11);
+				assert!(!SygmaBridge::would_rate_limit(resource_id, bridged));
+				assert!(SygmaBridge::would_rate_limit(resource_id, bridged + 1));
+				assert_eq!(SygmaBridge::token_bucket(resource_id), Some((0, 1)));
+				assert_ok!(deposit());
+
+				// the TVL cap applies to the resource once the bucket is removed
+				assert_ok!(SygmaBridge::set_token_bucket(Origin::root(), resource_id, None));
+				assert_ok!(SygmaBridge::set_global_tvl_cap(
+					Origin::root(),
+					Some(SygmaBridge::global_tvl() + bridged)
+				));
+				assert!(!SygmaBridge::would_rate_limit(resource_id, bridged));
+				assert!(SygmaBridge::would_rate_limit(resource_id, bridged + 1));
+				assert_ok!(deposit());
+				assert!(SygmaBridge::would_rate_limit(resource_id, 1));
+				assert_ok!(SygmaBridge::set_global_tvl_cap(Origin::root(), None));
+				assert!(!SygmaBridge::would_rate_limit(resource_id, bridged));
+
+				// a circuit breaker tripped by the resource stops any amount of it
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					PauseReason::CircuitBreaker { resource: Some(resource_id) },
+					None
+				));
+				assert!(SygmaBridge::would_rate_limit(resource_id, 0));
+				assert!(!SygmaBridge::would_rate_limit(UsdtResourceId::get(), 0));
 			})
 		}
 
 		#[test]
-		fn access_control() {
+		fn emergency_withdraw_should_be_gated_and_timelocked() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let from_account = SygmaBridge::bridge_account(&BridgeAccount::Pallet);
+				let native: AssetId = NativeLocation::get().into();
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					from_account.clone(),
+					1_000
+				));
 
+				// only the committee can withdraw
 				assert_noop!(
-					SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::emergency_withdraw(
+						Origin::signed(ALICE),
+						BridgeAccount::Pallet,
+						Box::new(native),
+						400,
+						BOB
+					),
+					DispatchError::BadOrigin
 				);
+				// the bridge must be halted
+				assert_noop!(
+					SygmaBridge::emergency_withdraw(
+						Origin::root(),
+						BridgeAccount::Pallet,
+						Box::new(native),
+						400,
+						BOB
+					),
+					bridge::Error::<Runtime>::BridgeNotHalted
+				);
+				assert_ok!(SygmaBridge::pause_all_bridges(Origin::root()));
+
+				// first call schedules the withdrawal
+				assert_ok!(SygmaBridge::emergency_withdraw(
+					Origin::root(),
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
+					BOB
+				));
+				let executable_at = 1 + EmergencyWithdrawalDelay::get();
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EmergencyWithdrawalScheduled {
+						from: BridgeAccount::Pallet,
+						asset_id: native,
+						amount: 400,
+						to: BOB,
+						executable_at,
+					},
+				)]);
+				assert_eq!(Balances::free_balance(&from_account), 1_000);
 
+				System::set_block_number(executable_at - 1);
 				assert_noop!(
-					SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::emergency_withdraw(
+						Origin::root(),
+						BridgeAccount::Pallet,
+						Box::new(native),
+						400,
+						BOB
+					),
+					bridge::Error::<Runtime>::EmergencyWithdrawalTimelocked
 				);
+
+				// the bridge is halted again by the time the delay elapses
+				System::set_block_number(executable_at);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 				assert_noop!(
-					SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::emergency_withdraw(
+						Origin::root(),
+						BridgeAccount::Pallet,
+						Box::new(native),
+						400,
+						BOB
+					),
+					bridge::Error::<Runtime>::BridgeNotHalted
 				);
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
 
-				// Grant ALICE the access of `set_mpc_address`
-				assert_ok!(AccessSegregator::grant_access(
+				let bob_balance = Balances::free_balance(&BOB);
+				assert_ok!(SygmaBridge::emergency_withdraw(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"set_mpc_address".to_vec(),
-					ALICE
-				));
-				// Grant BOB the access of `pause_bridge` and `unpause_bridge`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
 					BOB
 				));
-				assert_ok!(AccessSegregator::grant_access(
+				assert_eq!(Balances::free_balance(&from_account), 600);
+				assert_eq!(Balances::free_balance(&BOB), bob_balance + 400);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EmergencyWithdrawal {
+						from: BridgeAccount::Pallet,
+						asset_id: native,
+						amount: 400,
+						to: BOB,
+					},
+				)]);
+				assert_eq!(
+					SygmaBridge::manual_interventions().last().map(|i| (i.operation, i.subject)),
+					Some((
+						InterventionOperation::EmergencyWithdraw,
+						InterventionSubject::Account { account: BridgeAccount::Pallet }
+					))
+				);
+
+				// an executed withdrawal has to be scheduled again
+				assert_ok!(SygmaBridge::emergency_withdraw(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_bridge".to_vec(),
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
 					BOB
 				));
+				assert_eq!(Balances::free_balance(&from_account), 600);
 
-				// BOB set mpc address should still failed
-				assert_noop!(
-					SygmaBridge::set_mpc_address(Some(BOB).into(), test_mpc_addr),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				// ALICE set mpc address should work
-				assert_ok!(SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr));
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
+				// so does one left unexecuted past its delay
+				System::set_block_number(executable_at + 2 * EmergencyWithdrawalDelay::get());
+				assert_ok!(SygmaBridge::emergency_withdraw(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
+					BOB
 				));
-
-				// ALICE pause&unpause bridge should still failed
-				assert_noop!(
-					SygmaBridge::pause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert_noop!(
-					SygmaBridge::unpause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				// BOB pause&unpause bridge should work
-				assert_ok!(SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
-				assert_ok!(SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(&from_account), 600);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::EmergencyWithdrawalScheduled {
+						from: BridgeAccount::Pallet,
+						asset_id: native,
+						amount: 400,
+						to: BOB,
+						executable_at: executable_at + 3 * EmergencyWithdrawalDelay::get(),
+					},
+				)]);
 			})
 		}
 
 		#[test]
-		fn multi_domain_test() {
+		fn emergency_withdraw_without_delay_should_not_expire() {
 			new_test_ext().execute_with(|| {
-				// root register domainID 1 with chainID 0, should be ok
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), 1u8, U256::from(0)));
-
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// alice register domainID 1 with chainID 1, should raise error AccessDenied
-				assert_noop!(
-					SygmaBridge::register_domain(Origin::from(Some(ALICE)), 1u8, U256::from(1)),
-					Error::<Runtime>::AccessDenied
-				);
-				// Grant ALICE the access of `register_domain`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				// alice register domainID 1 with chainID 1, should be ok
+				EmergencyWithdrawalDelay::set(&0);
+				let from_account = SygmaBridge::bridge_account(&BridgeAccount::Pallet);
+				let native: AssetId = NativeLocation::get().into();
 				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
+					Origin::root(),
+					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				// should emit RegisterDestDomain event
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					from_account.clone(),
+					1_000
+				));
+				assert_ok!(SygmaBridge::pause_all_bridges(Origin::root()));
+
+				// the first call still schedules the withdrawal, executable right away
+				assert_ok!(SygmaBridge::emergency_withdraw(
+					Origin::root(),
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
+					BOB
+				));
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::RegisterDestDomain {
-						sender: ALICE,
-						domain_id: 1,
-						chain_id: U256::from(1),
+					SygmaBridgeEvent::EmergencyWithdrawalScheduled {
+						from: BridgeAccount::Pallet,
+						asset_id: native,
+						amount: 400,
+						to: BOB,
+						executable_at: 1,
 					},
 				)]);
-				// storage check
-				assert!(DestDomainIds::<Runtime>::get(1u8));
-				assert_eq!(DestChainIds::<Runtime>::get(1u8).unwrap(), U256::from(1));
+				assert_eq!(Balances::free_balance(&from_account), 1_000);
 
-				// alice unregister domainID 1 with chainID 0, should raise error AccessDenied
-				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(0)),
-					Error::<Runtime>::AccessDenied
-				);
-				// Grant ALICE the access of `unregister_domain`
-				assert_ok!(AccessSegregator::grant_access(
+				// and it stays executable any number of blocks later
+				System::set_block_number(100);
+				let bob_balance = Balances::free_balance(&BOB);
+				assert_ok!(SygmaBridge::emergency_withdraw(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unregister_domain".to_vec(),
-					ALICE
-				));
-				// alice unregister domainID 1 with chainID 2, should raise error
-				// DestChainIDNotMatch
-				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(2)),
-					Error::<Runtime>::DestChainIDNotMatch
-				);
-				// alice unregister domainID 2 with chainID 2, should raise error
-				// DestDomainNotSupported
-				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 2u8, U256::from(2)),
-					Error::<Runtime>::DestDomainNotSupported
-				);
-				// alice unregister domainID 1 with chainID 1, should success
-				assert_ok!(SygmaBridge::unregister_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
-					U256::from(1)
+					BridgeAccount::Pallet,
+					Box::new(native),
+					400,
+					BOB
 				));
-				// should emit UnregisterDestDomain event
+				assert_eq!(Balances::free_balance(&from_account), 600);
+				assert_eq!(Balances::free_balance(&BOB), bob_balance + 400);
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::UnregisterDestDomain {
-						sender: ALICE,
-						domain_id: 1,
-						chain_id: U256::from(1),
+					SygmaBridgeEvent::EmergencyWithdrawal {
+						from: BridgeAccount::Pallet,
+						asset_id: native,
+						amount: 400,
+						to: BOB,
 					},
 				)]);
-
-				// storage check
-				// DomainID 1 should not support anymore
-				assert!(!DestDomainIds::<Runtime>::get(1u8));
-				// corresponding chainID should be None since kv not exist anymore
-				assert!(DestChainIds::<Runtime>::get(1u8).is_none());
 			})
 		}
 
 		#[test]
-		fn deposit_with_decimal_converter() {
+		fn resource_decimals_should_be_resolved_per_domain() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let resource_id = NativeResourceId::get();
+				let (domain_18, domain_6) = (DomainID::new(1), DomainID::new(2));
+				for domain_id in [domain_18, domain_6] {
+					assert_ok!(SygmaBridge::register_domain(
+						Origin::root(),
+						domain_id,
+						U256::from(domain_id.into_inner())
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::root()));
 
-				// native asset with 12 decimal
-				let fee_native_asset = 1_000_000_000_000u128; // 1.0 native asset
-				let amount_native_asset = 123_456_789_123_456u128; // 123.456_789_123_456
-				let adjusted_amount_native_asset = 122_456_789_123_456_000_000u128; // amount_native_asset - fee_native_asset then adjust it to 18 decimals
-
-				// usdt asset with 18 decimal
-				let fee_usdt_asset = 1_000_000_000_000_000_000u128; // 1.0 usdt asset
-				let amount_usdt_asset = 123_456_789_123_456_789_123u128; // 123.456_789_123_456_789_123
-				let adjusted_amount_usdt_asset = 122_456_789_123_456_789_123u128; // amount_usdt_asset - fee_usdt_asset then adjust it to 18 decimals
-
-				// astr asset with 24 decimal
-				let fee_astr_asset = 1_000_000_000_000_000_000_000_000u128; // 1.0 astr asset
-				let amount_astr_asset = 123_456_789_123_456_789_123_456_789u128; // 123.456_789_123_456_789_123_456_789
-				let adjusted_amount_astr_asset = 122_456_789_123_456_789_123u128; // amount_astr_asset - fee_astr_asset then adjust it to 18 decimals
-
-				// set fees
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_native_asset
-				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					fee_usdt_asset
-				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_noop!(
+					SygmaBridge::set_resource_decimals(
+						Origin::signed(ALICE),
+						resource_id,
+						Some(domain_6),
+						Some(6)
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::set_resource_decimals(
+						Origin::root(),
+						resource_id,
+						Some(domain_6),
+						Some(60)
+					),
+					bridge::Error::<Runtime>::InvalidDecimals
+				);
+				assert_ok!(SygmaBridge::set_resource_decimals(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					fee_astr_asset
+					resource_id,
+					Some(domain_6),
+					Some(6)
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::ResourceDecimalsSet {
+						resource_id,
+						domain_id: Some(domain_6),
+						decimals: Some(6),
+					},
+				)]);
+				assert_eq!(SygmaBridge::resource_decimals(&resource_id, domain_18), 18);
+				assert_eq!(SygmaBridge::resource_decimals(&resource_id, domain_6), 6);
+
+				// deposit data carries the amount in the decimals of each dest domain
+				for (domain_id, bridged_amount) in
+					[(domain_18, 199_000_000_000_000_000_000u128), (domain_6, 199_000_000)]
+				{
+					let asset: MultiAsset =
+						(Concrete(NativeLocation::get()), Fungible(amount)).into();
+					assert_eq!(
+						SygmaBridge::dest_amount(asset.clone(), domain_id),
+						Some(bridged_amount + bridged_amount / 199)
+					);
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(asset),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[domain_id.into_inner()]),
+							)
+						}),
+					));
+					let expected_data = SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						bridged_amount,
+						b"ethereum recipient".to_vec(),
+					);
+					assert!(System::events().iter().any(|r| matches!(
+						&r.event,
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+							dest_domain_id,
+							deposit_data,
+							..
+						}) if *dest_domain_id == domain_id && *deposit_data == expected_data
+					)));
+				}
+
+				// proposals from each domain carry the amount in its decimals and credit the
+				// same local amount
+				let proposals: Vec<Proposal> =
+					[(domain_18, 100_000_000_000_000_000_000u128), (domain_6, 100_000_000)]
+						.into_iter()
+						.map(|(origin_domain_id, bridged_amount)| Proposal {
+							origin_domain_id,
+							resource_id,
+							deposit_nonce: 1,
+							data: SygmaBridge::create_deposit_data(
+								DataFormat::EvmAbi,
+								bridged_amount,
+								MultiLocation::new(
+									0,
+									X1(AccountId32 { network: None, id: BOB.into() }),
+								)
+								.encode(),
+							),
+						})
+						.collect();
+				let signature = pair.sign_prehashed(
+					&SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals),
+				);
+				let bob_balance = Balances::free_balance(BOB);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 2 * 100_000_000_000_000);
+
+				// the entry of a domain takes precedence over the default of the resource
+				assert_ok!(SygmaBridge::set_resource_decimals(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					resource_id,
+					None,
+					Some(8)
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_eq!(SygmaBridge::resource_decimals(&resource_id, domain_18), 8);
+				assert_eq!(SygmaBridge::resource_decimals(&resource_id, domain_6), 6);
+				assert_ok!(SygmaBridge::set_resource_decimals(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					resource_id,
+					Some(domain_6),
+					None
 				));
+				assert_eq!(SygmaBridge::resource_decimals(&resource_id, domain_6), 8);
+			})
+		}
 
+		#[test]
+		fn proposals_of_a_delayed_resource_should_be_released_after_the_delay() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let resource_id = NativeResourceId::get();
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-
-				// deposit native asset which has 12 decimal
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(amount_native_asset)).into()
-					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount_native_asset);
-				// native asset should be reserved so that BridgeAccount should hold it
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(Balances::force_set_balance(
+					Origin::root(),
+					AccountId::new(
 						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
 							.unwrap()
-					)),
-					amount_native_asset - fee_native_asset
-				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee_native_asset);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_native_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: fee_native_asset,
-						fee_asset_id: NativeLocation::get().into(),
-					}),
-				]);
-
-				// deposit usdt asset which has 18 decimal
-				// Register foreign asset (usdt) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
-
-				// Mint some usdt to ALICE for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					ALICE,
-					ENDOWED_BALANCE,
-				)); // make sure Alice owns enough funds here
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
-
-				// deposit
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(UsdtLocation::get()), Fungible(amount_usdt_asset)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), &ALICE),
-					ENDOWED_BALANCE - amount_usdt_asset
-				);
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
 					),
-					122_456_789_123_456_789_123
-				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()),
-					fee_usdt_asset
-				);
-
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						deposit_nonce: 1,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_usdt_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						fee_amount: fee_usdt_asset,
-						fee_asset_id: UsdtLocation::get().into(),
-					}),
-				]);
-
-				// deposit astr asset which has 24 decimal
-				// Register foreign asset (astr) with asset id 1
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
-
-				// Mint some astr to ALICE for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(1),
-					ALICE,
-					ENDOWED_BALANCE,
-				)); // make sure Alice owns enough funds here
-				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), ENDOWED_BALANCE);
-
-				// deposit
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(AstrLocation::get()), Fungible(amount_astr_asset)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					ENDOWED_BALANCE
 				));
-				// Check balances
-				assert_eq!(
-					Assets::balance(AstrAssetId::get(), &ALICE),
-					ENDOWED_BALANCE - amount_astr_asset
+
+				assert_noop!(
+					SygmaBridge::set_settlement_delay(Origin::signed(ALICE), resource_id, Some(10)),
+					bridge::Error::<Runtime>::AccessDenied
 				);
-				// astr asset should be reserved so that BridgeAccount should hold it(Astr is not
-				// defined in ConcrateSygmaAsset)
-				assert_eq!(
-					Assets::balance(
-						AstrAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-								.unwrap()
-						),
+				assert_ok!(SygmaBridge::set_settlement_delay(
+					Origin::root(),
+					resource_id,
+					Some(10)
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::SettlementDelaySet { resource_id, delay: Some(10) },
+				)]);
+
+				let proposal = |deposit_nonce| Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					resource_id,
+					deposit_nonce,
+					data: SygmaBridge::create_deposit_data(
+						DataFormat::EvmAbi,
+						100_000_000_000_000_000_000, // 100 native with 18 decimals
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
 					),
-					amount_astr_asset - fee_astr_asset
-				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(
-					Assets::balance(AstrAssetId::get(), TreasuryAccount::get()),
-					fee_astr_asset
-				);
+				};
+				let execute = |proposals: Vec<Proposal>| {
+					let signature = pair.sign_prehashed(
+						&SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals),
+					);
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						signature.encode(),
+					)
+				};
+				let bob_balance = Balances::free_balance(BOB);
 
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: AstrResourceId::get(),
-						deposit_nonce: 2,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_astr_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: AstrResourceId::get(),
-						fee_amount: fee_astr_asset,
-						fee_asset_id: AstrLocation::get().into(),
-					}),
-				]);
+				// the proposal is only recorded
+				assert_ok!(execute(vec![proposal(1)]));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::SettlementScheduled {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						resource_id,
+						release_at: 11,
+					},
+				)]);
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(
+					SygmaBridge::pending_settlement(DEST_DOMAIN_ID, 1).map(|s| s.release_at),
+					Some(11)
+				);
 
-				// deposit astr asset which has 24 decimal, extreme small amount edge case
-				let amount_astr_asset_extreme_small_amount = 100_000; // 0.000000000000000000100000 astr
-				let fee_astr_asset_extreme_small_amount = 1;
-				// 0.000000000000000000000001 astr
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					fee_astr_asset_extreme_small_amount
-				));
-				// after decimal conversion from 24 to 18, the final amount will be 0 so that
-				// decimal conversion will raise error deposit should not work
+				// and can't be released before its release block
+				System::set_block_number(10);
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new(
-							(
-								Concrete(AstrLocation::get()),
-								Fungible(amount_astr_asset_extreme_small_amount)
-							)
-								.into()
-						),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::DecimalConversionFail
+					SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::SettlementDelayNotElapsed
 				);
+				assert_noop!(
+					SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 2),
+					bridge::Error::<Runtime>::SettlementNotFound
+				);
+
+				System::set_block_number(11);
+				assert_ok!(SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 1));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 100_000_000_000_000);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::pending_settlement(DEST_DOMAIN_ID, 1).is_none());
+				assert!(System::events().iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ProposalExecution {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						..
+					})
+				)));
+				assert_noop!(
+					SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::SettlementNotFound
+				);
+
+				// proposals are executed right away once the delay is removed
+				assert_ok!(SygmaBridge::set_settlement_delay(Origin::root(), resource_id, None));
+				assert_ok!(execute(vec![proposal(2)]));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 2 * 100_000_000_000_000);
+				assert!(SygmaBridge::pending_settlement(DEST_DOMAIN_ID, 2).is_none());
 			})
 		}
 
 		#[test]
-		fn proposal_execution_with_decimal_converter() {
+		fn emergency_freeze_should_pause_and_snapshot_the_bridge() {
 			new_test_ext().execute_with(|| {
-				// generate mpc keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
-				// set mpc address
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				// register domain
+				let domain_2: DomainID = DomainID::new(2);
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				let fee = 1_000_000_000_000u128; // 1 token in 12 decimals
-				let init_deposit = 10_000_000_000_000u128;
-				// 12 token in 12 decimal
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
+				let native_reserve = AccountId::new(
+					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
+				);
+				assert_ok!(Balances::force_set_balance(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
+					native_reserve.clone(),
+					ENDOWED_BALANCE
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				DepositCounts::<Runtime>::insert(DEST_DOMAIN_ID, 5);
+				MinUnprunedNonce::<Runtime>::insert(DEST_DOMAIN_ID, 3);
+				FailedProposalCounts::<Runtime>::insert(UsdtResourceId::get(), 2);
+
+				assert_noop!(
+					SygmaBridge::emergency_freeze(Origin::signed(ALICE), 3, 2),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// 3 resources are bound by the mock
+				assert_noop!(
+					SygmaBridge::emergency_freeze(Origin::root(), 2, 2),
+					bridge::Error::<Runtime>::FreezeWitnessTooLow
+				);
+
+				System::set_block_number(7);
+				assert_ok!(SygmaBridge::emergency_freeze(Origin::root(), 3, 2));
+				assert!(SygmaBridge::is_halted());
+
+				let snapshot = SygmaBridge::freeze_snapshot().unwrap();
+				assert_eq!(snapshot.frozen_at, 7);
+				assert_eq!(
+					snapshot
+						.resources
+						.iter()
+						.map(|r| (r.resource_id, r.failed_proposals))
+						.collect::<Vec<_>>(),
+					vec![
+						(NativeResourceId::get(), 0),
+						(UsdtResourceId::get(), 2),
+						(AstrResourceId::get(), 0)
+					]
+				);
+				assert_eq!(snapshot.resources[0].reserve_balance, Some(ENDOWED_BALANCE));
+				assert_eq!(
+					snapshot.domains.into_inner(),
+					vec![
+						DomainFreezeState {
+							domain_id: DEST_DOMAIN_ID,
+							deposit_nonce: 5,
+							min_unpruned_nonce: 3
+						},
+						DomainFreezeState {
+							domain_id: domain_2,
+							deposit_nonce: 0,
+							min_unpruned_nonce: 0
+						},
+					]
+				);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeFrozen {
+					sender: [0u8; 32].into(),
+					snapshot_hash: blake2_256(&SygmaBridge::freeze_snapshot().unwrap().encode()),
+				})]);
+
+				// the snapshot is not overwritten by a later freeze
+				assert_ok!(Balances::force_set_balance(Origin::root(), native_reserve, 1));
+				assert_noop!(
+					SygmaBridge::emergency_freeze(Origin::root(), 3, 2),
+					bridge::Error::<Runtime>::FreezeSnapshotExists
+				);
+				assert_eq!(
+					SygmaBridge::freeze_snapshot().unwrap().resources[0],
+					ResourceFreezeState {
+						resource_id: NativeResourceId::get(),
+						reserve_balance: Some(ENDOWED_BALANCE),
+						failed_proposals: 0,
+					}
+				);
+
+				assert_noop!(
+					SygmaBridge::clear_freeze_snapshot(Origin::signed(ALICE)),
+					DispatchError::BadOrigin
+				);
+				assert_ok!(SygmaBridge::clear_freeze_snapshot(Origin::root()));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FreezeSnapshotCleared,
+				)]);
+				assert!(SygmaBridge::freeze_snapshot().is_none());
+				assert!(SygmaBridge::is_halted());
+				assert_noop!(
+					SygmaBridge::clear_freeze_snapshot(Origin::root()),
+					bridge::Error::<Runtime>::FreezeSnapshotNotFound
+				);
+			})
+		}
+
+		#[test]
+		fn pending_settlements_should_list_proposals_until_finalized() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let resource_id = NativeResourceId::get();
+				assert_ok!(SygmaBridge::set_settlement_delay(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					resource_id,
+					Some(10)
 				));
-				// deposit in advance to make sure the native asset has enough funds in
-				// TransferReserveAccount by doing this, Alice will deposit (half of her native
-				// asset - fee) into TransferReserveAccount
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(ENDOWED_BALANCE / 2)).into()
-					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// BridgeAccount should have half of alice native asset - fee
+				assert!(SygmaBridge::pending_settlements(DEST_DOMAIN_ID).is_empty());
+
+				let proposal = |deposit_nonce| {
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						100_000_000_000_000_000_000,
+						&BOB,
+					)
+				};
+
+				assert_ok!(scenario.execute(vec![proposal(2), proposal(1)]));
+				System::set_block_number(4);
+				assert_ok!(scenario.execute(vec![proposal(3)]));
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					ENDOWED_BALANCE / 2 - fee
+					SygmaBridge::pending_settlements(DEST_DOMAIN_ID),
+					vec![(1, 11), (2, 11), (3, 14)]
 				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				assert!(SygmaBridge::pending_settlements(DomainID::new(2)).is_empty());
 
-				let bridge_amount = 100_000_000_000_000_000_000; // 100 native with 18 decimals
+				System::set_block_number(11);
+				assert_ok!(SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 1));
+				assert_eq!(
+					SygmaBridge::pending_settlements(DEST_DOMAIN_ID),
+					vec![(2, 11), (3, 14)]
+				);
+				assert_ok!(SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 2));
+				assert_eq!(SygmaBridge::pending_settlements(DEST_DOMAIN_ID), vec![(3, 14)]);
 
-				// proposal for bridging native asset to alice(native asset is 12 decimal)
-				let p_native = Proposal {
-					origin_domain_id: 1,
-					resource_id: NativeResourceId::get(),
-					deposit_nonce: 1,
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
-					),
+				System::set_block_number(14);
+				assert_ok!(SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 3));
+				assert!(SygmaBridge::pending_settlements(DEST_DOMAIN_ID).is_empty());
+			})
+		}
+
+		#[test]
+		fn cancelled_settlement_should_not_be_finalized() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let resource_id = NativeResourceId::get();
+				assert_ok!(SygmaBridge::set_settlement_delay(
+					Origin::root(),
+					resource_id,
+					Some(10)
+				));
+
+				let proposal = |deposit_nonce| {
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						100_000_000_000_000_000_000,
+						&BOB,
+					)
 				};
-				let proposals = vec![p_native];
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let signature = pair.sign_prehashed(&final_message);
+				let bob_balance = Balances::free_balance(BOB);
+				assert_ok!(scenario.execute(vec![proposal(1), proposal(2)]));
 
-				// check Alice balance of native asset before executing, should have half of the
-				// init native asset
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE / 2);
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals,
-					signature.encode()
+				assert_noop!(
+					SygmaBridge::cancel_settlement(Origin::signed(ALICE), DEST_DOMAIN_ID, 1),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::cancel_settlement(Origin::root(), DEST_DOMAIN_ID, 3),
+					bridge::Error::<Runtime>::SettlementNotFound
+				);
+
+				// cancelled before its release, along with the failure left by a former attempt
+				FailedProposals::<Runtime>::insert(
+					DEST_DOMAIN_ID,
+					1,
+					FailedProposal {
+						reason: ExecutionFailureReason::InsufficientReserve,
+						detail: b"InsufficientReserve".to_vec().try_into().unwrap(),
+					},
+				);
+				FailedProposalCounts::<Runtime>::insert(resource_id, 1);
+				System::set_block_number(5);
+				assert_ok!(SygmaBridge::cancel_settlement(Origin::root(), DEST_DOMAIN_ID, 1));
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_eq!(FailedProposalCounts::<Runtime>::get(resource_id), 0);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::SettlementCancelled {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+						resource_id,
+					},
+				)]);
+				assert!(SygmaBridge::pending_settlement(DEST_DOMAIN_ID, 1).is_none());
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// the proposal can't be finalized nor scheduled again
+				System::set_block_number(11);
+				assert_noop!(
+					SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 1),
+					bridge::Error::<Runtime>::SettlementNotFound
+				);
+				assert_ok!(scenario.execute(vec![proposal(1)]));
+				assert!(SygmaBridge::pending_settlement(DEST_DOMAIN_ID, 1).is_none());
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+
+				// the other settlement is still released
+				assert_ok!(SygmaBridge::finalize_proposal(Origin::signed(BOB), DEST_DOMAIN_ID, 2));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 100_000_000_000_000);
+
+				// cancellation can be disabled
+				assert_ok!(scenario.execute(vec![proposal(3)]));
+				EnableSettlementCancellation::set(&false);
+				assert_noop!(
+					SygmaBridge::cancel_settlement(Origin::root(), DEST_DOMAIN_ID, 3),
+					bridge::Error::<Runtime>::SettlementCancellationDisabled
+				);
+			})
+		}
+
+		#[test]
+		fn proposal_should_fail_if_the_source_amount_hint_disagrees() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let resource_id = NativeResourceId::get();
+				let native_reserve = scenario.reserve_account(&native());
+
+				// 100 native locked on the origin chain, where the asset has 6 decimals
+				let hint = [&100_000_000u128.to_be_bytes()[..], &[6u8]].concat();
+				let proposal = |deposit_nonce| {
+					// 100 native with 18 decimals
+					let mut proposal = scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						100_000_000_000_000_000_000,
+						&BOB,
+					);
+					proposal.data.extend([EXTRA_TAG_SOURCE_AMOUNT, 17]);
+					proposal.data.extend(hint.clone());
+					proposal
+				};
+
+				// the hint agrees with the amount to credit
+				let bob_balance = Balances::free_balance(BOB);
+				assert_ok!(scenario.execute(vec![proposal(1)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 100_000_000_000_000);
+
+				// the amounts of the origin domain are configured in 6 decimals by mistake, the
+				// proposal fails rather than crediting 10^12 times the amount
+				assert_ok!(SygmaBridge::set_resource_decimals(
+					Origin::root(),
+					resource_id,
+					Some(DEST_DOMAIN_ID),
+					Some(6)
 				));
-				// check Alice balance of native asset after executing, should have half of the init
-				// native asset + 100_000_000_000_000(12 decimal)
+				let bob_balance = Balances::free_balance(BOB);
+				let reserve_balance = Balances::free_balance(native_reserve.clone());
+				assert_ok!(scenario.execute(vec![proposal(2)]));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert_eq!(Balances::free_balance(native_reserve), reserve_balance);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DecimalMismatch {
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						hinted_amount: 100_000_000,
+						hinted_decimals: 6,
+						expected_amount: Some(100_000_000_000_000),
+						credited_amount: 100_000_000_000_000_000_000_000_000,
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "DecimalMismatch".as_bytes().to_vec(),
+						reason: ExecutionFailureReason::DecimalMismatch,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					}),
+				]);
+
+				// a malformed hint is rejected
+				let mut malformed = proposal(3);
+				malformed.data.pop();
+				malformed.data[malformed.data.len() - 17] = 16;
+				assert_ok!(scenario.execute(vec![malformed]));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+			})
+		}
+
+		#[test]
+		fn deposit_should_carry_asset_metadata_if_attached() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let deposit = || scenario.deposit(&native(), 200_000_000_000_000, DEST_DOMAIN_ID);
+				let deposit_data = |deposit_nonce| {
+					System::events()
+						.into_iter()
+						.find_map(|r| match r.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_nonce: nonce,
+								deposit_data,
+								..
+							}) if nonce == deposit_nonce => Some(deposit_data),
+							_ => None,
+						})
+						.unwrap()
+				};
+				let payload = SygmaBridge::create_deposit_data(
+					DataFormat::EvmAbi,
+					199_000_000_000_000_000_000,
+					b"ethereum recipient".to_vec(),
+				);
+
+				// no metadata unless attached
+				assert_ok!(deposit());
+				assert_eq!(deposit_data(0), payload);
+
+				AttachAssetMetadata::set(&true);
+				assert_ok!(deposit());
+				let metadata = sygma_codec::asset_metadata(12, b"Native", b"NAT").unwrap();
 				assert_eq!(
-					Balances::free_balance(ALICE),
-					ENDOWED_BALANCE / 2 + 100_000_000_000_000
+					deposit_data(1),
+					[payload, vec![EXTRA_TAG_ASSET_METADATA, metadata.len() as u8], metadata]
+						.concat()
+				);
+				// metadata entry is skipped by the readers of deposit data
+				assert_eq!(
+					SygmaBridge::extract_routed_deposit_data(DataFormat::EvmAbi, &deposit_data(1)),
+					Ok(None)
 				);
 
-				// proposal for bridging usdt asset to alice(usdt asset is 18 decimal)
-				// Register foreign asset (usdt) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				// metadata of other assets is not attached, or too large to be
+				assert_eq!(
+					SygmaBridge::asset_metadata_extra(&UsdtLocation::get().into()),
+					Ok(vec![])
+				);
+				assert_eq!(
+					SygmaBridge::asset_metadata_extra(&AstrLocation::get().into()),
+					Err(bridge::Error::<Runtime>::AssetMetadataTooLarge.into())
+				);
+			})
+		}
 
-				// Mint some USDT to liquidity holder for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-							.unwrap()
-					),
-					ENDOWED_BALANCE,
-				));
+		#[test]
+		fn scenario_fee_router_should_charge_each_asset_with_its_handler() {
+			let fee = 1_000_000_000_000u128;
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_asset(usdt())
+				.with_asset(usdc())
+				.with_flat_fee(fee)
+				.with_fee(
+					&usdt(),
+					FeeConfig::Percentage {
+						rate_basis_point: 100,
+						lower_bound: 10,
+						upper_bound: 1_000,
+					},
+				)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let treasury = TreasuryAccount::get();
+				for (asset, handler_type) in [
+					(native(), FeeHandlerType::BasicFeeHandler),
+					(usdt(), FeeHandlerType::PercentageFeeHandler),
+					(usdc(), FeeHandlerType::BasicFeeHandler),
+				] {
+					assert_eq!(
+						SygmaFeeHandlerRouter::handler_type((DEST_DOMAIN_ID, asset.id())),
+						Some(handler_type)
+					);
+				}
+
+				// flat fee of the native asset and of the onboarded USDC
+				let native_treasury = scenario.balance(&native(), &treasury);
+				assert_ok!(scenario.deposit(&native(), 200_000_000_000_000, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&native(), &treasury), native_treasury + fee);
 				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					ENDOWED_BALANCE
+					scenario.balance(&native(), &scenario.reserve_account(&native())),
+					199_000_000_000_000
+				);
+				assert_ok!(scenario.deposit(&usdc(), 1_000_000_000_000_000_000, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&usdc(), &treasury), fee);
+				assert_eq!(
+					scenario.balance(&usdc(), &scenario.reserve_account(&usdc())),
+					999_999_000_000_000_000
 				);
 
-				let p_usdt = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 2,
-					resource_id: UsdtResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
-					),
+				// 1% of USDT, bounded by the lower then by the upper bound
+				assert_ok!(scenario.deposit(&usdt(), 500, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&usdt(), &treasury), 10);
+				assert_ok!(scenario.deposit(&usdt(), 1_000_000, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&usdt(), &treasury), 1_010);
+				assert_eq!(
+					scenario.balance(&usdt(), &scenario.reserve_account(&usdt())),
+					490 + 999_000
+				);
+				assert_eq!(SygmaBridge::deposit_counts(DEST_DOMAIN_ID), 4);
+			})
+		}
+
+		#[test]
+		fn scenario_mintable_asset_should_round_trip() {
+			let fee = 1_000_000_000_000_000u128;
+			let amount = 1_000_000_000_000_000_000u128;
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(weth())
+				.with_flat_fee(fee)
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let weth = weth();
+				let reserve_account = scenario.reserve_account(&weth);
+				assert!(!scenario.is_reserved(&weth));
+				assert_eq!(scenario.balance(&weth, &reserve_account), 0);
+				let issuance = scenario.total_issuance(&weth);
+
+				// the bridged amount is burned rather than locked, the fee is kept
+				assert_ok!(scenario.deposit(&weth, amount, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&weth, &ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(scenario.balance(&weth, &TreasuryAccount::get()), fee);
+				assert_eq!(scenario.balance(&weth, &reserve_account), 0);
+				assert_eq!(scenario.total_issuance(&weth), issuance - amount + fee);
+
+				// and minted back when it returns
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					1,
+					&weth,
+					amount - fee,
+					&BOB
+				)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&weth, &BOB), amount - fee);
+				assert_eq!(scenario.balance(&weth, &reserve_account), 0);
+				assert_eq!(scenario.total_issuance(&weth), issuance);
+			})
+		}
+
+		#[test]
+		fn scenario_paused_route_should_only_stop_its_direction() {
+			let domain_b = DomainID::new(2);
+			let amount = 200_000_000_000_000u128;
+			let builder = || {
+				ScenarioBuilder::new()
+					.with_domain(1, 1)
+					.with_domain(2, 2)
+					.with_asset(native())
+					.with_flat_fee(1_000_000_000_000)
+					.with_mpc(test_key())
+					.with_reserve_balance(ENDOWED_BALANCE)
+			};
+
+			for paused in [
+				vec![RouteDirection::Inbound],
+				vec![RouteDirection::Outbound],
+				RouteDirection::ALL.to_vec(),
+			] {
+				let (mut ext, scenario) = builder().build();
+				ext.execute_with(|| {
+					for direction in &paused {
+						assert_ok!(SygmaBridge::pause_route(
+							Origin::root(),
+							*direction,
+							DEST_DOMAIN_ID
+						));
+					}
+
+					// deposits to the paused domain fail if its outbound route is paused
+					let deposit = scenario.deposit(&native(), amount, DEST_DOMAIN_ID);
+					if paused.contains(&RouteDirection::Outbound) {
+						assert_eq!(deposit, Err(bridge::Error::<Runtime>::BridgePaused.into()));
+					} else {
+						assert_ok!(deposit);
+					}
+					assert_ok!(scenario.deposit(&native(), amount, domain_b));
+
+					// proposals from the paused domain fail if its inbound route is paused
+					assert_ok!(scenario.execute(vec![
+						scenario.proposal(DEST_DOMAIN_ID, 1, &native(), amount, &BOB),
+						scenario.proposal(domain_b, 1, &native(), amount, &BOB),
+					]));
+					assert_eq!(
+						SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID),
+						!paused.contains(&RouteDirection::Inbound)
+					);
+					assert!(SygmaBridge::is_proposal_executed(1, domain_b));
+				})
+			}
+
+			// every route of a scenario built paused is stopped
+			let (mut ext, scenario) = builder().paused().build();
+			ext.execute_with(|| {
+				for domain_id in &scenario.domains {
+					assert_eq!(
+						scenario.deposit(&native(), amount, *domain_id),
+						Err(bridge::Error::<Runtime>::BridgePaused.into())
+					);
+					assert_ok!(scenario.execute(vec![scenario.proposal(
+						*domain_id,
+						1,
+						&native(),
+						amount,
+						&BOB
+					)]));
+					assert!(!SygmaBridge::is_proposal_executed(1, *domain_id));
+				}
+			})
+		}
+
+		#[test]
+		fn scenario_escrowed_transfer_should_be_claimed_once_deliverable() {
+			let usdt = ScenarioAsset { min_balance: 100, ..usdt() };
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(usdt.clone())
+				.with_mpc(test_key())
+				.with_reserve_balance(1_000)
+				.build();
+			ext.execute_with(|| {
+				let reserve_account = scenario.reserve_account(&usdt);
+				let escrow_account = SygmaBridge::escrow_account(&usdt.resource_id);
+				let claim = || {
+					SygmaBridge::claim_escrowed_transfer(Origin::signed(ALICE), DEST_DOMAIN_ID, 1)
 				};
-				let proposals_usdt = vec![p_usdt];
-				let final_message_usdt =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_usdt);
-				let signature_usdt = pair.sign_prehashed(&final_message_usdt);
+				// escrow holding account is kept alive
+				scenario.fund(&usdt, &escrow_account, 100);
 
-				// alice does not have any usdt at this moment
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), 0);
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals_usdt,
-					signature_usdt.encode()
-				));
-				// alice should have 100 usdt at this moment (100 usdt with 18 decimals)
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), &ALICE),
-					100_000_000_000_000_000_000
+				// 50 is below the min balance of a recipient not holding any USDT
+				let recipient = AccountId::new([9u8; 32]);
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					1,
+					&usdt,
+					50,
+					&recipient
+				)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_some());
+				assert_eq!(scenario.balance(&usdt, &reserve_account), 950);
+				assert_eq!(scenario.balance(&usdt, &escrow_account), 150);
+				assert_noop!(claim(), bridge::Error::<Runtime>::TransactFailed);
+
+				scenario.fund(&usdt, &recipient, 100);
+				assert_ok!(claim());
+				assert_eq!(scenario.balance(&usdt, &recipient), 150);
+				assert_eq!(scenario.balance(&usdt, &escrow_account), 100);
+				assert!(UnclaimedTransfers::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_noop!(claim(), bridge::Error::<Runtime>::EscrowedTransferNotFound);
+			})
+		}
+
+		#[test]
+		fn scenario_batch_with_duplicates_should_execute_each_nonce_once() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				// 40 nonces, 10 of which are submitted twice
+				let proposals: Vec<Proposal> = (1..=40)
+					.chain(1..=10)
+					.map(|deposit_nonce| {
+						scenario.proposal(
+							DEST_DOMAIN_ID,
+							deposit_nonce,
+							&native(),
+							1_000_000_000_000_000_000,
+							&BOB,
+						)
+					})
+					.collect();
+				assert_eq!(proposals.len(), 50);
+				let bob_balance = Balances::free_balance(BOB);
+
+				assert_ok!(scenario.execute(proposals));
+				assert!(
+					(1..=40).all(|nonce| SygmaBridge::is_proposal_executed(nonce, DEST_DOMAIN_ID))
 				);
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 40 * 1_000_000_000_000);
+				let duplicates: Vec<DepositNonce> = System::events()
+					.into_iter()
+					.filter_map(|r| match r.event {
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+							error,
+							deposit_nonce,
+							..
+						}) if error == b"ProposalAlreadyComplete".to_vec() => Some(deposit_nonce),
+						_ => None,
+					})
+					.collect();
+				assert_eq!(duplicates, (1..=10).collect::<Vec<_>>());
+			})
+		}
 
-				// proposal for bridging astr asset to alice(astr asset is 24 decimal)
-				// Register foreign asset (astr) with asset id 1
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
-				// Mint some astr to BridgeAccount for test because astr is reserved asset for
-				// testing
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(1),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-							.unwrap()
-					),
-					ENDOWED_BALANCE
-				));
+		#[test]
+		fn reentrant_execute_proposal_should_be_rejected() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let proposal = |deposit_nonce| {
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						100_000_000_000_000_000_000,
+						&BOB,
+					)
+				};
+				let bob_balance = Balances::free_balance(BOB);
+
+				// the transactor reenters with a validly signed batch while crediting nonce 1
+				let reentrant_batch = vec![proposal(2)];
+				ReentrantCall::set(&Some((
+					reentrant_batch.clone(),
+					scenario.sign(&reentrant_batch),
+				)));
+				assert_ok!(scenario.execute(vec![proposal(1)]));
 				assert_eq!(
-					Assets::balance(
-						AstrAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-								.unwrap()
-						),
-					),
-					ENDOWED_BALANCE
+					ReentrantCallResult::get(),
+					Some(Err(bridge::Error::<Runtime>::ReentrancyDetected.into()))
+				);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 100_000_000_000_000);
+				assert!(!ExecutionLock::<Runtime>::get());
+
+				// the batch goes through once submitted on its own
+				assert_ok!(scenario.execute(reentrant_batch));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 200_000_000_000_000);
+
+				// nothing is executed while the lock is held
+				ExecutionLock::<Runtime>::put(true);
+				assert_noop!(
+					scenario.execute(vec![proposal(3)]),
+					bridge::Error::<Runtime>::ReentrancyDetected
 				);
+			})
+		}
 
-				let p_astr = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 3,
-					resource_id: AstrResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
-					),
+		#[test]
+		fn reregistered_domain_should_keep_its_nonces_and_start_paused() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let amount = 200_000_000_000_000u128;
+				let last_deposit_nonce = || {
+					System::events()
+						.into_iter()
+						.filter_map(|r| match r.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_nonce,
+								..
+							}) => Some(deposit_nonce),
+							_ => None,
+						})
+						.last()
 				};
-				let proposals_astr = vec![p_astr];
-				let final_message_astr =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_astr);
-				let signature_astr = pair.sign_prehashed(&final_message_astr);
+				let old_batch = vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					1,
+					&native(),
+					100_000_000_000_000_000_000,
+					&BOB,
+				)];
+				assert_ok!(scenario.deposit(&native(), amount, DEST_DOMAIN_ID));
+				assert_eq!(last_deposit_nonce(), Some(0));
+				assert_ok!(scenario.execute(old_batch.clone()));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
 
-				// alice does not have any astr at this moment
-				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), 0);
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals_astr,
-					signature_astr.encode()
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1),
+					false
 				));
-				// alice should have 100 astr at this moment (100 astr with 24 decimals)
 				assert_eq!(
-					Assets::balance(AstrAssetId::get(), &ALICE),
-					100_000_000_000_000_000_000_000_000
+					SygmaBridge::unregistered_chain_ids(DEST_DOMAIN_ID),
+					Some(U256::from(1))
 				);
 
-				// extreme small amount edge case
-				let extreme_small_bridge_amount = 100_000; // 0.000000000000100000 native asset with 18 decimals
-										   // proposal for bridging native asset to alice(native asset is 12 decimal)
-				let p_native_extreme = Proposal {
-					origin_domain_id: 1,
-					resource_id: NativeResourceId::get(),
-					deposit_nonce: 4,
-					data: SygmaBridge::create_deposit_data(
-						extreme_small_bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
+				// the chainID only changes explicitly
+				assert_noop!(
+					SygmaBridge::register_domain(Origin::root(), DEST_DOMAIN_ID, U256::from(2)),
+					bridge::Error::<Runtime>::DestChainIDNotMatch
+				);
+				assert_noop!(
+					SygmaBridge::update_domain_chain_id(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						U256::from(2)
 					),
-				};
-				let proposals_extreme = vec![p_native_extreme];
-				let final_message_extreme =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_extreme);
-				let signature_extreme = pair.sign_prehashed(&final_message_extreme);
-
-				// execute_proposal extrinsic should work but it will actually failed at decimal
-				// conversion step because 0.000000000000100000 in 18 decimal converts to 12 decimal
-				// would be 0.000000000000 which is 0
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals_extreme,
-					signature_extreme.encode()
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::update_domain_chain_id(
+						Origin::root(),
+						DomainID::new(9),
+						U256::from(2)
+					),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+				assert_ok!(SygmaBridge::update_domain_chain_id(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(2)
 				));
-				// should emit FailedHandlerExecution event
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::FailedHandlerExecution {
-						error: vec![
-							68, 101, 99, 105, 109, 97, 108, 67, 111, 110, 118, 101, 114, 115, 105,
-							111, 110, 70, 97, 105, 108,
-						],
-						origin_domain_id: 1,
-						deposit_nonce: 4,
+					SygmaBridgeEvent::DomainChainIdUpdated {
+						domain_id: DEST_DOMAIN_ID,
+						old_chain_id: U256::from(1),
+						chain_id: U256::from(2),
 					},
 				)]);
-			})
-		}
 
-		#[test]
-		fn unpause_all_domains_test() {
-			new_test_ext().execute_with(|| {
-				// Grant ALICE the access of `register_domain`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					ALICE
-				));
-				// alice register some domains
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
-					U256::from(1)
-				));
+				// registered back paused
 				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					2u8,
+					Origin::root(),
+					DEST_DOMAIN_ID,
 					U256::from(2)
 				));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DomainReregistered {
+						sender: AccountId::new([0u8; 32]),
+						domain_id: DEST_DOMAIN_ID,
+						chain_id: U256::from(2),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+						dest_domain_id: DEST_DOMAIN_ID,
+						reason: PauseReason::Governance,
+						note: None,
+					}),
+				]);
+				assert_eq!(SygmaBridge::dest_chain_ids(DEST_DOMAIN_ID), Some(U256::from(2)));
+				assert_eq!(SygmaBridge::unregistered_chain_ids(DEST_DOMAIN_ID), None);
+				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
+				assert_noop!(
+					scenario.deposit(&native(), amount, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				// the batch signed before the unregistration is not executed again
+				let bob_balance = Balances::free_balance(BOB);
+				assert_ok!(scenario.execute(old_batch));
+				assert_eq!(Balances::free_balance(BOB), bob_balance);
+				assert!(System::events().into_iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error,
+						deposit_nonce: 1,
+						..
+					}) if error == b"ProposalAlreadyComplete".to_vec()
+				)));
+
+				// deposit nonces carry on
+				assert_ok!(scenario.deposit(&native(), amount, DEST_DOMAIN_ID));
+				assert_eq!(last_deposit_nonce(), Some(1));
+				assert_eq!(SygmaBridge::deposit_counts(DEST_DOMAIN_ID), 2);
+
+				// registering a registered domain again does not pause it
+				assert_noop!(
+					SygmaBridge::register_domain(Origin::root(), DEST_DOMAIN_ID, U256::from(3)),
+					bridge::Error::<Runtime>::DestChainIDNotMatch
+				);
 				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					3u8,
-					U256::from(3)
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(2)
 				));
+				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
+			})
+		}
 
-				// pause all
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 1));
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 2));
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 3));
+		#[test]
+		fn mpc_config_should_match_the_configured_signers() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(SygmaBridge::mpc_config(), (vec![], 0));
+
+				let mpc_addr = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr));
+				assert_eq!(SygmaBridge::mpc_config(), (vec![mpc_addr], 1));
+
+				// With a threshold the signers replace the single MPC address
+				RequireMpcThreshold::set(&true);
+				assert_eq!(SygmaBridge::mpc_config(), (vec![], 0));
+				let signers =
+					vec![MpcAddress([2u8; 20]), MpcAddress([3u8; 20]), MpcAddress([4u8; 20])];
+				assert_ok!(SygmaBridge::set_mpc_signers(Origin::root(), signers.clone(), 2));
+				assert_eq!(SygmaBridge::mpc_config(), (signers.clone(), 2));
+
+				let rotated = vec![MpcAddress([5u8; 20]), MpcAddress([6u8; 20])];
+				assert_ok!(SygmaBridge::set_mpc_signers(Origin::root(), rotated.clone(), 2));
+				assert_eq!(SygmaBridge::mpc_config(), (rotated, 2));
+			})
+		}
 
-				// double check if they are all paused
-				assert!(SygmaBridge::is_paused(1));
-				assert!(SygmaBridge::is_paused(2));
-				assert!(SygmaBridge::is_paused(3));
+		#[test]
+		fn execute_fresh_proposals_should_reject_batches_already_executed() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let proposals: Vec<Proposal> = (1..=3)
+					.map(|deposit_nonce| {
+						scenario.proposal(
+							DEST_DOMAIN_ID,
+							deposit_nonce,
+							&native(),
+							1_000_000_000_000_000_000,
+							&BOB,
+						)
+					})
+					.collect();
+				let execute_fresh = |expected_fresh: Vec<u8>| {
+					SygmaBridge::execute_fresh_proposals(
+						Origin::signed(ALICE),
+						proposals.clone(),
+						scenario.sign(&proposals),
+						expected_fresh,
+					)
+				};
 
-				SygmaBridge::unpause_all_domains();
+				// another relayer executed the first two nonces
+				assert_ok!(scenario.execute(proposals[..2].to_vec()));
 
-				// all domains should be unpaused now
-				assert!(!SygmaBridge::is_paused(1));
-				assert!(!SygmaBridge::is_paused(2));
-				assert!(!SygmaBridge::is_paused(3));
+				assert_noop!(execute_fresh(vec![]), bridge::Error::<Runtime>::InvalidFreshBitmap);
+				assert_noop!(
+					execute_fresh(vec![0b011, 0]),
+					bridge::Error::<Runtime>::InvalidFreshBitmap
+				);
+				// checked before the signature
+				assert_noop!(
+					SygmaBridge::execute_fresh_proposals(
+						Origin::signed(ALICE),
+						proposals.clone(),
+						vec![],
+						vec![0b011]
+					),
+					bridge::Error::<Runtime>::ProposalsAlreadyExecuted
+				);
+				assert_noop!(
+					execute_fresh(vec![0b011]),
+					bridge::Error::<Runtime>::ProposalsAlreadyExecuted
+				);
+				let bob_balance = Balances::free_balance(BOB);
+				let post_info = execute_fresh(vec![0b111]).unwrap();
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 1_000_000_000_000);
+				// the executed proposals are refunded
+				assert_eq!(
+					post_info.actual_weight,
+					Some(
+						SygmaBridge::execute_fresh_proposals_weight(&proposals)
+							.saturating_sub(SygmaBridge::proposal_weight().saturating_mul(2))
+					)
+				);
+				// without proposals expected fresh the batch is not filtered
+				let post_info = execute_fresh(vec![0]).unwrap();
+				assert_eq!(
+					post_info.actual_weight,
+					Some(
+						SygmaBridge::execute_fresh_proposals_weight(&proposals)
+							.saturating_sub(SygmaBridge::proposal_weight().saturating_mul(3))
+					)
+				);
 			})
 		}
 
 		#[test]
-		fn setup_order_test() {
-			new_test_ext().execute_with(|| {
-				// Make sure mpc address is not set
-				let default_addr: MpcAddress = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+		fn check_fresh_proposals_should_let_one_of_racing_relayers_in() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let batch = |nonces: Vec<DepositNonce>| {
+					let proposals: Vec<Proposal> = nonces
+						.into_iter()
+						.map(|deposit_nonce| {
+							scenario.proposal(
+								DEST_DOMAIN_ID,
+								deposit_nonce,
+								&native(),
+								1_000_000_000_000_000_000,
+								&BOB,
+							)
+						})
+						.collect();
+					RuntimeCall::SygmaBridge(bridge::Call::execute_fresh_proposals {
+						signature: scenario.sign(&proposals),
+						expected_fresh: vec![(1 << proposals.len()) - 1],
+						proposals,
+					})
+				};
+				let tag = |deposit_nonce: DepositNonce| {
+					("CheckFreshProposals", DEST_DOMAIN_ID, deposit_nonce).encode()
+				};
+				let validate = |who: &AccountId, call: &RuntimeCall| {
+					CheckFreshProposals::<Runtime>::new().validate(
+						who,
+						call,
+						&call.get_dispatch_info(),
+						0,
+					)
+				};
 
-				// Grant ALICE the access admin extrinsics
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unregister_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_bridge".to_vec(),
-					ALICE
+				// two relayers race with overlapping batches, the pool keeps one of them
+				let first = batch(vec![1, 2]);
+				let second = batch(vec![2, 3]);
+				assert_eq!(validate(&ALICE, &first).unwrap().provides, vec![tag(1), tag(2)]);
+				assert_eq!(validate(&BOB, &second).unwrap().provides, vec![tag(2), tag(3)]);
+
+				assert_ok!(CheckFreshProposals::<Runtime>::new().pre_dispatch(
+					&ALICE,
+					&first,
+					&first.get_dispatch_info(),
+					0
 				));
-				assert_ok!(AccessSegregator::grant_access(
+				assert_ok!(first.dispatch(Origin::signed(ALICE)));
+
+				// the second batch still has a fresh proposal
+				assert_eq!(validate(&BOB, &second).unwrap().provides, vec![tag(3)]);
+				// a batch of executed proposals is stale in the pool and the block
+				let stale = batch(vec![1, 2]);
+				assert_eq!(validate(&BOB, &stale), Err(InvalidTransaction::Stale.into()));
+				assert_eq!(
+					CheckFreshProposals::<Runtime>::new().pre_dispatch(
+						&BOB,
+						&stale,
+						&stale.get_dispatch_info(),
+						0
+					),
+					Err(InvalidTransaction::Stale.into())
+				);
+				let mut invalid = batch(vec![3]);
+				if let RuntimeCall::SygmaBridge(bridge::Call::execute_fresh_proposals {
+					expected_fresh,
+					..
+				}) = &mut invalid
+				{
+					expected_fresh.clear();
+				}
+				assert_eq!(validate(&BOB, &invalid), Err(InvalidTransaction::Call.into()));
+
+				// other calls are not checked
+				let other = RuntimeCall::SygmaBridge(bridge::Call::execute_proposal {
+					proposals: vec![],
+					signature: vec![],
+				});
+				assert_eq!(validate(&BOB, &other), Ok(Default::default()));
+			})
+		}
+
+		#[test]
+		fn normalized_counters_should_scale_amounts_but_not_transfers() {
+			let fee = 1_000_000_000_000u128;
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_asset(usdt())
+				.with_flat_fee(fee)
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let (native, usdt) = (native(), usdt());
+				// a native unit is worth 3 of the common unit, USDT is not scaled
+				AmountScales::set(&vec![(native.resource_id, 3)]);
+				let native_reserve = scenario.reserve_account(&native);
+				let native_before = scenario.balance(&native, &native_reserve);
+				let usdt_reserve = scenario.reserve_account(&usdt);
+				let usdt_before = scenario.balance(&usdt, &usdt_reserve);
+
+				// 199 native bridged in 18 decimals, the reserve locks the native amount
+				let amount = 200_000_000_000_000u128;
+				assert_ok!(scenario.deposit(&native, amount, DEST_DOMAIN_ID));
+				assert_eq!(
+					scenario.balance(&native, &native_reserve),
+					native_before + amount - fee
+				);
+				assert_eq!(SygmaBridge::global_tvl(), 3 * 199_000_000_000_000_000_000);
+				assert_eq!(SygmaBridge::account_volume(ALICE), 3 * 199_000_000_000_000_000_000);
+
+				let usdt_amount = 10_000_000_000_000_000_000u128;
+				assert_ok!(scenario.deposit(&usdt, usdt_amount, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&usdt, &usdt_reserve), usdt_before + usdt_amount - fee);
+				let locked = 3 * 199_000_000_000_000_000_000 + usdt_amount - fee;
+				assert_eq!(SygmaBridge::global_tvl(), locked);
+				assert_eq!(SygmaBridge::account_volume(ALICE), locked);
+
+				// the cap applies to the normalized value
+				assert_ok!(SygmaBridge::set_global_tvl_cap(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"retry".to_vec(),
-					ALICE
+					Some(locked + 2 * 100_000_000_000_000_000_000)
 				));
-
-				// alice setup bridges without mpc address setup
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(!SygmaBridge::would_rate_limit(
+					native.resource_id,
+					100_000_000_000_000_000_000
 				));
-				assert_ok!(SygmaBridge::unregister_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(SygmaBridge::would_rate_limit(
+					native.resource_id,
+					101_000_000_000_000_000_000
 				));
-				// register it back
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(!SygmaBridge::would_rate_limit(
+					usdt.resource_id,
+					101_000_000_000_000_000_000
 				));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::from(Some(ALICE)), 1u8));
-				// pause domain 2 again to see if mpc address setup will unpause it
-				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
 
-				// double check if it's paused
-				assert!(SygmaBridge::is_paused(1));
+				// executed proposals credit the native amount and release its scaled value
+				let bob_balance = scenario.balance(&native, &BOB);
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					1,
+					&native,
+					100_000_000_000_000_000_000,
+					&BOB
+				)]));
+				assert_eq!(scenario.balance(&native, &BOB), bob_balance + 100_000_000_000_000);
+				assert_eq!(SygmaBridge::global_tvl(), locked - 3 * 100_000_000_000_000_000_000);
+			})
+		}
 
-				// retry should not work here, should raise MissingMpcAddress
-				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::MissingMpcAddress
-				);
-				// deposit should not work, should raise MissingMpcAddress
+		#[test]
+		fn validate_deposit_should_report_the_failures_of_deposit() {
+			let fee = 1_000_000_000_000u128;
+			let amount = 200_000_000_000_000u128;
+			let builder = || {
+				ScenarioBuilder::new()
+					.with_domain(1, 1)
+					.with_asset(native())
+					.with_mpc(test_key())
+					.with_reserve_balance(ENDOWED_BALANCE)
+			};
+			// the dry-run reports the failure `deposit` returns
+			let check = |amount: u128,
+			             asset: MultiLocation,
+			             dest: MultiLocation,
+			             expected: DepositValidationError,
+			             error: bridge::Error<Runtime>| {
+				let asset: MultiAsset = (Concrete(asset), Fungible(amount)).into();
+				assert_eq!(SygmaBridge::validate_deposit(&ALICE, &asset, &dest), Err(expected));
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(AstrLocation::get()), Fungible(100)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					SygmaBridge::deposit(Origin::signed(ALICE), Box::new(asset), Box::new(dest)),
+					error
 				);
-				// proposal execution should not work either, should raise MissingMpcAddress
-				assert_noop!(
-					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
+			};
+			let native_location = NativeLocation::get();
+
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(fee)
+				.build();
+			ext.execute_with(|| {
+				check(
+					amount,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::MpcAddressMissing,
 					bridge::Error::<Runtime>::MissingMpcAddress,
 				);
+			});
 
-				// set mpc address to generated keypair's address
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+			let (mut ext, scenario) = builder().with_flat_fee(fee).paused().build();
+			ext.execute_with(|| {
+				check(
+					amount,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::DomainPaused { domain_id: DEST_DOMAIN_ID },
+					bridge::Error::<Runtime>::BridgePaused,
+				);
+			});
 
-				// double check if it's unpause now
-				assert!(!SygmaBridge::is_paused(1));
+			let (mut ext, scenario) = builder().build();
+			ext.execute_with(|| {
+				check(
+					amount,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::MissingFeeConfig {
+						resource_id: NativeResourceId::get(),
+						domain_id: DEST_DOMAIN_ID,
+					},
+					bridge::Error::<Runtime>::MissingFeeConfig,
+				);
+			});
 
-				// retry again, should work
-				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
-					deposit_on_block_height: 1234567u128,
-					dest_domain_id: DEST_DOMAIN_ID,
-					sender: ALICE,
-				})]);
+			let (mut ext, scenario) = builder().with_flat_fee(fee).build();
+			ext.execute_with(|| {
+				let unknown_domain = DomainID::new(9);
+				check(
+					amount,
+					native_location,
+					scenario.dest(unknown_domain),
+					DepositValidationError::DomainNotSupported { domain_id: unknown_domain },
+					bridge::Error::<Runtime>::UnsupportedDataFormat,
+				);
+				let unbound = MultiLocation::new(1, X1(Parachain(9999)));
+				check(
+					amount,
+					unbound,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::AssetNotBound {
+						asset_id: XcmAssetId::from(unbound).encode(),
+					},
+					bridge::Error::<Runtime>::AssetNotBound,
+				);
+				check(
+					fee,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::FeeTooExpensive { fee, min_amount: fee + 1 },
+					bridge::Error::<Runtime>::FeeTooExpensive,
+				);
+				check(
+					ENDOWED_BALANCE * 2,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::WithdrawFailed,
+					bridge::Error::<Runtime>::TransactFailed,
+				);
+				assert_ok!(SygmaBridge::set_global_tvl_cap(Origin::root(), Some(1)));
+				check(
+					amount,
+					native_location,
+					scenario.dest(DEST_DOMAIN_ID),
+					DepositValidationError::TvlCapExceeded { cap: 1, locked: 0 },
+					bridge::Error::<Runtime>::TvlCapExceeded,
+				);
+				assert_ok!(SygmaBridge::set_global_tvl_cap(Origin::root(), None));
+
+				// a valid deposit is dry-run without leaving anything behind
+				let asset: MultiAsset = (Concrete(native_location), Fungible(amount)).into();
+				let dest = scenario.dest(DEST_DOMAIN_ID);
+				let balance = Balances::free_balance(ALICE);
+				System::reset_events();
+				assert_eq!(SygmaBridge::validate_deposit(&ALICE, &asset, &dest), Ok(()));
+				assert_eq!(Balances::free_balance(ALICE), balance);
+				assert_eq!(SygmaBridge::global_tvl(), 0);
+				assert!(System::events().is_empty());
+				assert_ok!(scenario.deposit(&native(), amount, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(ALICE), balance - amount);
+			});
+		}
 
-				// deposit should work now
-				let fee = 1_000_000_000_000u128;
-				let amount = 200_000_000_000_000u128;
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+		#[test]
+		fn deposit_validation_error_encoding_should_be_stable() {
+			assert_eq!(DepositValidationError::MpcAddressMissing.encode(), vec![0]);
+			assert_eq!(DepositValidationError::WithdrawFailed.encode(), vec![7]);
+			assert_eq!(
+				DepositValidationError::FeeTooExpensive { fee: 1, min_amount: 2 }.encode(),
+				[vec![6], 1u128.encode(), 2u128.encode()].concat()
+			);
+			assert_eq!(DepositValidationError::Other(7).encode(), vec![11, 7]);
+		}
+
+		#[test]
+		fn proposals_over_recipient_inbound_cap_should_fail() {
+			let unit = 1_000_000_000_000_000_000u128;
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_asset(usdt())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let recipient = |account: AccountId32| {
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: account.into() }))
+						.encode()
+				};
+				let resource_id = NativeResourceId::get();
+				let capped = recipient(BOB);
+
+				// only the committee sets caps
+				assert_noop!(
+					SygmaBridge::set_recipient_inbound_cap(
+						Origin::signed(ALICE),
+						resource_id,
+						capped.clone(),
+						Some(2 * unit)
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::set_recipient_inbound_cap(
+						Origin::root(),
+						resource_id,
+						vec![1u8; MAX_RECIPIENT_LEN as usize + 1],
+						Some(2 * unit)
+					),
+					bridge::Error::<Runtime>::RecipientTooLong
+				);
+				assert_ok!(SygmaBridge::set_recipient_inbound_cap(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
+					resource_id,
+					capped.clone(),
+					Some(2 * unit)
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::RecipientInboundCapSet {
+						resource_id,
+						recipient: capped.clone(),
+						cap: Some(2 * unit),
+					},
+				)]);
+				let key = (resource_id, DeniedRecipient::truncate_from(capped.clone()));
+				assert_eq!(SygmaBridge::recipient_inbound_cap(&key), Some(2 * unit));
+
+				// the recipient receives up to its cap
+				let bob_balance = Balances::free_balance(BOB);
+				assert_ok!(scenario.execute(vec![
+					scenario.proposal(DEST_DOMAIN_ID, 1, &native(), unit, &BOB),
+					scenario.proposal(DEST_DOMAIN_ID, 2, &native(), unit, &BOB),
+					scenario.proposal(DEST_DOMAIN_ID, 3, &native(), unit, &BOB),
+				]));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 2 * 1_000_000_000_000);
+				assert_eq!(SygmaBridge::recipient_inbound(&key), 2 * unit);
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"RecipientCapExceeded".to_vec(),
+						reason: ExecutionFailureReason::InboundLimitExceeded,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 3,
+					})));
+
+				// other recipients and resources are not capped
+				assert_ok!(scenario.execute(vec![
+					scenario.proposal(DEST_DOMAIN_ID, 4, &native(), 3 * unit, &ALICE),
+					scenario.proposal(DEST_DOMAIN_ID, 5, &usdt(), 3 * unit, &BOB),
+				]));
+				assert!(SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+
+				// raising the cap lets the failed proposal through
+				assert_ok!(SygmaBridge::set_recipient_inbound_cap(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					resource_id,
+					capped.clone(),
+					Some(3 * unit)
 				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					3,
+					&native(),
+					unit,
+					&BOB
+				)]));
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::recipient_inbound(&key), 3 * unit);
+
+				// removing the cap clears the amount received
+				assert_ok!(SygmaBridge::set_recipient_inbound_cap(
+					Origin::root(),
+					resource_id,
+					capped,
+					None
 				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(SygmaBridge::recipient_inbound_cap(&key), None);
+				assert_eq!(SygmaBridge::recipient_inbound(&key), 0);
+			})
+		}
+
+		#[test]
+		fn proposals_crediting_protected_accounts_should_fail() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_asset(usdt())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let unit = 1_000_000_000_000_000_000u128;
+				let native_reserve = scenario.reserve_account(&native());
+				let usdt_reserve = scenario.reserve_account(&usdt());
+				let native_reserve_balance = scenario.balance(&native(), &native_reserve);
+				let usdt_reserve_balance = scenario.balance(&usdt(), &usdt_reserve);
+				let treasury_balance = scenario.balance(&native(), &TreasuryAccount::get());
+				let escrow = SygmaBridge::escrow_account(&NativeResourceId::get());
+				assert!(SygmaBridge::is_protected_account(&native_reserve));
+				assert!(SygmaBridge::is_protected_account(&escrow));
+				assert!(!SygmaBridge::is_protected_account(&BOB));
+
+				assert_ok!(scenario.execute(vec![
+					scenario.proposal(DEST_DOMAIN_ID, 1, &native(), unit, &native_reserve),
+					scenario.proposal(DEST_DOMAIN_ID, 2, &native(), unit, &TreasuryAccount::get()),
+					scenario.proposal(DEST_DOMAIN_ID, 3, &native(), unit, &escrow),
+					scenario.proposal(DEST_DOMAIN_ID, 4, &usdt(), unit, &usdt_reserve),
+				]));
+				for deposit_nonce in 1..=4 {
+					assert!(!SygmaBridge::is_proposal_executed(deposit_nonce, DEST_DOMAIN_ID));
+					assert!(System::events().iter().any(|r| r.event
+						== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+							error: b"ProtectedAccountRecipient".to_vec(),
+							reason: ExecutionFailureReason::ProtectedAccountRecipient,
+							origin_domain_id: DEST_DOMAIN_ID,
+							deposit_nonce,
+						})));
+				}
+				// no funds moved
+				assert_eq!(scenario.balance(&native(), &native_reserve), native_reserve_balance);
+				assert_eq!(scenario.balance(&usdt(), &usdt_reserve), usdt_reserve_balance);
+				assert_eq!(scenario.balance(&native(), &TreasuryAccount::get()), treasury_balance);
+				assert_eq!(scenario.balance(&native(), &escrow), 0);
+
+				// other recipients are credited
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					5,
+					&native(),
+					unit,
+					&BOB
+				)]));
+				assert!(SygmaBridge::is_proposal_executed(5, DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn deposit_should_send_notification_xcm_if_enabled() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let deposit = || scenario.deposit(&native(), 200_000_000_000_000, DEST_DOMAIN_ID);
+
+				// nothing is sent unless enabled
+				assert_ok!(deposit());
+				assert!(SentXcm::get().is_empty());
+
+				EnableDepositNotification::set(&true);
+				assert_ok!(deposit());
+				let transfer_id = SygmaBridge::transfer_id(DEST_DOMAIN_ID, 1);
+				let target = DepositNotificationTarget::get();
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
+					SentXcm::get(),
+					vec![(
+						MultiLocation::new(1, X1(Parachain(2005))),
+						Xcm(vec![
+							UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+							Transact {
+								origin_kind: OriginKind::SovereignAccount,
+								require_weight_at_most: target.weight,
+								call: [vec![42u8, 3], (DEST_DOMAIN_ID, 1u64, transfer_id).encode()]
+									.concat()
+									.into(),
+							},
+							SetTopic(transfer_id),
+						]),
+					)]
+				);
+				assert_eq!(
+					SentXcm::get()[0].1,
+					SygmaBridge::deposit_notification(&target, DEST_DOMAIN_ID, 1)
 				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
 
-				// proposal execution should work
-				let valid_native_transfer_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 1,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let proposals = vec![valid_native_transfer_proposal];
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
-				assert_ok!(SygmaBridge::execute_proposal(
+				// a failed notification does not fail the deposit
+				XcmSendFailure::set(&true);
+				assert_ok!(deposit());
+				assert_eq!(SentXcm::get().len(), 1);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::DepositNotificationFailed {
+						dest_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+						error: SendError::Transport("unreachable"),
+					},
+				)]);
+				assert_eq!(SygmaBridge::deposit_counts(DEST_DOMAIN_ID), 3);
+
+				// so is a multi-asset deposit
+				XcmSendFailure::set(&false);
+				assert_ok!(SygmaBridge::deposit_multi(
 					Origin::signed(ALICE),
-					proposals,
-					proposals_with_valid_signature.encode(),
+					vec![native().amount(200_000_000_000_000)],
+					Box::new(scenario.dest(DEST_DOMAIN_ID))
 				));
-				// check native asset balance
-				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
-				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
+				assert_eq!(SentXcm::get().len(), 2);
+				assert_eq!(
+					SentXcm::get()[1].1,
+					SygmaBridge::deposit_notification(&target, DEST_DOMAIN_ID, 3)
+				);
 			})
 		}
 
 		#[test]
-		fn deposit_native_asset_with_percentage_fee() {
+		fn correct_mpc_key_should_be_allowed_once_within_grace_period() {
+			let mpc_addr_a = MpcAddress([1u8; 20]);
+			let mpc_addr_b = MpcAddress([2u8; 20]);
+			let mpc_addr_c = MpcAddress([3u8; 20]);
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-
-				// test cases
-				let fee_rate_1 = 500u32; // 5%
-				let fee_rate_2 = 10000u32; // 100%
-				let fee_rate_3 = 9999u32; // 99.99%
-				let fee_rate_4 = 0u32; // 0%
-				let fee_rate_5 = 15000u32; // 150%
-
+				// nothing to correct before the MPC address is set
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_b),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
+				);
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
-				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+				assert_eq!(
+					SygmaBridge::mpc_key_grace_end(),
+					Some(System::block_number() + KeySetGracePeriod::get())
+				);
 
-				// test 5%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::signed(ALICE), mpc_addr_b),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), MpcAddress::default()),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+
+				System::set_block_number(System::block_number() + KeySetGracePeriod::get());
+				assert_ok!(SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_b));
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_b);
+				assert_eq!(SygmaBridge::mpc_key_grace_end(), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::MpcKeyCorrected {
+					old_mpc_addr: mpc_addr_a,
+					mpc_addr: mpc_addr_b,
+				})]);
+				assert_eq!(
+					SygmaBridge::manual_interventions().into_inner(),
+					vec![ManualIntervention {
+						block: System::block_number(),
+						operation: InterventionOperation::CorrectMpcKey,
+						operator_origin_hash: blake2_256(&Origin::root().caller().encode()),
+						subject: InterventionSubject::MpcKey {
+							old_mpc_addr: mpc_addr_a,
+							mpc_addr: mpc_addr_b,
+						},
+						amount: None,
+					}]
+				);
+
+				// a correction is single use, the address is immutable afterwards
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_c),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
+				);
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_c),
+					bridge::Error::<Runtime>::MpcAddrNotUpdatable
+				);
+			});
+
+			// the grace period expires
+			new_test_ext().execute_with(|| {
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+				System::set_block_number(System::block_number() + KeySetGracePeriod::get() + 1);
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_b),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
+				);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_a);
+			});
+		}
+
+		#[test]
+		fn correct_mpc_key_should_fail_once_the_bridge_was_unpaused() {
+			let mpc_addr_a = MpcAddress([1u8; 20]);
+			let mpc_addr_b = MpcAddress([2u8; 20]);
+			let setup = || {
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_rate_1,
-					0u128,
-					1_000_000_000_000_000u128
-				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					U256::from(1)
 				));
-				// Check balances of Alice after deposit 200 native token
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				// Check reserved native token
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					190_000_000_000_000u128
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+				assert!(SygmaBridge::mpc_key_grace_end().is_some());
+			};
+
+			// unpausing the domain ends the grace period, pausing it back does not reopen it
+			new_test_ext().execute_with(|| {
+				setup();
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::mpc_key_grace_end(), None);
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_halted());
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_b),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
+				);
+			});
+
+			// so does unpausing a single route
+			new_test_ext().execute_with(|| {
+				setup();
+				assert_ok!(SygmaBridge::unpause_route(
+					Origin::root(),
+					RouteDirection::Inbound,
+					DEST_DOMAIN_ID
+				));
+				assert_eq!(SygmaBridge::mpc_key_grace_end(), None);
+				assert_ok!(SygmaBridge::pause_route(
+					Origin::root(),
+					RouteDirection::Inbound,
+					DEST_DOMAIN_ID
+				));
+				assert!(SygmaBridge::is_halted());
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), mpc_addr_b),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
 				);
-				// Check fee collected
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10_000_000_000_000u128);
-				// Check event
-				let final_amount_in_deposit_event_1 = 190_000_000_000_000_000_000; // 200 cut 5% then adjust to 18 decimals
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event_1,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: 10_000_000_000_000u128,
-						fee_asset_id: NativeLocation::get().into(),
-					}),
-				]);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_a);
+			});
+		}
 
-				// test 100%
-				// should not work because 100% is out of fee rate
+		#[test]
+		fn correct_mpc_key_should_fail_once_a_proposal_is_executed() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				assert_ok!(scenario.execute(vec![scenario.proposal(
+					DEST_DOMAIN_ID,
+					1,
+					&native(),
+					1_000_000_000_000_000_000,
+					&BOB
+				)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// pausing the bridge back does not make the key correctable again
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
 				assert_noop!(
-					SygmaPercentageFeeHandler::set_fee_rate(
-						Origin::root(),
-						DEST_DOMAIN_ID,
-						Box::new(NativeLocation::get().into()),
-						fee_rate_2,
-						0u128,
-						1_000_000_000_000_000u128
-					),
-					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+					SygmaBridge::correct_mpc_key(Origin::root(), MpcAddress([2u8; 20])),
+					bridge::Error::<Runtime>::MpcKeyNotCorrectable
+				);
+				// and a key that served a proposal is never correctable, grace period or not
+				MpcKeyGraceEnd::<Runtime>::put(System::block_number());
+				assert_noop!(
+					SygmaBridge::correct_mpc_key(Origin::root(), MpcAddress([2u8; 20])),
+					bridge::Error::<Runtime>::MpcKeyInUse
 				);
+			})
+		}
 
-				// test 99.99%
-				// override 5% to 99.99%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+		#[test]
+		fn pause_history_should_record_pause_and_unpause_actions() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_rate_3,
-					0u128,
-					1_000_000_000_000_000u128
+					U256::from(1)
 				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DomainID::new(2),
+					U256::from(2)
 				));
-				// Check reserved native token, should increase by 0.02 to 190.020000000000
+				assert!(SygmaBridge::pause_history(DEST_DOMAIN_ID).is_empty());
+
+				System::set_block_number(2);
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				System::set_block_number(5);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					190_020_000_000_000u128
+					SygmaBridge::pause_history(DEST_DOMAIN_ID).into_inner(),
+					vec![(true, 2), (false, 5)]
 				);
-				// Check fee collected, should increase by 199.98 to 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+				// other domains are not affected
+				assert!(SygmaBridge::pause_history(DomainID::new(2)).is_empty());
 
-				// test 0%
-				// override 99.99% to 0%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				// pausing a route pauses the domain until it's unpaused
+				System::set_block_number(7);
+				assert_ok!(SygmaBridge::pause_route(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_rate_4,
-					0u128,
-					1_000_000_000_000_000u128
+					RouteDirection::Inbound,
+					DEST_DOMAIN_ID
 				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				System::set_block_number(9);
+				assert_ok!(SygmaBridge::unpause_route(
+					Origin::root(),
+					RouteDirection::Inbound,
+					DEST_DOMAIN_ID
 				));
-				// Check reserved native token, should increase by 200 to 390.020000000000
+				// MaxPauseHistory is 3, the oldest action is evicted
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					390_020_000_000_000u128
+					SygmaBridge::pause_history(DEST_DOMAIN_ID).into_inner(),
+					vec![(false, 5), (true, 7), (false, 9)]
 				);
-				// Check fee collected, should increase by 0 to 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
 
-				// test 150%
-				// should not work because 150% is out of fee rate
+				// actions on every domain are recorded for each of them
+				System::set_block_number(10);
+				assert_ok!(SygmaBridge::pause_all_bridges(Origin::root()));
+				assert_eq!(
+					SygmaBridge::pause_history(DEST_DOMAIN_ID).into_inner(),
+					vec![(true, 7), (false, 9), (true, 10)]
+				);
+				assert_eq!(
+					SygmaBridge::pause_history(DomainID::new(2)).into_inner(),
+					vec![(true, 10)]
+				);
+			})
+		}
+
+		/// xorshift64 generator driving the random interleavings of the nonce space tests
+		fn next_random(state: &mut u64) -> u64 {
+			*state ^= *state << 13;
+			*state ^= *state >> 7;
+			*state ^= *state << 17;
+			*state
+		}
+
+		#[test]
+		fn nonce_space_should_keep_consumed_nonces_through_random_interleavings() {
+			for seed in 1..=10u64 {
+				new_test_ext().execute_with(|| {
+					let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+					let domains = [DomainID::new(1), DomainID::new(2)];
+					let mut consumed: BTreeSet<(DomainID, DepositNonce)> = BTreeSet::new();
+					let mut next_nonce = [0u64; 2];
+					let check_nonce_space = |consumed: &BTreeSet<(DomainID, DepositNonce)>| {
+						for domain_id in domains {
+							for nonce in 0..576 {
+								assert_eq!(
+									nonces::is_consumed::<Runtime>(domain_id, nonce),
+									consumed.contains(&(domain_id, nonce)),
+									"seed {seed}, domain {domain_id:?}, nonce {nonce}"
+								);
+							}
+						}
+					};
+					for step in 0..600 {
+						let d = (next_random(&mut state) % 2) as usize;
+						let domain_id = domains[d];
+						match next_random(&mut state) % 6 {
+							// execution of the next nonce, so that words fill up for the pruning
+							0..=2 => {
+								nonces::consume::<Runtime>(domain_id, next_nonce[d]);
+								consumed.insert((domain_id, next_nonce[d]));
+								next_nonce[d] += 1;
+							},
+							// execution or invalidation of any nonce, consumed already or not
+							3 => {
+								let nonce = next_random(&mut state) % 512;
+								nonces::consume::<Runtime>(domain_id, nonce);
+								consumed.insert((domain_id, nonce));
+							},
+							_ => {
+								let threshold = (next_random(&mut state) % 4) as u32;
+								let max_entries = (next_random(&mut state) % 4) as u32;
+								nonces::prune::<Runtime>(domain_id, threshold, max_entries);
+							},
+						}
+
+						assert_ok!(nonces::check_invariants::<Runtime>());
+						if step % 25 == 0 {
+							check_nonce_space(&consumed);
+						}
+					}
+					check_nonce_space(&consumed);
+					// the interleavings did prune
+					assert!(domains.iter().any(|d| nonces::floor::<Runtime>(*d) > 0));
+				})
+			}
+		}
+
+		#[test]
+		fn proposal_that_moved_funds_should_never_move_funds_again() {
+			let amount = 1_000_000_000_000_000_000u128;
+			let credited: Balance = 1_000_000_000_000;
+			for seed in 1..=5u64 {
+				let (mut ext, scenario) = ScenarioBuilder::new()
+					.with_domain(1, 1)
+					.with_asset(native())
+					.with_mpc(test_key())
+					.with_reserve_balance(ENDOWED_BALANCE)
+					.build();
+				ext.execute_with(|| {
+					UsedNoncesPruneThreshold::set(&1);
+					let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+					let mut funded: BTreeSet<DepositNonce> = BTreeSet::new();
+					let mut invalidated: BTreeSet<DepositNonce> = BTreeSet::new();
+					let mut next_nonce = 0u64;
+					for _ in 0..300 {
+						match next_random(&mut state) % 8 {
+							// manual invalidation of any nonce
+							0 => {
+								let nonce = next_random(&mut state) % 160;
+								nonces::consume::<Runtime>(DEST_DOMAIN_ID, nonce);
+								invalidated.insert(nonce);
+							},
+							// domain cleared and registered back, it keeps its nonce space
+							1 => {
+								assert_ok!(SygmaBridge::unregister_domain(
+									Origin::root(),
+									DEST_DOMAIN_ID,
+									U256::from(1),
+									true
+								));
+								assert_ok!(SygmaBridge::register_domain(
+									Origin::root(),
+									DEST_DOMAIN_ID,
+									U256::from(1)
+								));
+								assert_ok!(SygmaBridge::unpause_bridge(
+									Origin::root(),
+									DEST_DOMAIN_ID
+								));
+							},
+							// execution of a batch of new and replayed nonces, pruning the
+							// filled words
+							_ => {
+								let batch: Vec<DepositNonce> = (0..3)
+									.map(|_| {
+										if next_random(&mut state) % 2 == 0 {
+											next_nonce += 1;
+											next_nonce - 1
+										} else {
+											next_random(&mut state) % 160
+										}
+									})
+									.collect();
+								let bob_balance = Balances::free_balance(BOB);
+								assert_ok!(scenario.execute(
+									batch
+										.iter()
+										.map(|nonce| {
+											scenario.proposal(
+												DEST_DOMAIN_ID,
+												*nonce,
+												&native(),
+												amount,
+												&BOB,
+											)
+										})
+										.collect()
+								));
+								let mut moving = 0;
+								for nonce in batch {
+									if !funded.contains(&nonce) && !invalidated.contains(&nonce) {
+										funded.insert(nonce);
+										moving += 1;
+									}
+								}
+								assert_eq!(
+									Balances::free_balance(BOB),
+									bob_balance + moving * credited,
+									"seed {seed}"
+								);
+							},
+						}
+						assert_ok!(nonces::check_invariants::<Runtime>());
+						assert!(funded.iter().all(|nonce| {
+							SygmaBridge::is_proposal_executed(*nonce, DEST_DOMAIN_ID)
+						}));
+					}
+					assert!(nonces::floor::<Runtime>(DEST_DOMAIN_ID) > 0);
+				})
+			}
+		}
+
+		#[test]
+		fn fees_of_a_vesting_resource_should_be_released_as_they_vest() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(usdt())
+				.with_flat_fee(100)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let usdt = usdt();
+				let domain_id = DomainID::new(1);
+				let partner = FeePartnerAccount::get();
+				let vesting_account = SygmaBridge::fee_vesting_account(&usdt.resource_id);
+				let release =
+					|| SygmaBridge::release_vested_fees(Origin::signed(ALICE), usdt.resource_id);
+
 				assert_noop!(
-					SygmaPercentageFeeHandler::set_fee_rate(
+					SygmaBridge::set_fee_vesting(
 						Origin::root(),
-						DEST_DOMAIN_ID,
-						Box::new(NativeLocation::get().into()),
-						fee_rate_5,
-						0u128,
-						1_000_000_000_000_000u128
+						usdt.resource_id,
+						partner.clone(),
+						0
 					),
-					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+					bridge::Error::<Runtime>::InvalidVestingRate
 				);
-
-				// Check reserved native token, should remain as 390.020000000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					390_020_000_000_000u128
+				assert_noop!(
+					SygmaBridge::set_fee_vesting(Origin::root(), [0xaa; 32], partner.clone(), 30),
+					bridge::Error::<Runtime>::AssetNotBound
 				);
-				// Check fee collected, should remain as 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+				assert_noop!(release(), bridge::Error::<Runtime>::NoFeeVesting);
 
-				// test fee bound: fee rate 5%
-				let fee_lower_bound = 100_000_000_000_000u128; // 100
-				let fee_upper_bound = 1_000_000_000_000_000u128;
-				// 1000
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				System::set_block_number(10);
+				assert_ok!(SygmaBridge::set_fee_vesting(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_rate_1,
-					fee_lower_bound,
-					fee_upper_bound
+					usdt.resource_id,
+					partner.clone(),
+					30
 				));
 
-				// with higher fee lower bound
-				// 5% fee of 200 token should be 10 but fee lower bound is 100, so fee is 100 now
-				assert_ok!(SygmaBridge::deposit(
+				// fees accrue to the schedule instead of going to the fee distributor
+				let treasury_balance = scenario.balance(&usdt, &TreasuryAccount::get());
+				assert_ok!(scenario.deposit(&usdt, 1_000, domain_id));
+				assert_ok!(scenario.deposit(&usdt, 1_000, domain_id));
+				assert_eq!(scenario.balance(&usdt, &TreasuryAccount::get()), treasury_balance);
+				assert_eq!(scenario.balance(&usdt, &vesting_account), 200);
+				assert_eq!(SygmaBridge::fee_vesting(usdt.resource_id).unwrap().accrued, 200);
+
+				// nothing vests in the block the schedule is set
+				assert_noop!(release(), bridge::Error::<Runtime>::NoVestedFees);
+
+				// `per_block` vest each block
+				System::set_block_number(12);
+				assert_ok!(release());
+				assert_eq!(scenario.balance(&usdt, &partner), 60);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::VestedFeesReleased {
+						resource_id: usdt.resource_id,
+						beneficiary: partner.clone(),
+						amount: 60,
+					},
+				)]);
+				assert_noop!(release(), bridge::Error::<Runtime>::NoVestedFees);
+
+				// vesting is capped by the accrued fees, fees accrued later vest at once up to
+				// the rate of the elapsed blocks
+				System::set_block_number(20);
+				assert_ok!(release());
+				assert_eq!(scenario.balance(&usdt, &partner), 200);
+				assert_ok!(scenario.deposit(&usdt, 1_000, domain_id));
+				assert_ok!(release());
+				assert_eq!(scenario.balance(&usdt, &partner), 300);
+				assert_eq!(scenario.balance(&usdt, &vesting_account), 0);
+				let schedule = SygmaBridge::fee_vesting(usdt.resource_id).unwrap();
+				assert_eq!((schedule.accrued, schedule.released), (300, 300));
+
+				// setting the schedule again restarts the vesting of the unreleased fees
+				assert_ok!(scenario.deposit(&usdt, 1_000, domain_id));
+				assert_ok!(SygmaBridge::set_fee_vesting(Origin::root(), usdt.resource_id, BOB, 50));
+				let schedule = SygmaBridge::fee_vesting(usdt.resource_id).unwrap();
+				assert_eq!((schedule.accrued, schedule.released, schedule.start), (100, 0, 20));
+				System::set_block_number(21);
+				let bob_balance = scenario.balance(&usdt, &BOB);
+				assert_ok!(release());
+				assert_eq!(scenario.balance(&usdt, &BOB), bob_balance + 50);
+				assert_eq!(scenario.balance(&usdt, &partner), 300);
+				assert_eq!(scenario.balance(&usdt, &vesting_account), 50);
+
+				// proposals can not credit the fees held for the beneficiary
+				assert!(SygmaBridge::is_protected_account(&vesting_account));
+			})
+		}
+
+		#[test]
+		fn fee_vesting_should_not_carry_unreleased_fees_over_to_a_rebound_asset() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(usdt())
+				.with_flat_fee(100)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let usdt = usdt();
+				let partner = FeePartnerAccount::get();
+				let usdc_asset: AssetId = UsdcLocation::get().into();
+
+				System::set_block_number(10);
+				assert_ok!(SygmaBridge::set_fee_vesting(
+					Origin::root(),
+					usdt.resource_id,
+					partner.clone(),
+					30
+				));
+				assert_ok!(scenario.deposit(&usdt, 1_000, DomainID::new(1)));
+				assert_ok!(SygmaBridge::rebind_resource(
+					Origin::root(),
+					usdt.resource_id,
+					Box::new(usdc_asset),
+					10
+				));
+
+				// the unreleased fees are held in the old asset
+				assert_noop!(
+					SygmaBridge::set_fee_vesting(Origin::root(), usdt.resource_id, BOB, 50),
+					bridge::Error::<Runtime>::VestingAssetMismatch
+				);
+
+				// they are still released in it
+				System::set_block_number(14);
+				assert_ok!(SygmaBridge::release_vested_fees(
 					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					usdt.resource_id
 				));
-				// Check reserved native token, should increase by 100 to 490.020000000000
+				assert_eq!(scenario.balance(&usdt, &partner), 100);
+
+				// then the schedule moves to the new asset
+				assert_ok!(SygmaBridge::set_fee_vesting(Origin::root(), usdt.resource_id, BOB, 50));
+				let schedule = SygmaBridge::fee_vesting(usdt.resource_id).unwrap();
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					490_020_000_000_000u128
+					(schedule.asset_id, schedule.accrued, schedule.released),
+					(usdc_asset, 0, 0)
 				);
-				// Check fee collected, should increase by 100 to 309.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 309_980_000_000_000u128);
+			})
+		}
 
-				// with lower fee upper bound
-				// 5% fee of 200000 token should be 10000 but fee upper bound is 1000, so fee is
-				// 1000 now
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(200_000_000_000_000_000)).into()
-					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
+		#[test]
+		fn deposit_should_prefix_the_recipient_with_its_format() {
+			let (mut ext, _) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_domain(2, 2)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let evm_domain = DomainID::new(1);
+				let substrate_domain = DomainID::new(2);
+				assert_ok!(SygmaBridge::set_data_format(
+					Origin::root(),
+					substrate_domain,
+					DataFormat::Scale
+				));
+				let amount = 10_000_000_000_000u128;
+				let dest = |recipient: &[u8], domain_id: DomainID| {
+					MultiLocation::new(
+						0,
+						X2(
+							slice_to_generalkey(recipient),
+							slice_to_generalkey(&[domain_id.into_inner()]),
+						),
+					)
+				};
+				let deposit = |recipient: &[u8], domain_id: DomainID| {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(native().amount(amount)),
+						Box::new(dest(recipient, domain_id)),
+					)
+				};
+				let deposit_with_format =
+					|recipient: &[u8], domain_id: DomainID, format: RecipientFormat| {
+						SygmaBridge::deposit_with_recipient_format(
+							Origin::signed(ALICE),
+							Box::new(native().amount(amount)),
+							Box::new(dest(recipient, domain_id)),
+							format,
 						)
-					}),
+					};
+				let last_recipient = |format: DataFormat| {
+					let deposit_data = System::events()
+						.into_iter()
+						.rev()
+						.find_map(|record| match record.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_data,
+								..
+							}) => Some(deposit_data),
+							_ => None,
+						})
+						.unwrap();
+					SygmaBridge::split_deposit_data(format, &deposit_data).unwrap().1
+				};
+				let evm_vector = &TAGGED_RECIPIENTS[0];
+				let account_vector = &TAGGED_RECIPIENTS[1];
+				// a SCALE encoded location as long as an EVM address
+				let location = MultiLocation::new(0, X1(GeneralIndex(u128::MAX))).encode();
+				assert_eq!(location.len(), 20);
+
+				// the recipient is carried as is unless `EnableRecipientFormat` is set
+				assert_noop!(
+					deposit_with_format(
+						evm_vector.recipient,
+						evm_domain,
+						RecipientFormat::RawEvm20
+					),
+					bridge::Error::<Runtime>::RecipientFormatDisabled
+				);
+				assert_ok!(deposit(evm_vector.recipient, evm_domain));
+				assert_eq!(last_recipient(DataFormat::EvmAbi), evm_vector.recipient);
+
+				// the format is told from the recipient
+				EnableRecipientFormat::set(&true);
+				assert_ok!(deposit(evm_vector.recipient, evm_domain));
+				assert_eq!(last_recipient(DataFormat::EvmAbi), evm_vector.data);
+				assert_ok!(deposit(account_vector.recipient, substrate_domain));
+				assert_eq!(last_recipient(DataFormat::Scale), account_vector.data);
+				let parachain = MultiLocation::new(1, X1(Parachain(1000))).encode();
+				assert_ok!(deposit(&parachain, substrate_domain));
+				assert_eq!(last_recipient(DataFormat::Scale), [&[2u8][..], &parachain].concat());
+
+				// or given explicitly
+				assert_ok!(deposit_with_format(
+					&location,
+					substrate_domain,
+					RecipientFormat::ScaleMultiLocation
 				));
-				// Check reserved native token, should increase by 199000 to 199490.020000000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_490_020_000_000_000u128
+				assert_eq!(last_recipient(DataFormat::Scale), [&[2u8][..], &location].concat());
+
+				// formats the dest domain does not handle are rejected
+				assert_noop!(
+					deposit(&location, substrate_domain),
+					bridge::Error::<Runtime>::RecipientFormatMismatch
 				);
-				// Check fee collected, should increase by 1000 to 1309.980000000000
-				assert_eq!(
-					Balances::free_balance(TreasuryAccount::get()),
-					1_309_980_000_000_000u128
+				assert_noop!(
+					deposit(account_vector.recipient, evm_domain),
+					bridge::Error::<Runtime>::RecipientFormatMismatch
+				);
+				assert_noop!(
+					deposit_with_format(
+						&parachain,
+						evm_domain,
+						RecipientFormat::ScaleMultiLocation
+					),
+					bridge::Error::<Runtime>::RecipientFormatMismatch
+				);
+				// as are raw recipients of the wrong length and recipients of unknown format
+				assert_noop!(
+					deposit_with_format(
+						evm_vector.recipient,
+						substrate_domain,
+						RecipientFormat::RawAccount32
+					),
+					bridge::Error::<Runtime>::RecipientFormatMismatch
+				);
+				assert_noop!(
+					deposit(b"ethereum recipient", evm_domain),
+					bridge::Error::<Runtime>::UnknownRecipientFormat
 				);
 			})
 		}
 
-		#[test]
-		fn percentage_fee_rate_not_set_for_domain_and_asset() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		#[test]
+		fn proposal_recipient_should_be_decoded_by_its_format() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				EnableRecipientFormat::set(&true);
+				let unit = 1_000_000_000_000_000_000u128;
+				let bob: [u8; 32] = BOB.into();
+				let bob_location =
+					MultiLocation::new(0, X1(Junction::AccountId32 { network: None, id: bob }));
+				let proposal = |deposit_nonce: u64, recipient: Vec<u8>| Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce,
+					resource_id: native().resource_id,
+					data: SygmaBridge::create_deposit_data(DataFormat::EvmAbi, unit, recipient),
+				};
+				let bob_balance = scenario.balance(&native(), &BOB);
+
+				assert_ok!(scenario.execute(vec![
+					proposal(1, [&[1u8][..], &bob].concat()),
+					proposal(2, [&[2u8][..], &bob_location.encode()].concat()),
+					proposal(3, [&[0u8][..], &[7u8; 20]].concat()),
+					// a location without format reads as an EVM address of the wrong length
+					proposal(4, bob_location.encode()),
+				]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_eq!(scenario.balance(&native(), &BOB), bob_balance + 2_000_000_000_000);
+
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"RecipientFormatMismatch".to_vec(),
+						reason: ExecutionFailureReason::RecipientInvalid,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 3,
+					})));
+				assert!(!SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: b"InvalidDepositData".to_vec(),
+						reason: ExecutionFailureReason::DecodeError,
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 4,
+					})));
+			})
+		}
+
+		/// Logger capturing the records of the bridge target, on the threads capturing them
+		struct CaptureLogger;
 
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+		std::thread_local! {
+			static CAPTURED_LOGS: std::cell::RefCell<Option<Vec<(log::Level, String)>>> =
+				std::cell::RefCell::new(None);
+		}
 
-				// only set fee handler but not set fee rate for domain and asset
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
-				));
+		impl log::Log for CaptureLogger {
+			fn enabled(&self, metadata: &log::Metadata) -> bool {
+				metadata.target() == bridge::LOG_TARGET
+			}
 
-				// deposit should not go through because fee rate is not set in storage, so when
-				// get_fee, it returns None
-				assert_noop!(
-					SygmaBridge::deposit(
+			fn log(&self, record: &log::Record) {
+				if !self.enabled(record.metadata()) {
+					return;
+				}
+				CAPTURED_LOGS.with(|logs| {
+					if let Some(logs) = logs.borrow_mut().as_mut() {
+						logs.push((record.level(), record.args().to_string()));
+					}
+				});
+			}
+
+			fn flush(&self) {}
+		}
+
+		/// Run `f` and return the bridge logs it emitted
+		fn capture_logs(f: impl FnOnce()) -> Vec<(log::Level, String)> {
+			static LOGGER: CaptureLogger = CaptureLogger;
+			static INIT: std::sync::Once = std::sync::Once::new();
+			INIT.call_once(|| {
+				log::set_logger(&LOGGER).expect("no other logger is set in tests");
+				log::set_max_level(log::LevelFilter::Trace);
+			});
+			CAPTURED_LOGS.with(|logs| *logs.borrow_mut() = Some(vec![]));
+			f();
+			CAPTURED_LOGS.with(|logs| logs.borrow_mut().take().unwrap_or_default())
+		}
+
+		#[test]
+		fn deposit_should_be_logged() {
+			let (mut ext, _) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				let recipient = [0xabu8; 20];
+				let logs = capture_logs(|| {
+					assert_ok!(SygmaBridge::deposit(
 						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::MissingFeeConfig
-				);
+						Box::new(native().amount(10_000_000_000_000)),
+						Box::new(MultiLocation::new(
+							0,
+							X2(slice_to_generalkey(&recipient), slice_to_generalkey(&[1])),
+						)),
+					));
+				});
+				let resource = hex::encode(NativeResourceId::get());
+
+				assert!(logs.contains(&(
+					log::Level::Trace,
+					"deposit: dest domain 1, final domain 1, recipient of 20 bytes".into()
+				)));
+				assert!(logs.contains(&(
+					log::Level::Trace,
+					format!(
+						"deposit: resource 0x{}, amount 10000000000000, fee 1000000000000, prefunded 0",
+						resource
+					)
+				)));
+				assert!(logs.iter().any(|(level, line)| *level == log::Level::Debug
+					&& line.starts_with(&format!(
+						"deposit: dest domain 1, nonce 0, resource 0x{}, amount 9000000000000, ",
+						resource
+					))));
+				// the recipient is never logged, nothing is logged above debug level
+				assert!(logs.iter().all(|(level, line)| *level >= log::Level::Debug
+					&& !line.contains(&hex::encode(recipient))));
 			})
 		}
 
 		#[test]
-		fn deposit_native_asset_with_percentage_fee_override_basic_fee_handler() {
+		fn route_info_should_reflect_route_state() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let resource_id = NativeResourceId::get();
+				let info = || SygmaBridge::route_info(resource_id, DEST_DOMAIN_ID);
+				let native_asset: XcmAssetId = NativeLocation::get().into();
 
-				assert_ok!(SygmaBridge::register_domain(
+				// nothing set up yet
+				assert_eq!(
+					info(),
+					RouteInfo {
+						enabled: false,
+						asset_id: Some(native_asset),
+						domain_registered: false,
+						data_format: None,
+						decimals: 18,
+						outbound_paused: false,
+						inbound_paused: false,
+						pause_reason: None,
+						blocked: false,
+						domain_healthy: true,
+						sunset_at: None,
+						circuit_broken: false,
+						fee: None,
+						min_transfer: None,
+						available_tokens: None,
+						inbound_limit: None,
+						inbound_window: None,
+						settlement_delay: None,
+					}
+				);
+				// resource not bound to an asset
+				assert_eq!(SygmaBridge::route_info([9u8; 32], DEST_DOMAIN_ID).asset_id, None);
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					U256::from(1)
+					Box::new(native_asset),
+					10
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// set fee handler with basic fee handler and fixed fee
 				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
+					Box::new(native_asset),
 					FeeHandlerType::BasicFeeHandler,
 				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
+					U256::from(1)
 				));
-
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				let limit = InboundLimit { max_single: 100, max_per_window: 1_000 };
+				assert_ok!(SygmaBridge::set_inbound_limit(
+					Origin::root(),
+					resource_id,
+					Some(limit)
 				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_ok!(SygmaBridge::set_token_bucket(
+					Origin::root(),
+					resource_id,
+					Some(TokenBucketConfig { capacity: 500, refill_per_block: 5 })
+				));
+				assert_ok!(SygmaBridge::set_settlement_delay(Origin::root(), resource_id, Some(3)));
+				assert_ok!(SygmaBridge::schedule_domain_sunset(Origin::root(), DEST_DOMAIN_ID, 50));
+				let window = InboundWindow { started_at: 1, amount: 40, exhausted: false };
+				InboundWindows::<Runtime>::insert(resource_id, &window);
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
+					info(),
+					RouteInfo {
+						enabled: true,
+						asset_id: Some(native_asset),
+						domain_registered: true,
+						data_format: Some(DataFormat::EvmAbi),
+						decimals: 18,
+						outbound_paused: false,
+						inbound_paused: false,
+						pause_reason: None,
+						blocked: false,
+						domain_healthy: true,
+						sunset_at: Some(50),
+						circuit_broken: false,
+						fee: Some(10),
+						min_transfer: Some(11),
+						available_tokens: Some(500),
+						inbound_limit: Some(limit),
+						inbound_window: Some(window),
+						settlement_delay: Some(3),
+					}
 				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// the inbound window is only reported while it runs
+				System::set_block_number(
+					1 + <Runtime as bridge::Config>::InboundLimitWindow::get(),
+				);
+				assert_eq!(info().inbound_window, None);
 
-				// Override Basic fee handler to Percentage fee handler with 5% fee rate
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
-				));
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				// a paused route stays enabled, the circuit breaker of the resource is told apart
+				assert_ok!(SygmaBridge::pause_bridge_with_reason(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					500u32,
-					0u128,
-					1_000_000_000_000_000u128
-				));
-
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					PauseReason::CircuitBreaker { resource: Some(resource_id) },
+					None
 				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
-				// Check reserved native token, should increase by 190
+				assert!(info().enabled);
+				assert!(info().outbound_paused);
+				assert!(info().circuit_broken);
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee + 190_000_000_000_000u128
+					info().pause_reason,
+					Some(PauseReason::CircuitBreaker { resource: Some(resource_id) })
 				);
-				// Check fee collected, should increase by 10
-				assert_eq!(
-					Balances::free_balance(TreasuryAccount::get()),
-					fee + 10_000_000_000_000u128
+				assert!(
+					!SygmaBridge::route_info(UsdtResourceId::get(), DEST_DOMAIN_ID).circuit_broken
 				);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!info().outbound_paused);
+				assert!(!info().circuit_broken);
+				assert_eq!(info().pause_reason, None);
+
+				// blocked by the health oracle or the sunset of the domain
+				DomainHealth::<Runtime>::insert(DEST_DOMAIN_ID, false);
+				assert!(info().blocked);
+				assert!(!info().domain_healthy);
+				DomainHealth::<Runtime>::insert(DEST_DOMAIN_ID, true);
+				assert!(!info().blocked);
+				System::set_block_number(50);
+				assert!(info().blocked);
 			})
 		}
 
 		#[test]
-		fn pause_all_bridges_test() {
-			new_test_ext().execute_with(|| {
-				let domain_1: DomainID = 1;
-				let domain_2: DomainID = 2;
-				let domain_3: DomainID = 3;
-
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
-
-				// all registered domains should be unpaused now
-				assert!(!IsPaused::<Runtime>::get(domain_1));
-				assert!(!IsPaused::<Runtime>::get(domain_2));
-				assert!(!IsPaused::<Runtime>::get(domain_3));
-
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::pause_all_bridges(unauthorized_account),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				// Grant ALICE the access
-				assert_ok!(AccessSegregator::grant_access(
+		fn duplicate_proposals_of_a_batch_should_be_skipped() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				EnableBatchDeduplication::set(&true);
+				let proposal = |deposit_nonce| {
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						1_000_000_000_000_000_000,
+						&BOB,
+					)
+				};
+				let bob_balance = Balances::free_balance(BOB);
+
+				assert_ok!(scenario.execute(vec![
+					proposal(1),
+					proposal(2),
+					proposal(1),
+					proposal(1)
+				]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				// deposited exactly once per nonce
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 2 * 1_000_000_000_000);
+				let skipped: Vec<DepositNonce> = System::events()
+					.into_iter()
+					.filter_map(|r| match r.event {
+						RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DuplicateProposalSkipped {
+							origin_domain_id,
+							deposit_nonce,
+						}) if origin_domain_id == DEST_DOMAIN_ID => Some(deposit_nonce),
+						_ => None,
+					})
+					.collect();
+				assert_eq!(skipped, vec![1, 1]);
+				// the repeats are not failed
+				assert!(!System::events().into_iter().any(|r| matches!(
+					r.event,
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution { .. })
+				)));
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+
+				// the same nonce of another origin domain is not a repeat
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_all_bridges".to_vec(),
-					ALICE
+					DomainID::new(2),
+					U256::from(2)
 				));
-
-				assert_ok!(SygmaBridge::pause_all_bridges(Origin::signed(ALICE)));
-
-				// all registered domains should be paused now
-				assert!(IsPaused::<Runtime>::get(domain_1));
-				assert!(IsPaused::<Runtime>::get(domain_2));
-				assert!(IsPaused::<Runtime>::get(domain_3));
-
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AllBridgePaused {
-					sender: ALICE,
-				})]);
+				System::reset_events();
+				assert_ok!(scenario.execute(vec![
+					proposal(3),
+					scenario.proposal(
+						DomainID::new(2),
+						3,
+						&native(),
+						1_000_000_000_000_000_000,
+						&BOB
+					),
+				]));
+				assert!(SygmaBridge::is_proposal_executed(3, DomainID::new(2)));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 4 * 1_000_000_000_000);
+
+				// without deduplication the repeat is failed as already complete
+				EnableBatchDeduplication::set(&false);
+				assert_ok!(scenario.execute(vec![proposal(4), proposal(4)]));
+				assert_eq!(Balances::free_balance(BOB), bob_balance + 5 * 1_000_000_000_000);
+				assert!(SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 4).is_none());
 			})
 		}
 
 		#[test]
-		fn unpause_all_bridges_test() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-
-				let domain_1: DomainID = 1;
-				let domain_2: DomainID = 2;
-				let domain_3: DomainID = 3;
+		fn deposits_in_range_should_report_records_and_gaps() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				for _ in 0..4 {
+					assert_ok!(scenario.deposit(&native(), 10_000_000_000_000, DEST_DOMAIN_ID));
+				}
+				let event_data = |nonce: DepositNonce| {
+					System::events()
+						.into_iter()
+						.find_map(|record| match record.event {
+							RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+								deposit_nonce,
+								deposit_data,
+								..
+							}) if deposit_nonce == nonce => Some(deposit_data),
+							_ => None,
+						})
+						.unwrap()
+				};
+				let recorded = |nonce: DepositNonce| {
+					DepositRangeEntry::Recorded(DepositRecordSummary {
+						deposit_nonce: nonce,
+						sender: ALICE.into(),
+						asset_id: native().id(),
+						amount: 9_000_000_000_000,
+						recorded_at: 1,
+						refund_requested: false,
+						cancelled: false,
+						deposit_data_hash: Some(blake2_256(&event_data(nonce))),
+					})
+				};
 
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
+				assert_eq!(
+					SygmaBridge::deposits_in_range(DEST_DOMAIN_ID, 0, 10),
+					DepositRange { entries: (0..4).map(recorded).collect(), next_nonce: None }
+				);
+				assert_eq!(
+					SygmaBridge::deposits_in_range(DEST_DOMAIN_ID, 1, 3),
+					DepositRange { entries: vec![recorded(1), recorded(2)], next_nonce: None }
+				);
+				// empty range, and no nonce assigned to another domain
+				assert!(SygmaBridge::deposits_in_range(DEST_DOMAIN_ID, 3, 1).entries.is_empty());
+				assert!(SygmaBridge::deposits_in_range(DomainID::new(2), 0, 10).entries.is_empty());
+
+				// pruned records are reported rather than left out
+				System::set_block_number(1 + DepositRecordRetention::get());
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 2));
+				assert!(DepositDataHashes::<Runtime>::get((DEST_DOMAIN_ID, 0)).is_none());
+				assert_eq!(
+					SygmaBridge::deposits_in_range(DEST_DOMAIN_ID, 0, 4).entries,
+					vec![
+						DepositRangeEntry::NotRetained(0),
+						DepositRangeEntry::NotRetained(1),
+						recorded(2),
+						recorded(3)
+					]
+				);
 
-				// mpc address not setup, should be error
-				assert_noop!(
-					SygmaBridge::unpause_all_bridges(Origin::root()),
-					bridge::Error::<Runtime>::MissingMpcAddress
+				// a call covers up to `MAX_DEPOSIT_RANGE` nonces, the next page follows on
+				DepositCounts::<Runtime>::insert(DEST_DOMAIN_ID, 3 * MAX_DEPOSIT_RANGE);
+				let first = SygmaBridge::deposits_in_range(DEST_DOMAIN_ID, 0, u64::MAX);
+				assert_eq!(first.entries.len() as u64, MAX_DEPOSIT_RANGE);
+				assert_eq!(first.next_nonce, Some(MAX_DEPOSIT_RANGE));
+				let last = SygmaBridge::deposits_in_range(
+					DEST_DOMAIN_ID,
+					2 * MAX_DEPOSIT_RANGE + 10,
+					u64::MAX,
+				);
+				assert_eq!(last.entries.len() as u64, MAX_DEPOSIT_RANGE - 10);
+				assert_eq!(
+					last.entries[0],
+					DepositRangeEntry::NotRetained(2 * MAX_DEPOSIT_RANGE + 10)
 				);
+				assert_eq!(last.next_nonce, None);
+			})
+		}
 
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+		#[test]
+		fn replayed_batch_should_not_block_the_rebind() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let batch = vec![
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						1,
+						&native(),
+						1_000_000_000_000_000_000,
+						&BOB,
+					),
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						2,
+						&native(),
+						1_000_000_000_000_000_000,
+						&BOB,
+					),
+				];
+				assert_ok!(scenario.execute(batch.clone()));
 
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::unpause_all_bridges(unauthorized_account),
-					bridge::Error::<Runtime>::AccessDenied
-				);
+				// anyone can submit the signed batch again, its nonces are already consumed
+				assert_ok!(scenario.execute(batch.clone()));
+				assert_ok!(scenario.execute(batch));
+				assert_eq!(FailedProposals::<Runtime>::iter().count(), 0);
+				assert_eq!(FailedProposalCounts::<Runtime>::get(NativeResourceId::get()), 0);
 
-				// Grant ALICE the access
-				assert_ok!(AccessSegregator::grant_access(
+				assert_ok!(SygmaBridge::rebind_resource(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_all_bridges".to_vec(),
-					ALICE
+					NativeResourceId::get(),
+					Box::new(UsdcLocation::get().into()),
+					10
 				));
+			})
+		}
 
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_1));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_2));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_3));
-
-				// all registered domains should be paused now
-				assert!(IsPaused::<Runtime>::get(domain_1));
-				assert!(IsPaused::<Runtime>::get(domain_2));
-				assert!(IsPaused::<Runtime>::get(domain_3));
-
-				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::signed(ALICE)));
+		#[test]
+		fn replayed_proposal_should_not_block_the_offboarding() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(usdc())
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let resource_id = usdc().resource_id;
+				let proposal = scenario.proposal(DEST_DOMAIN_ID, 1, &usdc(), 1_000, &BOB);
+				assert_ok!(scenario.execute(vec![proposal.clone()]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				// the executed proposal is replayed, its failure is not counted
+				assert_ok!(scenario.execute(vec![proposal.clone(), proposal]));
+				assert!(FailedProposals::<Runtime>::get(DEST_DOMAIN_ID, 1).is_none());
+				assert_eq!(FailedProposalCounts::<Runtime>::get(resource_id), 0);
+
+				assert_ok!(SygmaBridge::offboard_resource(Origin::root(), resource_id, true));
+				assert_eq!(SygmaBridge::rid_to_assetid(&resource_id), None);
+			})
+		}
 
-				// all registered domains should be unpaused now
-				assert!(!IsPaused::<Runtime>::get(domain_1));
-				assert!(!IsPaused::<Runtime>::get(domain_2));
-				assert!(!IsPaused::<Runtime>::get(domain_3));
+		#[test]
+		fn pending_refund_should_not_stop_the_pruning() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.build();
+			ext.execute_with(|| {
+				for _ in 0..4 {
+					assert_ok!(scenario.deposit(&native(), 10_000_000_000_000, DEST_DOMAIN_ID));
+				}
+				// the sender of the oldest record asks for a refund that is never resolved
+				assert_ok!(SygmaBridge::request_refund(Origin::signed(ALICE), DEST_DOMAIN_ID, 0));
 
+				System::set_block_number(1 + DepositRecordRetention::get());
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 4));
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::AllBridgeUnpaused { sender: ALICE },
+					SygmaBridgeEvent::DepositRecordsPruned { count: 3 },
 				)]);
+				assert_eq!(DepositRecords::<Runtime>::count(), 1);
+				assert!(
+					DepositRecords::<Runtime>::get((DEST_DOMAIN_ID, 0)).unwrap().refund_requested
+				);
+
+				// the record is still pruned in queue order once its refund is resolved
+				assert_ok!(scenario.deposit(&native(), 10_000_000_000_000, DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::refund_deposit(Origin::root(), DEST_DOMAIN_ID, 0));
+				System::set_block_number(1 + 2 * DepositRecordRetention::get());
+				assert_ok!(SygmaBridge::prune_deposit_records(Origin::signed(BOB), 4));
+				assert_eq!(DepositRecords::<Runtime>::count(), 0);
+				assert_eq!(
+					DepositRecordQueueHead::<Runtime>::get(),
+					DepositRecordQueueTail::<Runtime>::get()
+				);
 			})
 		}
 
 		#[test]
-		fn deposit_nonce_fix_should_work() {
-			new_test_ext().execute_with(|| {
-				// Nonce from source chain start from 1, set first batch of nonce under [1, 63]
-				for nonce in 1..64u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
-				}
-				// Nonce 0 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(0, 0));
-				// Nonce 1 should be set
-				assert!(SygmaBridge::is_proposal_executed(1, 0));
-				// Nonce 63 should be set
-				assert!(SygmaBridge::is_proposal_executed(63, 0));
+		fn onboarded_resource_accounts_should_be_protected_until_offboarded() {
+			let (mut ext, _) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_asset(usdc())
+				.build();
+			ext.execute_with(|| {
+				let resource_id = usdc().resource_id;
+				let escrow = SygmaBridge::escrow_account(&resource_id);
+				let vesting = SygmaBridge::fee_vesting_account(&resource_id);
+				assert_eq!(ProtectedAccounts::<Runtime>::get(&escrow), Some(resource_id));
+				assert_eq!(ProtectedAccounts::<Runtime>::get(&vesting), Some(resource_id));
+				assert!(SygmaBridge::is_protected_account(&escrow));
+				assert!(SygmaBridge::is_protected_account(&vesting));
+
+				// accounts of the resources of `ResourcePairs` are derived, not stored
+				let native_escrow = SygmaBridge::escrow_account(&NativeResourceId::get());
+				assert!(ProtectedAccounts::<Runtime>::get(&native_escrow).is_none());
+				assert!(SygmaBridge::is_protected_account(&native_escrow));
+				assert!(SygmaBridge::is_protected_account(&SygmaBridge::fee_prefund_account()));
+
+				assert_ok!(SygmaBridge::offboard_resource(Origin::root(), resource_id, true));
+				assert_eq!(ProtectedAccounts::<Runtime>::iter().count(), 0);
+				assert!(!SygmaBridge::is_protected_account(&escrow));
+				assert!(!SygmaBridge::is_protected_account(&vesting));
+			})
+		}
 
-				// set second batch of nonce under [64, 127]
-				for nonce in 64..128u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
+		#[test]
+		fn unregister_domain_should_wait_for_executions_to_catch_up_with_deposits() {
+			let (mut ext, scenario) = ScenarioBuilder::new()
+				.with_domain(1, 1)
+				.with_asset(native())
+				.with_flat_fee(1_000_000_000_000)
+				.with_mpc(test_key())
+				.with_reserve_balance(ENDOWED_BALANCE)
+				.build();
+			ext.execute_with(|| {
+				let unregister = || {
+					SygmaBridge::unregister_domain(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						U256::from(1),
+						false,
+					)
+				};
+				for _ in 0..2 {
+					assert_ok!(scenario.deposit(&native(), 10_000_000_000_000, DEST_DOMAIN_ID));
 				}
-				// Nonce 64 should be set
-				assert!(SygmaBridge::is_proposal_executed(64, 0));
-				// Nonce 127 should be set
-				assert!(SygmaBridge::is_proposal_executed(127, 0));
-				// Nonce 128 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(128, 0));
+				let proposal = |deposit_nonce| {
+					scenario.proposal(
+						DEST_DOMAIN_ID,
+						deposit_nonce,
+						&native(),
+						1_000_000_000_000_000_000,
+						&BOB,
+					)
+				};
 
-				// set future batch of nonce under [256, 300]
-				for nonce in 256..301u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
-				}
-				// Nonce 256 should be set
-				assert!(SygmaBridge::is_proposal_executed(256, 0));
-				// Nonce 300 should be set
-				assert!(SygmaBridge::is_proposal_executed(300, 0));
-				// Nonce 301 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(301, 0));
+				// no proposal failed, the deposits are still pending
+				assert_eq!(SygmaBridge::highest_executed_nonce(DEST_DOMAIN_ID), None);
+				assert_noop!(unregister(), bridge::Error::<Runtime>::DomainBacklogNotDrained);
+
+				// replaying an executed proposal doesn't drain the backlog
+				assert_ok!(scenario.execute(vec![proposal(0)]));
+				assert_ok!(scenario.execute(vec![proposal(0)]));
+				assert_eq!(SygmaBridge::highest_executed_nonce(DEST_DOMAIN_ID), Some(0));
+				assert_noop!(unregister(), bridge::Error::<Runtime>::DomainBacklogNotDrained);
+
+				assert_ok!(scenario.execute(vec![proposal(1)]));
+				assert_eq!(SygmaBridge::highest_executed_nonce(DEST_DOMAIN_ID), Some(1));
+				assert_ok!(unregister());
+				assert!(!SygmaBridge::dest_domain_ids(DEST_DOMAIN_ID));
 			})
 		}
 	}
@@ -0,0 +1,68 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Bridge metrics exported to the offchain local storage.
+//!
+//! With the `offchain-metrics` feature and `EnableOffchainMetrics` set, the offchain worker of
+//! the bridge writes the counters of each imported block to the persistent local storage, under
+//! the keys below, each value is a SCALE encoded `u64`. The `sygma_metrics` RPC reads them back
+//! and formats them for Prometheus, so that node operators can scrape them without an indexer.
+//!
+//! The counters of a block are the ones of `BlockActivity`, they are zero if the bridge saw no
+//! activity in the block.
+
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{offchain::storage::StorageValueRef, SaturatedConversion};
+
+use crate::{BlockActivity, Config, DestDomainIds, PausedRoutes, RouteDirection};
+
+/// Number of the last block the metrics were written for
+pub const BLOCK_KEY: &[u8] = b"sygma-bridge::metrics::block";
+/// Deposits initiated by accounts in the block
+pub const DEPOSITS_PER_BLOCK_KEY: &[u8] = b"sygma-bridge::metrics::deposits_per_block";
+/// Proposals executed in the block, by relayers or by the auto retry
+pub const EXECUTIONS_PER_BLOCK_KEY: &[u8] = b"sygma-bridge::metrics::executions_per_block";
+/// Proposals that failed to execute in the block
+pub const FAILED_EXECUTIONS_PER_BLOCK_KEY: &[u8] =
+	b"sygma-bridge::metrics::failed_executions_per_block";
+/// Registered domains paused in any direction at the end of the block
+pub const PAUSED_DOMAINS_KEY: &[u8] = b"sygma-bridge::metrics::paused_domains";
+
+/// Metrics keys along with the Prometheus name and help of the metric
+pub const METRICS: [(&[u8], &str, &str); 5] = [
+	(BLOCK_KEY, "sygma_metrics_block", "Number of the last block the metrics were written for"),
+	(DEPOSITS_PER_BLOCK_KEY, "sygma_deposits_per_block", "Deposits initiated in the block"),
+	(EXECUTIONS_PER_BLOCK_KEY, "sygma_executions_per_block", "Proposals executed in the block"),
+	(
+		FAILED_EXECUTIONS_PER_BLOCK_KEY,
+		"sygma_failed_executions_per_block",
+		"Proposals that failed to execute in the block",
+	),
+	(PAUSED_DOMAINS_KEY, "sygma_paused_domains", "Registered domains paused"),
+];
+
+/// Write the metrics of `block_number` to the persistent local storage, must be called from
+/// an offchain worker
+#[cfg_attr(not(feature = "offchain-metrics"), allow(dead_code))]
+pub(crate) fn write_metrics<T: Config>(block_number: BlockNumberFor<T>) {
+	let activity = BlockActivity::<T>::get()
+		.filter(|activity| activity.block == block_number)
+		.unwrap_or_default();
+	let paused_domains = DestDomainIds::<T>::iter_keys()
+		.filter(|domain_id| {
+			RouteDirection::ALL
+				.into_iter()
+				.any(|direction| PausedRoutes::<T>::get((direction, *domain_id)))
+		})
+		.count();
+
+	for (key, value) in [
+		(BLOCK_KEY, block_number.saturated_into::<u64>()),
+		(DEPOSITS_PER_BLOCK_KEY, activity.deposits.into()),
+		(EXECUTIONS_PER_BLOCK_KEY, activity.executions.into()),
+		(FAILED_EXECUTIONS_PER_BLOCK_KEY, activity.failed_executions.into()),
+		(PAUSED_DOMAINS_KEY, paused_domains as u64),
+	] {
+		StorageValueRef::persistent(key).set(&value);
+	}
+}
@@ -4,13 +4,22 @@
 #[allow(unused_imports)]
 use super::*;
 
+use codec::Decode;
+#[cfg(feature = "try-runtime")]
+use codec::Encode;
 #[cfg(feature = "try-runtime")]
 use frame_support::ensure;
-use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
+use frame_support::{
+	pallet_prelude::ValueQuery,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec, Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 use log;
 #[cfg(feature = "try-runtime")]
 use sp_std::vec::Vec;
-use sygma_traits::MpcAddress;
+use sygma_traits::{DepositNonce, DomainID, MpcAddress};
+use xcm::latest::AssetId;
 
 const EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 #[cfg(feature = "try-runtime")]
@@ -69,3 +78,351 @@ impl<T: Config> OnRuntimeUpgrade for FixMpcAddress<T> {
 		Ok(())
 	}
 }
+
+/// Set `DataFormat::EvmAbi` for the domains registered before `DataFormats` was introduced,
+/// they would reject every transfer as `UnsupportedDataFormat` otherwise
+pub struct InitDataFormats<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for InitDataFormats<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(1) {
+			log::info!("Start sygma bridge data formats migration");
+
+			let mut count = 0u64;
+			for (domain_id, _) in DestDomainIds::<T>::iter() {
+				if !DataFormats::<T>::contains_key(domain_id) {
+					DataFormats::<T>::insert(domain_id, DataFormat::EvmAbi);
+				}
+				count += 1;
+			}
+
+			// Set new storage version to 2
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge data formats migration done👏");
+
+			// iter + contains_key + insert per domain, and put
+			T::DbWeight::get().reads_writes(2 * count + 1, count + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(1),
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(2),
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+		ensure!(
+			DestDomainIds::<T>::iter_keys()
+				.all(|domain_id| DataFormats::<T>::contains_key(domain_id)),
+			"Registered domain without data format in post migrate"
+		);
+
+		log::info!("Sygma bridge data formats post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+/// Per domain pause flags, replaced by `PausedRoutes` in storage version 3
+#[frame_support::storage_alias]
+type IsPaused<T: Config> = StorageMap<Pallet<T>, Twox64Concat, DomainID, bool, ValueQuery>;
+
+/// Move the per domain pause flags of `IsPaused` to `PausedRoutes`, a paused domain gets both of
+/// its directions paused. A snapshot taken by `pause_everything` before the upgrade is converted
+/// the same way.
+pub struct SplitPauseDirections<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SplitPauseDirections<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(2) {
+			log::info!("Start sygma bridge pause directions migration");
+
+			let mut count = 0u64;
+			for (domain_id, paused) in IsPaused::<T>::drain() {
+				for direction in RouteDirection::ALL {
+					PausedRoutes::<T>::insert((direction, domain_id), paused);
+				}
+				count += 1;
+			}
+
+			let snapshot_key = PrePauseSnapshot::<T>::hashed_key();
+			let old_snapshot = frame_support::storage::unhashed::get::<
+				sp_std::vec::Vec<(DomainID, PauseInfo)>,
+			>(&snapshot_key);
+			if let Some(old_snapshot) = old_snapshot {
+				let snapshot: sp_std::vec::Vec<((RouteDirection, DomainID), PauseInfo)> =
+					old_snapshot
+						.into_iter()
+						.flat_map(|(domain_id, info)| {
+							RouteDirection::ALL
+								.into_iter()
+								.map(move |direction| ((direction, domain_id), info.clone()))
+						})
+						.collect();
+				PrePauseSnapshot::<T>::put(BoundedVec::truncate_from(snapshot));
+			}
+
+			// Set new storage version to 3
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge pause directions migration done👏");
+
+			// drain + inserts per domain, snapshot and put
+			T::DbWeight::get().reads_writes(count + 2, 3 * count + 2)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(2),
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		let paused: Vec<DomainID> =
+			IsPaused::<T>::iter().filter(|(_, paused)| *paused).map(|(d, _)| d).collect();
+		Ok(paused.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(3),
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+		ensure!(IsPaused::<T>::iter().next().is_none(), "IsPaused not drained in post migrate");
+
+		let paused = Vec::<DomainID>::decode(&mut &state[..])
+			.map_err(|_| "Can not decode pre migrate state")?;
+		ensure!(
+			paused.into_iter().all(|domain_id| RouteDirection::ALL
+				.into_iter()
+				.all(|direction| PausedRoutes::<T>::get((direction, domain_id)))),
+			"Paused domain with an unpaused route in post migrate"
+		);
+
+		log::info!("Sygma bridge pause directions post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+/// Deposit record before storage version 4, without the `cancelled` flag
+#[derive(Decode)]
+struct DepositRecordV3<AccountId, BlockNumber> {
+	sender: AccountId,
+	asset_id: AssetId,
+	amount: u128,
+	recorded_at: BlockNumber,
+	refund_requested: bool,
+}
+
+/// Add the `cancelled` flag to the deposit records, none of them is cancelled
+pub struct AddDepositCancellation<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for AddDepositCancellation<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(3) {
+			log::info!("Start sygma bridge deposit cancellation migration");
+
+			let mut count = 0u64;
+			DepositRecords::<T>::translate::<DepositRecordV3<T::AccountId, BlockNumberFor<T>>, _>(
+				|_, record| {
+					count += 1;
+					Some(DepositRecord {
+						sender: record.sender,
+						asset_id: record.asset_id,
+						amount: record.amount,
+						recorded_at: record.recorded_at,
+						refund_requested: record.refund_requested,
+						cancelled: false,
+					})
+				},
+			);
+
+			// Set new storage version to 4
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge deposit cancellation migration done👏");
+
+			// translate per record, and put
+			T::DbWeight::get().reads_writes(count + 1, count + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(3),
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		Ok(DepositRecords::<T>::count().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(4),
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+
+		let count = u32::decode(&mut &state[..]).map_err(|_| "Can not decode pre migrate state")?;
+		ensure!(
+			DepositRecords::<T>::iter_values().filter(|record| !record.cancelled).count()
+				== count as usize,
+			"Deposit record lost in post migrate"
+		);
+
+		log::info!("Sygma bridge deposit cancellation post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+/// Count the `UsedNonces` words of each origin domain into `UsedNoncesCount`, the pruning of the
+/// nonce space relies on it
+pub struct CountUsedNonces<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CountUsedNonces<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(4) {
+			log::info!("Start sygma bridge used nonces count migration");
+
+			let mut counts = sp_std::collections::btree_map::BTreeMap::<DomainID, u32>::new();
+			let mut words = 0u64;
+			for (domain_id, _) in UsedNonces::<T>::iter_keys() {
+				let count = counts.entry(domain_id).or_default();
+				*count = count.saturating_add(1);
+				words += 1;
+			}
+			let domains = counts.len() as u64;
+			for (domain_id, count) in counts {
+				UsedNoncesCount::<T>::insert(domain_id, count);
+			}
+
+			// Set new storage version to 5
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge used nonces count migration done👏");
+
+			// iter per word, insert per domain, and put
+			T::DbWeight::get().reads_writes(words + 1, domains + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(4),
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(5),
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+		crate::nonces::check_invariants::<T>()?;
+
+		log::info!("Sygma bridge used nonces count post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+/// Set `HighestExecutedNonce` of each origin domain from its nonce space, `unregister_domain`
+/// would take the domain for one without executions otherwise
+pub struct InitHighestExecutedNonce<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for InitHighestExecutedNonce<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == StorageVersion::new(5) {
+			log::info!("Start sygma bridge highest executed nonce migration");
+
+			let mut highest =
+				sp_std::collections::btree_map::BTreeMap::<DomainID, DepositNonce>::new();
+			let mut reads = 0u64;
+			for (domain_id, floor) in MinUnprunedNonce::<T>::iter() {
+				if floor > 0 {
+					highest.insert(domain_id, floor - 1);
+				}
+				reads += 1;
+			}
+			for (domain_id, index, word) in UsedNonces::<T>::iter() {
+				if word != 0 {
+					let nonce = index
+						.saturating_mul(64)
+						.saturating_add(63 - u64::from(word.leading_zeros()));
+					let entry = highest.entry(domain_id).or_default();
+					*entry = (*entry).max(nonce);
+				}
+				reads += 1;
+			}
+			let domains = highest.len() as u64;
+			for (domain_id, nonce) in highest {
+				HighestExecutedNonce::<T>::insert(domain_id, nonce);
+			}
+
+			// Set new storage version to 6
+			StorageVersion::new(6).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge highest executed nonce migration done👏");
+
+			// iter per floor and word, insert per domain, and put
+			T::DbWeight::get().reads_writes(reads + 1, domains + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(5),
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == StorageVersion::new(6),
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+		ensure!(
+			UsedNonces::<T>::iter_keys()
+				.all(|(domain_id, _)| HighestExecutedNonce::<T>::contains_key(domain_id)),
+			"Domain with executed nonces without highest executed nonce in post migrate"
+		);
+
+		log::info!("Sygma bridge highest executed nonce post migration check passed👏");
+
+		Ok(())
+	}
+}
@@ -1,36 +1,42 @@
 // The Licensed Work is (c) 2022 Sygma
 // SPDX-License-Identifier: LGPL-3.0-only
 
-#![cfg(test)]
+//! Mock runtime of the bridge pallet with the access segregator and the fee handlers, also
+//! available to the tests of other crates with the `test-utils` feature
 
 use crate as sygma_bridge;
+use codec::Encode;
 use fixed::{types::extra::U16, FixedU128};
 use frame_support::{
+	derive_impl,
+	dispatch::DispatchResult,
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ContainsPair, PalletInfoAccess},
-	PalletId,
+	weights::Weight,
 };
 use frame_system::{self as system, EnsureSigned};
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_core::{hash::H256, Get};
-use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32, BuildStorage, Perbill,
+	AccountId32, BuildStorage, DispatchError, Perbill,
 };
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::{marker::PhantomData, prelude::*, result};
 
 use sygma_traits::{
-	ChainID, DecimalConverter, DomainID, ExtractDestinationData, ResourceId,
-	VerifyingContractAddress,
+	AmountNormalizer, AssetBalanceInspector, AssetMatchingMode, AssetMetadata,
+	AssetMetadataProvider, DecimalConverter, DistributeFee, DomainID, ExtractDestinationData,
+	FeeConfig, IsRegisteredDomain, ResourceId, ResourceOnboarder,
 };
 use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
 use xcm_builder::{
 	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking, ParentIsPreset,
 	SiblingParachainConvertsVia,
 };
-use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles};
+use xcm_executor::traits::{
+	ConvertLocation, Error as ExecutionError, MatchesFungibles, TransactAsset,
+};
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -44,7 +50,7 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		AccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 4,
 		SygmaBasicFeeHandler: sygma_basic_feehandler::{Pallet, Call, Storage, Event<T>} = 5,
-		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>} = 6,
+		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Config<T>, Event<T>} = 6,
 		SygmaPercentageFeeHandler: sygma_percentage_feehandler::{Pallet, Call, Storage, Event<T>} = 7,
 		SygmaFeeHandlerRouter: sygma_fee_handler_router::{Pallet, Call, Storage, Event<T>} = 8,
 	}
@@ -171,36 +177,46 @@ parameter_types! {
 		(BridgePalletIndex::get(), b"retry".to_vec()),
 		(BridgePalletIndex::get(), b"pause_all_bridges".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_all_bridges".to_vec()),
+		(BridgePalletIndex::get(), b"sweep_escrowed_transfer".to_vec()),
+		(BridgePalletIndex::get(), b"add_relayer".to_vec()),
+		(BridgePalletIndex::get(), b"remove_relayer".to_vec()),
+		(BridgePalletIndex::get(), b"set_mpc_signers".to_vec()),
+		(BridgePalletIndex::get(), b"refund_deposit".to_vec()),
+		(BridgePalletIndex::get(), b"set_health_oracle".to_vec()),
+		(BridgePalletIndex::get(), b"set_inbound_limit".to_vec()),
+		(BridgePalletIndex::get(), b"clear_inbound_window".to_vec()),
+		(BridgePalletIndex::get(), b"set_fee_discount".to_vec()),
+		(BridgePalletIndex::get(), b"set_data_format".to_vec()),
 	].to_vec();
 }
 
+#[derive_impl(sygma_access_segregator::config_preludes::TestDefaultConfig as sygma_access_segregator::DefaultConfig)]
 impl sygma_access_segregator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
-	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Runtime>;
 }
 
+#[derive_impl(sygma_fee_handler_router::config_preludes::TestDefaultConfig as sygma_fee_handler_router::DefaultConfig)]
 impl sygma_fee_handler_router::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BasicFeeHandler = SygmaBasicFeeHandler;
 	type DynamicFeeHandler = ();
 	type PercentageFeeHandler = SygmaPercentageFeeHandler;
 	type PalletIndex = FeeHandlerRouterPalletIndex;
-	type WeightInfo = sygma_fee_handler_router::weights::SygmaWeightInfo<Runtime>;
 }
 
+#[derive_impl(sygma_basic_feehandler::config_preludes::TestDefaultConfig as sygma_basic_feehandler::DefaultConfig)]
 impl sygma_basic_feehandler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = BaiscFeeHandlerPalletIndex;
-	type WeightInfo = sygma_basic_feehandler::weights::SygmaWeightInfo<Runtime>;
 }
 
+#[derive_impl(sygma_percentage_feehandler::config_preludes::TestDefaultConfig as sygma_percentage_feehandler::DefaultConfig)]
 impl sygma_percentage_feehandler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = PercentageFeeHandlerPalletIndex;
-	type WeightInfo = sygma_percentage_feehandler::weights::SygmaWeightInfo<Runtime>;
 }
 
 fn bridge_accounts_generator() -> BTreeMap<XcmAssetId, AccountId32> {
@@ -208,15 +224,13 @@ fn bridge_accounts_generator() -> BTreeMap<XcmAssetId, AccountId32> {
 	account_map.insert(NativeLocation::get().into(), BridgeAccountNative::get());
 	account_map.insert(UsdtLocation::get().into(), BridgeAccountOtherTokens::get());
 	account_map.insert(AstrLocation::get().into(), BridgeAccountOtherTokens::get());
+	account_map.insert(UsdcLocation::get().into(), BridgeAccountOtherTokens::get());
 	account_map
 }
 
 parameter_types! {
-	pub TreasuryAccount: AccountId32 = AccountId32::new([100u8; 32]);
-	pub EIP712ChainID: ChainID = primitive_types::U256([1u64; 4]);
-	pub DestVerifyingContractAddress: VerifyingContractAddress = primitive_types::H160([1u8; 20]);
-	pub BridgeAccountNative: AccountId32 = SygmaBridgePalletId::get().into_account_truncating();
-	pub BridgeAccountOtherTokens: AccountId32 = SygmaBridgePalletId::get().into_sub_account_truncating(1u32);
+	pub BridgeAccountNative: AccountId32 = sygma_bridge::accounts::pallet_account(SygmaBridgePalletId::get());
+	pub BridgeAccountOtherTokens: AccountId32 = sygma_bridge::accounts::token_reserve_account(SygmaBridgePalletId::get(), 1);
 	pub BridgeAccounts: BTreeMap<XcmAssetId, AccountId32> = bridge_accounts_generator();
 	pub CheckingAccount: AccountId32 = AccountId32::new([102u8; 32]);
 	pub RelayNetwork: NetworkId = NetworkId::Polkadot;
@@ -241,12 +255,74 @@ parameter_types! {
 			slice_to_generalkey(b"astr"),
 		),
 	);
+	pub UsdcAssetId: AssetId = 2;
+	pub UsdcLocation: MultiLocation = MultiLocation::new(
+		1,
+		X3(
+			Parachain(2005),
+			slice_to_generalkey(b"sygma"),
+			slice_to_generalkey(b"usdc"),
+		),
+	);
+	// Minted by the bridge, parachain 2004 being this chain
+	pub WethAssetId: AssetId = 3;
+	pub WethLocation: MultiLocation = MultiLocation::new(
+		1,
+		X3(
+			Parachain(2004),
+			slice_to_generalkey(b"sygma"),
+			slice_to_generalkey(b"weth"),
+		),
+	);
 	pub NativeResourceId: ResourceId = hex_literal::hex!("00e6dfb61a2fb903df487c401663825643bb825d41695e63df8af6162ab145a6");
 	pub UsdtResourceId: ResourceId = hex_literal::hex!("00b14e071ddad0b12be5aca6dffc5f2584ea158d9b0ce73e1437115e97a32a3e");
 	pub AstrResourceId: ResourceId = hex_literal::hex!("4e071db61a2fb903df487c401663825643ba158d9b0ce73e1437163825643bba");
 	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdtLocation::get().into(), UsdtResourceId::get()), (AstrLocation::get().into(), AstrResourceId::get())];
-	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdtLocation::get().into(), 18u8), (AstrLocation::get().into(), 24u8)];
-	pub const SygmaBridgePalletId: PalletId = PalletId(*b"sygma/01");
+	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdtLocation::get().into(), 18u8), (AstrLocation::get().into(), 24u8), (UsdcLocation::get().into(), 18u8)];
+	pub storage AssetMatching: AssetMatchingMode = AssetMatchingMode::Strict;
+	pub storage PauseOnDepositNonceAnomaly: bool = false;
+	pub storage RequireRelayerAllowlist: bool = false;
+	pub storage RequireMpcThreshold: bool = false;
+	pub storage EnableSignatureCache: bool = false;
+	pub storage EnableOffchainMetrics: bool = false;
+	pub storage ExpectedDestParents: u8 = 0;
+	pub storage UsedNoncesPruneThreshold: u32 = 0;
+	pub storage AllowPartialTransfer: bool = false;
+	pub storage KeepFeeOnFailedDeposit: bool = false;
+	pub storage EnableDepositPermits: bool = true;
+	pub storage EnableDepositRoots: bool = false;
+	pub storage MaxDepositDataSize: u32 = 256;
+	pub storage EnableAccountHistory: bool = true;
+	pub storage HashDepositData: bool = false;
+	pub storage CompressDepositData: bool = false;
+	pub storage EnableSettlementCancellation: bool = true;
+	pub storage AttachAssetMetadata: bool = false;
+	pub storage EnableDepositNotification: bool = false;
+	pub storage EnableRecipientFormat: bool = false;
+	pub storage EnableBatchDeduplication: bool = false;
+	pub storage EmergencyWithdrawalDelay: u64 = 10;
+	pub DepositNotificationTarget: sygma_bridge::NotificationTarget = sygma_bridge::NotificationTarget {
+		dest: MultiLocation::new(1, X1(Parachain(2005))),
+		call_index: [42, 3],
+		weight: Weight::from_parts(1_000_000_000, 4096),
+	};
+	// Messages sent by `RecordingXcmRouter`, and whether it fails to send them
+	pub storage SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+	pub storage XcmSendFailure: bool = false;
+	// Scale of the amounts of a resource in the aggregate counters, by resource
+	pub storage AmountScales: Vec<(ResourceId, u128)> = vec![];
+	// Amount `UnderDeliveringTransactor` keeps back from each deposit
+	pub storage DepositShortfall: u128 = 0;
+	// Batch `ReentrantTransactor` submits to `execute_proposal` on its next deposit, and the result
+	pub storage ReentrantCall: Option<(Vec<sygma_bridge::Proposal>, Vec<u8>)> = None;
+	pub storage ReentrantCallResult: Option<DispatchResult> = None;
+	// Whether `SplitFeeDistributor` splits the fees between the partner and stakers accounts
+	pub storage SplitFees: bool = false;
+	pub FeePartnerAccount: AccountId32 = AccountId32::new([103u8; 32]);
+	pub FeeStakersAccount: AccountId32 = AccountId32::new([104u8; 32]);
+	// Domain `RejectingDomainRegistry` rejects whether it's registered or not
+	pub storage RejectedDomain: Option<DomainID> = None;
+	pub storage FailAssetCreation: bool = false;
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -287,6 +363,10 @@ impl MatchesFungibles<AssetId, Balance> for SimpleForeignAssetConverter {
 					Ok((UsdtAssetId::get(), *amount))
 				} else if id == &AstrLocation::get() {
 					Ok((AstrAssetId::get(), *amount))
+				} else if id == &UsdcLocation::get() {
+					Ok((UsdcAssetId::get(), *amount))
+				} else if id == &WethLocation::get() {
+					Ok((WethAssetId::get(), *amount))
 				} else {
 					Err(ExecutionError::AssetNotHandled)
 				}
@@ -314,6 +394,175 @@ pub type FungiblesTransactor = FungiblesAdapter<
 /// Means for transacting assets on this chain.
 pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
 
+/// Transactor crediting `DepositShortfall` less than the deposited amount, as a transactor
+/// rounding the amount or charging on deposit would
+pub struct UnderDeliveringTransactor<Inner>(PhantomData<Inner>);
+
+impl<Inner: TransactAsset> TransactAsset for UnderDeliveringTransactor<Inner> {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: &XcmContext) -> XcmResult {
+		match what.fun {
+			Fungible(amount) => Inner::deposit_asset(
+				&(what.id, amount.saturating_sub(DepositShortfall::get())).into(),
+				who,
+				context,
+			),
+			_ => Inner::deposit_asset(what, who, context),
+		}
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> result::Result<xcm_executor::Assets, XcmError> {
+		Inner::withdraw_asset(what, who, maybe_context)
+	}
+}
+
+/// Transactor submitting `ReentrantCall` to `execute_proposal` before a deposit, as a
+/// transactor calling back into the runtime would
+pub struct ReentrantTransactor<Inner>(PhantomData<Inner>);
+
+impl<Inner: TransactAsset> TransactAsset for ReentrantTransactor<Inner> {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: &XcmContext) -> XcmResult {
+		if let Some((proposals, signature)) = ReentrantCall::get() {
+			ReentrantCall::set(&None);
+			ReentrantCallResult::set(&Some(SygmaBridge::execute_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposals,
+				signature,
+			)));
+		}
+		Inner::deposit_asset(what, who, context)
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> result::Result<xcm_executor::Assets, XcmError> {
+		Inner::withdraw_asset(what, who, maybe_context)
+	}
+}
+
+/// Domain registry rejecting `RejectedDomain`, the other domains are looked up in the bridge
+pub struct RejectingDomainRegistry;
+impl IsRegisteredDomain for RejectingDomainRegistry {
+	fn is_registered(domain: DomainID) -> bool {
+		RejectedDomain::get() != Some(domain)
+			&& sygma_bridge::LocalDomainRegistry::<Runtime>::is_registered(domain)
+	}
+}
+
+/// Resource onboarder setting the fees with the fee handlers, creating an asset fails if
+/// `FailAssetCreation` is set
+pub struct MockResourceOnboarder;
+impl ResourceOnboarder for MockResourceOnboarder {
+	fn set_fee(domain: DomainID, asset: &XcmAssetId, fee: FeeConfig) -> DispatchResult {
+		sygma_bridge::FeeHandlerOnboarder::<Runtime>::set_fee(domain, asset, fee)
+	}
+
+	fn create_asset(_asset: &XcmAssetId, _decimals: u8) -> DispatchResult {
+		if FailAssetCreation::get() {
+			return Err(DispatchError::Other("asset creation failed"));
+		}
+		Ok(())
+	}
+}
+
+/// Fee distributor splitting the fees 50/50 between `FeePartnerAccount` and `FeeStakersAccount`
+/// if `SplitFees` is set, depositing them to the fee reserve account otherwise
+pub struct SplitFeeDistributor;
+impl DistributeFee<AccountId32> for SplitFeeDistributor {
+	fn distribute_fee(payer: &AccountId32, fee: MultiAsset) -> DispatchResult {
+		if !SplitFees::get() {
+			return sygma_bridge::FeeReserveDistributor::<Runtime>::distribute_fee(payer, fee);
+		}
+		let amount = match fee.fun {
+			Fungible(amount) => amount,
+			_ => return Err(DispatchError::Other("non fungible fee")),
+		};
+		let partner_share = amount / 2;
+		for (account, share) in [
+			(FeePartnerAccount::get(), partner_share),
+			(FeeStakersAccount::get(), amount - partner_share),
+		] {
+			AssetTransactors::deposit_asset(
+				&(fee.id, Fungible(share)).into(),
+				&Junction::AccountId32 { network: None, id: account.into() }.into(),
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| DispatchError::Other("fee share not deposited"))?;
+		}
+		Ok(())
+	}
+}
+
+/// Metadata of the native asset and of ASTR, whose name is too long to be attached
+pub struct SygmaAssetMetadata;
+impl AssetMetadataProvider for SygmaAssetMetadata {
+	fn metadata(asset_id: &XcmAssetId) -> Option<AssetMetadata> {
+		if *asset_id == NativeLocation::get().into() {
+			Some(AssetMetadata { name: b"Native".to_vec(), symbol: b"NAT".to_vec(), decimals: 12 })
+		} else if *asset_id == AstrLocation::get().into() {
+			Some(AssetMetadata { name: vec![b'a'; 65], symbol: b"ASTR".to_vec(), decimals: 24 })
+		} else {
+			None
+		}
+	}
+}
+
+/// Amounts of the resources of `AmountScales` multiplied by their scale, the others unscaled
+pub struct ScaledAmounts;
+impl AmountNormalizer for ScaledAmounts {
+	fn normalize(resource_id: &ResourceId, amount: u128) -> u128 {
+		AmountScales::get()
+			.iter()
+			.find(|(id, _)| id == resource_id)
+			.map_or(amount, |(_, scale)| amount.saturating_mul(*scale))
+	}
+}
+
+/// XCM router recording the messages in `SentXcm`, or failing if `XcmSendFailure` is set
+pub struct RecordingXcmRouter;
+impl SendXcm for RecordingXcmRouter {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		if XcmSendFailure::get() {
+			return Err(SendError::Transport("unreachable"));
+		}
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		Ok(((dest, message), MultiAssets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+		let mut sent = SentXcm::get();
+		sent.push(ticket);
+		SentXcm::set(&sent);
+		Ok(hash)
+	}
+}
+
+/// Balance of the native asset and of the assets of `SimpleForeignAssetConverter`
+pub struct SygmaBalanceInspector;
+impl AssetBalanceInspector for SygmaBalanceInspector {
+	fn balance_of(asset_id: &XcmAssetId, who: &MultiLocation) -> Option<u128> {
+		let account = LocationToAccountId::convert_location(who)?;
+		if *asset_id == NativeLocation::get().into() {
+			return Some(Balances::free_balance(account));
+		}
+		let (asset, _) =
+			SimpleForeignAssetConverter::matches_fungibles(&(*asset_id, 0u128).into()).ok()?;
+		Some(Assets::balance(asset, account))
+	}
+}
+
 pub struct ConcrateSygmaAsset;
 impl ConcrateSygmaAsset {
 	pub fn id(asset: &MultiAsset) -> Option<MultiLocation> {
@@ -460,43 +709,88 @@ impl ExtractDestinationData for DestinationDataParser {
 				0,
 				Junctions::X2(
 					GeneralKey { length: recipient_len, data: recipient },
-					GeneralKey { length: _domain_len, data: dest_domain_id },
+					GeneralKey { length: domain_len, data: dest_domain_id },
 				),
 			) => {
+				// domain id is a single byte, a longer key is a recipient in the wrong place
+				if *domain_len != 1 {
+					return None;
+				}
 				let d = u8::default();
 				let domain_id = dest_domain_id.as_slice().first().unwrap_or(&d);
 				if *domain_id == d {
 					return None;
 				}
-				Some((recipient[..*recipient_len as usize].to_vec(), *domain_id))
+				Some((recipient[..*recipient_len as usize].to_vec(), DomainID::new(*domain_id)))
 			},
 			_ => None,
 		}
 	}
 }
 
+#[derive_impl(sygma_bridge::config_preludes::TestDefaultConfig as sygma_bridge::DefaultConfig)]
 impl sygma_bridge::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TransferReserveAccounts = BridgeAccounts;
-	type FeeReserveAccount = TreasuryAccount;
-	type EIP712ChainID = EIP712ChainID;
-	type DestVerifyingContractAddress = DestVerifyingContractAddress;
 	type FeeHandler = SygmaFeeHandlerRouter;
-	type AssetTransactor = AssetTransactors;
+	type FeeDistributor = SplitFeeDistributor;
+	type DomainRegistry = RejectingDomainRegistry;
+	type ResourceOnboarder = MockResourceOnboarder;
+	type AssetTransactor = ReentrantTransactor<UnderDeliveringTransactor<AssetTransactors>>;
+	type BalanceInspector = SygmaBalanceInspector;
 	type ResourcePairs = ResourcePairs;
+	type AssetMatchingMode = AssetMatching;
+	type PauseOnDepositNonceAnomaly = PauseOnDepositNonceAnomaly;
+	type RequireRelayerAllowlist = RequireRelayerAllowlist;
+	type RequireMpcThreshold = RequireMpcThreshold;
+	type EnableSignatureCache = EnableSignatureCache;
+	type EnableOffchainMetrics = EnableOffchainMetrics;
+	type ExpectedDestParents = ExpectedDestParents;
+	type UsedNoncesPruneThreshold = UsedNoncesPruneThreshold;
+	type AllowPartialTransfer = AllowPartialTransfer;
+	type KeepFeeOnFailedDeposit = KeepFeeOnFailedDeposit;
+	type EnableDepositPermits = EnableDepositPermits;
+	type EnableDepositRoots = EnableDepositRoots;
+	type MaxDepositDataSize = MaxDepositDataSize;
+	type EnableAccountHistory = EnableAccountHistory;
+	type HashDepositData = HashDepositData;
+	type CompressDepositData = CompressDepositData;
+	type EnableSettlementCancellation = EnableSettlementCancellation;
+	type AttachAssetMetadata = AttachAssetMetadata;
+	type AssetMetadataProvider = SygmaAssetMetadata;
+	type AmountNormalizer = ScaledAmounts;
+	type DepositNotifier = RecordingXcmRouter;
+	type EnableDepositNotification = EnableDepositNotification;
+	type DepositNotificationTarget = DepositNotificationTarget;
+	type EnableRecipientFormat = EnableRecipientFormat;
+	type EnableBatchDeduplication = EnableBatchDeduplication;
+	type EmergencyWithdrawalDelay = EmergencyWithdrawalDelay;
 	type IsReserve = ReserveChecker;
 	type ExtractDestData = DestinationDataParser;
-	type PalletId = SygmaBridgePalletId;
 	type PalletIndex = BridgePalletIndex;
-	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
-	type WeightInfo = sygma_bridge::weights::SygmaWeightInfo<Runtime>;
+	type DecimalConverter =
+		SygmaDecimalConverter<sygma_bridge::WithOnboardedDecimals<Runtime, AssetDecimalPairs>>;
 }
 
+// Constants of the bridge left to `TestDefaultConfig`, named for the tests
+pub type SygmaBridgePalletId = <Runtime as sygma_bridge::Config>::PalletId;
+pub type TreasuryAccount = <Runtime as sygma_bridge::Config>::FeeReserveAccount;
+pub type EIP712ChainID = <Runtime as sygma_bridge::Config>::EIP712ChainID;
+pub type DestVerifyingContractAddress =
+	<Runtime as sygma_bridge::Config>::DestVerifyingContractAddress;
+pub type DepositRecordRetention = <Runtime as sygma_bridge::Config>::DepositRecordRetention;
+pub type CancellationWindow = <Runtime as sygma_bridge::Config>::CancellationWindow;
+pub type KeySetGracePeriod = <Runtime as sygma_bridge::Config>::KeySetGracePeriod;
+pub type DepositDataRetention = <Runtime as sygma_bridge::Config>::DepositDataRetention;
+pub type ExecutionReceiptRetention = <Runtime as sygma_bridge::Config>::ExecutionReceiptRetention;
+pub type AckResourceId = <Runtime as sygma_bridge::Config>::AckResourceId;
+pub type MultiAssetResourceId = <Runtime as sygma_bridge::Config>::MultiAssetResourceId;
+
 pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
 pub const ASSET_OWNER: AccountId32 = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
 pub const ENDOWED_BALANCE: Balance = 1_000_000_000_000_000_000_000_000_000;
-pub const DEST_DOMAIN_ID: DomainID = 1;
+pub const DEST_DOMAIN_ID: DomainID = DomainID::new(1);
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
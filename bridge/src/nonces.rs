@@ -0,0 +1,182 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Nonce space of the proposals of each origin domain, the replay protection of the bridge.
+//!
+//! A nonce is consumed once its proposal is executed, or once it is invalidated without being
+//! executed, e.g. by `cancel_settlement`. The state of an origin domain is made of:
+//!
+//! - `UsedNonces`: bitmap words, bit `nonce % 64` of word `nonce / 64` is set once the nonce is
+//!   consumed
+//! - `UsedNoncesCount`: number of `UsedNonces` words of the domain, the pruning starts above
+//!   `UsedNoncesPruneThreshold` of them
+//! - `MinUnprunedNonce`: floor of the domain, every nonce below it is consumed
+//! - `HighestExecutedNonce`: highest consumed nonce of the domain, it only moves up
+//!
+//! Only this module reads and writes them, and it upholds for each origin domain:
+//!
+//! 1. A consumed nonce is never consumable again: bits are only ever set, the floor only moves
+//!    up, and a word is only removed once all of its nonces are consumed and the floor moves
+//!    past it.
+//! 2. No word lies entirely below the floor, the word holding the floor is the next to prune.
+//! 3. `UsedNoncesCount` is the number of words of the domain.
+//!
+//! So a proposal that moved funds can never be executed again, whatever the order of the
+//! executions, invalidations and prunings. Unregistering a domain keeps its nonce space, so
+//! that nothing is replayed once it is registered back.
+
+use sygma_traits::{DepositNonce, DomainID};
+
+use crate::{Config, HighestExecutedNonce, MinUnprunedNonce, UsedNonces, UsedNoncesCount};
+
+/// Number of nonces of a `UsedNonces` word
+const WORD_BITS: u64 = 64;
+
+fn word_index(nonce: DepositNonce) -> u64 {
+	nonce / WORD_BITS
+}
+
+fn bit(nonce: DepositNonce) -> u64 {
+	1 << (nonce % WORD_BITS)
+}
+
+/// Bits of the word `index` standing for the nonces below `floor`
+fn bits_below(index: u64, floor: DepositNonce) -> u64 {
+	let start = index.saturating_mul(WORD_BITS);
+	if floor <= start {
+		0
+	} else if floor - start >= WORD_BITS {
+		u64::MAX
+	} else {
+		(1u64 << (floor - start)) - 1
+	}
+}
+
+/// Set `mask` in the word `index` of the origin domain
+fn set_bits<T: Config>(domain_id: DomainID, index: u64, mask: u64) {
+	let word = UsedNonces::<T>::get(domain_id, index);
+	if word == 0 {
+		UsedNoncesCount::<T>::mutate(domain_id, |count| *count = count.saturating_add(1));
+	}
+	UsedNonces::<T>::insert(domain_id, index, word | mask);
+}
+
+/// Raise the highest consumed nonce of the origin domain to `nonce`
+fn raise_highest<T: Config>(domain_id: DomainID, nonce: DepositNonce) {
+	HighestExecutedNonce::<T>::mutate(domain_id, |highest| {
+		if highest.map_or(true, |highest| highest < nonce) {
+			*highest = Some(nonce);
+		}
+	});
+}
+
+/// Return true if `nonce` of the origin domain is consumed
+pub fn is_consumed<T: Config>(domain_id: DomainID, nonce: DepositNonce) -> bool {
+	nonce < floor::<T>(domain_id)
+		|| UsedNonces::<T>::get(domain_id, word_index(nonce)) & bit(nonce) != 0
+}
+
+/// Consume `nonce` of the origin domain, a nonce already consumed is left as is
+pub fn consume<T: Config>(domain_id: DomainID, nonce: DepositNonce) {
+	if nonce < floor::<T>(domain_id) {
+		return;
+	}
+	set_bits::<T>(domain_id, word_index(nonce), bit(nonce));
+	raise_highest::<T>(domain_id, nonce);
+}
+
+/// Consume the nonces of `start..end` of the origin domain. A range starting at or below the
+/// floor moves the floor up to its end.
+pub fn consume_range<T: Config>(domain_id: DomainID, start: DepositNonce, end: DepositNonce) {
+	if start < end {
+		raise_highest::<T>(domain_id, end - 1);
+	}
+	let floor = floor::<T>(domain_id);
+	if start <= floor {
+		if end > floor {
+			MinUnprunedNonce::<T>::insert(domain_id, end);
+		}
+		return;
+	}
+
+	let mut nonce = start;
+	while nonce < end {
+		let index = word_index(nonce);
+		let word_end = end.min(index.saturating_add(1).saturating_mul(WORD_BITS));
+		let mask = bits_below(index, word_end) & !bits_below(index, nonce);
+		set_bits::<T>(domain_id, index, mask);
+		nonce = word_end;
+	}
+}
+
+/// Lowest nonce of the origin domain above which the consumed nonces are kept in `UsedNonces`
+pub fn floor<T: Config>(domain_id: DomainID) -> DepositNonce {
+	MinUnprunedNonce::<T>::get(domain_id)
+}
+
+/// Number of `UsedNonces` words of the origin domain
+pub fn word_count<T: Config>(domain_id: DomainID) -> u32 {
+	UsedNoncesCount::<T>::get(domain_id)
+}
+
+/// Return true if a nonce of any origin domain was ever consumed
+pub fn any_consumed<T: Config>() -> bool {
+	UsedNonces::<T>::iter_keys().next().is_some()
+		|| MinUnprunedNonce::<T>::iter_values().any(|floor| floor > 0)
+}
+
+/// Prune up to `max_entries` of the lowest words of the origin domain while it has more words
+/// than `threshold`, return the number of pruned words. Only words whose nonces are all
+/// consumed are pruned, the floor moves past them. A nonce that is never consumed keeps the
+/// words above it from being pruned.
+pub fn prune<T: Config>(domain_id: DomainID, threshold: u32, max_entries: u32) -> u32 {
+	if threshold == 0 {
+		return 0;
+	}
+
+	let mut count = UsedNoncesCount::<T>::get(domain_id);
+	let mut floor = floor::<T>(domain_id);
+	let mut pruned = 0u32;
+	while count > threshold && pruned < max_entries {
+		let index = word_index(floor);
+		if UsedNonces::<T>::get(domain_id, index) | bits_below(index, floor) != u64::MAX {
+			break;
+		}
+		UsedNonces::<T>::remove(domain_id, index);
+		count -= 1;
+		pruned += 1;
+		floor = index.saturating_add(1).saturating_mul(WORD_BITS);
+	}
+
+	if pruned > 0 {
+		UsedNoncesCount::<T>::insert(domain_id, count);
+		MinUnprunedNonce::<T>::insert(domain_id, floor);
+	}
+	pruned
+}
+
+/// Check invariants 2 and 3 for every origin domain with a nonce space
+#[cfg(any(feature = "try-runtime", test))]
+pub fn check_invariants<T: Config>() -> Result<(), &'static str> {
+	let domains: sp_std::collections::btree_set::BTreeSet<DomainID> = UsedNonces::<T>::iter_keys()
+		.map(|(domain_id, _)| domain_id)
+		.chain(UsedNoncesCount::<T>::iter_keys())
+		.collect();
+	for domain_id in domains {
+		let floor_index = word_index(floor::<T>(domain_id));
+		let mut count = 0u32;
+		for (index, word) in UsedNonces::<T>::iter_prefix(domain_id) {
+			if index < floor_index {
+				return Err("UsedNonces word below the floor");
+			}
+			if word == 0 {
+				return Err("Empty UsedNonces word");
+			}
+			count = count.saturating_add(1);
+		}
+		if count != UsedNoncesCount::<T>::get(domain_id) {
+			return Err("UsedNoncesCount does not match the UsedNonces words");
+		}
+	}
+	Ok(())
+}
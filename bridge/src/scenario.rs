@@ -0,0 +1,391 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Builder of end-to-end scenarios on the mock runtime. A scenario starts with its domains
+//! registered, its assets bound along with their fees, the MPC key set and the reserves funded,
+//! so that a test goes straight to the deposits and the signed proposals:
+//!
+//! ```ignore
+//! let (mut ext, scenario) = ScenarioBuilder::new()
+//! 	.with_domain(1, 1)
+//! 	.with_asset(usdc())
+//! 	.with_flat_fee(100)
+//! 	.with_mpc(test_key())
+//! 	.build();
+//! ext.execute_with(|| assert_ok!(scenario.deposit(&usdc(), 1_000, DomainID::new(1))));
+//! ```
+//!
+//! Other crates get it for their tests with the `test-utils` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! sygma-bridge = { path = "../bridge", features = ["test-utils"] }
+//! ```
+
+use codec::Encode;
+use frame_support::{
+	assert_ok,
+	crypto::ecdsa::ECDSAExt,
+	traits::{
+		tokens::fungibles::{Create, Inspect},
+		ContainsPair, Get,
+	},
+};
+use primitive_types::U256;
+use sp_core::{ecdsa, Pair};
+use sp_runtime::{AccountId32, DispatchResult};
+use sygma_traits::{DomainID, FeeConfig, MpcAddress, ResourceId, ResourceOnboarder};
+use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
+
+use crate::{
+	mock::{
+		new_test_ext, slice_to_generalkey, AssetId, Assets, Balance, Balances, NativeLocation,
+		NativeResourceId, ResourcePairs, Runtime, RuntimeOrigin, SygmaBridge, UsdcAssetId,
+		UsdcLocation, UsdtAssetId, UsdtLocation, UsdtResourceId, WethAssetId, WethLocation, ALICE,
+		ASSET_OWNER, BOB, ENDOWED_BALANCE,
+	},
+	Config, DataFormat, FeeHandlerOnboarder, OnboardingSpec, Proposal,
+};
+
+/// Asset of a scenario, onboarded by the builder unless `ResourcePairs` binds it already
+#[derive(Clone, Debug)]
+pub struct ScenarioAsset {
+	pub location: MultiLocation,
+	pub resource_id: ResourceId,
+	/// Id of the asset in the assets pallet, `None` for the native asset
+	pub asset_id: Option<AssetId>,
+	pub decimals: u8,
+	/// Min balance of the asset created in the assets pallet
+	pub min_balance: Balance,
+	/// Whether the asset is onboarded as minted by the bridge, it's up to `IsReserve` whether it
+	/// is actually minted
+	pub mintable: bool,
+}
+
+impl ScenarioAsset {
+	pub fn id(&self) -> XcmAssetId {
+		self.location.into()
+	}
+
+	pub fn amount(&self, amount: Balance) -> MultiAsset {
+		(Concrete(self.location), Fungible(amount)).into()
+	}
+}
+
+/// Native asset of the mock runtime, with 12 decimals
+pub fn native() -> ScenarioAsset {
+	ScenarioAsset {
+		location: NativeLocation::get(),
+		resource_id: NativeResourceId::get(),
+		asset_id: None,
+		decimals: 12,
+		min_balance: 1,
+		mintable: false,
+	}
+}
+
+/// USDT, reserved in this chain and bound by `ResourcePairs`
+pub fn usdt() -> ScenarioAsset {
+	ScenarioAsset {
+		location: UsdtLocation::get(),
+		resource_id: UsdtResourceId::get(),
+		asset_id: Some(UsdtAssetId::get()),
+		decimals: 18,
+		min_balance: 1,
+		mintable: false,
+	}
+}
+
+/// USDC, reserved in this chain and onboarded by the builder
+pub fn usdc() -> ScenarioAsset {
+	ScenarioAsset {
+		location: UsdcLocation::get(),
+		resource_id: [0xc0; 32],
+		asset_id: Some(UsdcAssetId::get()),
+		decimals: 18,
+		min_balance: 1,
+		mintable: false,
+	}
+}
+
+/// WETH, minted and burned by the bridge and onboarded by the builder
+pub fn weth() -> ScenarioAsset {
+	ScenarioAsset {
+		location: WethLocation::get(),
+		resource_id: [0xe7; 32],
+		asset_id: Some(WethAssetId::get()),
+		decimals: 18,
+		min_balance: 1,
+		mintable: true,
+	}
+}
+
+/// MPC key of the scenarios, the same one in every scenario
+pub fn test_key() -> ecdsa::Pair {
+	ecdsa::Pair::from_seed(&[7u8; 32])
+}
+
+#[derive(Default)]
+pub struct ScenarioBuilder {
+	domains: Vec<(DomainID, u64)>,
+	assets: Vec<ScenarioAsset>,
+	/// Fees in the order they were given, the fee of a single resource overrides the flat fee
+	/// given before
+	fees: Vec<(Option<ResourceId>, FeeConfig)>,
+	mpc: Option<ecdsa::Pair>,
+	reserve_balance: Balance,
+	paused: bool,
+}
+
+impl ScenarioBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Register `domain_id` with `chain_id`, it's unpaused once the MPC key is set
+	pub fn with_domain(mut self, domain_id: u8, chain_id: u64) -> Self {
+		self.domains.push((DomainID::new(domain_id), chain_id));
+		self
+	}
+
+	/// Bind `asset`, ALICE is endowed with it
+	pub fn with_asset(mut self, asset: ScenarioAsset) -> Self {
+		self.assets.push(asset);
+		self
+	}
+
+	/// Charge a fixed `fee` for the deposits of every asset to every domain
+	pub fn with_flat_fee(mut self, fee: Balance) -> Self {
+		self.fees.push((None, FeeConfig::Basic(fee)));
+		self
+	}
+
+	/// Charge `fee` for the deposits of `asset` to every domain
+	pub fn with_fee(mut self, asset: &ScenarioAsset, fee: FeeConfig) -> Self {
+		self.fees.push((Some(asset.resource_id), fee));
+		self
+	}
+
+	pub fn with_mpc(mut self, pair: ecdsa::Pair) -> Self {
+		self.mpc = Some(pair);
+		self
+	}
+
+	/// Fund the reserve account of every reserved asset with `amount`
+	pub fn with_reserve_balance(mut self, amount: Balance) -> Self {
+		self.reserve_balance = amount;
+		self
+	}
+
+	/// Leave every domain paused
+	pub fn paused(mut self) -> Self {
+		self.paused = true;
+		self
+	}
+
+	pub fn build(self) -> (sp_io::TestExternalities, Scenario) {
+		let scenario = Scenario {
+			signer: self.mpc.clone(),
+			relayer: ALICE,
+			depositor: ALICE,
+			recipient: BOB,
+			domains: self.domains.iter().map(|(domain_id, _)| *domain_id).collect(),
+			assets: self.assets.clone(),
+		};
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			for (domain_id, chain_id) in &self.domains {
+				assert_ok!(SygmaBridge::register_domain(
+					RuntimeOrigin::root(),
+					*domain_id,
+					U256::from(*chain_id)
+				));
+			}
+			// Setting the MPC address pauses every domain
+			if let Some(pair) = &self.mpc {
+				assert_ok!(SygmaBridge::set_mpc_address(
+					RuntimeOrigin::root(),
+					MpcAddress(pair.public().to_eth_address().unwrap())
+				));
+			}
+			for domain_id in &scenario.domains {
+				if self.paused && !SygmaBridge::is_paused(*domain_id) {
+					assert_ok!(SygmaBridge::pause_bridge(RuntimeOrigin::root(), *domain_id));
+				} else if !self.paused && SygmaBridge::is_paused(*domain_id) {
+					assert_ok!(SygmaBridge::unpause_bridge(RuntimeOrigin::root(), *domain_id));
+				}
+			}
+
+			for asset in &self.assets {
+				if let Some(asset_id) = asset.asset_id {
+					assert_ok!(<Assets as Create<AccountId32>>::create(
+						asset_id,
+						ASSET_OWNER,
+						true,
+						asset.min_balance
+					));
+					scenario.fund(asset, &ALICE, ENDOWED_BALANCE);
+				}
+				if !ResourcePairs::get()
+					.iter()
+					.any(|(_, resource_id)| *resource_id == asset.resource_id)
+				{
+					assert_ok!(SygmaBridge::onboard_resource(
+						RuntimeOrigin::root(),
+						Box::new(OnboardingSpec {
+							asset: asset.id(),
+							resource_id: asset.resource_id,
+							fees: vec![],
+							inbound_limit: None,
+							mintable: asset.mintable,
+							decimals: asset.decimals,
+						})
+					));
+				}
+				let fee = self.fees.iter().rev().find_map(|(resource_id, fee)| {
+					(resource_id.is_none() || *resource_id == Some(asset.resource_id))
+						.then_some(fee)
+				});
+				if let Some(fee) = fee {
+					for domain_id in &scenario.domains {
+						assert_ok!(FeeHandlerOnboarder::<Runtime>::set_fee(
+							*domain_id,
+							&asset.id(),
+							*fee
+						));
+					}
+				}
+				if self.reserve_balance > 0 && scenario.is_reserved(asset) {
+					scenario.fund(asset, &scenario.reserve_account(asset), self.reserve_balance);
+				}
+			}
+		});
+		(ext, scenario)
+	}
+}
+
+/// Handles of a built scenario, to be used within its externalities
+pub struct Scenario {
+	/// MPC key signing the proposals, `None` if the MPC address is not set
+	pub signer: Option<ecdsa::Pair>,
+	/// Account submitting the proposals
+	pub relayer: AccountId32,
+	/// Account making the deposits
+	pub depositor: AccountId32,
+	/// Account credited by the proposals
+	pub recipient: AccountId32,
+	pub domains: Vec<DomainID>,
+	pub assets: Vec<ScenarioAsset>,
+}
+
+impl Scenario {
+	/// Sign `proposals` with the MPC key
+	pub fn sign(&self, proposals: &[Proposal]) -> Vec<u8> {
+		let signer = self.signer.as_ref().expect("scenario has no MPC key");
+		signer
+			.sign_prehashed(&SygmaBridge::construct_ecdsa_signing_proposals_data(
+				&proposals.to_vec(),
+			))
+			.encode()
+	}
+
+	/// Sign and submit `proposals`
+	pub fn execute(&self, proposals: Vec<Proposal>) -> DispatchResult {
+		let signature = self.sign(&proposals);
+		SygmaBridge::execute_proposal(
+			RuntimeOrigin::signed(self.relayer.clone()),
+			proposals,
+			signature,
+		)
+	}
+
+	/// Proposal from `origin_domain_id` crediting `amount`, in 18 decimals, of `asset` to `who`
+	pub fn proposal(
+		&self,
+		origin_domain_id: DomainID,
+		deposit_nonce: u64,
+		asset: &ScenarioAsset,
+		amount: u128,
+		who: &AccountId32,
+	) -> Proposal {
+		Proposal {
+			origin_domain_id,
+			deposit_nonce,
+			resource_id: asset.resource_id,
+			data: SygmaBridge::create_deposit_data(
+				DataFormat::EvmAbi,
+				amount,
+				MultiLocation::new(
+					0,
+					X1(Junction::AccountId32 { network: None, id: who.clone().into() }),
+				)
+				.encode(),
+			),
+		}
+	}
+
+	/// Dest of the deposits to a recipient of `dest_domain_id`
+	pub fn dest(&self, dest_domain_id: DomainID) -> MultiLocation {
+		MultiLocation::new(
+			0,
+			X2(
+				slice_to_generalkey(b"ethereum recipient"),
+				slice_to_generalkey(&[dest_domain_id.into_inner()]),
+			),
+		)
+	}
+
+	/// Deposit `amount` of `asset` from the depositor to a recipient of `dest_domain_id`
+	pub fn deposit(
+		&self,
+		asset: &ScenarioAsset,
+		amount: Balance,
+		dest_domain_id: DomainID,
+	) -> DispatchResult {
+		SygmaBridge::deposit(
+			RuntimeOrigin::signed(self.depositor.clone()),
+			Box::new(asset.amount(amount)),
+			Box::new(self.dest(dest_domain_id)),
+		)
+	}
+
+	/// Whether `asset` is held in reserve by the bridge rather than minted and burned
+	pub fn is_reserved(&self, asset: &ScenarioAsset) -> bool {
+		<Runtime as Config>::IsReserve::contains(&asset.amount(0), &MultiLocation::here())
+	}
+
+	pub fn reserve_account(&self, asset: &ScenarioAsset) -> AccountId32 {
+		AccountId32::new(SygmaBridge::get_token_reserved_account(&asset.id()).unwrap())
+	}
+
+	pub fn balance(&self, asset: &ScenarioAsset, who: &AccountId32) -> Balance {
+		match asset.asset_id {
+			Some(asset_id) => Assets::balance(asset_id, who),
+			None => Balances::free_balance(who),
+		}
+	}
+
+	pub fn total_issuance(&self, asset: &ScenarioAsset) -> Balance {
+		match asset.asset_id {
+			Some(asset_id) => <Assets as Inspect<AccountId32>>::total_issuance(asset_id),
+			None => Balances::total_issuance(),
+		}
+	}
+
+	/// Add `amount` of `asset` to the balance of `who`
+	pub fn fund(&self, asset: &ScenarioAsset, who: &AccountId32, amount: Balance) {
+		match asset.asset_id {
+			Some(asset_id) => assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(ASSET_OWNER),
+				codec::Compact(asset_id),
+				who.clone(),
+				amount
+			)),
+			None => assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				who.clone(),
+				Balances::free_balance(who) + amount
+			)),
+		}
+	}
+}
@@ -5,6 +5,14 @@
 //! DATE: 2023-04-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! PLACEHOLDERS: the run below measured `deposit`, `execute_proposal`, `pause_all_bridges`,
+//! `pause_bridge`, `register_domain`, `retry`, `set_mpc_address`, `unpause_all_bridges`,
+//! `unpause_bridge` and `unregister_domain` only. The weights of the other calls, marked
+//! `Placeholder` below, are hand-written estimates, and the reads and writes of the measured calls
+//! were adjusted by hand for the storage they access since. None of these numbers, those of
+//! `deposit` and `execute_proposal` included, are to be relied on until this file is regenerated
+//! from `benchmarking.rs` with `make build-benchmark benchmark-weights`.
 
 // Executed Command:
 // ./target/release/standalone-node-template
@@ -29,7 +37,7 @@
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
-use frame_support::{traits::Get, weights::Weight};
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions for `sygma_bridge`.
@@ -37,77 +45,110 @@ pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:0 w:1)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:2 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
 	fn pause_bridge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `109`
 		//  Estimated: `3683`
-		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(12_000_000, 0)
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3683))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:1)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:4 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	fn unpause_bridge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `143`
 		//  Estimated: `7216`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7216))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:1)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:0)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:2 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	fn set_mpc_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `5034`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5034))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DataFormats (r:1 w:1)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof Skipped: SygmaBridge PauseReasons (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:0 w:1)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
-	/// Storage: SygmaBridge DestChainIds (r:0 w:1)
-	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:0 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
 	fn register_domain() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 10_000_000 picoseconds.
-		Weight::from_parts(10_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Estimated: `7443`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7443))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:1)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
 	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HighestExecutedNonce (r:1 w:0)
+	/// Proof Skipped: SygmaBridge HighestExecutedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainSunsets (r:0 w:1)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:0 w:1)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
 	fn unregister_domain() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `137`
 		//  Estimated: `7204`
-		// Minimum execution time: 16_000_000 picoseconds.
-		Weight::from_parts(17_000_000, 0)
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7204))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:0)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
@@ -118,20 +159,60 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
 	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeVestings (r:1 w:0)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	fn deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
-		//  Estimated: `26878`
+		//  Estimated: `29417`
 		// Minimum execution time: 89_000_000 picoseconds.
 		Weight::from_parts(91_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 26878))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(T::DbWeight::get().reads(25))
+			.saturating_add(T::DbWeight::get().writes(19))
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:0)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	fn retry() -> Weight {
@@ -145,14 +226,18 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:0)
-	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ExecutionLock (r:1 w:1)
+	/// Proof: SygmaBridge ExecutionLock (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
 	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposals (r:0 w:1000)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 1000]`.
 	fn execute_proposal(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -163,39 +248,2177 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 16593))
 			// Standard Error: 18_882
 			.saturating_add(Weight::from_parts(10_748_102, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `b` is `[100, 100000]`.
+	fn verify_signature(b: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(112_415_208, 0)
+			.saturating_add(Weight::from_parts(0, 1491))
+			.saturating_add(Weight::from_parts(3_412, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:1 w:0)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge RequiredSettlementDelay (r:1 w:0)
+	/// Proof: SygmaBridge RequiredSettlementDelay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn per_proposal_fungible() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(53_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 
 	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
 	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
-	/// Storage: SygmaBridge IsPaused (r:3 w:2)
-	/// Proof: SygmaBridge IsPaused (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:2)
+	/// Proof: SygmaBridge PausedRoutes (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:2)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:2 w:2)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
 	fn pause_all_bridges() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `192`
 		//  Estimated: `8445`
-		// Minimum execution time: 82_000_000 picoseconds.
-		Weight::from_parts(84_000_000, 0)
+		// Minimum execution time: 86_000_000 picoseconds.
+		Weight::from_parts(88_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8445))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
 	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
 	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
-	/// Storage: SygmaBridge IsPaused (r:3 w:2)
-	/// Proof: SygmaBridge IsPaused (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:2)
+	/// Proof: SygmaBridge PausedRoutes (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:2)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:2 w:2)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	fn unpause_all_bridges() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
 		//  Estimated: `8445`
-		// Minimum execution time: 81_000_000 picoseconds.
-		Weight::from_parts(99_000_000, 0)
+		// Minimum execution time: 85_000_000 picoseconds.
+		Weight::from_parts(103_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8445))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: SygmaBridge UnclaimedTransfers (r:1 w:1)
+	/// Proof: SygmaBridge UnclaimedTransfers (max_values: None, max_size: Some(1340), added: 3815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_escrowed_transfer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(63_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: SygmaBridge UnclaimedTransfers (r:1 w:1)
+	/// Proof: SygmaBridge UnclaimedTransfers (max_values: None, max_size: Some(1340), added: 3815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn sweep_escrowed_transfer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(64_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge NonceReservations (r:0 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn reserve_nonce() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 14125))
 			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: SygmaBridge NonceReservations (r:1 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn commit_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(92_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29476))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+
+	/// Storage: SygmaBridge NonceReservations (r:1 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	fn reclaim_nonce_reservation() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3626))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge Relayers (r:0 w:1)
+	/// Proof Skipped: SygmaBridge Relayers (max_values: None, max_size: None, mode: Measured)
+	fn add_relayer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge Relayers (r:0 w:1)
+	/// Proof Skipped: SygmaBridge Relayers (max_values: None, max_size: None, mode: Measured)
+	fn remove_relayer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcSigners (r:0 w:1)
+	/// Proof Skipped: SygmaBridge MpcSigners (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcThreshold (r:0 w:1)
+	/// Proof Skipped: SygmaBridge MpcThreshold (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 16]`.
+	fn set_mpc_signers(s: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_412_305, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(Weight::from_parts(96_518, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcSigners (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcSigners (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcThreshold (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcThreshold (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 16]`.
+	fn verify_mpc_threshold(s: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(8_734_601, 0)
+			.saturating_add(Weight::from_parts(0, 1558))
+			.saturating_add(Weight::from_parts(45_329_190, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(s.into()))
+	}
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueHead (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueHead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1000)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_deposit_records(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_207_514, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(11_035_982, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5135).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	fn request_refund() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3617))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn refund_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn deposit_with_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(92_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HealthOracle (r:0 w:1)
+	/// Proof: SygmaBridge HealthOracle (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_health_oracle() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge HealthOracle (r:1 w:0)
+	/// Proof: SygmaBridge HealthOracle (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:1)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	fn report_domain_health() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3632))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn set_inbound_limit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge InboundWindows (r:0 w:1)
+	/// Proof: SygmaBridge InboundWindows (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn clear_inbound_window() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FeeDiscounts (r:0 w:1)
+	/// Proof: SygmaBridge FeeDiscounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_fee_discount() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	fn pause_bridge_with_reason() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3683))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	fn prefund_fees() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_prefund() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(51_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn rebind_resource() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4634))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:0 w:1)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	fn set_data_format() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3483))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge AutoRetryEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AutoRetryEnabled (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_auto_retry() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge GlobalTvlCap (r:0 w:1)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_global_tvl_cap() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge ResourceRebinds (r:4 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1143), added: 3618, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:100 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:0 w:100)
+	/// Proof: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: Some(566), added: 3041, mode: MaxEncodedLen)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:0 w:100)
+	/// Proof: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: Some(566), added: 3041, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn onboard_resource(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(43_512_311, 0)
+			.saturating_add(Weight::from_parts(0, 15462))
+			.saturating_add(Weight::from_parts(18_903_226, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge OnboardedResources (r:2 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:5 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1143), added: 3618, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundWindows (r:0 w:1)
+	/// Proof: SygmaBridge InboundWindows (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AutoRetryEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AutoRetryEnabled (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:0 w:1)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucketConfigs (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucketConfigs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucket (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucket (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn offboard_resource() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(52_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 19086))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: SygmaBridge MinReserveBalance (r:0 w:1)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn set_min_reserve_balance() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge DepositDataOf (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositDataOf (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_deposit_data(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_318_207, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(8_527_613, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3020).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge TokenBucketConfigs (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucketConfigs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucket (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucket (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn set_token_bucket() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:0)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeReserveThresholds (r:0 w:1)
+	/// Proof: SygmaBridge FeeReserveThresholds (max_values: None, max_size: Some(624), added: 3099, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeReserveLowAssets (r:0 w:1)
+	/// Proof: SygmaBridge FeeReserveLowAssets (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn set_fee_reserve_threshold() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4045))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge AckEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AckEnabled (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	fn set_ack_enabled() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3475))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge AcksHalted (r:0 w:1)
+	/// Proof: SygmaBridge AcksHalted (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_acks_halted() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(636), added: 3111, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OverruledResourcePairs (r:1 w:0)
+	/// Proof: SygmaBridge OverruledResourcePairs (max_values: None, max_size: Some(642), added: 3117, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1260), added: 3735, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn resolve_binding_conflict() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4725))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge ExecutionReceipts (r:1000 w:1000)
+	/// Proof: SygmaBridge ExecutionReceipts (max_values: None, max_size: Some(1251), added: 3726, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_execution_receipts(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_104_522, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(8_213_460, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3726).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge RecipientDenylist (r:0 w:1)
+	/// Proof: SygmaBridge RecipientDenylist (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn add_denied_recipient() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge RecipientDenylist (r:1 w:1)
+	/// Proof: SygmaBridge RecipientDenylist (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn remove_denied_recipient() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3611))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PermitNonces (r:1 w:1)
+	/// Proof: SygmaBridge PermitNonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn deposit_with_permit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(143_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(T::DbWeight::get().reads(26))
+			.saturating_add(T::DbWeight::get().writes(19))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:0 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	fn pause_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3610))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unpause_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8981))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestChainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:1)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn schedule_domain_sunset() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7204))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CancelledDeposits (r:0 w:1)
+	/// Proof: SygmaBridge CancelledDeposits (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn cancel_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge EmergencyWithdrawals (r:1 w:1)
+	/// Proof: SygmaBridge EmergencyWithdrawals (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn emergency_withdraw() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(63_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8883))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:0 w:1)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_resource_decimals() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4615))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge RequiredSettlementDelay (r:0 w:1)
+	/// Proof: SygmaBridge RequiredSettlementDelay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_settlement_delay() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge PendingSettlements (r:1 w:1)
+	/// Proof: SygmaBridge PendingSettlements (max_values: None, max_size: Some(575), added: 3050, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:1 w:0)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn finalize_proposal() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FreezeSnapshot (r:1 w:1)
+	/// Proof: SygmaBridge FreezeSnapshot (max_values: Some(1), max_size: Some(8710), added: 9205, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:0)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:100 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:100 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:256 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:256 w:512)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:256 w:0)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MinUnprunedNonce (r:256 w:0)
+	/// Proof Skipped: SygmaBridge MinUnprunedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:256 w:256)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 100]`.
+	/// The range of component `d` is `[0, 256]`.
+	fn emergency_freeze(r: u32, d: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(30_412_508, 0)
+			.saturating_add(Weight::from_parts(0, 10195))
+			.saturating_add(Weight::from_parts(14_806_331, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(12_170_944, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 3625).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2620).saturating_mul(d.into()))
+	}
+	/// Storage: SygmaBridge FreezeSnapshot (r:1 w:1)
+	/// Proof: SygmaBridge FreezeSnapshot (max_values: Some(1), max_size: Some(8710), added: 9205, mode: MaxEncodedLen)
+	fn clear_freeze_snapshot() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10195))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge PendingSettlements (r:1 w:1)
+	/// Proof: SygmaBridge PendingSettlements (max_values: None, max_size: Some(575), added: 3050, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNoncesCount (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNoncesCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HighestExecutedNonce (r:1 w:1)
+	/// Proof Skipped: SygmaBridge HighestExecutedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FailedProposals (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AutoRetryQueue (r:0 w:1)
+	/// Proof Skipped: SygmaBridge AutoRetryQueue (max_values: None, max_size: None, mode: Measured)
+	fn cancel_settlement() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4040))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
+	fn update_domain_chain_id() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge RecipientInboundCap (r:0 w:1)
+	/// Proof: SygmaBridge RecipientInboundCap (max_values: None, max_size: Some(194), added: 2669, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge RecipientInbound (r:0 w:1)
+	/// Proof: SygmaBridge RecipientInbound (max_values: None, max_size: Some(194), added: 2669, mode: MaxEncodedLen)
+	fn set_recipient_inbound_cap() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:1 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:1)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:4 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNoncesCount (r:2 w:0)
+	/// Proof Skipped: SygmaBridge UsedNoncesCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn correct_mpc_key() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8015))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge FeeVestings (r:1 w:1)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	fn set_fee_vesting() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4156))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge FeeVestings (r:1 w:1)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn release_vested_fees() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(42_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests
+impl super::WeightInfo for () {
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:2 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	fn pause_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3683`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3683))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:4 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unpause_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `7216`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7216))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:1)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:2 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_mpc_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `5034`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5034))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DataFormats (r:1 w:1)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof Skipped: SygmaBridge PauseReasons (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:0 w:1)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:0 w:2)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	fn register_domain() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `7443`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7443))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HighestExecutedNonce (r:1 w:0)
+	/// Proof Skipped: SygmaBridge HighestExecutedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainSunsets (r:0 w:1)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:0 w:1)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
+	fn unregister_domain() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `137`
+		//  Estimated: `7204`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7204))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeVestings (r:1 w:0)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `29417`
+		// Minimum execution time: 89_000_000 picoseconds.
+		Weight::from_parts(91_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(RocksDbWeight::get().reads(25))
+			.saturating_add(RocksDbWeight::get().writes(19))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	fn retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177`
+		//  Estimated: `8946`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8946))
+			.saturating_add(RocksDbWeight::get().reads(3))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ExecutionLock (r:1 w:1)
+	/// Proof: SygmaBridge ExecutionLock (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposals (r:0 w:1000)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn execute_proposal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `16593`
+		// Minimum execution time: 123_000_000 picoseconds.
+		Weight::from_parts(151_050_908, 0)
+			.saturating_add(Weight::from_parts(0, 16593))
+			// Standard Error: 18_882
+			.saturating_add(Weight::from_parts(10_748_102, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `b` is `[100, 100000]`.
+	fn verify_signature(b: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(112_415_208, 0)
+			.saturating_add(Weight::from_parts(0, 1491))
+			.saturating_add(Weight::from_parts(3_412, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+	}
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:1 w:0)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge RequiredSettlementDelay (r:1 w:0)
+	/// Proof: SygmaBridge RequiredSettlementDelay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn per_proposal_fungible() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(53_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:2)
+	/// Proof: SygmaBridge PausedRoutes (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:2)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:2 w:2)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	fn pause_all_bridges() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `192`
+		//  Estimated: `8445`
+		// Minimum execution time: 86_000_000 picoseconds.
+		Weight::from_parts(88_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8445))
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:2)
+	/// Proof: SygmaBridge PausedRoutes (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseReasons (r:2 w:2)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:2 w:2)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unpause_all_bridges() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `282`
+		//  Estimated: `8445`
+		// Minimum execution time: 85_000_000 picoseconds.
+		Weight::from_parts(103_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8445))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	/// Storage: SygmaBridge UnclaimedTransfers (r:1 w:1)
+	/// Proof: SygmaBridge UnclaimedTransfers (max_values: None, max_size: Some(1340), added: 3815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_escrowed_transfer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(63_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	/// Storage: SygmaBridge UnclaimedTransfers (r:1 w:1)
+	/// Proof: SygmaBridge UnclaimedTransfers (max_values: None, max_size: Some(1340), added: 3815, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn sweep_escrowed_transfer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(64_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge NonceReservations (r:0 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn reserve_nonce() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 14125))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	/// Storage: SygmaBridge NonceReservations (r:1 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn commit_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(92_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29476))
+			.saturating_add(RocksDbWeight::get().reads(23))
+			.saturating_add(RocksDbWeight::get().writes(17))
+	}
+
+	/// Storage: SygmaBridge NonceReservations (r:1 w:1)
+	/// Proof Skipped: SygmaBridge NonceReservations (max_values: None, max_size: None, mode: Measured)
+	fn reclaim_nonce_reservation() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3626))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge Relayers (r:0 w:1)
+	/// Proof Skipped: SygmaBridge Relayers (max_values: None, max_size: None, mode: Measured)
+	fn add_relayer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge Relayers (r:0 w:1)
+	/// Proof Skipped: SygmaBridge Relayers (max_values: None, max_size: None, mode: Measured)
+	fn remove_relayer() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcSigners (r:0 w:1)
+	/// Proof Skipped: SygmaBridge MpcSigners (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcThreshold (r:0 w:1)
+	/// Proof Skipped: SygmaBridge MpcThreshold (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 16]`.
+	fn set_mpc_signers(s: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_412_305, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(Weight::from_parts(96_518, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcSigners (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcSigners (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcThreshold (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcThreshold (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 16]`.
+	fn verify_mpc_threshold(s: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(8_734_601, 0)
+			.saturating_add(Weight::from_parts(0, 1558))
+			.saturating_add(Weight::from_parts(45_329_190, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(s.into()))
+	}
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueHead (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueHead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1000)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_deposit_records(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_207_514, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(11_035_982, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5135).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	fn request_refund() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3617))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositDataHashes (r:0 w:1)
+	/// Proof: SygmaBridge DepositDataHashes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn refund_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn deposit_with_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(92_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(RocksDbWeight::get().reads(24))
+			.saturating_add(RocksDbWeight::get().writes(18))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HealthOracle (r:0 w:1)
+	/// Proof: SygmaBridge HealthOracle (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_health_oracle() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge HealthOracle (r:1 w:0)
+	/// Proof: SygmaBridge HealthOracle (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:1)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	fn report_domain_health() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3632))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn set_inbound_limit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge InboundWindows (r:0 w:1)
+	/// Proof: SygmaBridge InboundWindows (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn clear_inbound_window() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FeeDiscounts (r:0 w:1)
+	/// Proof: SygmaBridge FeeDiscounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_fee_discount() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	fn pause_bridge_with_reason() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3683))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	fn prefund_fees() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_prefund() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(51_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn rebind_resource() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4634))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:0 w:1)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	fn set_data_format() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3483))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge AutoRetryEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AutoRetryEnabled (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_auto_retry() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge GlobalTvlCap (r:0 w:1)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_global_tvl_cap() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge ResourceRebinds (r:4 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1143), added: 3618, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:100 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:0 w:100)
+	/// Proof: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: Some(566), added: 3041, mode: MaxEncodedLen)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:0 w:100)
+	/// Proof: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: Some(566), added: 3041, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn onboard_resource(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(43_512_311, 0)
+			.saturating_add(Weight::from_parts(0, 15462))
+			.saturating_add(Weight::from_parts(18_903_226, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2508).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge OnboardedResources (r:2 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:5 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1143), added: 3618, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundLimits (r:0 w:1)
+	/// Proof: SygmaBridge InboundLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge InboundWindows (r:0 w:1)
+	/// Proof: SygmaBridge InboundWindows (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AutoRetryEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AutoRetryEnabled (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:0 w:1)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucketConfigs (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucketConfigs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucket (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucket (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn offboard_resource() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(52_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 19086))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	/// Storage: SygmaBridge MinReserveBalance (r:0 w:1)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn set_min_reserve_balance() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge DepositDataOf (r:1000 w:1000)
+	/// Proof: SygmaBridge DepositDataOf (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_deposit_data(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_318_207, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(8_527_613, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3020).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge TokenBucketConfigs (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucketConfigs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TokenBucket (r:0 w:1)
+	/// Proof: SygmaBridge TokenBucket (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn set_token_bucket() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:0)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeReserveThresholds (r:0 w:1)
+	/// Proof: SygmaBridge FeeReserveThresholds (max_values: None, max_size: Some(624), added: 3099, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeeReserveLowAssets (r:0 w:1)
+	/// Proof: SygmaBridge FeeReserveLowAssets (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn set_fee_reserve_threshold() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4045))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge AckEnabled (r:0 w:1)
+	/// Proof: SygmaBridge AckEnabled (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	fn set_ack_enabled() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3475))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge AcksHalted (r:0 w:1)
+	/// Proof: SygmaBridge AcksHalted (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_acks_halted() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:1)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(636), added: 3111, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OverruledResourcePairs (r:1 w:0)
+	/// Proof: SygmaBridge OverruledResourcePairs (max_values: None, max_size: Some(642), added: 3117, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1260), added: 3735, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ProtectedAccounts (r:0 w:2)
+	/// Proof: SygmaBridge ProtectedAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn resolve_binding_conflict() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4725))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge ExecutionReceipts (r:1000 w:1000)
+	/// Proof: SygmaBridge ExecutionReceipts (max_values: None, max_size: Some(1251), added: 3726, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_execution_receipts(n: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(9_104_522, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(8_213_460, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3726).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaBridge RecipientDenylist (r:0 w:1)
+	/// Proof: SygmaBridge RecipientDenylist (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn add_denied_recipient() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge RecipientDenylist (r:1 w:1)
+	/// Proof: SygmaBridge RecipientDenylist (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn remove_denied_recipient() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3611))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PermitNonces (r:1 w:1)
+	/// Proof: SygmaBridge PermitNonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge LastDepositNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge LastDepositNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CounterForDepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge CounterForDepositRecords (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueueTail (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecordQueueTail (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecordQueue (r:0 w:1)
+	/// Proof: SygmaBridge DepositRecordQueue (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainHealth (r:1 w:0)
+	/// Proof: SygmaBridge DomainHealth (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:0)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FeePrefunds (r:1 w:1)
+	/// Proof: SygmaBridge FeePrefunds (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositHistory (r:1 w:1)
+	/// Proof: SygmaBridge DepositHistory (max_values: None, max_size: Some(4102), added: 6577, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvlCap (r:1 w:0)
+	/// Proof: SygmaBridge GlobalTvlCap (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AccountVolume (r:1 w:1)
+	/// Proof: SygmaBridge AccountVolume (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockActivity (r:1 w:1)
+	/// Proof: SygmaBridge BlockActivity (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeafCount (r:2 w:2)
+	/// Proof: SygmaBridge BlockDepositLeafCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge BlockDepositLeaves (r:1 w:2)
+	/// Proof: SygmaBridge BlockDepositLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRoots (r:0 w:1)
+	/// Proof: SygmaBridge DepositRoots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn deposit_with_permit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(143_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 29417))
+			.saturating_add(RocksDbWeight::get().reads(26))
+			.saturating_add(RocksDbWeight::get().writes(19))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:0 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	fn pause_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3610))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:1)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:1 w:1)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PauseHistory (r:1 w:1)
+	/// Proof: SygmaBridge PauseHistory (max_values: None, max_size: Some(331), added: 2806, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:0 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unpause_route() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8981))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestChainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DomainSunsets (r:1 w:1)
+	/// Proof: SygmaBridge DomainSunsets (max_values: None, max_size: Some(13), added: 2488, mode: MaxEncodedLen)
+	fn schedule_domain_sunset() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7204))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge GlobalTvl (r:1 w:1)
+	/// Proof: SygmaBridge GlobalTvl (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CancelledDeposits (r:0 w:1)
+	/// Proof: SygmaBridge CancelledDeposits (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn cancel_deposit() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:3 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge EmergencyWithdrawals (r:1 w:1)
+	/// Proof: SygmaBridge EmergencyWithdrawals (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn emergency_withdraw() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(63_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8883))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:0 w:1)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_resource_decimals() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4615))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge RequiredSettlementDelay (r:0 w:1)
+	/// Proof: SygmaBridge RequiredSettlementDelay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_settlement_delay() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge PendingSettlements (r:1 w:1)
+	/// Proof: SygmaBridge PendingSettlements (max_values: None, max_size: Some(575), added: 3050, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PausedRoutes (r:1 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceRebinds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DataFormats (r:1 w:0)
+	/// Proof: SygmaBridge DataFormats (max_values: None, max_size: Some(18), added: 2493, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceDomainDecimals (r:1 w:0)
+	/// Proof: SygmaBridge ResourceDomainDecimals (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DefaultResourceDecimals (r:1 w:0)
+	/// Proof: SygmaBridge DefaultResourceDecimals (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MinReserveBalance (r:1 w:0)
+	/// Proof: SygmaBridge MinReserveBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn finalize_proposal() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FreezeSnapshot (r:1 w:1)
+	/// Proof: SygmaBridge FreezeSnapshot (max_values: Some(1), max_size: Some(8710), added: 9205, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge OnboardedResources (r:1 w:0)
+	/// Proof: SygmaBridge OnboardedResources (max_values: None, max_size: Some(580), added: 3055, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceRebinds (r:100 w:0)
+	/// Proof: SygmaBridge ResourceRebinds (max_values: None, max_size: Some(1150), added: 3625, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:100 w:0)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:256 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:256 w:512)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositCounts (r:256 w:0)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MinUnprunedNonce (r:256 w:0)
+	/// Proof Skipped: SygmaBridge MinUnprunedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PauseReasons (r:256 w:256)
+	/// Proof: SygmaBridge PauseReasons (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 100]`.
+	/// The range of component `d` is `[0, 256]`.
+	fn emergency_freeze(r: u32, d: u32, ) -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(30_412_508, 0)
+			.saturating_add(Weight::from_parts(0, 10195))
+			.saturating_add(Weight::from_parts(14_806_331, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(12_170_944, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 3625).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2620).saturating_mul(d.into()))
+	}
+	/// Storage: SygmaBridge FreezeSnapshot (r:1 w:1)
+	/// Proof: SygmaBridge FreezeSnapshot (max_values: Some(1), max_size: Some(8710), added: 9205, mode: MaxEncodedLen)
+	fn clear_freeze_snapshot() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10195))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge PendingSettlements (r:1 w:1)
+	/// Proof: SygmaBridge PendingSettlements (max_values: None, max_size: Some(575), added: 3050, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNoncesCount (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNoncesCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge HighestExecutedNonce (r:1 w:1)
+	/// Proof Skipped: SygmaBridge HighestExecutedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FailedProposals (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposalCounts (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposalCounts (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AutoRetryQueue (r:0 w:1)
+	/// Proof Skipped: SygmaBridge AutoRetryQueue (max_values: None, max_size: None, mode: Measured)
+	fn cancel_settlement() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4040))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DestChainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UnregisteredChainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge UnregisteredChainIds (max_values: None, max_size: None, mode: Measured)
+	fn update_domain_chain_id() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge RecipientInboundCap (r:0 w:1)
+	/// Proof: SygmaBridge RecipientInboundCap (max_values: None, max_size: Some(194), added: 2669, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge RecipientInbound (r:0 w:1)
+	/// Proof: SygmaBridge RecipientInbound (max_values: None, max_size: Some(194), added: 2669, mode: MaxEncodedLen)
+	fn set_recipient_inbound_cap() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcKeyGraceEnd (r:1 w:1)
+	/// Proof: SygmaBridge MpcKeyGraceEnd (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:1)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:2 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PausedRoutes (r:4 w:0)
+	/// Proof Skipped: SygmaBridge PausedRoutes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNoncesCount (r:2 w:0)
+	/// Proof Skipped: SygmaBridge UsedNoncesCount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ManualInterventions (r:1 w:1)
+	/// Proof: SygmaBridge ManualInterventions (max_values: Some(1), max_size: Some(5698), added: 6193, mode: MaxEncodedLen)
+	fn correct_mpc_key() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8015))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge FeeVestings (r:1 w:1)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	fn set_fee_vesting() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4156))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge FeeVestings (r:1 w:1)
+	/// Proof: SygmaBridge FeeVestings (max_values: None, max_size: Some(691), added: 3166, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn release_vested_fees() -> Weight {
+		// Placeholder: hand-written estimate, not benchmarked
+		Weight::from_parts(42_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
 }
@@ -0,0 +1,48 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Acknowledgment of an executed proposal, sent back to its origin domain as a generic message.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::deposit::{hex_zero_padding_32, DataFormat};
+
+/// Outcome of an executed proposal carried by its acknowledgment. Each variant keeps its index,
+/// new variants are appended.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub enum AckStatus {
+	/// Whole amount credited to the recipient
+	#[codec(index = 0)]
+	Delivered,
+	/// Recipient credited less than the amount
+	#[codec(index = 1)]
+	PartiallyDelivered,
+	/// Amount held in escrow until the recipient claims it
+	#[codec(index = 2)]
+	Escrowed,
+	/// Transfer forwarded to its final dest domain
+	#[codec(index = 3)]
+	Forwarded,
+}
+
+/// Encode the acknowledgment of the proposal of `deposit_nonce`, `delivered_amount` is in 18
+/// decimals. With `DataFormat::EvmAbi` it's `abi.encodePacked(uint64, uint8, uint256)`, with
+/// `DataFormat::Scale` the SCALE encoded `(u64, AckStatus, u128)`.
+pub fn ack_data(
+	format: DataFormat,
+	deposit_nonce: u64,
+	status: AckStatus,
+	delivered_amount: u128,
+) -> Vec<u8> {
+	match format {
+		DataFormat::EvmAbi => [
+			&deposit_nonce.to_be_bytes()[..],
+			&status.encode()[..],
+			&hex_zero_padding_32(delivered_amount)[..],
+		]
+		.concat(),
+		DataFormat::Scale => (deposit_nonce, status, delivered_amount).encode(),
+	}
+}
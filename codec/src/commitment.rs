@@ -0,0 +1,83 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Commitment to the deposits of a block, for the dest domains verifying deposits against a
+//! light client of the origin chain rather than trusting the relayers.
+//!
+//! The leaf of a deposit is the keccak256 of the ABI encoded
+//! `(uint8 destDomainID, uint64 depositNonce, bytes32 resourceID, bytes32 keccak256(data))`,
+//! `data` being the deposit data as relayed. The deposits of a block are committed to by the
+//! root of a binary Merkle tree over their leaves, in deposit order. Pairs are hashed sorted,
+//! the way `MerkleProof` of OpenZeppelin verifies them, and a node without sibling is promoted
+//! to the next level as is.
+
+use alloc::vec::Vec;
+use ethabi::{encode as abi_encode, token::Token};
+
+use crate::keccak_256;
+
+/// Leaf of a deposit in the Merkle tree of its block
+pub fn deposit_leaf(
+	dest_domain_id: u8,
+	deposit_nonce: u64,
+	resource_id: [u8; 32],
+	data: &[u8],
+) -> [u8; 32] {
+	keccak_256(&abi_encode(&[
+		Token::Uint(dest_domain_id.into()),
+		Token::Uint(deposit_nonce.into()),
+		Token::FixedBytes(resource_id.to_vec()),
+		Token::FixedBytes(keccak_256(data).to_vec()),
+	]))
+}
+
+/// Root of the Merkle tree of `leaves`, zero if there is no leaf
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		level = next_level(&level);
+	}
+	level.first().copied().unwrap_or_default()
+}
+
+/// Proof of the leaf at `index` in the Merkle tree of `leaves`, the siblings from the leaf
+/// level up. `None` if `index` is out of range.
+pub fn merkle_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+	if index >= leaves.len() {
+		return None;
+	}
+	let mut proof = Vec::new();
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(index ^ 1) {
+			proof.push(*sibling);
+		}
+		level = next_level(&level);
+		index /= 2;
+	}
+	Some(proof)
+}
+
+/// Return true if `proof` proves `leaf` in the Merkle tree of `root`
+pub fn verify_merkle_proof(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+	proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == root
+}
+
+/// Parent level of `level`, the last node is promoted if it has no sibling
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[left, right] => hash_pair(left, right),
+			_ => pair[0],
+		})
+		.collect()
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+	if a <= b {
+		keccak_256(&[&a[..], &b[..]].concat())
+	} else {
+		keccak_256(&[&b[..], &a[..]].concat())
+	}
+}
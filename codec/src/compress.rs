@@ -0,0 +1,46 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Optional compression of deposit data, for domains exchanging large payloads. Compressed
+//! deposit data starts with a data version byte:
+//! data version              uint8       byte   0
+//! payload                   bytes       bytes  1 - END
+//!
+//! With `DATA_VERSION_LZ4` the payload is the little endian `u32` size of the deposit data
+//! followed by its LZ4 block. Both ends of a route must agree on carrying the version byte.
+
+use alloc::vec::Vec;
+
+/// Payload is the deposit data itself
+pub const DATA_VERSION_RAW: u8 = 0;
+/// Payload is the LZ4 block of the deposit data, prefixed by its size
+pub const DATA_VERSION_LZ4: u8 = 1;
+
+/// Prefix `data` with its data version, LZ4 compressing it if that makes it smaller
+pub fn compress_deposit_data(data: &[u8]) -> Vec<u8> {
+	let block = lz4_flex::block::compress(data);
+	match u32::try_from(data.len()) {
+		Ok(size) if block.len() + 4 < data.len() => {
+			[&[DATA_VERSION_LZ4][..], &size.to_le_bytes()[..], &block[..]].concat()
+		},
+		_ => [&[DATA_VERSION_RAW][..], data].concat(),
+	}
+}
+
+/// Strip the data version of `data`, decompressing its payload if needed. Return `None` if the
+/// version is unknown, the payload is malformed or the deposit data exceeds `max_size` bytes,
+/// which is checked before anything is allocated.
+pub fn decompress_deposit_data(data: &[u8], max_size: usize) -> Option<Vec<u8>> {
+	match data.split_first()? {
+		(&DATA_VERSION_RAW, payload) if payload.len() <= max_size => Some(payload.to_vec()),
+		(&DATA_VERSION_LZ4, payload) if payload.len() >= 4 => {
+			let (size, block) = payload.split_at(4);
+			let size = u32::from_le_bytes(size.try_into().ok()?) as usize;
+			if size > max_size {
+				return None;
+			}
+			lz4_flex::block::decompress(block, size).ok().filter(|data| data.len() == size)
+		},
+		_ => None,
+	}
+}
@@ -0,0 +1,83 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Deposit data carried by deposits and proposals: the amount, in 18 decimals, the recipient
+//! and an optional extra section, see `extra`.
+
+use alloc::vec::Vec;
+use codec::{Compact, CompactLen, Decode, Encode, MaxEncodedLen};
+use ethabi::ethereum_types::U256;
+use scale_info::TypeInfo;
+
+/// Encoding of the amount and recipient of the deposit data exchanged with a domain. The
+/// extra section, if any, follows them unchanged in both formats.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub enum DataFormat {
+	/// 32 bytes big endian amount, 32 bytes big endian recipient length and the recipient,
+	/// as expected by the EVM bridge contracts
+	#[codec(index = 0)]
+	EvmAbi,
+	/// SCALE encoded `(u128, Vec<u8>)` of the amount and the recipient
+	#[codec(index = 1)]
+	Scale,
+}
+
+/// Encode the amount and recipient of a deposit, without extra section
+pub fn deposit_data(format: DataFormat, amount: u128, recipient: &[u8]) -> Vec<u8> {
+	match format {
+		DataFormat::EvmAbi => [
+			&hex_zero_padding_32(amount)[..],
+			&hex_zero_padding_32(recipient.len() as u128)[..],
+			recipient,
+		]
+		.concat(),
+		DataFormat::Scale => (amount, recipient).encode(),
+	}
+}
+
+/// Length of the deposit data created for a recipient of `recipient_len` bytes, without the
+/// extra section
+pub fn deposit_data_len(format: DataFormat, recipient_len: usize) -> usize {
+	let header_len = match format {
+		DataFormat::EvmAbi => 64,
+		DataFormat::Scale => {
+			16 + Compact::<u32>::compact_len(&u32::try_from(recipient_len).unwrap_or(u32::MAX))
+		},
+	};
+	header_len.saturating_add(recipient_len)
+}
+
+/// Split deposit data into the amount, the recipient data and the extra section, `None` if
+/// it's malformed. With `DataFormat::EvmAbi` the data is laid out as follows:
+/// amount                    uint256     bytes  0 - 32
+/// recipient data length     uint256     bytes  32 - 64
+/// recipient data            bytes       bytes  64 - 64 + len(recipient data)
+/// extra section             bytes       bytes  64 + len(recipient data) - END
+pub fn split_deposit_data(format: DataFormat, data: &[u8]) -> Option<(u128, &[u8], &[u8])> {
+	let (amount, recipient_len, header_len) = match format {
+		DataFormat::EvmAbi => {
+			if data.len() < 64 {
+				return None;
+			}
+			let amount: u128 = U256::from_big_endian(&data[0..32]).try_into().ok()?;
+			let recipient_len: usize = U256::from_big_endian(&data[32..64]).try_into().ok()?;
+			(amount, recipient_len, 64)
+		},
+		DataFormat::Scale => {
+			let mut input = data;
+			let amount = u128::decode(&mut input).ok()?;
+			let recipient_len = <Compact<u32>>::decode(&mut input).ok()?.0 as usize;
+			(amount, recipient_len, data.len() - input.len())
+		},
+	};
+	let recipient_end = header_len.checked_add(recipient_len)?;
+	let recipient = data.get(header_len..recipient_end)?;
+	Some((amount, recipient, &data[recipient_end..]))
+}
+
+/// 32 bytes big endian encoding of `i`, as an ABI encoded `uint256`
+pub fn hex_zero_padding_32(i: u128) -> [u8; 32] {
+	let mut result = [0u8; 32];
+	U256::from(i).to_big_endian(&mut result);
+	result
+}
@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 /// Port from https://github.com/gakonst/ethers-rs/blob/master/ethers-core/src/types/transaction/eip712.rs
-/// Replace hash provided by `sp_io`
+/// Replace hash provided by `tiny-keccak`
+use alloc::{vec, vec::Vec};
 use ethabi::{
 	encode as abi_encode,
 	ethereum_types::{Address, U256},
 	token::Token,
 };
-use sp_io::hashing::keccak_256;
-use sp_std::{vec, vec::Vec};
+
+use crate::keccak_256;
 
 /// Pre-computed value of the following statement:
 ///
@@ -0,0 +1,81 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Extra section of deposit data, a sequence of type-length-value entries:
+//! tag                       uint8       byte   0
+//! value length              uint8       byte   1
+//! value                     bytes       bytes  2 - 2 + value length
+//!
+//! Tags are registered below, each tag appears at most once. Readers skip the entries of the
+//! tags they don't know, so that an extension can be added without breaking the domains that
+//! don't support it yet.
+
+use alloc::vec::Vec;
+
+// A tag is never reassigned, and tag 0 is never assigned. Tag 3 was set aside for the
+// acknowledgment requests of the origin domain, acknowledgments are enabled per domain instead
+// and it stays unassigned.
+
+/// Memo of the transfer, opaque to the bridge
+pub const EXTRA_TAG_MEMO: u8 = 1;
+/// Final dest domain of a transfer routed through an intermediate domain, a single byte
+pub const EXTRA_TAG_ROUTE: u8 = 2;
+/// Amount locked on the origin chain in the decimals of the asset there, a 16 bytes big endian
+/// amount followed by the decimals byte. It's checked against the amount credited.
+pub const EXTRA_TAG_SOURCE_AMOUNT: u8 = 4;
+/// Metadata of the deposited asset, see `metadata`
+pub const EXTRA_TAG_ASSET_METADATA: u8 = 5;
+/// Tags left to downstream chains for their own extensions, never assigned here
+pub const EXTRA_TAGS_DOWNSTREAM: core::ops::RangeInclusive<u8> = 0x80..=0xff;
+
+/// Max length of the value of an entry
+pub const MAX_EXTRA_VALUE_LEN: usize = u8::MAX as usize;
+
+/// Why entries can not be encoded in an extra section
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ExtraError {
+	/// Tag appears more than once
+	DuplicateTag(u8),
+	/// Value of the tag exceeds `MAX_EXTRA_VALUE_LEN`
+	ValueTooLong(u8),
+	/// Extra section would exceed the max size
+	TooLarge,
+}
+
+/// Encode `entries` as an extra section of at most `max_size` bytes
+pub fn encode_extra(entries: &[(u8, &[u8])], max_size: usize) -> Result<Vec<u8>, ExtraError> {
+	let mut extra = Vec::new();
+	for (i, (tag, value)) in entries.iter().enumerate() {
+		if entries[..i].iter().any(|(previous, _)| previous == tag) {
+			return Err(ExtraError::DuplicateTag(*tag));
+		}
+		let len = u8::try_from(value.len()).map_err(|_| ExtraError::ValueTooLong(*tag))?;
+		extra.extend_from_slice(&[*tag, len]);
+		extra.extend_from_slice(value);
+	}
+	if extra.len() > max_size {
+		return Err(ExtraError::TooLarge);
+	}
+	Ok(extra)
+}
+
+/// Decode the entries of `known_tags` in an extra section, in the order they appear. The
+/// entries of other tags are skipped. Return `None` if an entry is truncated or a tag repeats.
+pub fn decode_extra<'a>(extra: &'a [u8], known_tags: &[u8]) -> Option<Vec<(u8, &'a [u8])>> {
+	let mut entries = Vec::new();
+	let mut seen = Vec::new();
+	let mut input = extra;
+	while let [tag, len, rest @ ..] = input {
+		let value = rest.get(..*len as usize)?;
+		if seen.contains(tag) {
+			return None;
+		}
+		seen.push(*tag);
+		if known_tags.contains(tag) {
+			entries.push((*tag, value));
+		}
+		input = &rest[*len as usize..];
+	}
+	// a lone byte is a truncated entry
+	input.is_empty().then_some(entries)
+}
@@ -0,0 +1,353 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Encoding shared by the Sygma bridge and its relayers.
+//!
+//! - `deposit`: deposit data exchanged with a domain, in the format configured for it
+//! - `recipient`: recipient of deposit data prefixed with its format, for the dest handler
+//! - `extra`: type-length-value entries of the extra section of deposit data
+//! - `multi`: deposit data of a deposit moving several assets under one nonce
+//! - `metadata`: metadata of an asset attached to its deposits for the dest domain
+//! - `compress`: optional LZ4 compression of deposit data behind a data version byte
+//! - `ack`: acknowledgment of an executed proposal sent back to its origin domain
+//! - `commitment`: Merkle root over the deposits of a block, for light client verification
+//! - `proposal`: digest the MPC signs for a batch of proposals, the EIP-712 hash expected by
+//!   the EVM bridge contracts
+//! - `vectors`: golden vectors pinning them, checked by this crate and by the bridge pallet
+//!
+//! The crate has no frame dependencies and builds without `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod ack;
+pub mod commitment;
+pub mod compress;
+pub mod deposit;
+pub mod eip712;
+pub mod encode;
+pub mod extra;
+pub mod metadata;
+pub mod multi;
+pub mod proposal;
+pub mod recipient;
+pub mod vectors;
+
+pub use ack::{ack_data, AckStatus};
+pub use commitment::{deposit_leaf, merkle_proof, merkle_root, verify_merkle_proof};
+pub use compress::{
+	compress_deposit_data, decompress_deposit_data, DATA_VERSION_LZ4, DATA_VERSION_RAW,
+};
+pub use deposit::{
+	deposit_data, deposit_data_len, hex_zero_padding_32, split_deposit_data, DataFormat,
+};
+pub use ethabi::ethereum_types::{Address, U256};
+pub use extra::{
+	decode_extra, encode_extra, ExtraError, EXTRA_TAGS_DOWNSTREAM, EXTRA_TAG_ASSET_METADATA,
+	EXTRA_TAG_MEMO, EXTRA_TAG_ROUTE, EXTRA_TAG_SOURCE_AMOUNT, MAX_EXTRA_VALUE_LEN,
+};
+pub use metadata::{
+	asset_metadata, split_asset_metadata, MAX_ASSET_NAME_LEN, MAX_ASSET_SYMBOL_LEN,
+};
+pub use multi::{
+	multi_asset_deposit_data, multi_asset_deposit_data_len, split_multi_asset_deposit_data,
+	AssetItem,
+};
+pub use proposal::{proposal_digest, ProposalRef};
+pub use recipient::{split_tagged_recipient, tagged_recipient, RecipientFormat};
+
+/// Keccak-256 hash of `data`, as computed by the EVM
+pub fn keccak_256(data: &[u8]) -> [u8; 32] {
+	use tiny_keccak::{Hasher, Keccak};
+
+	let mut keccak = Keccak::v256();
+	keccak.update(data);
+	let mut output = [0u8; 32];
+	keccak.finalize(&mut output);
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		eip712::{EIP712_DOMAIN_TYPE_HASH, EIP712_DOMAIN_TYPE_HASH_WITH_SALT},
+		vectors::{ACK_DATA, DEPOSIT_DATA, PROPOSAL_DIGESTS, TAGGED_RECIPIENTS},
+	};
+	use codec::Encode;
+
+	#[test]
+	fn keccak_256_should_match_domain_type_hashes() {
+		assert_eq!(
+			keccak_256(
+				b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+			),
+			EIP712_DOMAIN_TYPE_HASH
+		);
+		assert_eq!(
+			keccak_256(
+				b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)"
+			),
+			EIP712_DOMAIN_TYPE_HASH_WITH_SALT
+		);
+	}
+
+	#[test]
+	fn hex_zero_padding_32_should_be_big_endian() {
+		let mut expected = [0u8; 32];
+		expected[31] = 100;
+		assert_eq!(hex_zero_padding_32(100), expected);
+		expected[30] = 1;
+		assert_eq!(hex_zero_padding_32(356), expected);
+	}
+
+	#[test]
+	fn deposit_data_should_match_vectors() {
+		for vector in DEPOSIT_DATA {
+			let mut data = deposit_data(vector.format, vector.amount, vector.recipient);
+			assert_eq!(data.len(), deposit_data_len(vector.format, vector.recipient.len()));
+			data.extend_from_slice(vector.extra);
+			assert_eq!(data, vector.data);
+
+			assert_eq!(
+				split_deposit_data(vector.format, vector.data),
+				Some((vector.amount, vector.recipient, vector.extra))
+			);
+		}
+	}
+
+	#[test]
+	fn split_deposit_data_should_reject_truncated_data() {
+		for vector in DEPOSIT_DATA {
+			let recipient_end = vector.data.len() - vector.extra.len();
+			let header_len = recipient_end - vector.recipient.len();
+			assert_eq!(split_deposit_data(vector.format, &vector.data[..header_len - 1]), None);
+			assert_eq!(split_deposit_data(vector.format, &vector.data[..recipient_end - 1]), None);
+		}
+	}
+
+	#[test]
+	fn tagged_recipients_should_match_vectors() {
+		for vector in TAGGED_RECIPIENTS {
+			assert_eq!(vector.data[0], vector.format.encode()[0]);
+			assert_eq!(
+				tagged_recipient(vector.format, vector.recipient),
+				Some(vector.data.to_vec())
+			);
+			assert_eq!(
+				split_tagged_recipient(vector.data),
+				Some((vector.format, vector.recipient))
+			);
+		}
+	}
+
+	#[test]
+	fn tagged_recipient_should_reject_mismatched_formats() {
+		let evm_address = [1u8; 20];
+		let account = [2u8; 32];
+		assert_eq!(tagged_recipient(RecipientFormat::RawEvm20, &account), None);
+		assert_eq!(tagged_recipient(RecipientFormat::RawAccount32, &evm_address), None);
+		assert_eq!(split_tagged_recipient(&[&[0u8][..], &account].concat()), None);
+		assert_eq!(split_tagged_recipient(&[&[1u8][..], &evm_address].concat()), None);
+		// unknown format and missing format
+		assert_eq!(split_tagged_recipient(&[&[3u8][..], &account].concat()), None);
+		assert_eq!(split_tagged_recipient(&[]), None);
+
+		assert!(RecipientFormat::RawEvm20.is_supported_by(DataFormat::EvmAbi));
+		assert!(!RecipientFormat::RawEvm20.is_supported_by(DataFormat::Scale));
+		for format in [RecipientFormat::RawAccount32, RecipientFormat::ScaleMultiLocation] {
+			assert!(!format.is_supported_by(DataFormat::EvmAbi));
+			assert!(format.is_supported_by(DataFormat::Scale));
+		}
+	}
+
+	#[test]
+	fn compressed_deposit_data_should_round_trip() {
+		// data that does not shrink is carried raw
+		assert_eq!(compress_deposit_data(b"sygma"), [&[DATA_VERSION_RAW][..], b"sygma"].concat());
+		assert_eq!(
+			decompress_deposit_data(&[&[DATA_VERSION_RAW][..], b"sygma"].concat(), 5),
+			Some(b"sygma".to_vec())
+		);
+
+		for vector in DEPOSIT_DATA {
+			let data = compress_deposit_data(vector.data);
+			assert_eq!(
+				decompress_deposit_data(&data, vector.data.len()),
+				Some(vector.data.to_vec())
+			);
+		}
+
+		// large calldata is compressed
+		let calldata = [&[7u8; 600][..], b"transfer(address,uint256)", &[0u8; 400][..]].concat();
+		let payload = deposit_data(DataFormat::EvmAbi, 1_000_000_000_000_000_000, &calldata);
+		let data = compress_deposit_data(&payload);
+		assert_eq!(data[0], DATA_VERSION_LZ4);
+		assert_eq!(data[1..5], (payload.len() as u32).to_le_bytes());
+		assert!(data.len() < payload.len());
+		assert_eq!(decompress_deposit_data(&data, payload.len()), Some(payload.clone()));
+		assert_eq!(
+			split_deposit_data(
+				DataFormat::EvmAbi,
+				&decompress_deposit_data(&data, payload.len()).unwrap()
+			),
+			Some((1_000_000_000_000_000_000, &calldata[..], &[][..]))
+		);
+	}
+
+	#[test]
+	fn decompress_deposit_data_should_reject_invalid_data() {
+		let payload = [0u8; 256];
+		let data = compress_deposit_data(&payload);
+		assert_eq!(data[0], DATA_VERSION_LZ4);
+
+		// deposit data above the max size is rejected before decompression
+		assert_eq!(decompress_deposit_data(&data, payload.len() - 1), None);
+		assert_eq!(decompress_deposit_data(&compress_deposit_data(&payload[..10]), 9), None);
+		// unknown version, missing version, truncated block and wrong size
+		assert_eq!(decompress_deposit_data(&[&[2u8][..], &data[1..]].concat(), 256), None);
+		assert_eq!(decompress_deposit_data(&[], 256), None);
+		assert_eq!(decompress_deposit_data(&data[..data.len() - 1], 256), None);
+		let mut wrong_size = data.clone();
+		wrong_size[1] = 255;
+		assert_eq!(decompress_deposit_data(&wrong_size, 256), None);
+	}
+
+	#[test]
+	fn extra_section_should_round_trip() {
+		let extra = encode_extra(&[(2, &[7]), (1, b"memo")], 16).unwrap();
+		assert_eq!(extra, [&[2, 1, 7, 1, 4][..], b"memo"].concat());
+		assert_eq!(decode_extra(&extra, &[1, 2]), Some(vec![(2, &[7][..]), (1, &b"memo"[..])]));
+		assert_eq!(decode_extra(&[], &[1, 2]), Some(vec![]));
+
+		assert_eq!(encode_extra(&[(2, &[7]), (2, &[8])], 16), Err(ExtraError::DuplicateTag(2)));
+		assert_eq!(encode_extra(&[(1, &[0u8; 256])], 512), Err(ExtraError::ValueTooLong(1)));
+		assert_eq!(encode_extra(&[(1, b"memo")], 5), Err(ExtraError::TooLarge));
+	}
+
+	#[test]
+	fn decode_extra_should_skip_unknown_tags() {
+		// unknown tags 0x90 and 5 around and between the known 1 and 2
+		let extra =
+			[&[0x90, 3, 1, 2, 3][..], &[2, 1, 7], &[5, 0], &[1, 4], b"memo", &[0xff, 2, 0, 0]]
+				.concat();
+		assert_eq!(decode_extra(&extra, &[1, 2]), Some(vec![(2, &[7][..]), (1, &b"memo"[..])]));
+		assert_eq!(decode_extra(&extra, &[3]), Some(vec![]));
+
+		// truncated value, lone tag and repeated tag, known or not
+		assert_eq!(decode_extra(&extra[..extra.len() - 1], &[1, 2]), None);
+		assert_eq!(decode_extra(&[2, 1, 7, 5], &[2]), None);
+		assert_eq!(decode_extra(&[2, 1, 7, 2, 1, 8], &[2]), None);
+		assert_eq!(decode_extra(&[5, 0, 5, 0], &[2]), None);
+	}
+
+	#[test]
+	fn asset_metadata_should_round_trip() {
+		let metadata = asset_metadata(6, b"USD Coin", b"USDC").unwrap();
+		assert_eq!(metadata, [&[6, 8][..], b"USD Coin", &[4], b"USDC"].concat());
+		assert_eq!(split_asset_metadata(&metadata), Some((6, &b"USD Coin"[..], &b"USDC"[..])));
+		assert_eq!(
+			split_asset_metadata(&asset_metadata(18, b"", b"").unwrap()),
+			Some((18, &[][..], &[][..]))
+		);
+		// metadata fits in an extra entry
+		assert!(3 + MAX_ASSET_NAME_LEN + MAX_ASSET_SYMBOL_LEN <= MAX_EXTRA_VALUE_LEN);
+
+		assert_eq!(asset_metadata(6, &[b'a'; MAX_ASSET_NAME_LEN + 1], b"USDC"), None);
+		assert_eq!(asset_metadata(6, b"USD Coin", &[b'a'; MAX_ASSET_SYMBOL_LEN + 1]), None);
+		// truncated metadata and trailing bytes are rejected
+		assert_eq!(split_asset_metadata(&metadata[..metadata.len() - 1]), None);
+		assert_eq!(split_asset_metadata(&metadata[..9]), None);
+		assert_eq!(split_asset_metadata(&[&metadata[..], &[0]].concat()), None);
+	}
+
+	#[test]
+	fn multi_asset_deposit_data_should_round_trip() {
+		let items: [AssetItem; 2] = [([1u8; 32], 100), ([2u8; 32], u128::MAX)];
+		let recipient = b"ethereum recipient";
+
+		let data = multi_asset_deposit_data(DataFormat::EvmAbi, &items, recipient);
+		assert_eq!(data[..32], hex_zero_padding_32(2));
+		assert_eq!(data[32..64], [1u8; 32]);
+		assert_eq!(data[64..96], hex_zero_padding_32(100));
+		assert_eq!(data[160..192], hex_zero_padding_32(recipient.len() as u128));
+		assert_eq!(data[192..], recipient[..]);
+
+		for format in [DataFormat::EvmAbi, DataFormat::Scale] {
+			for count in 0..=items.len() {
+				let data = multi_asset_deposit_data(format, &items[..count], recipient);
+				assert_eq!(
+					data.len(),
+					multi_asset_deposit_data_len(format, count, recipient.len())
+				);
+				assert_eq!(
+					split_multi_asset_deposit_data(format, &data),
+					Some((items[..count].to_vec(), &recipient[..]))
+				);
+				// truncated data and trailing bytes are rejected
+				assert_eq!(split_multi_asset_deposit_data(format, &data[..data.len() - 1]), None);
+				assert_eq!(
+					split_multi_asset_deposit_data(format, &[&data[..], &[0]].concat()),
+					None
+				);
+			}
+		}
+
+		// a count beyond the data is rejected before anything is allocated
+		let mut data = multi_asset_deposit_data(DataFormat::EvmAbi, &items, recipient);
+		data[..32].copy_from_slice(&[0xff; 32]);
+		assert_eq!(split_multi_asset_deposit_data(DataFormat::EvmAbi, &data), None);
+		data[..32].copy_from_slice(&hex_zero_padding_32(u64::MAX as u128));
+		assert_eq!(split_multi_asset_deposit_data(DataFormat::EvmAbi, &data), None);
+	}
+
+	#[test]
+	fn merkle_proof_should_verify_every_deposit() {
+		let leaves: Vec<[u8; 32]> = (0..7u64)
+			.map(|nonce| deposit_leaf(1, nonce, [1u8; 32], b"deposit data"))
+			.collect();
+		assert_eq!(merkle_root(&[]), [0u8; 32]);
+		assert_eq!(merkle_root(&leaves[..1]), leaves[0]);
+
+		for count in 1..=leaves.len() {
+			let root = merkle_root(&leaves[..count]);
+			for (index, leaf) in leaves[..count].iter().enumerate() {
+				let proof = merkle_proof(&leaves[..count], index).unwrap();
+				assert!(verify_merkle_proof(root, *leaf, &proof));
+				// a leaf of another deposit, or of the same deposit with other data, is rejected
+				assert!(!verify_merkle_proof(root, deposit_leaf(1, 9, [1u8; 32], b""), &proof));
+			}
+			assert_eq!(merkle_proof(&leaves[..count], count), None);
+		}
+
+		// sibling order doesn't matter
+		assert_eq!(merkle_root(&leaves[..2]), merkle_root(&[leaves[1], leaves[0]]));
+		assert_ne!(deposit_leaf(1, 0, [1u8; 32], b"a"), deposit_leaf(2, 0, [1u8; 32], b"a"));
+	}
+
+	#[test]
+	fn ack_data_should_match_vectors() {
+		for vector in ACK_DATA {
+			assert_eq!(
+				ack_data(
+					vector.format,
+					vector.deposit_nonce,
+					vector.status,
+					vector.delivered_amount
+				),
+				vector.data
+			);
+		}
+	}
+
+	#[test]
+	fn proposal_digest_should_match_vectors() {
+		for vector in PROPOSAL_DIGESTS {
+			assert_eq!(
+				proposal_digest(vector.proposals, vector.chain_id, vector.verifying_contract),
+				vector.digest
+			);
+		}
+	}
+}
@@ -0,0 +1,45 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Metadata of an asset carried by an entry of the extra section of deposit data, for the dest
+//! domain to register an asset bridged for the first time:
+//! decimals                  uint8       byte   0
+//! name length               uint8       byte   1
+//! name                      bytes       bytes  2 - 2 + name length
+//! symbol length             uint8       byte   2 + name length
+//! symbol                    bytes       bytes  3 + name length - END
+
+use alloc::vec::Vec;
+
+/// Max length of the name of an asset
+pub const MAX_ASSET_NAME_LEN: usize = 64;
+/// Max length of the symbol of an asset
+pub const MAX_ASSET_SYMBOL_LEN: usize = 16;
+
+/// Encode the metadata of an asset, `None` if the name or the symbol is too long
+pub fn asset_metadata(decimals: u8, name: &[u8], symbol: &[u8]) -> Option<Vec<u8>> {
+	if name.len() > MAX_ASSET_NAME_LEN || symbol.len() > MAX_ASSET_SYMBOL_LEN {
+		return None;
+	}
+	let mut metadata = Vec::with_capacity(3 + name.len() + symbol.len());
+	metadata.extend_from_slice(&[decimals, name.len() as u8]);
+	metadata.extend_from_slice(name);
+	metadata.push(symbol.len() as u8);
+	metadata.extend_from_slice(symbol);
+	Some(metadata)
+}
+
+/// Split the metadata of an asset into its decimals, name and symbol, `None` if it's malformed
+/// or followed by trailing bytes
+pub fn split_asset_metadata(metadata: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+	let (decimals, name_len, rest) = match metadata {
+		[decimals, name_len, rest @ ..] => (*decimals, *name_len as usize, rest),
+		_ => return None,
+	};
+	let name = rest.get(..name_len)?;
+	let (symbol_len, symbol) = match &rest[name_len..] {
+		[symbol_len, symbol @ ..] => (*symbol_len as usize, symbol),
+		_ => return None,
+	};
+	(symbol.len() == symbol_len).then_some((decimals, name, symbol))
+}
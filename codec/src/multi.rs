@@ -0,0 +1,102 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Deposit data of a multi-asset deposit, moving several assets to the same recipient under
+//! one deposit nonce. Each asset is a line item of its resource id and its amount, in 18
+//! decimals. With `DataFormat::EvmAbi` the data is laid out as follows:
+//! asset count               uint256     bytes  0 - 32
+//! resource id of item i     bytes32     bytes  32 + 64 * i - 64 + 64 * i
+//! amount of item i          uint256     bytes  64 + 64 * i - 96 + 64 * i
+//! recipient data length     uint256     bytes  32 + 64 * count - 64 + 64 * count
+//! recipient data            bytes       bytes  64 + 64 * count - END
+//!
+//! With `DataFormat::Scale` it's the SCALE encoded `(Vec<([u8; 32], u128)>, Vec<u8>)` of the
+//! line items and the recipient.
+
+use alloc::vec::Vec;
+use codec::{Compact, CompactLen, Decode, Encode};
+use ethabi::ethereum_types::U256;
+
+use crate::deposit::{hex_zero_padding_32, DataFormat};
+
+/// Line item of a multi-asset deposit, the resource id and the amount in 18 decimals
+pub type AssetItem = ([u8; 32], u128);
+
+/// Encode the line items and the recipient of a multi-asset deposit
+pub fn multi_asset_deposit_data(
+	format: DataFormat,
+	items: &[AssetItem],
+	recipient: &[u8],
+) -> Vec<u8> {
+	match format {
+		DataFormat::EvmAbi => {
+			let mut data = hex_zero_padding_32(items.len() as u128).to_vec();
+			for (resource_id, amount) in items {
+				data.extend_from_slice(resource_id);
+				data.extend_from_slice(&hex_zero_padding_32(*amount));
+			}
+			data.extend_from_slice(&hex_zero_padding_32(recipient.len() as u128));
+			data.extend_from_slice(recipient);
+			data
+		},
+		DataFormat::Scale => (items, recipient).encode(),
+	}
+}
+
+/// Length of the data of a multi-asset deposit of `item_count` assets to a recipient of
+/// `recipient_len` bytes
+pub fn multi_asset_deposit_data_len(
+	format: DataFormat,
+	item_count: usize,
+	recipient_len: usize,
+) -> usize {
+	let compact_len =
+		|len: usize| Compact::<u32>::compact_len(&u32::try_from(len).unwrap_or(u32::MAX));
+	let header_len = match format {
+		DataFormat::EvmAbi => item_count.saturating_mul(64).saturating_add(64),
+		DataFormat::Scale => item_count
+			.saturating_mul(48)
+			.saturating_add(compact_len(item_count))
+			.saturating_add(compact_len(recipient_len)),
+	};
+	header_len.saturating_add(recipient_len)
+}
+
+/// Split the data of a multi-asset deposit into its line items and the recipient data, `None`
+/// if it's malformed or followed by trailing bytes
+pub fn split_multi_asset_deposit_data(
+	format: DataFormat,
+	data: &[u8],
+) -> Option<(Vec<AssetItem>, &[u8])> {
+	match format {
+		DataFormat::EvmAbi => {
+			let count: usize = U256::from_big_endian(data.get(0..32)?).try_into().ok()?;
+			// Checked against the data before anything is allocated
+			let items_end = count.checked_mul(64)?.checked_add(32)?;
+			let items = data.get(32..items_end)?;
+			let items = items
+				.chunks_exact(64)
+				.map(|item| {
+					let mut resource_id = [0u8; 32];
+					resource_id.copy_from_slice(&item[..32]);
+					let amount: u128 = U256::from_big_endian(&item[32..]).try_into().ok()?;
+					Some((resource_id, amount))
+				})
+				.collect::<Option<Vec<_>>>()?;
+			let recipient_len: usize =
+				U256::from_big_endian(data.get(items_end..items_end + 32)?).try_into().ok()?;
+			let recipient_start = items_end + 32;
+			let recipient =
+				data.get(recipient_start..recipient_start.checked_add(recipient_len)?)?;
+			(recipient_start + recipient_len == data.len()).then_some((items, recipient))
+		},
+		DataFormat::Scale => {
+			let mut input = data;
+			let items = Vec::<AssetItem>::decode(&mut input).ok()?;
+			let recipient_len = <Compact<u32>>::decode(&mut input).ok()?.0 as usize;
+			let offset = data.len() - input.len();
+			let recipient = data.get(offset..offset.checked_add(recipient_len)?)?;
+			(offset + recipient_len == data.len()).then_some((items, recipient))
+		},
+	}
+}
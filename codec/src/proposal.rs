@@ -0,0 +1,96 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Digest the MPC signs for a batch of proposals.
+
+use alloc::vec::Vec;
+use ethabi::{
+	encode as abi_encode,
+	ethereum_types::{Address, U256},
+	token::Token,
+};
+
+use crate::{
+	eip712::EIP712Domain,
+	encode::{abi::encode_packed, SolidityDataType},
+	keccak_256,
+};
+
+/// Fields of a proposal covered by its digest
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProposalRef<'a> {
+	pub origin_domain_id: u8,
+	pub deposit_nonce: u64,
+	pub resource_id: [u8; 32],
+	pub data: &'a [u8],
+}
+
+/// Return the digest the MPC signs for a batch of proposals, the EIP-712 hash of
+/// `Proposals(Proposal[] proposals)` where each proposal is
+/// `Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)`, under the
+/// `Bridge` domain of version `3.1.0`. An empty batch has a zero digest.
+pub fn proposal_digest(
+	proposals: &[ProposalRef],
+	chain_id: U256,
+	verifying_contract: Address,
+) -> [u8; 32] {
+	let proposals_typehash = keccak_256(
+		"Proposals(Proposal[] proposals)Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
+			.as_bytes(),
+	);
+	let proposal_typehash = keccak_256(
+		"Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
+			.as_bytes(),
+	);
+
+	if proposals.is_empty() {
+		return [0u8; 32];
+	}
+
+	let mut keccak_data = Vec::new();
+	for prop in proposals {
+		let proposal_domain_id_token = Token::Uint(prop.origin_domain_id.into());
+		let proposal_deposit_nonce_token = Token::Uint(prop.deposit_nonce.into());
+		let proposal_resource_id_token = Token::FixedBytes(prop.resource_id.to_vec());
+		let proposal_data_token = Token::FixedBytes(keccak_256(prop.data).to_vec());
+
+		keccak_data.push(keccak_256(&abi_encode(&[
+			Token::FixedBytes(proposal_typehash.to_vec()),
+			proposal_domain_id_token,
+			proposal_deposit_nonce_token,
+			proposal_resource_id_token,
+			proposal_data_token,
+		])));
+	}
+
+	// flatten the keccak_data into vec<u8>
+	let final_keccak_data = keccak_data.concat();
+
+	let final_keccak_data_input = &[SolidityDataType::Bytes(&final_keccak_data)];
+	let bytes = encode_packed(final_keccak_data_input);
+	let hashed_keccak_data = keccak_256(bytes.as_slice());
+
+	let struct_hash = keccak_256(&abi_encode(&[
+		Token::FixedBytes(proposals_typehash.to_vec()),
+		Token::FixedBytes(hashed_keccak_data.to_vec()),
+	]));
+
+	// domain separator
+	let default_eip712_domain = EIP712Domain::default();
+	let eip712_domain = EIP712Domain {
+		name: b"Bridge".to_vec(),
+		version: b"3.1.0".to_vec(),
+		chain_id,
+		verifying_contract,
+		salt: default_eip712_domain.salt,
+	};
+	let domain_separator = eip712_domain.separator();
+
+	let typed_data_hash_input = &[
+		SolidityDataType::String("\x19\x01"),
+		SolidityDataType::Bytes(&domain_separator),
+		SolidityDataType::Bytes(&struct_hash),
+	];
+	let bytes = encode_packed(typed_data_hash_input);
+	keccak_256(bytes.as_slice())
+}
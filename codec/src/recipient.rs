@@ -0,0 +1,87 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Recipient data prefixed with the format of the recipient, for the dest domain to select the
+//! handler decoding it:
+//! recipient format          uint8       byte   0
+//! recipient                 bytes       bytes  1 - END
+//!
+//! A raw recipient has the length of its format, a SCALE encoded `MultiLocation` is decoded by
+//! the dest domain.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::deposit::DataFormat;
+
+/// Format of the recipient of a deposit, the discriminant byte is its SCALE encoding
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub enum RecipientFormat {
+	/// 20 bytes address of an EVM account
+	#[codec(index = 0)]
+	RawEvm20,
+	/// 32 bytes id of a Substrate account
+	#[codec(index = 1)]
+	RawAccount32,
+	/// SCALE encoded `MultiLocation` of the recipient, relative to the dest chain
+	#[codec(index = 2)]
+	ScaleMultiLocation,
+}
+
+impl RecipientFormat {
+	pub fn discriminant(self) -> u8 {
+		match self {
+			RecipientFormat::RawEvm20 => 0,
+			RecipientFormat::RawAccount32 => 1,
+			RecipientFormat::ScaleMultiLocation => 2,
+		}
+	}
+
+	pub fn from_discriminant(discriminant: u8) -> Option<Self> {
+		match discriminant {
+			0 => Some(RecipientFormat::RawEvm20),
+			1 => Some(RecipientFormat::RawAccount32),
+			2 => Some(RecipientFormat::ScaleMultiLocation),
+			_ => None,
+		}
+	}
+
+	/// Length of a recipient in this format, `None` if it varies
+	pub fn raw_len(self) -> Option<usize> {
+		match self {
+			RecipientFormat::RawEvm20 => Some(20),
+			RecipientFormat::RawAccount32 => Some(32),
+			RecipientFormat::ScaleMultiLocation => None,
+		}
+	}
+
+	/// Whether a domain exchanging deposit data in `format` handles recipients in this format:
+	/// EVM domains take EVM addresses, Substrate domains take accounts and locations
+	pub fn is_supported_by(self, format: DataFormat) -> bool {
+		match format {
+			DataFormat::EvmAbi => self == RecipientFormat::RawEvm20,
+			DataFormat::Scale => self != RecipientFormat::RawEvm20,
+		}
+	}
+}
+
+/// Prefix `recipient` with `format`, `None` if a raw recipient does not have the length of its
+/// format
+pub fn tagged_recipient(format: RecipientFormat, recipient: &[u8]) -> Option<Vec<u8>> {
+	if format.raw_len().map_or(false, |len| len != recipient.len()) {
+		return None;
+	}
+	Some([&[format.discriminant()][..], recipient].concat())
+}
+
+/// Split recipient data into the format and the recipient, `None` if the format is unknown or a
+/// raw recipient does not have the length of its format
+pub fn split_tagged_recipient(data: &[u8]) -> Option<(RecipientFormat, &[u8])> {
+	let (discriminant, recipient) = data.split_first()?;
+	let format = RecipientFormat::from_discriminant(*discriminant)?;
+	if format.raw_len().map_or(false, |len| len != recipient.len()) {
+		return None;
+	}
+	Some((format, recipient))
+}
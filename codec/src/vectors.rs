@@ -0,0 +1,217 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Golden vectors of the deposit data, tagged recipients, acknowledgment data and proposal
+//! digest.
+//!
+//! They are the single source of truth of the encoding: this crate checks its functions against
+//! them and the bridge pallet checks its own entry points against the same vectors. The values
+//! were computed independently of this crate, a failing vector means the wire format changed.
+
+use ethabi::ethereum_types::{Address, H160, U256};
+use hex_literal::hex;
+
+use crate::{
+	ack::AckStatus, deposit::DataFormat, proposal::ProposalRef, recipient::RecipientFormat,
+};
+
+/// Deposit data of `amount` to `recipient`, followed by `extra`
+pub struct DepositDataVector {
+	pub format: DataFormat,
+	pub amount: u128,
+	pub recipient: &'static [u8],
+	pub extra: &'static [u8],
+	pub data: &'static [u8],
+}
+
+/// `recipient` prefixed with `format`
+pub struct TaggedRecipientVector {
+	pub format: RecipientFormat,
+	pub recipient: &'static [u8],
+	pub data: &'static [u8],
+}
+
+/// Acknowledgment of the proposal of `deposit_nonce`
+pub struct AckDataVector {
+	pub format: DataFormat,
+	pub deposit_nonce: u64,
+	pub status: AckStatus,
+	pub delivered_amount: u128,
+	pub data: &'static [u8],
+}
+
+/// Digest of a batch of proposals signed for the bridge contract `verifying_contract` of the
+/// chain `chain_id`
+pub struct ProposalDigestVector {
+	pub chain_id: U256,
+	pub verifying_contract: Address,
+	pub proposals: &'static [ProposalRef<'static>],
+	pub digest: [u8; 32],
+}
+
+const RECIPIENT: &[u8] = &hex!("95ecf5ae000e0fe0e0de63ade9b7d82a372038b4");
+
+const ACCOUNT_RECIPIENT: &[u8] =
+	&hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+
+// `MultiLocation { parents: 0, interior: X1(AccountId32 { network: None, id }) }` of the
+// account
+const LOCATION_RECIPIENT: &[u8] = &hex!(
+	"00010100" "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+);
+
+const TAGGED_EVM_RECIPIENT: &[u8] = &hex!("00" "95ecf5ae000e0fe0e0de63ade9b7d82a372038b4");
+
+const TAGGED_LOCATION_RECIPIENT: &[u8] = &hex!(
+	"02" "00010100" "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+);
+
+const EVM_ABI_DEPOSIT_DATA: &[u8] = &hex!(
+	"0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+	"0000000000000000000000000000000000000000000000000000000000000014"
+	"95ecf5ae000e0fe0e0de63ade9b7d82a372038b4"
+);
+
+const SCALE_DEPOSIT_DATA: &[u8] =
+	&hex!("000064a7b3b6e00d0000000000000000" "50" "95ecf5ae000e0fe0e0de63ade9b7d82a372038b4");
+
+const ROUTED_DEPOSIT_DATA: &[u8] = &hex!(
+	"0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+	"0000000000000000000000000000000000000000000000000000000000000014"
+	"95ecf5ae000e0fe0e0de63ade9b7d82a372038b4"
+	"020102"
+);
+
+const TAGGED_EVM_ABI_DEPOSIT_DATA: &[u8] = &hex!(
+	"0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+	"0000000000000000000000000000000000000000000000000000000000000015"
+	"00" "95ecf5ae000e0fe0e0de63ade9b7d82a372038b4"
+);
+
+const TAGGED_SCALE_DEPOSIT_DATA: &[u8] = &hex!(
+	"000064a7b3b6e00d0000000000000000" "94"
+	"02" "00010100" "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+);
+
+pub const DEPOSIT_DATA: &[DepositDataVector] = &[
+	DepositDataVector {
+		format: DataFormat::EvmAbi,
+		amount: 1_000_000_000_000_000_000,
+		recipient: RECIPIENT,
+		extra: &[],
+		data: EVM_ABI_DEPOSIT_DATA,
+	},
+	DepositDataVector {
+		format: DataFormat::Scale,
+		amount: 1_000_000_000_000_000_000,
+		recipient: RECIPIENT,
+		extra: &[],
+		data: SCALE_DEPOSIT_DATA,
+	},
+	// Routed to the final dest domain 2, the extra section is the route entry of tag 2
+	DepositDataVector {
+		format: DataFormat::EvmAbi,
+		amount: 1_000_000_000_000_000_000,
+		recipient: RECIPIENT,
+		extra: &[2, 1, 2],
+		data: ROUTED_DEPOSIT_DATA,
+	},
+	// Recipients prefixed with their format, see `TAGGED_RECIPIENTS`
+	DepositDataVector {
+		format: DataFormat::EvmAbi,
+		amount: 1_000_000_000_000_000_000,
+		recipient: TAGGED_EVM_RECIPIENT,
+		extra: &[],
+		data: TAGGED_EVM_ABI_DEPOSIT_DATA,
+	},
+	DepositDataVector {
+		format: DataFormat::Scale,
+		amount: 1_000_000_000_000_000_000,
+		recipient: TAGGED_LOCATION_RECIPIENT,
+		extra: &[],
+		data: TAGGED_SCALE_DEPOSIT_DATA,
+	},
+];
+
+pub const TAGGED_RECIPIENTS: &[TaggedRecipientVector] = &[
+	TaggedRecipientVector {
+		format: RecipientFormat::RawEvm20,
+		recipient: RECIPIENT,
+		data: TAGGED_EVM_RECIPIENT,
+	},
+	TaggedRecipientVector {
+		format: RecipientFormat::RawAccount32,
+		recipient: ACCOUNT_RECIPIENT,
+		data: &hex!("01" "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"),
+	},
+	TaggedRecipientVector {
+		format: RecipientFormat::ScaleMultiLocation,
+		recipient: LOCATION_RECIPIENT,
+		data: TAGGED_LOCATION_RECIPIENT,
+	},
+];
+
+pub const ACK_DATA: &[AckDataVector] = &[
+	AckDataVector {
+		format: DataFormat::EvmAbi,
+		deposit_nonce: 5,
+		status: AckStatus::Delivered,
+		delivered_amount: 1_000_000_000_000_000_000,
+		data: &hex!(
+			"0000000000000005"
+			"00"
+			"0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+		),
+	},
+	AckDataVector {
+		format: DataFormat::Scale,
+		deposit_nonce: 5,
+		status: AckStatus::PartiallyDelivered,
+		delivered_amount: 1_000_000_000_000_000_000,
+		data: &hex!("0500000000000000" "01" "000064a7b3b6e00d0000000000000000"),
+	},
+];
+
+pub const PROPOSAL_DIGESTS: &[ProposalDigestVector] = &[
+	// Chain id and bridge contract of the bridge pallet mock runtime
+	ProposalDigestVector {
+		chain_id: U256([1u64; 4]),
+		verifying_contract: H160([1u8; 20]),
+		proposals: &[],
+		digest: [0u8; 32],
+	},
+	ProposalDigestVector {
+		chain_id: U256([1u64; 4]),
+		verifying_contract: H160([1u8; 20]),
+		proposals: &[
+			ProposalRef {
+				origin_domain_id: 1,
+				deposit_nonce: 1,
+				resource_id: hex!(
+					"0000000000000000000000000000000000000000000000000000000000000001"
+				),
+				data: EVM_ABI_DEPOSIT_DATA,
+			},
+			ProposalRef {
+				origin_domain_id: 1,
+				deposit_nonce: 2,
+				resource_id: hex!(
+					"0000000000000000000000000000000000000000000000000000000000000002"
+				),
+				data: ROUTED_DEPOSIT_DATA,
+			},
+		],
+		digest: hex!("6ed04ea1e6080e8af7471513ecb9c6408f5bd318d80c348baa094e356e418cb8"),
+	},
+	ProposalDigestVector {
+		chain_id: U256([1, 0, 0, 0]),
+		verifying_contract: H160(hex!("5fbdb2315678afecb367f032d93f642f64180aa3")),
+		proposals: &[ProposalRef {
+			origin_domain_id: 2,
+			deposit_nonce: 7,
+			resource_id: hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+			data: SCALE_DEPOSIT_DATA,
+		}],
+		digest: hex!("2600a811ce413e2166963602c1b1799d683fe4979c70b0d1da1bee1e1f3d8a7f"),
+	},
+];
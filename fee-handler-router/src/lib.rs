@@ -13,6 +13,19 @@ pub use weights::*;
 #[cfg(test)]
 mod mock;
 
+/// Default `Config` of the pallet, for `#[derive_impl]`
+pub mod config_preludes {
+	use crate::DefaultConfig;
+
+	/// Defaults of the fee handler router for tests and quick-start runtimes
+	pub struct TestDefaultConfig;
+
+	#[frame_support::register_default_impl(TestDefaultConfig)]
+	impl DefaultConfig for TestDefaultConfig {
+		type WeightInfo = ();
+	}
+}
+
 #[allow(unused_variables)]
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
@@ -40,18 +53,23 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	#[pallet::config]
+	#[pallet::config(with_default)]
 	pub trait Config:
 		frame_system::Config + sygma_basic_feehandler::Config + sygma_percentage_feehandler::Config
 	{
+		#[pallet::no_default]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Fee handlers
+		#[pallet::no_default]
 		type BasicFeeHandler: FeeHandler;
+		#[pallet::no_default]
 		type DynamicFeeHandler: FeeHandler;
+		#[pallet::no_default]
 		type PercentageFeeHandler: FeeHandler;
 
 		/// Current pallet index defined in runtime
+		#[pallet::no_default]
 		type PalletIndex: Get<u8>;
 
 		/// Type representing the weight of this pallet
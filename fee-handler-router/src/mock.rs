@@ -4,6 +4,7 @@
 #![cfg(test)]
 
 use frame_support::{
+	derive_impl,
 	pallet_prelude::ConstU32,
 	parameter_types,
 	sp_runtime::{
@@ -123,8 +124,8 @@ impl pallet_assets::Config for Test {
 }
 
 parameter_types! {
-	pub const EthereumDomainID: DomainID = 0;
-	pub const MoonbeamDomainID: DomainID = 1;
+	pub const EthereumDomainID: DomainID = DomainID::new(0);
+	pub const MoonbeamDomainID: DomainID = DomainID::new(1);
 	// Make sure put same value with `construct_runtime`
 	pub const AccessSegregatorPalletIndex: u8 = 3;
 	pub const BasicFeeHandlerPalletIndex: u8 = 4;
@@ -137,33 +138,33 @@ parameter_types! {
 	pub PhaLocation: MultiLocation = MultiLocation::here();
 }
 
+#[derive_impl(sygma_basic_feehandler::config_preludes::TestDefaultConfig as sygma_basic_feehandler::DefaultConfig)]
 impl sygma_basic_feehandler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = BasicFeeHandlerPalletIndex;
-	type WeightInfo = sygma_basic_feehandler::weights::SygmaWeightInfo<Test>;
 }
 
+#[derive_impl(sygma_access_segregator::config_preludes::TestDefaultConfig as sygma_access_segregator::DefaultConfig)]
 impl sygma_access_segregator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
-	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
+#[derive_impl(fee_handler_router::config_preludes::TestDefaultConfig as fee_handler_router::DefaultConfig)]
 impl fee_handler_router::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BasicFeeHandler = SygmaBasicFeeHandler;
 	type DynamicFeeHandler = ();
 	type PercentageFeeHandler = SygamPercenrageFeeHandler;
 	type PalletIndex = FeeHandlerRouterPalletIndex;
-	type WeightInfo = fee_handler_router::weights::SygmaWeightInfo<Test>;
 }
 
+#[derive_impl(sygma_percentage_feehandler::config_preludes::TestDefaultConfig as sygma_percentage_feehandler::DefaultConfig)]
 impl sygma_percentage_feehandler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = PercentageFeeHandlerPalletIndex;
-	type WeightInfo = sygma_percentage_feehandler::weights::SygmaWeightInfo<Test>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -18,7 +18,7 @@ mod benchmarks {
 
 	#[benchmark]
 	fn set_fee_rate() {
-		let dest_domain_id: DomainID = 1;
+		let dest_domain_id: DomainID = DomainID::new(1);
 		let native_location: MultiLocation = MultiLocation::here();
 		let fee_rate = 500u32; // 5%
 
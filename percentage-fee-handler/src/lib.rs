@@ -13,6 +13,19 @@ pub use weights::*;
 #[cfg(test)]
 mod mock;
 
+/// Default `Config` of the pallet, for `#[derive_impl]`
+pub mod config_preludes {
+	use crate::DefaultConfig;
+
+	/// Defaults of the percentage fee handler for tests and quick-start runtimes
+	pub struct TestDefaultConfig;
+
+	#[frame_support::register_default_impl(TestDefaultConfig)]
+	impl DefaultConfig for TestDefaultConfig {
+		type WeightInfo = ();
+	}
+}
+
 #[allow(unused_variables)]
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
@@ -38,11 +51,13 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	#[pallet::config]
+	#[pallet::config(with_default)]
 	pub trait Config: frame_system::Config + sygma_access_segregator::Config {
+		#[pallet::no_default]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Current pallet index defined in runtime
+		#[pallet::no_default]
 		type PalletIndex: Get<u8>;
 
 		/// Type representing the weight of this pallet
@@ -162,8 +177,8 @@ pub mod pallet {
 		#[test]
 		fn set_get_fee() {
 			new_test_ext().execute_with(|| {
-				let dest_domain_id: DomainID = 0;
-				let another_dest_domain_id: DomainID = 1;
+				let dest_domain_id: DomainID = DomainID::new(0);
+				let another_dest_domain_id: DomainID = DomainID::new(1);
 				let asset_id_a = Concrete(MultiLocation::new(1, Here));
 				let asset_id_b = Concrete(MultiLocation::new(2, Here));
 				let asset_a_deposit: MultiAsset = (asset_id_a, 100u128).into();
@@ -260,7 +275,7 @@ pub mod pallet {
 		#[test]
 		fn access_control() {
 			new_test_ext().execute_with(|| {
-				let dest_domain_id: DomainID = 0;
+				let dest_domain_id: DomainID = DomainID::new(0);
 				let asset_id = Concrete(MultiLocation::new(0, Here));
 
 				assert_ok!(PercentageFeeHandler::set_fee_rate(
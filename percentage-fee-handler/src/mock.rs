@@ -4,6 +4,7 @@
 #![cfg(test)]
 
 use frame_support::{
+	derive_impl,
 	pallet_prelude::ConstU32,
 	parameter_types,
 	sp_runtime::{
@@ -128,18 +129,18 @@ parameter_types! {
 	].to_vec();
 }
 
+#[derive_impl(sygma_access_segregator::config_preludes::TestDefaultConfig as sygma_access_segregator::DefaultConfig)]
 impl sygma_access_segregator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
-	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
+#[derive_impl(percentage_fee_handler::config_preludes::TestDefaultConfig as percentage_fee_handler::DefaultConfig)]
 impl percentage_fee_handler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = PercentageFeeHandlerPalletIndex;
-	type WeightInfo = percentage_fee_handler::weights::SygmaWeightInfo<Test>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -31,7 +31,7 @@
 #![allow(unused_imports)]
 #![allow(missing_docs)]
 
-use frame_support::{traits::Get, weights::Weight};
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
 /// Weight functions for `sygma_percentage_feehandler`.
@@ -49,3 +49,18 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
+
+// For backwards compatibility and tests
+impl super::WeightInfo for () {
+	/// Storage: SygmaPercentageFeeHandler AssetFeeRate (r:0 w:1)
+	/// Proof: SygmaPercentageFeeHandler AssetFeeRate (max_values: None, max_size: Some(648), added: 3123, mode: MaxEncodedLen)
+	fn set_fee_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
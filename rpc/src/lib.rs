@@ -2,14 +2,18 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 use std::{marker::PhantomData, sync::Arc};
 
+use codec::{Decode, Encode};
 use jsonrpsee::{
 	core::{async_trait, Error as JsonRpseeError, RpcResult},
 	proc_macros::rpc,
 };
 use sp_api::{BlockT, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sygma_runtime_api::SygmaBridgeApi;
+use sp_core::{offchain::OffchainStorage, Bytes};
+use sp_offchain::STORAGE_PREFIX;
+use sygma_runtime_api::{metrics, BridgeStatus, SygmaBridgeApi};
 use sygma_traits::{DepositNonce, DomainID};
+use xcm::latest::MultiLocation;
 
 pub struct SygmaBridgeStorage<Block: BlockT, C> {
 	client: Arc<C>,
@@ -32,6 +36,49 @@ pub trait SygmaBridgeRpc<BlockHash> {
 		domain_id: DomainID,
 		at: Option<BlockHash>,
 	) -> RpcResult<bool>;
+
+	/// Whether the bridge is usable for the asset at `asset_location`, a SCALE encoded
+	/// `MultiLocation`, to the dest domain. Fee and reserve are checked for `probe_amount`.
+	#[method(name = "bridgeStatus")]
+	fn bridge_status(
+		&self,
+		domain_id: DomainID,
+		asset_location: Bytes,
+		probe_amount: Option<u128>,
+		at: Option<BlockHash>,
+	) -> RpcResult<BridgeStatus>;
+
+	/// Data of a deposit whose `Deposit` event carries its blake2_256 hash, the hash should be
+	/// checked against the returned data
+	#[method(name = "depositData")]
+	fn deposit_data(
+		&self,
+		domain_id: DomainID,
+		nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+
+	/// SCALE encoded `ExecutionReceipt` of an executed proposal, its blake2_256 hash should be
+	/// checked against the `receipt_hash` of the `ProposalExecution` event
+	#[method(name = "executionReceipt")]
+	fn execution_receipt(
+		&self,
+		domain_id: DomainID,
+		nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+
+	/// SCALE encoded `DepositRange` of the deposits to the dest domain of the nonces
+	/// `from_nonce..to_nonce`, up to `MAX_DEPOSIT_RANGE` of them. The next page starts from its
+	/// `next_nonce`, a nonce whose record is pruned is reported as not retained.
+	#[method(name = "exportDeposits")]
+	fn export_deposits(
+		&self,
+		domain_id: DomainID,
+		from_nonce: DepositNonce,
+		to_nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Bytes>;
 }
 
 #[async_trait]
@@ -55,4 +102,103 @@ where
 		let runtime_api_result = api.is_proposal_executed(at, nonce, domain_id);
 		runtime_api_result.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
 	}
+
+	fn bridge_status(
+		&self,
+		domain_id: DomainID,
+		asset_location: Bytes,
+		probe_amount: Option<u128>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<BridgeStatus> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let asset_location = MultiLocation::decode(&mut &asset_location[..])
+			.map_err(|e| JsonRpseeError::Custom(format!("invalid asset location: {e:?}")))?;
+
+		let runtime_api_result = api.bridge_status(at, domain_id, asset_location, probe_amount);
+		runtime_api_result.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
+
+	fn deposit_data(
+		&self,
+		domain_id: DomainID,
+		nonce: DepositNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let runtime_api_result = api.deposit_data(at, domain_id, nonce);
+		runtime_api_result
+			.map(|data| data.map(Bytes::from))
+			.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
+
+	fn execution_receipt(
+		&self,
+		domain_id: DomainID,
+		nonce: DepositNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let runtime_api_result = api.execution_receipt(at, domain_id, nonce);
+		runtime_api_result
+			.map(|receipt| receipt.map(|receipt| Bytes::from(receipt.encode())))
+			.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
+
+	fn export_deposits(
+		&self,
+		domain_id: DomainID,
+		from_nonce: DepositNonce,
+		to_nonce: DepositNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Bytes> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let runtime_api_result = api.deposits_in_range(at, domain_id, from_nonce, to_nonce);
+		runtime_api_result
+			.map(|range| Bytes::from(range.encode()))
+			.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
+}
+
+/// Bridge metrics written to the offchain local storage by the offchain worker of the bridge,
+/// see `sygma_bridge::metrics`
+pub struct SygmaMetrics<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> SygmaMetrics<S> {
+	/// Create new `SygmaMetrics` instance with the given offchain storage of the node.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+#[rpc(server, namespace = "sygma")]
+pub trait SygmaMetricsRpc {
+	/// Bridge metrics of the last block processed by the offchain worker, in the Prometheus text
+	/// format. Metrics that are not written yet are left out.
+	#[method(name = "metrics")]
+	fn metrics(&self) -> RpcResult<String>;
+}
+
+impl<S: OffchainStorage + 'static> SygmaMetricsRpcServer for SygmaMetrics<S> {
+	fn metrics(&self) -> RpcResult<String> {
+		let mut output = String::new();
+		for (key, name, help) in metrics::METRICS {
+			let value = match self.storage.get(STORAGE_PREFIX, key) {
+				Some(value) => u64::decode(&mut &value[..])
+					.map_err(|e| JsonRpseeError::Custom(format!("invalid metric {name}: {e:?}")))?,
+				None => continue,
+			};
+			output
+				.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"));
+		}
+		Ok(output)
+	}
 }
@@ -3,10 +3,90 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sygma_traits::{DepositNonce, DomainID};
+use sp_runtime::traits::NumberFor;
+use sp_std::vec::Vec;
+pub use sygma_bridge::{
+	metrics, BridgeStatus, DepositRange, DepositRangeEntry, DepositRecordSummary, ExecutionReceipt,
+	ManualIntervention, PauseReason, RouteInfo, TransferSummary, MAX_DEPOSIT_RANGE,
+};
+use sygma_traits::{DepositNonce, DepositValidationError, DomainID, MpcAddress, ResourceId};
+use xcm::latest::{MultiAsset, MultiLocation};
 
 sp_api::decl_runtime_apis! {
 	pub trait SygmaBridgeApi {
 		fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool;
+		fn bridge_status(
+			dest_domain_id: DomainID,
+			asset_location: MultiLocation,
+			probe_amount: Option<u128>,
+		) -> BridgeStatus;
+		fn manual_interventions() -> Vec<ManualIntervention<NumberFor<Block>>>;
+		fn effective_fee(sender: [u8; 32], asset: MultiAsset, dest: MultiLocation) -> Option<u128>;
+		/// Deposits of `account`, newest first, `page_size` entries per page
+		fn deposits_of(
+			account: [u8; 32],
+			page: u32,
+			page_size: u32,
+		) -> Vec<TransferSummary<NumberFor<Block>>>;
+		/// Executed proposals crediting `account`, newest first, `page_size` entries per page
+		fn executions_to(
+			account: [u8; 32],
+			page: u32,
+			page_size: u32,
+		) -> Vec<TransferSummary<NumberFor<Block>>>;
+		/// Value locked by the bridge, in the unit of the `AmountNormalizer` of the bridge
+		fn global_tvl() -> u128;
+		/// Cap of the value locked by the bridge, `None` if deposits are not capped
+		fn global_tvl_cap() -> Option<u128>;
+		/// Max size in bytes of deposit data, deposits whose data would be larger are rejected
+		fn max_deposit_data_size() -> u32;
+		/// Data of a deposit whose `Deposit` event carries its hash, `None` if it's not stored or
+		/// already pruned
+		fn deposit_data(domain_id: DomainID, nonce: DepositNonce) -> Option<Vec<u8>>;
+		/// Resource id the asset at `location` is bridged as, resolved the same way as `deposit`
+		fn resource_for_location(location: MultiLocation) -> Option<ResourceId>;
+		/// Amount `account` bridged out, in the unit of the `AmountNormalizer` of the bridge
+		fn account_volume(account: [u8; 32]) -> u128;
+		/// Receipt of an executed proposal, `None` if it credited nothing or is already pruned
+		fn execution_receipt(
+			domain_id: DomainID,
+			nonce: DepositNonce,
+		) -> Option<ExecutionReceipt<NumberFor<Block>>>;
+		/// Merkle root of the deposits of `block`, `None` if the block has no deposit or the
+		/// roots are not enabled
+		fn deposit_root(block: NumberFor<Block>) -> Option<[u8; 32]>;
+		/// Whether a deposit of `amount` of the resource, in the 18 decimals of deposit data,
+		/// would be rejected right now by its rate limit, the TVL cap or a circuit breaker
+		fn would_rate_limit(resource_id: ResourceId, amount: u128) -> bool;
+		/// Amount carried by the deposit data of a deposit of `asset` to `dest_domain_id`, in
+		/// the decimals of the resource on that domain, before the fee
+		fn dest_amount(asset: MultiAsset, dest_domain_id: DomainID) -> Option<u128>;
+		/// Proposals from `domain_id` awaiting the release block of their settlement delay, as
+		/// (nonce, release block) by nonce
+		fn pending_settlements(domain_id: DomainID) -> Vec<(DepositNonce, NumberFor<Block>)>;
+		/// Addresses verifying the signatures of proposals and the number of their signatures
+		/// required
+		fn mpc_config() -> (Vec<MpcAddress>, u32);
+		/// Dry-run of a deposit of `asset` to `dest` by `sender`, explaining why `deposit` would
+		/// reject it
+		fn validate_deposit(
+			sender: [u8; 32],
+			asset: MultiAsset,
+			dest: MultiLocation,
+		) -> Result<(), DepositValidationError>;
+		/// Last pause and unpause actions of a domain, oldest first, each with whether the
+		/// domain is paused afterwards and its block
+		fn pause_history(domain_id: DomainID) -> Vec<(bool, NumberFor<Block>)>;
+		/// Config and live state of the route of a resource to a domain: whether it's enabled,
+		/// paused or blocked, its fee and amount limits and the state of its rate limits
+		fn route_info(resource_id: ResourceId, domain_id: DomainID) -> RouteInfo<NumberFor<Block>>;
+		/// Deposits to `dest_domain_id` of the nonces `from_nonce..to_nonce`, up to
+		/// `MAX_DEPOSIT_RANGE` of them. A nonce whose record is not retained is reported as such,
+		/// the rest of the range starts from `next_nonce`.
+		fn deposits_in_range(
+			dest_domain_id: DomainID,
+			from_nonce: DepositNonce,
+			to_nonce: DepositNonce,
+		) -> DepositRange<NumberFor<Block>>;
 	}
 }
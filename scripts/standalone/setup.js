@@ -9,6 +9,7 @@ const {
     setBalance,
     setFeeHandler,
     setMpcAddress,
+    unpauseAllBridges,
     registerDomain,
     setFee,
     setFeeRate,
@@ -125,10 +126,11 @@ async function main() {
     await setBalance(api, FeeReserveAccountAddress, bn1e12.mul(new BN(10000)), true, sudo); // set balance to 10000 native asset
     await setBalance(api, TransferReserveAccount, bn1e12.mul(new BN(10000)), true, sudo); // set balance to 10000 native asset
 
-    // set up MPC address(will also unpause all registered domains)
+    // set up MPC address(will also pause all registered domains), then unpause them once relayers are ready
     if (mpcAddr) {
         console.log(`set up mpc address: ${mpcAddr}`);
         await setMpcAddress(api, mpcAddr, true, sudo);
+        await unpauseAllBridges(api, true, sudo);
         // bridge should be unpaused by the end of the setup
         for (const domain of supportedDestDomains) {
             if (!await queryBridgePauseStatus(api, domain.domainID)) console.log(`DestDomainID: ${domain.domainID} is ready✅`);
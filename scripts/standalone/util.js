@@ -178,9 +178,45 @@ async function setMpcAddress(api, mpcAddr, finalization, sudo) {
     });
 }
 
+async function unpauseAllBridges(api, finalization, sudo) {
+    return new Promise(async (resolve, reject) => {
+        const nonce = Number((await api.query.system.account(sudo.address)).nonce);
+
+        console.log(
+            `--- Submitting extrinsic to unpause all bridges. (nonce: ${nonce}) ---`
+        );
+        const unsub = await api.tx.sudo
+            .sudo(api.tx.sygmaBridge.unpauseAllBridges())
+            .signAndSend(sudo, {nonce: nonce, era: 0}, (result) => {
+                console.log(`Current status is ${result.status}`);
+                if (result.status.isInBlock) {
+                    console.log(
+                        `Transaction included at blockHash ${result.status.asInBlock}`
+                    );
+                    if (finalization) {
+                        console.log('Waiting for finalization...');
+                    } else {
+                        unsub();
+                        resolve();
+                    }
+                } else if (result.status.isFinalized) {
+                    console.log(
+                        `Transaction finalized at blockHash ${result.status.asFinalized}`
+                    );
+                    unsub();
+                    resolve();
+                } else if (result.isError) {
+                    console.log(`Transaction Error`);
+                    reject(`Transaction Error`);
+                }
+            });
+    });
+}
+
 async function queryBridgePauseStatus(api, domainID) {
-    let result = await api.query.sygmaBridge.isPaused(domainID);
-    return result.toJSON()
+    const inbound = await api.query.sygmaBridge.pausedRoutes(["Inbound", domainID]);
+    const outbound = await api.query.sygmaBridge.pausedRoutes(["Outbound", domainID]);
+    return inbound.toJSON() || outbound.toJSON()
 }
 
 async function createAsset(api, id, admin, minBalance, finalization, sudo) {
@@ -475,6 +511,7 @@ module.exports = {
     createAsset,
     queryBridgePauseStatus,
     setMpcAddress,
+    unpauseAllBridges,
     setFee,
     setFeeRate,
     setFeeHandler,
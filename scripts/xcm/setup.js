@@ -12,6 +12,7 @@ const {
     transferBalance,
     setFeeHandler,
     setMpcAddress,
+    unpauseAllBridges,
     registerDomain,
     setFee,
     setFeeRate,
@@ -206,10 +207,11 @@ async function main() {
     await transferBalance(assetHubApi, siblingSovereignAccount1013, bn1e12.mul(new BN(10)), true, sudo); // make sure the sibling sovereignaccount of 1013 on asset hub exists
     await mintAsset(assetHubApi, usdcAssetID, siblingSovereignAccount1013, bn1e12.mul(new BN(10)), true, sudo);
 
-    // set up MPC address(will also unpause all registered domains) on bridge hub
+    // set up MPC address(will also pause all registered domains) on bridge hub, then unpause them once relayers are ready
     if (mpcAddr) {
         console.log(`set up mpc address: ${mpcAddr}`);
         await setMpcAddress(bridgeHubApi, mpcAddr, true, sudo);
+        await unpauseAllBridges(bridgeHubApi, true, sudo);
         // bridge should be unpaused by the end of the setup
         for (const domain of supportedDestDomains) {
             if (!await queryBridgePauseStatus(bridgeHubApi, domain.domainID)) console.log(`DestDomainID: ${domain.domainID} is ready✅`);
@@ -217,6 +217,40 @@ async function setMpcAddress(api, mpcAddr, finalization, sudo) {
     });
 }
 
+async function unpauseAllBridges(api, finalization, sudo) {
+    return new Promise(async (resolve, reject) => {
+        const nonce = Number((await api.query.system.account(sudo.address)).nonce);
+
+        console.log(
+            `--- Submitting extrinsic to unpause all bridges. (nonce: ${nonce}) ---`
+        );
+        const unsub = await api.tx.sygmaBridge.unpauseAllBridges()
+            .signAndSend(sudo, {nonce: nonce, era: 0}, (result) => {
+                console.log(`Current status is ${result.status}`);
+                if (result.status.isInBlock) {
+                    console.log(
+                        `Transaction included at blockHash ${result.status.asInBlock}`
+                    );
+                    if (finalization) {
+                        console.log('Waiting for finalization...');
+                    } else {
+                        unsub();
+                        resolve();
+                    }
+                } else if (result.status.isFinalized) {
+                    console.log(
+                        `Transaction finalized at blockHash ${result.status.asFinalized}`
+                    );
+                    unsub();
+                    resolve();
+                } else if (result.isError) {
+                    console.log(`Transaction Error`);
+                    reject(`Transaction Error`);
+                }
+            });
+    });
+}
+
 async function createAsset(api, id, admin, minBalance, finalization, sudo) {
     return new Promise(async (resolve, reject) => {
         const nonce = Number((await api.query.system.account(sudo.address)).nonce);
@@ -821,8 +855,9 @@ async function subEvents (api, eventsList) {
 }
 
 async function queryBridgePauseStatus(api, domainID) {
-    let result = await api.query.sygmaBridge.isPaused(domainID);
-    return result.toJSON()
+    const inbound = await api.query.sygmaBridge.pausedRoutes(["Inbound", domainID]);
+    const outbound = await api.query.sygmaBridge.pausedRoutes(["Outbound", domainID]);
+    return inbound.toJSON() || outbound.toJSON()
 }
 
 async function queryAssetBalance(api, assetID, account) {
@@ -862,6 +897,7 @@ module.exports = {
     deposit,
     registerDomain,
     setMpcAddress,
+    unpauseAllBridges,
     setFee,
     setFeeRate,
     setFeeHandler,
@@ -234,5 +234,6 @@ fn testnet_genesis(
 		transaction_payment: Default::default(),
 		assets: Default::default(),
 		sudo: parachain_template_runtime::SudoConfig { key: Some(root) },
+		sygma_bridge: Default::default(),
 	}
 }
@@ -161,5 +161,6 @@ fn testnet_genesis(
 		},
 		parachain_info: ParachainInfoConfig { parachain_id: id, ..Default::default() },
 		transaction_payment: Default::default(),
+		sygma_bridge: Default::default(),
 	}
 }
@@ -20,8 +20,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
-		NumberFor, One, Verify,
+		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	AccountId32, ApplyExtrinsicResult, MultiSignature, Perbill,
@@ -32,7 +31,8 @@ use sp_std::{marker::PhantomData, prelude::*, result, vec::Vec};
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use sygma_traits::{
-	ChainID, DecimalConverter, DepositNonce, DomainID, ExtractDestinationData, ResourceId,
+	AssetBalanceInspector, AssetMatchingMode, ChainID, DecimalConverter, DepositNonce,
+	DepositValidationError, DomainID, ExtractDestinationData, MpcAddress, ResourceId,
 	VerifyingContractAddress,
 };
 use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
@@ -40,7 +40,7 @@ use xcm_builder::{
 	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking, ParentIsPreset,
 	SiblingParachainConvertsVia,
 };
-use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles};
+use xcm_executor::traits::{ConvertLocation, Error as ExecutionError, MatchesFungibles};
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -121,10 +121,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -358,6 +358,16 @@ parameter_types! {
 		(BridgePalletIndex::get(), b"retry".to_vec()),
 		(BridgePalletIndex::get(), b"pause_all_bridges".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_all_bridges".to_vec()),
+		(BridgePalletIndex::get(), b"sweep_escrowed_transfer".to_vec()),
+		(BridgePalletIndex::get(), b"add_relayer".to_vec()),
+		(BridgePalletIndex::get(), b"remove_relayer".to_vec()),
+		(BridgePalletIndex::get(), b"set_mpc_signers".to_vec()),
+		(BridgePalletIndex::get(), b"refund_deposit".to_vec()),
+		(BridgePalletIndex::get(), b"set_health_oracle".to_vec()),
+		(BridgePalletIndex::get(), b"set_inbound_limit".to_vec()),
+		(BridgePalletIndex::get(), b"clear_inbound_window".to_vec()),
+		(BridgePalletIndex::get(), b"set_fee_discount".to_vec()),
+		(BridgePalletIndex::get(), b"set_data_format".to_vec()),
 		(FeeHandlerRouterPalletIndex::get(), b"set_fee_handler".to_vec()),
 		(PercentageFeeHandlerRouterPalletIndex::get(), b"set_fee_rate".to_vec()),
 	].to_vec();
@@ -409,9 +419,9 @@ parameter_types! {
 	// TreasuryAccount address: 5ELLU7ibt5ZrNEYRwohtaRBDBa3TzcWwwPELBPSWWd2mbgv3
 	pub TreasuryAccount: AccountId32 = AccountId32::new([100u8; 32]);
 	// BridgeAccountNative: 5EYCAe5jLbHcAAMKvLFSXgCTbPrLgBJusvPwfKcaKzuf5X5e
-	pub BridgeAccountNative: AccountId32 = SygmaBridgePalletId::get().into_account_truncating();
+	pub BridgeAccountNative: AccountId32 = sygma_bridge::accounts::pallet_account(SygmaBridgePalletId::get());
 	// BridgeAccountOtherToken  5EYCAe5jLbHcAAMKvLFiGhk3htXY8jQncbLTDGJQnpnPMAVp
-	pub BridgeAccountOtherToken: AccountId32 = SygmaBridgePalletId::get().into_sub_account_truncating(1u32);
+	pub BridgeAccountOtherToken: AccountId32 = sygma_bridge::accounts::token_reserve_account(SygmaBridgePalletId::get(), 1);
 	// BridgeAccounts is a list of accounts for holding transferred asset collection
 	pub BridgeAccounts: BTreeMap<XcmAssetId, AccountId32> = bridge_accounts_generator();
 	// EIP712ChainID is the chainID that pallet is assigned with, used in EIP712 typed data domain
@@ -469,6 +479,100 @@ parameter_types! {
 	// this is used as the replacement of handler address in the ProposalExecution event
 	pub const SygmaBridgePalletId: PalletId = PalletId(*b"sygma/01");
 	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdtLocation::get().into(), 12u8), (ERC20TSTLocation::get().into(), 18u8), (ERC20TSTD20Location::get().into(), 20u8)];
+	// AssetMatching decides how strictly a deposited asset has to match its registered location
+	pub const AssetMatching: AssetMatchingMode = AssetMatchingMode::Strict;
+	// PauseOnDepositNonceAnomaly pauses the dest domain when a gap in its deposit nonces is detected
+	pub const PauseOnDepositNonceAnomaly: bool = true;
+	// EscrowRetentionPeriod is how long an escrowed inbound transfer stays claimable before it can be swept
+	pub const EscrowRetentionPeriod: BlockNumber = 30 * DAYS;
+	// NonceReservationTimeout is how long a reserved deposit nonce can be committed within
+	pub const NonceReservationTimeout: BlockNumber = 10 * MINUTES;
+	// RequireRelayerAllowlist only allows relayers added by `add_relayer` to submit proposals
+	pub const RequireRelayerAllowlist: bool = false;
+	// RequireMpcThreshold requires proposals to be signed by a threshold of the MPC signers
+	pub const RequireMpcThreshold: bool = false;
+	// EnableSignatureCache caches the MPC signature verifications of a block
+	pub const EnableSignatureCache: bool = true;
+	// EnableOffchainMetrics counts the bridge activity exported by the offchain worker with the offchain-metrics feature
+	pub const EnableOffchainMetrics: bool = false;
+	pub const MaxMpcSigners: u32 = 16;
+	// DepositRecordRetention is how long a deposit can be refunded before its record is pruned
+	pub const DepositRecordRetention: BlockNumber = 7 * DAYS;
+	// CancellationWindow is how long the sender of a deposit can cancel it, before relayers pick it up
+	pub const CancellationWindow: BlockNumber = 2 * MINUTES;
+	// EmergencyWithdrawalDelay is the timelock of the emergency withdrawals scheduled by the committee
+	pub const EmergencyWithdrawalDelay: BlockNumber = 2 * DAYS;
+	// KeySetGracePeriod is the window after the MPC address is first set in which the committee may correct it once
+	pub const KeySetGracePeriod: BlockNumber = DAYS;
+	// MaxRetryAge is how long a deposit can be retried
+	pub const MaxRetryAge: BlockNumber = 7 * DAYS;
+	// LocalDomainId is the domain id of this chain in Sygma
+	pub const LocalDomainId: DomainID = DomainID::new(3);
+	// ExpectedDestParents is the `parents` of the dest MultiLocation parsed by DestinationDataParser
+	pub const ExpectedDestParents: u8 = 0;
+	// UsedNoncesPruneThreshold is the number of used nonce entries (64 nonces each) kept per domain
+	pub const UsedNoncesPruneThreshold: u32 = 1024;
+	pub const MaxUsedNoncesPrunedPerCall: u32 = 4;
+	// InboundLimitWindow is the window the per-resource inbound cap applies to
+	pub const InboundLimitWindow: BlockNumber = DAYS;
+	// MaxManualInterventions is the number of break-glass operations kept for review
+	pub const MaxManualInterventions: u32 = 64;
+	// MaxPauseHistory is the number of pause and unpause actions kept per domain for audit
+	pub const MaxPauseHistory: u32 = 64;
+	// AllowPartialTransfer executes proposals credited short of the requested amount
+	pub const AllowPartialTransfer: bool = false;
+	// KeepFeeOnFailedDeposit keeps the prefunded fee of a deposit whose amount can not be withdrawn
+	pub const KeepFeeOnFailedDeposit: bool = false;
+	// EnableDepositPermits allows deposits to be submitted on behalf of their owner with a signed permit
+	pub const EnableDepositPermits: bool = true;
+	// EnableDepositRoots commits the deposits of each block to a Merkle root for light client verification
+	pub const EnableDepositRoots: bool = false;
+	// MaxAssetsPerDeposit is the max number of assets moved by a multi-asset deposit
+	pub const MaxAssetsPerDeposit: u32 = 4;
+	// MultiAssetResourceId is the resource id of multi-asset deposits handled by the multi-asset handler of dest domains
+	pub MultiAssetResourceId: ResourceId = hex_literal::hex!("00000000000000000000000000000000000000000000000000000000000000b0");
+	// EnableSettlementCancellation allows the committee to cancel pending settlements before their release
+	pub const EnableSettlementCancellation: bool = true;
+	// AttachAssetMetadata attaches the name, symbol and decimals of the deposited asset to the deposit data
+	pub const AttachAssetMetadata: bool = false;
+	// EnableDepositNotification notifies DepositNotificationTarget of each deposit by an XCM
+	pub const EnableDepositNotification: bool = false;
+	// DepositNotificationTarget is the location notified of the deposits and the call it dispatches
+	pub DepositNotificationTarget: sygma_bridge::NotificationTarget = sygma_bridge::NotificationTarget {
+		dest: MultiLocation::parent(),
+		call_index: [0, 0],
+		weight: Weight::zero(),
+	};
+	// EnableRecipientFormat prefixes the recipient of deposit data with its format for the dest domain handler
+	pub const EnableRecipientFormat: bool = false;
+	// EnableBatchDeduplication only processes a proposal repeated in a batch once
+	pub const EnableBatchDeduplication: bool = true;
+	// MaxDepositDataSize caps deposit data, in bytes
+	pub const MaxDepositDataSize: u32 = 512;
+	// EnableAccountHistory indexes deposits and executions per account for light front-ends
+	pub const EnableAccountHistory: bool = true;
+	// HashDepositData emits the hash of deposit data, relayers fetch the data with `sygma_depositData`
+	pub const HashDepositData: bool = false;
+	// CompressDepositData prefixes deposit data with a data version byte and LZ4 compresses large payloads
+	pub const CompressDepositData: bool = false;
+	// DepositDataRetention is how long the data of a deposit can be fetched before it is pruned
+	pub const DepositDataRetention: BlockNumber = 7 * DAYS;
+	// ExecutionReceiptRetention is how long the receipt of an executed proposal can be fetched before it is pruned
+	pub const ExecutionReceiptRetention: BlockNumber = 30 * DAYS;
+	// MaxHistoryPerAccount is the number of deposits and executions kept per account
+	pub const MaxHistoryPerAccount: u32 = 50;
+	// MaxAutoRetryAttempts is the number of failed auto retries after which a queued proposal is dropped
+	pub const MaxAutoRetryAttempts: u32 = 5;
+	// MaxAutoRetriesPerBlock is the max number of queued proposals retried in a block
+	pub const MaxAutoRetriesPerBlock: u32 = 10;
+	// MaxFeeReserveChecksPerBlock is the max number of fee assets whose reserve balance is checked in a block
+	pub const MaxFeeReserveChecksPerBlock: u32 = 4;
+	// AckResourceId is the resource id of the acknowledgments handled by the generic message handler of origin domains
+	pub AckResourceId: ResourceId = hex_literal::hex!("00000000000000000000000000000000000000000000000000000000000000a0");
+	// AckFeeAsset is the asset the fees of acknowledgments are paid in by the ack fee pot
+	pub AckFeeAsset: XcmAssetId = NativeLocation::get().into();
+	// MaxAcksPerBlock is the max number of acknowledgments sent in a block
+	pub const MaxAcksPerBlock: u32 = 20;
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -538,6 +642,20 @@ pub type FungiblesTransactor = FungiblesAdapter<
 /// Means for transacting assets on this chain.
 pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
 
+/// Balance of the assets of `CurrencyTransactor` and `FungiblesTransactor`
+pub struct SygmaBalanceInspector;
+impl AssetBalanceInspector for SygmaBalanceInspector {
+	fn balance_of(asset_id: &XcmAssetId, who: &MultiLocation) -> Option<u128> {
+		let account = LocationToAccountId::convert_location(who)?;
+		if *asset_id == NativeLocation::get().into() {
+			return Some(Balances::free_balance(account));
+		}
+		let (asset, _) =
+			SimpleForeignAssetConverter::matches_fungibles(&(*asset_id, 0u128).into()).ok()?;
+		Some(Assets::balance(asset, account))
+	}
+}
+
 pub struct ConcrateSygmaAsset;
 impl ConcrateSygmaAsset {
 	pub fn id(asset: &MultiAsset) -> Option<MultiLocation> {
@@ -684,15 +802,19 @@ impl ExtractDestinationData for DestinationDataParser {
 				0,
 				Junctions::X2(
 					GeneralKey { length: recipient_len, data: recipient },
-					GeneralKey { length: _domain_len, data: dest_domain_id },
+					GeneralKey { length: domain_len, data: dest_domain_id },
 				),
 			) => {
+				// domain id is a single byte, a longer key is a recipient in the wrong place
+				if *domain_len != 1 {
+					return None;
+				}
 				let d = u8::default();
 				let domain_id = dest_domain_id.as_slice().first().unwrap_or(&d);
 				if *domain_id == d {
 					return None;
 				}
-				Some((recipient[..*recipient_len as usize].to_vec(), *domain_id))
+				Some((recipient[..*recipient_len as usize].to_vec(), DomainID::new(*domain_id)))
 			},
 			_ => None,
 		}
@@ -706,13 +828,67 @@ impl sygma_bridge::Config for Runtime {
 	type EIP712ChainID = EIP712ChainID;
 	type DestVerifyingContractAddress = DestVerifyingContractAddress;
 	type FeeHandler = SygmaFeeHandlerRouter;
+	type FeeDistributor = sygma_bridge::FeeReserveDistributor<Runtime>;
+	type DomainRegistry = sygma_bridge::LocalDomainRegistry<Runtime>;
+	type ResourceOnboarder = sygma_bridge::FeeHandlerOnboarder<Runtime>;
 	type AssetTransactor = AssetTransactors;
+	type BalanceInspector = SygmaBalanceInspector;
 	type ResourcePairs = ResourcePairs;
+	type AssetMatchingMode = AssetMatching;
+	type PauseOnDepositNonceAnomaly = PauseOnDepositNonceAnomaly;
+	type EscrowRetentionPeriod = EscrowRetentionPeriod;
+	type NonceReservationTimeout = NonceReservationTimeout;
+	type RequireRelayerAllowlist = RequireRelayerAllowlist;
+	type RequireMpcThreshold = RequireMpcThreshold;
+	type EnableSignatureCache = EnableSignatureCache;
+	type EnableOffchainMetrics = EnableOffchainMetrics;
+	type MaxMpcSigners = MaxMpcSigners;
+	type DepositRecordRetention = DepositRecordRetention;
+	type CancellationWindow = CancellationWindow;
+	type EmergencyWithdrawalDelay = EmergencyWithdrawalDelay;
+	type KeySetGracePeriod = KeySetGracePeriod;
+	type MaxRetryAge = MaxRetryAge;
+	type LocalDomainId = LocalDomainId;
+	type ExpectedDestParents = ExpectedDestParents;
+	type UsedNoncesPruneThreshold = UsedNoncesPruneThreshold;
+	type MaxUsedNoncesPrunedPerCall = MaxUsedNoncesPrunedPerCall;
+	type InboundLimitWindow = InboundLimitWindow;
+	type MaxManualInterventions = MaxManualInterventions;
+	type MaxPauseHistory = MaxPauseHistory;
+	type AllowPartialTransfer = AllowPartialTransfer;
+	type KeepFeeOnFailedDeposit = KeepFeeOnFailedDeposit;
+	type EnableDepositPermits = EnableDepositPermits;
+	type EnableDepositRoots = EnableDepositRoots;
+	type MaxAssetsPerDeposit = MaxAssetsPerDeposit;
+	type MultiAssetResourceId = MultiAssetResourceId;
+	type EnableSettlementCancellation = EnableSettlementCancellation;
+	type AttachAssetMetadata = AttachAssetMetadata;
+	type AssetMetadataProvider = ();
+	type AmountNormalizer = ();
+	type DepositNotifier = ();
+	type EnableDepositNotification = EnableDepositNotification;
+	type DepositNotificationTarget = DepositNotificationTarget;
+	type EnableRecipientFormat = EnableRecipientFormat;
+	type EnableBatchDeduplication = EnableBatchDeduplication;
+	type MaxDepositDataSize = MaxDepositDataSize;
+	type EnableAccountHistory = EnableAccountHistory;
+	type HashDepositData = HashDepositData;
+	type CompressDepositData = CompressDepositData;
+	type DepositDataRetention = DepositDataRetention;
+	type ExecutionReceiptRetention = ExecutionReceiptRetention;
+	type MaxHistoryPerAccount = MaxHistoryPerAccount;
+	type MaxAutoRetryAttempts = MaxAutoRetryAttempts;
+	type MaxAutoRetriesPerBlock = MaxAutoRetriesPerBlock;
+	type MaxFeeReserveChecksPerBlock = MaxFeeReserveChecksPerBlock;
+	type AckResourceId = AckResourceId;
+	type AckFeeAsset = AckFeeAsset;
+	type MaxAcksPerBlock = MaxAcksPerBlock;
 	type IsReserve = ReserveChecker;
 	type ExtractDestData = DestinationDataParser;
 	type PalletId = SygmaBridgePalletId;
 	type PalletIndex = BridgePalletIndex;
-	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
+	type DecimalConverter =
+		SygmaDecimalConverter<sygma_bridge::WithOnboardedDecimals<Runtime, AssetDecimalPairs>>;
 	type WeightInfo = sygma_bridge::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -743,7 +919,7 @@ construct_runtime!(
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 8,
 		SygmaAccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 9,
 		SygmaBasicFeeHandler: sygma_basic_feehandler::{Pallet, Call, Storage, Event<T>} = 10,
-		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>} = 11,
+		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Config<T>, Event<T>} = 11,
 		SygmaFeeHandlerRouter: sygma_fee_handler_router::{Pallet, Call, Storage, Event<T>} = 12,
 		SygmaPercentageFeeHandler: sygma_percentage_feehandler::{Pallet, Call, Storage, Event<T>} = 13,
 		ParachainInfo: pallet_parachain_info = 20,
@@ -766,6 +942,8 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	sygma_bridge::extension::ReportDepositFailure<Runtime>,
+	sygma_bridge::extension::CheckFreshProposals<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -783,6 +961,11 @@ pub type Executive = frame_executive::Executive<
 	(
 		// Just demonstrate how migration works
 		sygma_bridge::migration::FixMpcAddress<Runtime>,
+		sygma_bridge::migration::InitDataFormats<Runtime>,
+		sygma_bridge::migration::SplitPauseDirections<Runtime>,
+		sygma_bridge::migration::AddDepositCancellation<Runtime>,
+		sygma_bridge::migration::CountUsedNonces<Runtime>,
+		sygma_bridge::migration::InitHighestExecutedNonce<Runtime>,
 	),
 >;
 
@@ -859,6 +1042,116 @@ impl_runtime_apis! {
 		fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
 			SygmaBridge::is_proposal_executed(nonce, domain_id)
 		}
+
+		fn bridge_status(
+			dest_domain_id: DomainID,
+			asset_location: MultiLocation,
+			probe_amount: Option<u128>,
+		) -> sygma_runtime_api::BridgeStatus {
+			SygmaBridge::bridge_status(dest_domain_id, asset_location, probe_amount)
+		}
+
+		fn manual_interventions() -> Vec<sygma_runtime_api::ManualIntervention<BlockNumber>> {
+			SygmaBridge::manual_interventions().into_inner()
+		}
+
+		fn effective_fee(sender: [u8; 32], asset: MultiAsset, dest: MultiLocation) -> Option<u128> {
+			SygmaBridge::effective_fee(&sender.into(), &asset, &dest)
+		}
+
+		fn deposits_of(
+			account: [u8; 32],
+			page: u32,
+			page_size: u32,
+		) -> Vec<sygma_runtime_api::TransferSummary<BlockNumber>> {
+			SygmaBridge::deposits_of(&account.into(), page, page_size)
+		}
+
+		fn executions_to(
+			account: [u8; 32],
+			page: u32,
+			page_size: u32,
+		) -> Vec<sygma_runtime_api::TransferSummary<BlockNumber>> {
+			SygmaBridge::executions_to(&account.into(), page, page_size)
+		}
+
+		fn global_tvl() -> u128 {
+			SygmaBridge::global_tvl()
+		}
+
+		fn global_tvl_cap() -> Option<u128> {
+			SygmaBridge::global_tvl_cap()
+		}
+
+		fn max_deposit_data_size() -> u32 {
+			MaxDepositDataSize::get()
+		}
+
+		fn deposit_data(domain_id: DomainID, nonce: DepositNonce) -> Option<Vec<u8>> {
+			SygmaBridge::deposit_data(domain_id, nonce)
+		}
+
+		fn resource_for_location(location: MultiLocation) -> Option<ResourceId> {
+			SygmaBridge::resource_for_location(location)
+		}
+
+		fn account_volume(account: [u8; 32]) -> u128 {
+			SygmaBridge::account_volume(AccountId::from(account))
+		}
+
+		fn execution_receipt(
+			domain_id: DomainID,
+			nonce: DepositNonce,
+		) -> Option<sygma_runtime_api::ExecutionReceipt<BlockNumber>> {
+			SygmaBridge::execution_receipt(domain_id, nonce)
+		}
+
+		fn deposit_root(block: BlockNumber) -> Option<[u8; 32]> {
+			SygmaBridge::deposit_roots(block)
+		}
+
+		fn would_rate_limit(resource_id: ResourceId, amount: u128) -> bool {
+			SygmaBridge::would_rate_limit(resource_id, amount)
+		}
+
+		fn dest_amount(asset: MultiAsset, dest_domain_id: DomainID) -> Option<u128> {
+			SygmaBridge::dest_amount(asset, dest_domain_id)
+		}
+
+		fn pending_settlements(domain_id: DomainID) -> Vec<(DepositNonce, BlockNumber)> {
+			SygmaBridge::pending_settlements(domain_id)
+		}
+
+		fn mpc_config() -> (Vec<MpcAddress>, u32) {
+			SygmaBridge::mpc_config()
+		}
+
+		fn validate_deposit(
+			sender: [u8; 32],
+			asset: MultiAsset,
+			dest: MultiLocation,
+		) -> Result<(), DepositValidationError> {
+			SygmaBridge::validate_deposit(&sender.into(), &asset, &dest)
+		}
+
+		fn pause_history(domain_id: DomainID) -> Vec<(bool, BlockNumber)> {
+			SygmaBridge::pause_history(domain_id).into_inner()
+		}
+
+		fn route_info(
+			resource_id: ResourceId,
+			domain_id: DomainID,
+		) -> sygma_runtime_api::RouteInfo<BlockNumber> {
+			SygmaBridge::route_info(resource_id, domain_id)
+		}
+
+		fn deposits_in_range(
+			dest_domain_id: DomainID,
+			from_nonce: DepositNonce,
+			to_nonce: DepositNonce,
+		) -> sygma_runtime_api::DepositRange<BlockNumber> {
+			SygmaBridge::deposits_in_range(dest_domain_id, from_nonce, to_nonce)
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
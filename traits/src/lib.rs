@@ -7,15 +7,66 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchResult;
 use primitive_types::{H160, U256};
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 use xcm::latest::{prelude::*, MultiLocation};
 
-pub type DomainID = u8;
+// Tags of the entries of the deposit data extra section, registered in `sygma_codec::extra`
+pub use sygma_codec::extra::{
+	EXTRA_TAGS_DOWNSTREAM, EXTRA_TAG_ASSET_METADATA, EXTRA_TAG_MEMO, EXTRA_TAG_ROUTE,
+	EXTRA_TAG_SOURCE_AMOUNT,
+};
+
 pub type DepositNonce = u64;
 pub type ResourceId = [u8; 32];
 pub type ChainID = U256;
 pub type VerifyingContractAddress = H160;
 
+/// Id of a domain connected by Sygma. It encodes as a bare `u8`, so it can replace the former
+/// `u8` alias in storage and in deposit data without a migration.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Hash,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+#[serde(transparent)]
+pub struct DomainID(u8);
+
+impl DomainID {
+	pub const fn new(id: u8) -> Self {
+		Self(id)
+	}
+
+	/// Raw id, as carried by deposit data and the transfer id
+	pub const fn into_inner(self) -> u8 {
+		self.0
+	}
+}
+
+impl From<u8> for DomainID {
+	fn from(id: u8) -> Self {
+		Self(id)
+	}
+}
+
+impl From<DomainID> for u8 {
+	fn from(id: DomainID) -> Self {
+		id.0
+	}
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
 pub enum TransferType {
 	FungibleTransfer,
@@ -23,6 +74,134 @@ pub enum TransferType {
 	GenericTransfer,
 }
 
+/// How a deposited asset's `MultiLocation` is matched against the registered resource pairs
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum AssetMatchingMode {
+	/// Locations must be exactly equal. This is the safest option, but the same asset referred
+	/// from a different relative position (e.g. by the relay chain) is rejected.
+	Strict,
+	/// Ignore the `parents` field and compare the interior junctions only. Assets that share an
+	/// interior under different parents become indistinguishable, e.g. `(1, Here)` would match a
+	/// native asset registered as `(0, Here)`.
+	IgnoreParents,
+	/// Ignore the `NetworkId` carried by account junctions. Accounts with the same key on
+	/// different networks become indistinguishable.
+	IgnoreNetworkId,
+	/// Ignore both the `parents` field and the account junctions' `NetworkId`
+	Lenient,
+}
+
+/// Why a proposal failed to execute, emitted along with `FailedHandlerExecution` so that relayers
+/// don't have to match on the error string. The SCALE encoding is a contract with relayers: each
+/// variant keeps its index, new variants are appended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ExecutionFailureReason {
+	/// Resource id is not bound to an asset or the asset has no reserve account
+	#[codec(index = 0)]
+	ResourceNotRegistered,
+	/// Reserve account can not cover the transfer, or would drop below its minimum balance
+	#[codec(index = 1)]
+	InsufficientReserve,
+	/// Deposit data or amount can not be decoded
+	#[codec(index = 2)]
+	DecodeError,
+	/// Recipient can not be credited
+	#[codec(index = 3)]
+	RecipientInvalid,
+	/// Amount is below the existential deposit and can not be escrowed either
+	#[codec(index = 4)]
+	EdBelowMinimum,
+	/// Proposal is rejected by the bridge state, e.g. domain paused or not supported
+	#[codec(index = 5)]
+	Filtered,
+	/// Any other failure, carrying the index of the pallet error if there is one
+	#[codec(index = 6)]
+	Other(u8),
+	/// Amount exceeds the inbound single cap of the resource or the inbound cap of the
+	/// recipient, or the inbound window cap of the resource is exhausted
+	#[codec(index = 7)]
+	InboundLimitExceeded,
+	/// Amount to credit disagrees with the source amount hint of the deposit data, the
+	/// decimals configured for the resource drifted from the origin chain
+	#[codec(index = 8)]
+	DecimalMismatch,
+	/// Recipient is the fee reserve, a transfer reserve or an account of the bridge
+	#[codec(index = 9)]
+	ProtectedAccountRecipient,
+}
+
+/// Why a deposit failed, emitted along with `DepositFailed` for indexers that only watch events.
+/// Like `ExecutionFailureReason`, each variant keeps its index and new variants are appended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum DepositFailureReason {
+	/// Recipient or dest domain can not be extracted from the dest, or the route is invalid
+	#[codec(index = 0)]
+	InvalidDestination,
+	/// Dest domain is not registered, paused or unhealthy, or the MPC address is not set
+	#[codec(index = 1)]
+	DomainUnavailable,
+	/// Asset is not bound to a resource, or its decimals can not be converted
+	#[codec(index = 2)]
+	AssetNotSupported,
+	/// Fee is not configured for the asset and domain, exceeds the amount or can not be paid
+	/// from the prefund
+	#[codec(index = 3)]
+	FeeNotPayable,
+	/// Asset can not be withdrawn from the sender, e.g. insufficient balance
+	#[codec(index = 4)]
+	WithdrawFailed,
+	/// Deposit exceeds a bridge limit, the global TVL cap, the rate limit of the resource or the
+	/// deposit data size
+	#[codec(index = 5)]
+	LimitExceeded,
+	/// Any other failure, carrying the index of the pallet error if there is one
+	#[codec(index = 6)]
+	Other(u8),
+}
+
+/// Why a deposit would be rejected, with the parameters for a UI to explain it. Returned by the
+/// dry-run of a deposit before it's submitted. Variants are only appended.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+pub enum DepositValidationError {
+	/// MPC address is not set, no deposit is accepted
+	#[codec(index = 0)]
+	MpcAddressMissing,
+	/// Recipient or dest domain can not be extracted from the dest
+	#[codec(index = 1)]
+	InvalidDestination,
+	/// Dest domain is not registered, sunset or has no data format
+	#[codec(index = 2)]
+	DomainNotSupported { domain_id: DomainID },
+	/// Deposits to the dest domain are paused, or the domain is unhealthy
+	#[codec(index = 3)]
+	DomainPaused { domain_id: DomainID },
+	/// Asset is not bound to a resource, `asset_id` is the SCALE encoded XCM asset id
+	#[codec(index = 4)]
+	AssetNotBound { asset_id: Vec<u8> },
+	/// No fee is configured for the resource and the dest domain
+	#[codec(index = 5)]
+	MissingFeeConfig { resource_id: ResourceId, domain_id: DomainID },
+	/// Amount does not cover the fee, `min_amount` is the smallest amount that does
+	#[codec(index = 6)]
+	FeeTooExpensive { fee: u128, min_amount: u128 },
+	/// Asset can not be withdrawn from the sender, e.g. insufficient balance
+	#[codec(index = 7)]
+	WithdrawFailed,
+	/// Deposit would push the value locked by the bridge over the cap, in its normalized unit
+	#[codec(index = 8)]
+	TvlCapExceeded { cap: u128, locked: u128 },
+	/// Amount exceeds what the rate limit of the resource lets through now, in the 18
+	/// decimals of deposit data
+	#[codec(index = 9)]
+	RateLimitExceeded { resource_id: ResourceId, available: u128 },
+	/// Deposit data would exceed its max size in bytes
+	#[codec(index = 10)]
+	DepositDataTooLarge { max_size: u32 },
+	/// Any other failure, carrying the index of the pallet error if there is one
+	#[codec(index = 11)]
+	Other(u8),
+}
+
 #[derive(
 	Clone,
 	Eq,
@@ -63,6 +242,96 @@ pub trait DecimalConverter {
 	fn convert_from(asset: &MultiAsset) -> Option<MultiAsset>;
 }
 
+/// Read the balance of an asset held by a location, the bridge compares it before and after a
+/// deposit to learn the amount actually credited by the asset transactor
+pub trait AssetBalanceInspector {
+	/// Return `None` if the balance can not be read, e.g. the location is not a local account
+	fn balance_of(asset_id: &AssetId, who: &MultiLocation) -> Option<u128>;
+}
+
+impl AssetBalanceInspector for () {
+	fn balance_of(_asset_id: &AssetId, _who: &MultiLocation) -> Option<u128> {
+		None
+	}
+}
+
+/// Metadata of a local asset, for the dest domains to register it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetMetadata {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+/// Source of the metadata of local assets, e.g. `pallet-assets`
+pub trait AssetMetadataProvider {
+	/// Return `None` if the asset has no metadata
+	fn metadata(asset_id: &AssetId) -> Option<AssetMetadata>;
+}
+
+impl AssetMetadataProvider for () {
+	fn metadata(_asset_id: &AssetId) -> Option<AssetMetadata> {
+		None
+	}
+}
+
+/// Scale amounts of the resources to a common unit, e.g. by their price, for the aggregate
+/// counters of the bridge. The transferred amounts are not normalized.
+pub trait AmountNormalizer {
+	/// `amount` of the resource, in the 18 decimals of deposit data, in the common unit
+	fn normalize(resource_id: &ResourceId, amount: u128) -> u128;
+}
+
+impl AmountNormalizer for () {
+	fn normalize(_resource_id: &ResourceId, amount: u128) -> u128 {
+		amount
+	}
+}
+
+/// Split the fee collected by a deposit between its recipients, e.g. treasury, burn, partners
+/// or stakers. The fee is already withdrawn from `payer` when it's called.
+pub trait DistributeFee<AccountId> {
+	fn distribute_fee(payer: &AccountId, fee: MultiAsset) -> DispatchResult;
+}
+
+/// Source of truth of the domains a bridge may talk to, e.g. a registry pallet shared by
+/// several bridge instances
+pub trait IsRegisteredDomain {
+	fn is_registered(domain: DomainID) -> bool;
+}
+
+/// Fee of deposits of an asset to a domain, set up when the resource is onboarded
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum FeeConfig {
+	/// Fixed fee, in the local decimals of the asset
+	Basic(u128),
+	/// Share of the amount in basis points, bounded by `lower_bound` and `upper_bound`
+	Percentage { rate_basis_point: u32, lower_bound: u128, upper_bound: u128 },
+}
+
+/// Steps of onboarding a resource carried out outside the bridge, e.g. by the fee handlers or
+/// the assets pallet. A failing step reverts the whole onboarding.
+pub trait ResourceOnboarder {
+	/// Set the fee of deposits of `asset` to `domain`
+	fn set_fee(domain: DomainID, asset: &AssetId, fee: FeeConfig) -> DispatchResult;
+	/// Create the asset of a resource minted by the bridge
+	fn create_asset(asset: &AssetId, decimals: u8) -> DispatchResult;
+}
+
+/// Chain-wide emergency stop of the bridge, e.g. driven by `pallet-safe-mode` entering and
+/// exiting the safe mode
+pub trait BridgeEmergency {
+	/// Pause all domains, remembering their pause states
+	fn pause_everything();
+	/// Restore the pause states remembered by `pause_everything`
+	fn restore_from_snapshot();
+}
+
+impl BridgeEmergency for () {
+	fn pause_everything() {}
+	fn restore_from_snapshot() {}
+}
+
 // when integrating with parachain, parachain team can implement their own version
 pub trait AssetTypeIdentifier {
 	fn is_native_asset(asset: &MultiAsset) -> bool;